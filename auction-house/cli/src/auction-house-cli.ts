@@ -809,6 +809,7 @@ programCommand('execute_sale')
       programAsSignerBump,
       buyPriceAdjusted,
       tokenSizeAdjusted,
+      null,
       {
         accounts: {
           buyer: buyerWalletKey,
@@ -1525,7 +1526,7 @@ programCommand('update_auction_house')
       //@ts-ignore
       rso = auctionHouseObj.requiresSignOff;
     }
-    await anchorProgram.rpc.updateAuctionHouse(sfbp, rso, ccsp, {
+    await anchorProgram.rpc.updateAuctionHouse(sfbp, rso, ccsp, null, null, {
       accounts: {
         treasuryMint: tMintKey,
         payer: walletKeyPair.publicKey,
@@ -6,8 +6,8 @@
  */
 
 import * as web3 from '@solana/web3.js';
-import * as beetSolana from '@metaplex-foundation/beet-solana';
 import * as beet from '@metaplex-foundation/beet';
+import * as beetSolana from '@metaplex-foundation/beet-solana';
 
 /**
  * Arguments used to create {@link AuctionHouse}
@@ -32,6 +32,11 @@ export type AuctionHouseArgs = {
   hasAuctioneer: boolean;
   auctioneerAddress: web3.PublicKey;
   scopes: boolean[] /* size: 7 */;
+  maxReferralBasisPoints: number;
+  hasGovernance: boolean;
+  receiptRetentionPeriod: beet.bignum;
+  auctioneerCount: number;
+  scopeDelegations: number[] /* size: 7 */;
 };
 
 export const auctionHouseDiscriminator = [40, 108, 215, 107, 213, 85, 245, 48];
@@ -61,6 +66,11 @@ export class AuctionHouse implements AuctionHouseArgs {
     readonly hasAuctioneer: boolean,
     readonly auctioneerAddress: web3.PublicKey,
     readonly scopes: boolean[] /* size: 7 */,
+    readonly maxReferralBasisPoints: number,
+    readonly hasGovernance: boolean,
+    readonly receiptRetentionPeriod: beet.bignum,
+    readonly auctioneerCount: number,
+    readonly scopeDelegations: number[] /* size: 7 */,
   ) {}
 
   /**
@@ -85,6 +95,11 @@ export class AuctionHouse implements AuctionHouseArgs {
       args.hasAuctioneer,
       args.auctioneerAddress,
       args.scopes,
+      args.maxReferralBasisPoints,
+      args.hasGovernance,
+      args.receiptRetentionPeriod,
+      args.auctioneerCount,
+      args.scopeDelegations,
     );
   }
 
@@ -199,6 +214,21 @@ export class AuctionHouse implements AuctionHouseArgs {
       hasAuctioneer: this.hasAuctioneer,
       auctioneerAddress: this.auctioneerAddress.toBase58(),
       scopes: this.scopes,
+      maxReferralBasisPoints: this.maxReferralBasisPoints,
+      hasGovernance: this.hasGovernance,
+      receiptRetentionPeriod: (() => {
+        const x = <{ toNumber: () => number }>this.receiptRetentionPeriod;
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber();
+          } catch (_) {
+            return x;
+          }
+        }
+        return x;
+      })(),
+      auctioneerCount: this.auctioneerCount,
+      scopeDelegations: this.scopeDelegations,
     };
   }
 }
@@ -232,6 +262,11 @@ export const auctionHouseBeet = new beet.BeetStruct<
     ['hasAuctioneer', beet.bool],
     ['auctioneerAddress', beetSolana.publicKey],
     ['scopes', beet.uniformFixedSizeArray(beet.bool, 7)],
+    ['maxReferralBasisPoints', beet.u16],
+    ['hasGovernance', beet.bool],
+    ['receiptRetentionPeriod', beet.i64],
+    ['auctioneerCount', beet.u8],
+    ['scopeDelegations', beet.uniformFixedSizeArray(beet.u8, 7)],
   ],
   AuctionHouse.fromArgs,
   'AuctionHouse',
@@ -6,8 +6,9 @@
  */
 
 import * as web3 from '@solana/web3.js';
-import * as beetSolana from '@metaplex-foundation/beet-solana';
 import * as beet from '@metaplex-foundation/beet';
+import * as beetSolana from '@metaplex-foundation/beet-solana';
+import { AuctioneerLimits, auctioneerLimitsBeet } from '../types/AuctioneerLimits';
 
 /**
  * Arguments used to create {@link Auctioneer}
@@ -18,6 +19,10 @@ export type AuctioneerArgs = {
   auctioneerAuthority: web3.PublicKey;
   auctionHouse: web3.PublicKey;
   bump: number;
+  limits: AuctioneerLimits;
+  salesCount: beet.bignum;
+  scopes: boolean[] /* size: 7 */;
+  limitedScopes: boolean[] /* size: 7 */;
 };
 
 export const auctioneerDiscriminator = [46, 101, 92, 150, 138, 30, 245, 120];
@@ -33,13 +38,25 @@ export class Auctioneer implements AuctioneerArgs {
     readonly auctioneerAuthority: web3.PublicKey,
     readonly auctionHouse: web3.PublicKey,
     readonly bump: number,
+    readonly limits: AuctioneerLimits,
+    readonly salesCount: beet.bignum,
+    readonly scopes: boolean[] /* size: 7 */,
+    readonly limitedScopes: boolean[] /* size: 7 */,
   ) {}
 
   /**
    * Creates a {@link Auctioneer} instance from the provided args.
    */
   static fromArgs(args: AuctioneerArgs) {
-    return new Auctioneer(args.auctioneerAuthority, args.auctionHouse, args.bump);
+    return new Auctioneer(
+      args.auctioneerAuthority,
+      args.auctionHouse,
+      args.bump,
+      args.limits,
+      args.salesCount,
+      args.scopes,
+      args.limitedScopes,
+    );
   }
 
   /**
@@ -100,31 +117,33 @@ export class Auctioneer implements AuctioneerArgs {
 
   /**
    * Returns the byteSize of a {@link Buffer} holding the serialized data of
-   * {@link Auctioneer}
+   * {@link Auctioneer} for the provided args.
+   *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
    */
-  static get byteSize() {
-    return auctioneerBeet.byteSize;
+  static byteSize(args: AuctioneerArgs) {
+    const instance = Auctioneer.fromArgs(args);
+    return auctioneerBeet.toFixedFromValue({
+      accountDiscriminator: auctioneerDiscriminator,
+      ...instance,
+    }).byteSize;
   }
 
   /**
    * Fetches the minimum balance needed to exempt an account holding
    * {@link Auctioneer} data from rent
    *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
    * @param connection used to retrieve the rent exemption information
    */
   static async getMinimumBalanceForRentExemption(
+    args: AuctioneerArgs,
     connection: web3.Connection,
     commitment?: web3.Commitment,
   ): Promise<number> {
-    return connection.getMinimumBalanceForRentExemption(Auctioneer.byteSize, commitment);
-  }
-
-  /**
-   * Determines if the provided {@link Buffer} has the correct byte size to
-   * hold {@link Auctioneer} data.
-   */
-  static hasCorrectByteSize(buf: Buffer, offset = 0) {
-    return buf.byteLength - offset === Auctioneer.byteSize;
+    return connection.getMinimumBalanceForRentExemption(Auctioneer.byteSize(args), commitment);
   }
 
   /**
@@ -136,6 +155,20 @@ export class Auctioneer implements AuctioneerArgs {
       auctioneerAuthority: this.auctioneerAuthority.toBase58(),
       auctionHouse: this.auctionHouse.toBase58(),
       bump: this.bump,
+      limits: this.limits,
+      salesCount: (() => {
+        const x = <{ toNumber: () => number }>this.salesCount;
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber();
+          } catch (_) {
+            return x;
+          }
+        }
+        return x;
+      })(),
+      scopes: this.scopes,
+      limitedScopes: this.limitedScopes,
     };
   }
 }
@@ -144,7 +177,7 @@ export class Auctioneer implements AuctioneerArgs {
  * @category Accounts
  * @category generated
  */
-export const auctioneerBeet = new beet.BeetStruct<
+export const auctioneerBeet = new beet.FixableBeetStruct<
   Auctioneer,
   AuctioneerArgs & {
     accountDiscriminator: number[] /* size: 8 */;
@@ -155,6 +188,10 @@ export const auctioneerBeet = new beet.BeetStruct<
     ['auctioneerAuthority', beetSolana.publicKey],
     ['auctionHouse', beetSolana.publicKey],
     ['bump', beet.u8],
+    ['limits', auctioneerLimitsBeet],
+    ['salesCount', beet.u64],
+    ['scopes', beet.uniformFixedSizeArray(beet.bool, 7)],
+    ['limitedScopes', beet.uniformFixedSizeArray(beet.bool, 7)],
   ],
   Auctioneer.fromArgs,
   'Auctioneer',
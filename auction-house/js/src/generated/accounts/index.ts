@@ -1,19 +1,34 @@
 export * from './AuctionHouse';
+export * from './AuctionHouseGovernance';
 export * from './Auctioneer';
 export * from './BidReceipt';
+export * from './CollectionStats';
+export * from './CounterOffer';
+export * from './FeeDiscountSchedule';
 export * from './ListingReceipt';
+export * from './PendingAuctionHouseChange';
 export * from './PurchaseReceipt';
 
+import { CounterOffer } from './CounterOffer';
+import { FeeDiscountSchedule } from './FeeDiscountSchedule';
+import { AuctionHouseGovernance } from './AuctionHouseGovernance';
+import { PendingAuctionHouseChange } from './PendingAuctionHouseChange';
 import { BidReceipt } from './BidReceipt';
 import { ListingReceipt } from './ListingReceipt';
 import { PurchaseReceipt } from './PurchaseReceipt';
 import { AuctionHouse } from './AuctionHouse';
 import { Auctioneer } from './Auctioneer';
+import { CollectionStats } from './CollectionStats';
 
 export const accountProviders = {
+  CounterOffer,
+  FeeDiscountSchedule,
+  AuctionHouseGovernance,
+  PendingAuctionHouseChange,
   BidReceipt,
   ListingReceipt,
   PurchaseReceipt,
   AuctionHouse,
   Auctioneer,
+  CollectionStats,
 };
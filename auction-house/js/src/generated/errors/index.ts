@@ -962,6 +962,711 @@ createErrorFromNameLookup.set(
   () => new SaleRequiresExactlyOneSignerError(),
 );
 
+/**
+ * InvalidReferralBasisPoints: 'Referral basis points exceed the maximum allowed by the Auction House.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidReferralBasisPointsError extends Error {
+  readonly code: number = 0x179d;
+  readonly name: string = 'InvalidReferralBasisPoints';
+  constructor() {
+    super('Referral basis points exceed the maximum allowed by the Auction House.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidReferralBasisPointsError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179d, () => new InvalidReferralBasisPointsError());
+createErrorFromNameLookup.set(
+  'InvalidReferralBasisPoints',
+  () => new InvalidReferralBasisPointsError(),
+);
+
+/**
+ * GovernanceEnabled: 'Auction House is governed, changes must go through a governance proposal.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class GovernanceEnabledError extends Error {
+  readonly code: number = 0x179e;
+  readonly name: string = 'GovernanceEnabled';
+  constructor() {
+    super('Auction House is governed, changes must go through a governance proposal.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, GovernanceEnabledError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179e, () => new GovernanceEnabledError());
+createErrorFromNameLookup.set('GovernanceEnabled', () => new GovernanceEnabledError());
+
+/**
+ * TooManyGovernanceSigners: 'Too many governance signers.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TooManyGovernanceSignersError extends Error {
+  readonly code: number = 0x179f;
+  readonly name: string = 'TooManyGovernanceSigners';
+  constructor() {
+    super('Too many governance signers.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TooManyGovernanceSignersError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179f, () => new TooManyGovernanceSignersError());
+createErrorFromNameLookup.set(
+  'TooManyGovernanceSigners',
+  () => new TooManyGovernanceSignersError(),
+);
+
+/**
+ * InvalidGovernanceThreshold: 'Governance threshold must be between one and the number of signers.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidGovernanceThresholdError extends Error {
+  readonly code: number = 0x17a0;
+  readonly name: string = 'InvalidGovernanceThreshold';
+  constructor() {
+    super('Governance threshold must be between one and the number of signers.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidGovernanceThresholdError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a0, () => new InvalidGovernanceThresholdError());
+createErrorFromNameLookup.set(
+  'InvalidGovernanceThreshold',
+  () => new InvalidGovernanceThresholdError(),
+);
+
+/**
+ * InvalidGovernanceDelay: 'Governance delay cannot be negative.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidGovernanceDelayError extends Error {
+  readonly code: number = 0x17a1;
+  readonly name: string = 'InvalidGovernanceDelay';
+  constructor() {
+    super('Governance delay cannot be negative.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidGovernanceDelayError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a1, () => new InvalidGovernanceDelayError());
+createErrorFromNameLookup.set('InvalidGovernanceDelay', () => new InvalidGovernanceDelayError());
+
+/**
+ * NotAGovernanceSigner: 'Signer is not part of the Auction House governance.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class NotAGovernanceSignerError extends Error {
+  readonly code: number = 0x17a2;
+  readonly name: string = 'NotAGovernanceSigner';
+  constructor() {
+    super('Signer is not part of the Auction House governance.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, NotAGovernanceSignerError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a2, () => new NotAGovernanceSignerError());
+createErrorFromNameLookup.set('NotAGovernanceSigner', () => new NotAGovernanceSignerError());
+
+/**
+ * ChangeAlreadyApproved: 'Change already approved by this signer.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ChangeAlreadyApprovedError extends Error {
+  readonly code: number = 0x17a3;
+  readonly name: string = 'ChangeAlreadyApproved';
+  constructor() {
+    super('Change already approved by this signer.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ChangeAlreadyApprovedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a3, () => new ChangeAlreadyApprovedError());
+createErrorFromNameLookup.set('ChangeAlreadyApproved', () => new ChangeAlreadyApprovedError());
+
+/**
+ * ChangeNotApproved: 'Change has not been approved by enough governance signers.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ChangeNotApprovedError extends Error {
+  readonly code: number = 0x17a4;
+  readonly name: string = 'ChangeNotApproved';
+  constructor() {
+    super('Change has not been approved by enough governance signers.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ChangeNotApprovedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a4, () => new ChangeNotApprovedError());
+createErrorFromNameLookup.set('ChangeNotApproved', () => new ChangeNotApprovedError());
+
+/**
+ * ChangeTimelocked: 'Change is still timelocked.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ChangeTimelockedError extends Error {
+  readonly code: number = 0x17a5;
+  readonly name: string = 'ChangeTimelocked';
+  constructor() {
+    super('Change is still timelocked.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ChangeTimelockedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a5, () => new ChangeTimelockedError());
+createErrorFromNameLookup.set('ChangeTimelocked', () => new ChangeTimelockedError());
+
+/**
+ * ListingUnavailable: 'Listing is no longer available for purchase.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ListingUnavailableError extends Error {
+  readonly code: number = 0x17a6;
+  readonly name: string = 'ListingUnavailable';
+  constructor() {
+    super('Listing is no longer available for purchase.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ListingUnavailableError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a6, () => new ListingUnavailableError());
+createErrorFromNameLookup.set('ListingUnavailable', () => new ListingUnavailableError());
+
+/**
+ * DelegatedBidRequiresSplTreasuryMint: 'Delegated bids are only available for SPL treasury mints.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class DelegatedBidRequiresSplTreasuryMintError extends Error {
+  readonly code: number = 0x17a7;
+  readonly name: string = 'DelegatedBidRequiresSplTreasuryMint';
+  constructor() {
+    super('Delegated bids are only available for SPL treasury mints.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, DelegatedBidRequiresSplTreasuryMintError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a7, () => new DelegatedBidRequiresSplTreasuryMintError());
+createErrorFromNameLookup.set(
+  'DelegatedBidRequiresSplTreasuryMint',
+  () => new DelegatedBidRequiresSplTreasuryMintError(),
+);
+
+/**
+ * InsufficientDelegatedFunds: 'Payment account allowance for the Auction House does not cover the bid price.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InsufficientDelegatedFundsError extends Error {
+  readonly code: number = 0x17a8;
+  readonly name: string = 'InsufficientDelegatedFunds';
+  constructor() {
+    super('Payment account allowance for the Auction House does not cover the bid price.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InsufficientDelegatedFundsError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a8, () => new InsufficientDelegatedFundsError());
+createErrorFromNameLookup.set(
+  'InsufficientDelegatedFunds',
+  () => new InsufficientDelegatedFundsError(),
+);
+
+/**
+ * CollectionNotVerified: 'Metadata does not belong to a verified collection.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class CollectionNotVerifiedError extends Error {
+  readonly code: number = 0x17a9;
+  readonly name: string = 'CollectionNotVerified';
+  constructor() {
+    super('Metadata does not belong to a verified collection.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, CollectionNotVerifiedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a9, () => new CollectionNotVerifiedError());
+createErrorFromNameLookup.set('CollectionNotVerified', () => new CollectionNotVerifiedError());
+
+/**
+ * TooManyFeeDiscounts: 'Too many fee discounts.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TooManyFeeDiscountsError extends Error {
+  readonly code: number = 0x17aa;
+  readonly name: string = 'TooManyFeeDiscounts';
+  constructor() {
+    super('Too many fee discounts.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TooManyFeeDiscountsError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17aa, () => new TooManyFeeDiscountsError());
+createErrorFromNameLookup.set('TooManyFeeDiscounts', () => new TooManyFeeDiscountsError());
+
+/**
+ * InvalidFeeDiscountProof: 'Fee discount proof is not a discounted NFT held by the buyer or seller.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidFeeDiscountProofError extends Error {
+  readonly code: number = 0x17ab;
+  readonly name: string = 'InvalidFeeDiscountProof';
+  constructor() {
+    super('Fee discount proof is not a discounted NFT held by the buyer or seller.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidFeeDiscountProofError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ab, () => new InvalidFeeDiscountProofError());
+createErrorFromNameLookup.set('InvalidFeeDiscountProof', () => new InvalidFeeDiscountProofError());
+
+/**
+ * CounterOfferClosed: 'Counter offer was already accepted or withdrawn.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class CounterOfferClosedError extends Error {
+  readonly code: number = 0x17ac;
+  readonly name: string = 'CounterOfferClosed';
+  constructor() {
+    super('Counter offer was already accepted or withdrawn.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, CounterOfferClosedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ac, () => new CounterOfferClosedError());
+createErrorFromNameLookup.set('CounterOfferClosed', () => new CounterOfferClosedError());
+
+/**
+ * BidReceiptClosed: 'Bid was canceled or already filled.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class BidReceiptClosedError extends Error {
+  readonly code: number = 0x17ad;
+  readonly name: string = 'BidReceiptClosed';
+  constructor() {
+    super('Bid was canceled or already filled.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, BidReceiptClosedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ad, () => new BidReceiptClosedError());
+createErrorFromNameLookup.set('BidReceiptClosed', () => new BidReceiptClosedError());
+
+/**
+ * InvalidCounterOfferPrice: 'Counter offer price must be non-zero and differ from the bid price.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidCounterOfferPriceError extends Error {
+  readonly code: number = 0x17ae;
+  readonly name: string = 'InvalidCounterOfferPrice';
+  constructor() {
+    super('Counter offer price must be non-zero and differ from the bid price.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidCounterOfferPriceError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ae, () => new InvalidCounterOfferPriceError());
+createErrorFromNameLookup.set(
+  'InvalidCounterOfferPrice',
+  () => new InvalidCounterOfferPriceError(),
+);
+
+/**
+ * ReceiptIsActive: 'Receipt must be canceled or fulfilled before it can be closed.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ReceiptIsActiveError extends Error {
+  readonly code: number = 0x17af;
+  readonly name: string = 'ReceiptIsActive';
+  constructor() {
+    super('Receipt must be canceled or fulfilled before it can be closed.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ReceiptIsActiveError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17af, () => new ReceiptIsActiveError());
+createErrorFromNameLookup.set('ReceiptIsActive', () => new ReceiptIsActiveError());
+
+/**
+ * ReceiptRetentionPeriodActive: 'Receipt is still within the Auction House retention period.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ReceiptRetentionPeriodActiveError extends Error {
+  readonly code: number = 0x17b0;
+  readonly name: string = 'ReceiptRetentionPeriodActive';
+  constructor() {
+    super('Receipt is still within the Auction House retention period.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ReceiptRetentionPeriodActiveError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b0, () => new ReceiptRetentionPeriodActiveError());
+createErrorFromNameLookup.set(
+  'ReceiptRetentionPeriodActive',
+  () => new ReceiptRetentionPeriodActiveError(),
+);
+
+/**
+ * InvalidReceiptRetentionPeriod: 'Receipt retention period cannot be negative.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidReceiptRetentionPeriodError extends Error {
+  readonly code: number = 0x17b1;
+  readonly name: string = 'InvalidReceiptRetentionPeriod';
+  constructor() {
+    super('Receipt retention period cannot be negative.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidReceiptRetentionPeriodError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b1, () => new InvalidReceiptRetentionPeriodError());
+createErrorFromNameLookup.set(
+  'InvalidReceiptRetentionPeriod',
+  () => new InvalidReceiptRetentionPeriodError(),
+);
+
+/**
+ * AuctioneerDelegationExpired: 'Auctioneer delegation has expired.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AuctioneerDelegationExpiredError extends Error {
+  readonly code: number = 0x17b2;
+  readonly name: string = 'AuctioneerDelegationExpired';
+  constructor() {
+    super('Auctioneer delegation has expired.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AuctioneerDelegationExpiredError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b2, () => new AuctioneerDelegationExpiredError());
+createErrorFromNameLookup.set(
+  'AuctioneerDelegationExpired',
+  () => new AuctioneerDelegationExpiredError(),
+);
+
+/**
+ * AuctioneerPriceLimitExceeded: 'Price is above the Auctioneer delegation limit.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AuctioneerPriceLimitExceededError extends Error {
+  readonly code: number = 0x17b3;
+  readonly name: string = 'AuctioneerPriceLimitExceeded';
+  constructor() {
+    super('Price is above the Auctioneer delegation limit.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AuctioneerPriceLimitExceededError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b3, () => new AuctioneerPriceLimitExceededError());
+createErrorFromNameLookup.set(
+  'AuctioneerPriceLimitExceeded',
+  () => new AuctioneerPriceLimitExceededError(),
+);
+
+/**
+ * AuctioneerSaleLimitReached: 'Auctioneer delegation has reached its maximum number of sales.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AuctioneerSaleLimitReachedError extends Error {
+  readonly code: number = 0x17b4;
+  readonly name: string = 'AuctioneerSaleLimitReached';
+  constructor() {
+    super('Auctioneer delegation has reached its maximum number of sales.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AuctioneerSaleLimitReachedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b4, () => new AuctioneerSaleLimitReachedError());
+createErrorFromNameLookup.set(
+  'AuctioneerSaleLimitReached',
+  () => new AuctioneerSaleLimitReachedError(),
+);
+
+/**
+ * ListingNotStarted: 'Listing cannot be bought before its start time.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ListingNotStartedError extends Error {
+  readonly code: number = 0x17b5;
+  readonly name: string = 'ListingNotStarted';
+  constructor() {
+    super('Listing cannot be bought before its start time.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ListingNotStartedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b5, () => new ListingNotStartedError());
+createErrorFromNameLookup.set('ListingNotStarted', () => new ListingNotStartedError());
+
+/**
+ * PartialBidOverfilled: 'Sale exceeds the tokens left on the bid.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PartialBidOverfilledError extends Error {
+  readonly code: number = 0x17b6;
+  readonly name: string = 'PartialBidOverfilled';
+  constructor() {
+    super('Sale exceeds the tokens left on the bid.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PartialBidOverfilledError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b6, () => new PartialBidOverfilledError());
+createErrorFromNameLookup.set('PartialBidOverfilled', () => new PartialBidOverfilledError());
+
+/**
+ * PartialBidNotSupported: 'Partially fillable bids can't be settled by an auctioneer.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PartialBidNotSupportedError extends Error {
+  readonly code: number = 0x17b7;
+  readonly name: string = 'PartialBidNotSupported';
+  constructor() {
+    super("Partially fillable bids can't be settled by an auctioneer.");
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PartialBidNotSupportedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b7, () => new PartialBidNotSupportedError());
+createErrorFromNameLookup.set('PartialBidNotSupported', () => new PartialBidNotSupportedError());
+
+/**
+ * GovernedInstructionChange: 'Change authorizes an instruction and can only be executed by it.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class GovernedInstructionChangeError extends Error {
+  readonly code: number = 0x17b8;
+  readonly name: string = 'GovernedInstructionChange';
+  constructor() {
+    super('Change authorizes an instruction and can only be executed by it.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, GovernedInstructionChangeError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b8, () => new GovernedInstructionChangeError());
+createErrorFromNameLookup.set(
+  'GovernedInstructionChange',
+  () => new GovernedInstructionChangeError(),
+);
+
+/**
+ * InvalidGovernedInstructionChange: 'A change authorizing an instruction can't contain any other change.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidGovernedInstructionChangeError extends Error {
+  readonly code: number = 0x17b9;
+  readonly name: string = 'InvalidGovernedInstructionChange';
+  constructor() {
+    super("A change authorizing an instruction can't contain any other change.");
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidGovernedInstructionChangeError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b9, () => new InvalidGovernedInstructionChangeError());
+createErrorFromNameLookup.set(
+  'InvalidGovernedInstructionChange',
+  () => new InvalidGovernedInstructionChangeError(),
+);
+
+/**
+ * GovernedChangeMismatch: 'Pending change does not authorize this instruction.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class GovernedChangeMismatchError extends Error {
+  readonly code: number = 0x17ba;
+  readonly name: string = 'GovernedChangeMismatch';
+  constructor() {
+    super('Pending change does not authorize this instruction.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, GovernedChangeMismatchError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ba, () => new GovernedChangeMismatchError());
+createErrorFromNameLookup.set('GovernedChangeMismatch', () => new GovernedChangeMismatchError());
+
+/**
+ * ProgrammableNftNotSupported: 'Programmable NFTs are not supported by this instruction.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ProgrammableNftNotSupportedError extends Error {
+  readonly code: number = 0x17bb;
+  readonly name: string = 'ProgrammableNftNotSupported';
+  constructor() {
+    super('Programmable NFTs are not supported by this instruction.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ProgrammableNftNotSupportedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17bb, () => new ProgrammableNftNotSupportedError());
+createErrorFromNameLookup.set(
+  'ProgrammableNftNotSupported',
+  () => new ProgrammableNftNotSupportedError(),
+);
+
+/**
+ * PaymentAccountDelegated: 'Payment account is already delegated to another account.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PaymentAccountDelegatedError extends Error {
+  readonly code: number = 0x17bc;
+  readonly name: string = 'PaymentAccountDelegated';
+  constructor() {
+    super('Payment account is already delegated to another account.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PaymentAccountDelegatedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17bc, () => new PaymentAccountDelegatedError());
+createErrorFromNameLookup.set('PaymentAccountDelegated', () => new PaymentAccountDelegatedError());
+
+/**
+ * PartialBidReservationExceeded: 'Sale exceeds the escrow amount reserved for the bid.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PartialBidReservationExceededError extends Error {
+  readonly code: number = 0x17bd;
+  readonly name: string = 'PartialBidReservationExceeded';
+  constructor() {
+    super('Sale exceeds the escrow amount reserved for the bid.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PartialBidReservationExceededError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17bd, () => new PartialBidReservationExceededError());
+createErrorFromNameLookup.set(
+  'PartialBidReservationExceeded',
+  () => new PartialBidReservationExceededError(),
+);
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
@@ -65,7 +65,7 @@ export const auctioneerExecutePartialSaleStruct = new beet.FixableBeetArgsStruct
  * @property [_writable_] buyerTradeState
  * @property [_writable_] sellerTradeState
  * @property [_writable_] freeTradeState
- * @property [] ahAuctioneerPda
+ * @property [_writable_] ahAuctioneerPda
  * @property [] programAsSigner
  * @category Instructions
  * @category AuctioneerExecutePartialSale
@@ -209,7 +209,7 @@ export function createAuctioneerExecutePartialSaleInstruction(
     },
     {
       pubkey: accounts.ahAuctioneerPda,
-      isWritable: false,
+      isWritable: true,
       isSigner: false,
     },
     {
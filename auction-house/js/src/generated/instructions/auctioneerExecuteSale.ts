@@ -61,7 +61,7 @@ export const auctioneerExecuteSaleStruct = new beet.BeetArgsStruct<
  * @property [_writable_] buyerTradeState
  * @property [_writable_] sellerTradeState
  * @property [_writable_] freeTradeState
- * @property [] ahAuctioneerPda
+ * @property [_writable_] ahAuctioneerPda
  * @property [] programAsSigner
  * @category Instructions
  * @category AuctioneerExecuteSale
@@ -203,7 +203,7 @@ export function createAuctioneerExecuteSaleInstruction(
     },
     {
       pubkey: accounts.ahAuctioneerPda,
-      isWritable: false,
+      isWritable: true,
       isSigner: false,
     },
     {
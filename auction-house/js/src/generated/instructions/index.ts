@@ -1,31 +1,57 @@
+export * from './acceptCounterOffer';
+export * from './approveAuctionHouseChange';
 export * from './auctioneerBuy';
 export * from './auctioneerCancel';
 export * from './auctioneerDeposit';
 export * from './auctioneerExecutePartialSale';
+export * from './auctioneerExecutePartialSaleAtPrice';
 export * from './auctioneerExecuteSale';
 export * from './auctioneerPublicBuy';
 export * from './auctioneerSell';
 export * from './auctioneerWithdraw';
 export * from './buy';
 export * from './cancel';
+export * from './cancelAuctionHouseChange';
 export * from './cancelBidReceipt';
 export * from './cancelListingReceipt';
 export * from './cancelRemainingAccounts';
+export * from './closeBidReceipt';
 export * from './closeEscrowAccount';
+export * from './closeListingReceipt';
+export * from './closePurchaseReceipt';
 export * from './createAuctionHouse';
+export * from './createFeeDiscountSchedule';
 export * from './delegateAuctioneer';
+export * from './delegatedBuy';
+export * from './delegatedPublicBuy';
 export * from './deposit';
+export * from './enableGovernance';
+export * from './executeAuctionHouseChange';
 export * from './executePartialSale';
+export * from './executePartialSaleWithReferral';
 export * from './executeSale';
 export * from './executeSaleRemainingAccounts';
+export * from './executeSaleWithReferral';
+export * from './initCollectionStats';
+export * from './makeCounterOffer';
+export * from './partialPublicBuy';
 export * from './printBidReceipt';
 export * from './printListingReceipt';
 export * from './printPurchaseReceipt';
+export * from './proposeAuctionHouseChange';
 export * from './publicBuy';
+export * from './revokeAuctioneer';
+export * from './scheduledSell';
 export * from './sell';
 export * from './sellRemainingAccounts';
+export * from './setAuctioneerLimits';
+export * from './setMaxReferralBasisPoints';
+export * from './setReceiptRetentionPeriod';
+export * from './sweep';
 export * from './updateAuctionHouse';
 export * from './updateAuctioneer';
+export * from './updateFeeDiscountSchedule';
 export * from './withdraw';
+export * from './withdrawCounterOffer';
 export * from './withdrawFromFee';
 export * from './withdrawFromTreasury';
@@ -1,5 +1,11 @@
+export * from './AuctionHouseChange';
+export * from './AuctioneerChange';
+export * from './AuctioneerLimits';
+export * from './AuctioneerScopeLimits';
 export * from './AuthorityScope';
 export * from './BidType';
 export * from './CancelType';
+export * from './FeeDiscount';
 export * from './ListingType';
 export * from './PurchaseType';
+export * from './SweepListing';
@@ -0,0 +1,308 @@
+//! Open listings and bids for a single mint on a single auction house.
+
+use std::cmp::Ordering;
+
+use anchor_lang::prelude::Pubkey;
+
+/// How the listed token is moved to the buyer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TokenTransfer {
+    /// SPL Token transfer signed by the program as signer.
+    Token,
+    /// Token Metadata transfer of a programmable NFT, checked against its rule set if it has one.
+    Programmable { rule_set: Option<Pubkey> },
+}
+
+/// A listing ingested from a `ListingReceipt`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Listing {
+    pub trade_state: Pubkey,
+    pub seller: Pubkey,
+    pub token_account: Pubkey,
+    pub metadata: Pubkey,
+    /// Price of all `token_size` tokens.
+    pub price: u64,
+    pub token_size: u64,
+    /// Tokens not yet sold through partial fills.
+    pub remaining: u64,
+    pub created_at: i64,
+    /// Time a scheduled listing becomes buyable, `None` for listings buyable right away.
+    pub start_time: Option<i64>,
+    /// Creators of the token, in metadata order, paid royalties on every sale.
+    pub creators: Vec<Pubkey>,
+    /// Verified collection of the token, whose stats record the sale when they exist.
+    pub collection: Option<Pubkey>,
+    pub transfer: TokenTransfer,
+}
+
+impl Listing {
+    /// Price of a single token, as Auction House computes it for partial sales.
+    pub fn unit_price(&self) -> u64 {
+        self.price / self.token_size
+    }
+
+    /// Price a bid for `size` tokens has to offer to take part of this listing.
+    pub fn partial_price(&self, size: u64) -> Option<u64> {
+        self.unit_price().checked_mul(size)
+    }
+
+    /// Whether the listing can be bought at `now`.
+    pub fn is_started(&self, now: i64) -> bool {
+        !matches!(self.start_time, Some(start_time) if now < start_time)
+    }
+
+    fn cmp_price(&self, other: &Listing) -> Ordering {
+        (u128::from(self.price) * u128::from(other.token_size))
+            .cmp(&(u128::from(other.price) * u128::from(self.token_size)))
+    }
+}
+
+/// A bid ingested from a `BidReceipt`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Bid {
+    pub trade_state: Pubkey,
+    pub buyer: Pubkey,
+    /// Token account a private bid was placed on, `None` for public bids.
+    pub token_account: Option<Pubkey>,
+    pub metadata: Pubkey,
+    /// Price offered for all `token_size` tokens.
+    pub price: u64,
+    pub token_size: u64,
+    pub created_at: i64,
+    /// Token account a delegated bid pulls the price from, `None` for escrow backed bids.
+    pub payment_account: Option<Pubkey>,
+}
+
+impl Bid {
+    /// Size of the fill this bid can settle against `listing`, if any.
+    pub fn fill_size(&self, listing: &Listing) -> Option<u64> {
+        if self.buyer == listing.seller
+            || matches!(self.token_account, Some(token_account) if token_account != listing.token_account)
+        {
+            return None;
+        }
+
+        let full = listing.remaining == listing.token_size
+            && self.token_size == listing.token_size
+            && self.price == listing.price;
+        let partial = self.token_size < listing.token_size
+            && self.token_size <= listing.remaining
+            && listing.partial_price(self.token_size) == Some(self.price);
+
+        if full || partial {
+            Some(self.token_size)
+        } else {
+            None
+        }
+    }
+}
+
+/// Open orders of one mint on one auction house, listings cheapest unit price first and bids
+/// oldest first.
+#[derive(Clone, Debug, Default)]
+pub struct OrderBook {
+    listings: Vec<Listing>,
+    bids: Vec<Bid>,
+}
+
+impl OrderBook {
+    pub fn listings(&self) -> &[Listing] {
+        &self.listings
+    }
+
+    pub fn bids(&self) -> &[Bid] {
+        &self.bids
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.listings.is_empty() && self.bids.is_empty()
+    }
+
+    /// Add a listing, keeping what partial fills already sold if it is in the book.
+    pub(crate) fn upsert_listing(&mut self, mut listing: Listing) {
+        if let Some(existing) = self
+            .listings
+            .iter_mut()
+            .find(|existing| existing.trade_state == listing.trade_state)
+        {
+            listing.remaining = existing.remaining;
+            *existing = listing;
+        } else {
+            self.listings.push(listing);
+        }
+
+        self.listings
+            .sort_by(|a, b| a.cmp_price(b).then(a.created_at.cmp(&b.created_at)));
+    }
+
+    pub(crate) fn upsert_bid(&mut self, bid: Bid) {
+        self.remove(&bid.trade_state);
+        self.bids.push(bid);
+        self.bids.sort_by_key(|bid| bid.created_at);
+    }
+
+    pub(crate) fn remove(&mut self, trade_state: &Pubkey) {
+        self.listings
+            .retain(|listing| listing.trade_state != *trade_state);
+        self.bids.retain(|bid| bid.trade_state != *trade_state);
+    }
+
+    /// Take every bid that settles against a listing buyable at `now` out of the book, along with
+    /// the listing as it was before the fill and the size of the fill.
+    pub(crate) fn take_matches(&mut self, now: i64) -> Vec<(Listing, Bid, u64)> {
+        let mut matches = vec![];
+
+        for listing in self
+            .listings
+            .iter_mut()
+            .filter(|listing| listing.is_started(now))
+        {
+            let mut i = 0;
+            while i < self.bids.len() && listing.remaining > 0 {
+                match self.bids[i].fill_size(listing) {
+                    Some(size) => {
+                        matches.push((listing.clone(), self.bids.remove(i), size));
+                        listing.remaining -= size;
+                    }
+                    None => i += 1,
+                }
+            }
+        }
+        self.listings.retain(|listing| listing.remaining > 0);
+
+        matches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn listing(price: u64, token_size: u64, created_at: i64) -> Listing {
+        Listing {
+            trade_state: Pubkey::new_unique(),
+            seller: Pubkey::new_unique(),
+            token_account: Pubkey::new_unique(),
+            metadata: Pubkey::new_unique(),
+            price,
+            token_size,
+            remaining: token_size,
+            created_at,
+            start_time: None,
+            creators: vec![],
+            collection: None,
+            transfer: TokenTransfer::Token,
+        }
+    }
+
+    fn bid(price: u64, token_size: u64, created_at: i64) -> Bid {
+        Bid {
+            trade_state: Pubkey::new_unique(),
+            buyer: Pubkey::new_unique(),
+            token_account: None,
+            metadata: Pubkey::new_unique(),
+            price,
+            token_size,
+            created_at,
+            payment_account: None,
+        }
+    }
+
+    #[test]
+    fn cmp_price_compares_unit_prices() {
+        assert_eq!(
+            listing(10, 1, 0).cmp_price(&listing(30, 3, 0)),
+            Ordering::Equal
+        );
+        assert_eq!(
+            listing(10, 2, 0).cmp_price(&listing(6, 1, 0)),
+            Ordering::Less
+        );
+        assert_eq!(
+            listing(u64::MAX, 1, 0).cmp_price(&listing(u64::MAX, 2, 0)),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn fill_size_full_listing() {
+        let listing = listing(100, 1, 0);
+
+        assert_eq!(bid(100, 1, 0).fill_size(&listing), Some(1));
+        assert_eq!(bid(99, 1, 0).fill_size(&listing), None);
+        assert_eq!(bid(101, 1, 0).fill_size(&listing), None);
+    }
+
+    #[test]
+    fn fill_size_partial_listing() {
+        let mut listing = listing(100, 4, 0);
+
+        assert_eq!(bid(50, 2, 0).fill_size(&listing), Some(2));
+        assert_eq!(bid(60, 2, 0).fill_size(&listing), None);
+
+        // Once partly sold, the listing can't be bought whole anymore.
+        listing.remaining = 1;
+        assert_eq!(bid(50, 2, 0).fill_size(&listing), None);
+        assert_eq!(bid(25, 1, 0).fill_size(&listing), Some(1));
+        assert_eq!(bid(100, 4, 0).fill_size(&listing), None);
+    }
+
+    #[test]
+    fn fill_size_skips_own_and_other_token_account_bids() {
+        let listing = listing(100, 1, 0);
+
+        let mut own = bid(100, 1, 0);
+        own.buyer = listing.seller;
+        assert_eq!(own.fill_size(&listing), None);
+
+        let mut private = bid(100, 1, 0);
+        private.token_account = Some(Pubkey::new_unique());
+        assert_eq!(private.fill_size(&listing), None);
+        private.token_account = Some(listing.token_account);
+        assert_eq!(private.fill_size(&listing), Some(1));
+    }
+
+    #[test]
+    fn take_matches_fills_oldest_bids_first() {
+        let mut book = OrderBook::default();
+        let listing = listing(100, 4, 0);
+        book.upsert_listing(listing.clone());
+        let newest = bid(75, 3, 2);
+        let oldest = bid(50, 2, 1);
+        book.upsert_bid(newest.clone());
+        book.upsert_bid(oldest.clone());
+
+        let matches = book.take_matches(0);
+
+        assert_eq!(matches, vec![(listing, oldest, 2)]);
+        assert_eq!(book.listings()[0].remaining, 2);
+        assert_eq!(book.bids(), &[newest]);
+    }
+
+    #[test]
+    fn take_matches_removes_sold_listings() {
+        let mut book = OrderBook::default();
+        book.upsert_listing(listing(100, 2, 0));
+        book.upsert_bid(bid(50, 1, 1));
+        book.upsert_bid(bid(50, 1, 2));
+
+        let matches = book.take_matches(0);
+
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[1].0.remaining, 1);
+        assert!(book.is_empty());
+    }
+
+    #[test]
+    fn take_matches_waits_for_start_time() {
+        let mut book = OrderBook::default();
+        let mut scheduled = listing(100, 1, 0);
+        scheduled.start_time = Some(10);
+        book.upsert_listing(scheduled);
+        book.upsert_bid(bid(100, 1, 1));
+
+        assert!(book.take_matches(9).is_empty());
+        assert_eq!(book.take_matches(10).len(), 1);
+        assert!(book.is_empty());
+    }
+}
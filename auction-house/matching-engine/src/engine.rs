@@ -0,0 +1,321 @@
+//! Order books of every auction house and mint fed to the engine.
+
+use std::collections::HashMap;
+
+use anchor_lang::{prelude::Pubkey, AccountDeserialize};
+use mpl_auction_house::{
+    constants::{DELEGATED_TRADE_STATE_SIZE, SCHEDULED_TRADE_STATE_SIZE},
+    pda::{
+        find_collection_stats_address, find_public_bid_trade_state_address,
+        find_trade_state_address,
+    },
+    receipt::{BidReceipt, ListingReceipt},
+    stats::CollectionStats,
+    AuctionHouse,
+};
+use mpl_token_metadata::{
+    pda::find_metadata_account,
+    state::{Metadata, ProgrammableConfig, TokenMetadataAccount, TokenStandard},
+};
+use solana_program::instruction::Instruction;
+use spl_associated_token_account::get_associated_token_address;
+
+use crate::{
+    book::{Bid, Listing, OrderBook, TokenTransfer},
+    error::{MatchingError, Result},
+    fill::{Fill, Referral},
+};
+
+#[derive(Default)]
+pub struct MatchingEngine {
+    auction_houses: HashMap<Pubkey, AuctionHouse>,
+    /// Order books keyed by auction house and token mint.
+    books: HashMap<(Pubkey, Pubkey), OrderBook>,
+    /// Book holding each ingested trade state.
+    trade_states: HashMap<Pubkey, (Pubkey, Pubkey)>,
+    /// Collection stats accounts keyed by auction house and collection mint.
+    collection_stats: HashMap<(Pubkey, Pubkey), Pubkey>,
+    referral: Option<Referral>,
+}
+
+impl MatchingEngine {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register the auction house receipts are ingested for.
+    pub fn add_auction_house(&mut self, address: Pubkey, auction_house: AuctionHouse) {
+        self.auction_houses.insert(address, auction_house);
+    }
+
+    /// Register an auction house from its account data.
+    pub fn ingest_auction_house(&mut self, address: Pubkey, data: &[u8]) -> Result<()> {
+        let auction_house = deserialize::<AuctionHouse>(data)?;
+        self.add_auction_house(address, auction_house);
+
+        Ok(())
+    }
+
+    /// Register a `CollectionStats` account, recording the sales of its collection from then on.
+    /// Sales of collections without stats account settle without one.
+    pub fn ingest_collection_stats(&mut self, data: &[u8]) -> Result<()> {
+        let stats = deserialize::<CollectionStats>(data)?;
+        let (address, _) =
+            find_collection_stats_address(&stats.auction_house, &stats.collection_mint);
+        self.collection_stats
+            .insert((stats.auction_house, stats.collection_mint), address);
+
+        Ok(())
+    }
+
+    /// Set the referrer paid on every fill settled from now on.
+    pub fn set_referral(&mut self, referral: Option<Referral>) {
+        self.referral = referral;
+    }
+
+    pub fn auction_house(&self, address: &Pubkey) -> Option<&AuctionHouse> {
+        self.auction_houses.get(address)
+    }
+
+    pub fn book(&self, auction_house: &Pubkey, token_mint: &Pubkey) -> Option<&OrderBook> {
+        self.books.get(&(*auction_house, *token_mint))
+    }
+
+    /// Add or update the listing of a `ListingReceipt` account. Receipts don't record the mint and
+    /// token account the listing was posted for, so they have to be passed in and are checked
+    /// against the trade state. The seller trade state data gives the start time of scheduled
+    /// listings and the token metadata data the creators, collection and token standard settling
+    /// the sale needs. Canceled and purchased listings are removed from the book.
+    pub fn ingest_listing_receipt(
+        &mut self,
+        data: &[u8],
+        token_mint: Pubkey,
+        token_account: Pubkey,
+        trade_state_data: &[u8],
+        metadata_data: &[u8],
+    ) -> Result<()> {
+        let receipt = deserialize::<ListingReceipt>(data)?;
+        let auction_house = self.known_auction_house(&receipt.auction_house)?;
+
+        if receipt.price == u64::MAX {
+            return Err(MatchingError::AuctioneerListing);
+        }
+        if receipt.token_size == 0 {
+            return Err(MatchingError::EmptyOrder);
+        }
+
+        let (trade_state, _) = find_trade_state_address(
+            &receipt.seller,
+            &receipt.auction_house,
+            &token_account,
+            &auction_house.treasury_mint,
+            &token_mint,
+            receipt.price,
+            receipt.token_size,
+        );
+        if trade_state != receipt.trade_state {
+            return Err(MatchingError::TradeStateMismatch(receipt.trade_state));
+        }
+
+        if receipt.canceled_at.is_some() || receipt.purchase_receipt.is_some() {
+            self.remove(&receipt.trade_state);
+            return Ok(());
+        }
+
+        let metadata = Metadata::safe_deserialize(metadata_data)
+            .map_err(|error| MatchingError::InvalidAccountData(error.to_string()))?;
+        if metadata.mint != token_mint || find_metadata_account(&token_mint).0 != receipt.metadata {
+            return Err(MatchingError::MetadataMismatch(token_mint));
+        }
+
+        let start_time = if trade_state_data.len() == SCHEDULED_TRADE_STATE_SIZE {
+            let mut start_time = [0; 8];
+            start_time.copy_from_slice(&trade_state_data[1..SCHEDULED_TRADE_STATE_SIZE]);
+            Some(i64::from_le_bytes(start_time))
+        } else {
+            None
+        };
+        let transfer = match metadata.token_standard {
+            Some(TokenStandard::ProgrammableNonFungible) => TokenTransfer::Programmable {
+                rule_set: match metadata.programmable_config {
+                    Some(ProgrammableConfig::V1 { rule_set }) => rule_set,
+                    None => None,
+                },
+            },
+            _ => TokenTransfer::Token,
+        };
+
+        self.book_mut(receipt.auction_house, token_mint, receipt.trade_state)
+            .upsert_listing(Listing {
+                trade_state: receipt.trade_state,
+                seller: receipt.seller,
+                token_account,
+                metadata: receipt.metadata,
+                price: receipt.price,
+                token_size: receipt.token_size,
+                remaining: receipt.token_size,
+                created_at: receipt.created_at,
+                start_time,
+                creators: metadata
+                    .data
+                    .creators
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|creator| creator.address)
+                    .collect(),
+                collection: metadata
+                    .collection
+                    .filter(|collection| collection.verified)
+                    .map(|collection| collection.key),
+                transfer,
+            });
+
+        Ok(())
+    }
+
+    /// Add or update the bid of a `BidReceipt` account, checking `token_mint` against the trade
+    /// state. The buyer trade state data tells delegated bids apart, which pull the price from
+    /// `payment_account`, or the buyer's associated token account of the treasury mint when it is
+    /// `None`. Canceled and purchased bids are removed from the book.
+    pub fn ingest_bid_receipt(
+        &mut self,
+        data: &[u8],
+        token_mint: Pubkey,
+        trade_state_data: &[u8],
+        payment_account: Option<Pubkey>,
+    ) -> Result<()> {
+        let receipt = deserialize::<BidReceipt>(data)?;
+        let auction_house = self.known_auction_house(&receipt.auction_house)?;
+
+        if receipt.token_size == 0 {
+            return Err(MatchingError::EmptyOrder);
+        }
+
+        let (trade_state, _) = match receipt.token_account {
+            Some(token_account) => find_trade_state_address(
+                &receipt.buyer,
+                &receipt.auction_house,
+                &token_account,
+                &auction_house.treasury_mint,
+                &token_mint,
+                receipt.price,
+                receipt.token_size,
+            ),
+            None => find_public_bid_trade_state_address(
+                &receipt.buyer,
+                &receipt.auction_house,
+                &auction_house.treasury_mint,
+                &token_mint,
+                receipt.price,
+                receipt.token_size,
+            ),
+        };
+        if trade_state != receipt.trade_state {
+            return Err(MatchingError::TradeStateMismatch(receipt.trade_state));
+        }
+
+        if receipt.canceled_at.is_some() || receipt.purchase_receipt.is_some() {
+            self.remove(&receipt.trade_state);
+            return Ok(());
+        }
+
+        let payment_account = if trade_state_data.len() == DELEGATED_TRADE_STATE_SIZE {
+            Some(payment_account.unwrap_or_else(|| {
+                get_associated_token_address(&receipt.buyer, &auction_house.treasury_mint)
+            }))
+        } else {
+            None
+        };
+
+        self.book_mut(receipt.auction_house, token_mint, receipt.trade_state)
+            .upsert_bid(Bid {
+                trade_state: receipt.trade_state,
+                buyer: receipt.buyer,
+                token_account: receipt.token_account,
+                metadata: receipt.metadata,
+                price: receipt.price,
+                token_size: receipt.token_size,
+                created_at: receipt.created_at,
+                payment_account,
+            });
+
+        Ok(())
+    }
+
+    /// Remove the order with `trade_state` from its book.
+    pub fn remove(&mut self, trade_state: &Pubkey) {
+        if let Some(key) = self.trade_states.remove(trade_state) {
+            if let Some(book) = self.books.get_mut(&key) {
+                book.remove(trade_state);
+            }
+        }
+    }
+
+    /// Take every crossing listing and bid out of the books, leaving scheduled listings that don't
+    /// start until after `now` alone. Filled bids and fully sold listings leave the book, partially
+    /// sold listings stay with what is left of them.
+    pub fn match_orders(&mut self, now: i64) -> Vec<Fill> {
+        let mut fills = vec![];
+
+        for (&(auction_house, token_mint), book) in self.books.iter_mut() {
+            for (listing, bid, size) in book.take_matches(now) {
+                self.trade_states.remove(&bid.trade_state);
+                if listing.remaining == size {
+                    self.trade_states.remove(&listing.trade_state);
+                }
+
+                fills.push(Fill {
+                    auction_house,
+                    token_mint,
+                    listing,
+                    bid,
+                    size,
+                });
+            }
+        }
+        self.books.retain(|_, book| !book.is_empty());
+
+        fills
+    }
+
+    /// Every instruction settling `fill`, with `bookkeeper` paying for the purchase receipt. The
+    /// sale pays the referrer set on the engine and is recorded in the ingested stats of the
+    /// token's collection.
+    pub fn instructions(&self, fill: &Fill, bookkeeper: &Pubkey) -> Result<Vec<Instruction>> {
+        let auction_house = self.known_auction_house(&fill.auction_house)?;
+        let collection_stats = fill.listing.collection.and_then(|collection| {
+            self.collection_stats
+                .get(&(fill.auction_house, collection))
+                .copied()
+        });
+
+        Ok(fill.instructions(
+            auction_house,
+            bookkeeper,
+            self.referral.as_ref(),
+            collection_stats,
+        ))
+    }
+
+    fn known_auction_house(&self, address: &Pubkey) -> Result<&AuctionHouse> {
+        self.auction_houses
+            .get(address)
+            .ok_or(MatchingError::UnknownAuctionHouse(*address))
+    }
+
+    fn book_mut(
+        &mut self,
+        auction_house: Pubkey,
+        token_mint: Pubkey,
+        trade_state: Pubkey,
+    ) -> &mut OrderBook {
+        self.trade_states
+            .insert(trade_state, (auction_house, token_mint));
+        self.books.entry((auction_house, token_mint)).or_default()
+    }
+}
+
+fn deserialize<T: AccountDeserialize>(data: &[u8]) -> Result<T> {
+    T::try_deserialize(&mut &data[..])
+        .map_err(|error| MatchingError::InvalidAccountData(error.to_string()))
+}
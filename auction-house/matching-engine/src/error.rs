@@ -0,0 +1,27 @@
+//! Errors returned while ingesting receipts and building settlement instructions.
+
+use anchor_lang::prelude::Pubkey;
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum MatchingError {
+    #[error("Account data could not be deserialized: {0}")]
+    InvalidAccountData(String),
+
+    #[error("Auction house {0} has not been added to the engine.")]
+    UnknownAuctionHouse(Pubkey),
+
+    #[error("Receipt does not match trade state {0}.")]
+    TradeStateMismatch(Pubkey),
+
+    #[error("Metadata does not belong to the listed mint {0}.")]
+    MetadataMismatch(Pubkey),
+
+    #[error("Orders must be for at least one token.")]
+    EmptyOrder,
+
+    #[error("Listings managed by an auctioneer can't be matched.")]
+    AuctioneerListing,
+}
+
+pub type Result<T> = std::result::Result<T, MatchingError>;
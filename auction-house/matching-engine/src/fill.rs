@@ -0,0 +1,245 @@
+//! Fills found by the engine and the instructions that settle them.
+
+use anchor_lang::{prelude::Pubkey, InstructionData, ToAccountMetas};
+use mpl_auction_house::{
+    pda::{
+        find_bid_receipt_address, find_escrow_payment_address, find_listing_receipt_address,
+        find_program_as_signer_address, find_purchase_receipt_address, find_trade_state_address,
+    },
+    AuctionHouse,
+};
+use mpl_token_metadata::pda::{find_master_edition_account, find_token_record_account};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    system_program, sysvar,
+};
+use spl_associated_token_account::get_associated_token_address;
+
+use crate::book::{Bid, Listing, TokenTransfer};
+
+/// Referrer paid `basis_points` of the price out of the Auction House fee on every fill.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Referral {
+    /// Wallet of the referrer, paid in its associated token account when the treasury mint is an
+    /// SPL token.
+    pub referrer: Pubkey,
+    pub basis_points: u16,
+}
+
+/// A bid that settles against a listing.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Fill {
+    pub auction_house: Pubkey,
+    pub token_mint: Pubkey,
+    /// The listing as it was before the fill.
+    pub listing: Listing,
+    pub bid: Bid,
+    /// Tokens sold to the bidder.
+    pub size: u64,
+}
+
+impl Fill {
+    /// Whether the fill takes only part of the listing and settles with `execute_partial_sale`.
+    pub fn is_partial(&self) -> bool {
+        self.size != self.listing.token_size
+    }
+
+    /// The `execute_sale` or `execute_partial_sale` instruction settling the fill, or their
+    /// `_with_referral` variant when a referral is set. Its remaining accounts carry the payment
+    /// account of a delegated bid, the creators, the referrer, the collection stats and the
+    /// accounts transferring a programmable NFT, in the order Auction House reads them.
+    pub fn execute_instruction(
+        &self,
+        auction_house: &AuctionHouse,
+        referral: Option<&Referral>,
+        collection_stats: Option<Pubkey>,
+    ) -> Instruction {
+        let listing = &self.listing;
+        let (program_as_signer, program_as_signer_bump) = find_program_as_signer_address();
+        let (free_trade_state, free_trade_state_bump) = find_trade_state_address(
+            &listing.seller,
+            &self.auction_house,
+            &listing.token_account,
+            &auction_house.treasury_mint,
+            &self.token_mint,
+            0,
+            listing.token_size,
+        );
+        let (escrow_payment_account, escrow_payment_bump) =
+            find_escrow_payment_address(&self.auction_house, &self.bid.buyer);
+
+        let is_native = auction_house.treasury_mint == spl_token::native_mint::id();
+        let treasury_account = |wallet: &Pubkey| {
+            if is_native {
+                *wallet
+            } else {
+                get_associated_token_address(wallet, &auction_house.treasury_mint)
+            }
+        };
+        let buyer_receipt_token_account =
+            get_associated_token_address(&self.bid.buyer, &self.token_mint);
+
+        // `ExecutePartialSale` takes the same accounts in the same order.
+        let mut accounts = mpl_auction_house::accounts::ExecuteSale {
+            buyer: self.bid.buyer,
+            seller: listing.seller,
+            token_account: listing.token_account,
+            token_mint: self.token_mint,
+            metadata: listing.metadata,
+            treasury_mint: auction_house.treasury_mint,
+            escrow_payment_account,
+            seller_payment_receipt_account: treasury_account(&listing.seller),
+            buyer_receipt_token_account,
+            authority: auction_house.authority,
+            auction_house: self.auction_house,
+            auction_house_fee_account: auction_house.auction_house_fee_account,
+            auction_house_treasury: auction_house.auction_house_treasury,
+            buyer_trade_state: self.bid.trade_state,
+            seller_trade_state: listing.trade_state,
+            free_trade_state,
+            token_program: spl_token::id(),
+            system_program: system_program::id(),
+            ata_program: spl_associated_token_account::id(),
+            program_as_signer,
+            rent: sysvar::rent::id(),
+        }
+        .to_account_metas(None);
+
+        if let Some(payment_account) = self.bid.payment_account {
+            accounts.push(AccountMeta::new(payment_account, false));
+        }
+        for creator in &listing.creators {
+            accounts.push(AccountMeta::new(*creator, false));
+            if !is_native {
+                accounts.push(AccountMeta::new(treasury_account(creator), false));
+            }
+        }
+        if let Some(referral) = referral {
+            accounts.push(AccountMeta::new(
+                treasury_account(&referral.referrer),
+                false,
+            ));
+        }
+        if let Some(collection_stats) = collection_stats {
+            accounts.push(AccountMeta::new(collection_stats, false));
+        }
+        if let TokenTransfer::Programmable { rule_set } = listing.transfer {
+            // Token Metadata updates the metadata of programmable NFTs on transfer.
+            if let Some(metadata) = accounts
+                .iter_mut()
+                .find(|account| account.pubkey == listing.metadata)
+            {
+                metadata.is_writable = true;
+            }
+            accounts.append(
+                &mut mpl_auction_house::accounts::ExecuteSaleRemainingAccounts {
+                    metadata_program: mpl_token_metadata::id(),
+                    edition: find_master_edition_account(&self.token_mint).0,
+                    owner_tr: find_token_record_account(&self.token_mint, &listing.token_account).0,
+                    destination_tr: find_token_record_account(
+                        &self.token_mint,
+                        &buyer_receipt_token_account,
+                    )
+                    .0,
+                    auth_rules_program: mpl_token_auth_rules::id(),
+                    auth_rules: rule_set.unwrap_or_else(mpl_token_metadata::id),
+                    sysvar_instructions: sysvar::instructions::id(),
+                }
+                .to_account_metas(None),
+            );
+        }
+
+        let data = match (self.is_partial(), referral) {
+            (true, None) => mpl_auction_house::instruction::ExecutePartialSale {
+                escrow_payment_bump,
+                _free_trade_state_bump: free_trade_state_bump,
+                program_as_signer_bump,
+                buyer_price: listing.price,
+                token_size: listing.token_size,
+                partial_order_size: Some(self.size),
+                partial_order_price: Some(self.bid.price),
+            }
+            .data(),
+            (true, Some(referral)) => {
+                mpl_auction_house::instruction::ExecutePartialSaleWithReferral {
+                    escrow_payment_bump,
+                    _free_trade_state_bump: free_trade_state_bump,
+                    program_as_signer_bump,
+                    buyer_price: listing.price,
+                    token_size: listing.token_size,
+                    partial_order_size: Some(self.size),
+                    partial_order_price: Some(self.bid.price),
+                    referral_basis_points: referral.basis_points,
+                }
+                .data()
+            }
+            (false, None) => mpl_auction_house::instruction::ExecuteSale {
+                escrow_payment_bump,
+                _free_trade_state_bump: free_trade_state_bump,
+                program_as_signer_bump,
+                buyer_price: listing.price,
+                token_size: listing.token_size,
+            }
+            .data(),
+            (false, Some(referral)) => mpl_auction_house::instruction::ExecuteSaleWithReferral {
+                escrow_payment_bump,
+                _free_trade_state_bump: free_trade_state_bump,
+                program_as_signer_bump,
+                buyer_price: listing.price,
+                token_size: listing.token_size,
+                referral_basis_points: referral.basis_points,
+            }
+            .data(),
+        };
+
+        Instruction {
+            program_id: mpl_auction_house::id(),
+            accounts,
+            data,
+        }
+    }
+
+    /// The `print_purchase_receipt` instruction to send right after the sale. Partial sales don't
+    /// print purchase receipts.
+    pub fn print_purchase_receipt_instruction(&self, bookkeeper: &Pubkey) -> Option<Instruction> {
+        if self.is_partial() {
+            return None;
+        }
+
+        let (purchase_receipt, purchase_receipt_bump) =
+            find_purchase_receipt_address(&self.listing.trade_state, &self.bid.trade_state);
+
+        Some(Instruction {
+            program_id: mpl_auction_house::id(),
+            accounts: mpl_auction_house::accounts::PrintPurchaseReceipt {
+                purchase_receipt,
+                listing_receipt: find_listing_receipt_address(&self.listing.trade_state).0,
+                bid_receipt: find_bid_receipt_address(&self.bid.trade_state).0,
+                bookkeeper: *bookkeeper,
+                system_program: system_program::id(),
+                rent: sysvar::rent::id(),
+                instruction: sysvar::instructions::id(),
+            }
+            .to_account_metas(None),
+            data: mpl_auction_house::instruction::PrintPurchaseReceipt {
+                purchase_receipt_bump,
+            }
+            .data(),
+        })
+    }
+
+    /// Every instruction settling the fill, in the order they have to be sent.
+    pub fn instructions(
+        &self,
+        auction_house: &AuctionHouse,
+        bookkeeper: &Pubkey,
+        referral: Option<&Referral>,
+        collection_stats: Option<Pubkey>,
+    ) -> Vec<Instruction> {
+        let mut instructions =
+            vec![self.execute_instruction(auction_house, referral, collection_stats)];
+        instructions.extend(self.print_purchase_receipt_instruction(bookkeeper));
+
+        instructions
+    }
+}
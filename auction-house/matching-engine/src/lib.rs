@@ -0,0 +1,15 @@
+//! Host-side matching engine for Auction House.
+//!
+//! The engine ingests `ListingReceipt` and `BidReceipt` accounts into an order book per auction
+//! house and mint, finds the bids that settle against a listing and builds the `execute_sale` or
+//! `execute_partial_sale` instructions that settle them.
+
+pub mod book;
+pub mod engine;
+pub mod error;
+pub mod fill;
+
+pub use book::*;
+pub use engine::*;
+pub use error::*;
+pub use fill::*;
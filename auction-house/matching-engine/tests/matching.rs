@@ -0,0 +1,444 @@
+#![cfg(feature = "test-bpf")]
+#[path = "../../program/tests/utils/mod.rs"]
+pub mod utils;
+
+use mpl_auction_house::{
+    pda::find_purchase_receipt_address, receipt::PurchaseReceipt, AuctionHouse,
+};
+use mpl_auction_house_matching_engine::MatchingEngine;
+use mpl_testing_utils::{solana::airdrop, utils::Metadata};
+use mpl_token_metadata::state::Creator;
+use solana_program_test::*;
+use solana_sdk::{
+    clock::Clock,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use utils::setup_functions::*;
+
+use anchor_lang::AccountDeserialize;
+
+const TEN_SOL: u64 = 10_000_000_000;
+
+async fn account_data(context: &mut ProgramTestContext, address: &Pubkey) -> Vec<u8> {
+    context
+        .banks_client
+        .get_account(*address)
+        .await
+        .unwrap()
+        .expect("Account Empty")
+        .data
+}
+
+async fn now(context: &mut ProgramTestContext) -> i64 {
+    context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp
+}
+
+async fn ingest_listing(
+    context: &mut ProgramTestContext,
+    engine: &mut MatchingEngine,
+    test_metadata: &Metadata,
+    (sell_acc, listing_receipt_acc): &(
+        mpl_auction_house::accounts::Sell,
+        mpl_auction_house::accounts::PrintListingReceipt,
+    ),
+) {
+    let data = account_data(context, &listing_receipt_acc.receipt).await;
+    let trade_state_data = account_data(context, &sell_acc.seller_trade_state).await;
+    let metadata_data = account_data(context, &test_metadata.pubkey).await;
+    engine
+        .ingest_listing_receipt(
+            &data,
+            test_metadata.mint.pubkey(),
+            sell_acc.token_account,
+            &trade_state_data,
+            &metadata_data,
+        )
+        .unwrap();
+}
+
+async fn ingested_listing(
+    context: &mut ProgramTestContext,
+    engine: &mut MatchingEngine,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    price: u64,
+    amount: u64,
+) -> Metadata {
+    let (test_metadata, sell_accounts) = listed_metadata(context, ahkey, ah, price, amount).await;
+    ingest_listing(context, engine, &test_metadata, &sell_accounts).await;
+
+    test_metadata
+}
+
+async fn place_bid(
+    context: &mut ProgramTestContext,
+    engine: &mut MatchingEngine,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    test_metadata: &Metadata,
+    price: u64,
+    token_size: u64,
+) -> Keypair {
+    let buyer = Keypair::new();
+    airdrop(context, &buyer.pubkey(), TEN_SOL).await.unwrap();
+    let ((bid_acc, bid_receipt_acc), buy_tx) = buy(
+        context,
+        ahkey,
+        ah,
+        test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        price,
+        token_size,
+    );
+    context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap();
+
+    let data = account_data(context, &bid_receipt_acc.receipt).await;
+    let trade_state_data = account_data(context, &bid_acc.buyer_trade_state).await;
+    engine
+        .ingest_bid_receipt(&data, test_metadata.mint.pubkey(), &trade_state_data, None)
+        .unwrap();
+
+    buyer
+}
+
+#[tokio::test]
+async fn match_full_listing_success() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    airdrop(&mut context, &ah.auction_house_fee_account, TEN_SOL)
+        .await
+        .unwrap();
+    let mut engine = MatchingEngine::new();
+    let ah_data = account_data(&mut context, &ahkey).await;
+    engine.ingest_auction_house(ahkey, &ah_data).unwrap();
+
+    let test_metadata =
+        ingested_listing(&mut context, &mut engine, &ahkey, &ah, 100_000_000, 1).await;
+    let _underpriced = place_bid(
+        &mut context,
+        &mut engine,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        90_000_000,
+        1,
+    )
+    .await;
+    let buyer = place_bid(
+        &mut context,
+        &mut engine,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        100_000_000,
+        1,
+    )
+    .await;
+
+    let now = now(&mut context).await;
+    let fills = engine.match_orders(now);
+    assert_eq!(fills.len(), 1);
+    let fill = &fills[0];
+    assert!(!fill.is_partial());
+    assert_eq!(fill.bid.buyer, buyer.pubkey());
+
+    let book = engine.book(&ahkey, &test_metadata.mint.pubkey()).unwrap();
+    assert!(book.listings().is_empty());
+    assert_eq!(book.bids().len(), 1);
+
+    let instructions = engine.instructions(fill, &authority.pubkey()).unwrap();
+    assert_eq!(instructions.len(), 2);
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&authority.pubkey()),
+        &[&authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    assert_eq!(
+        token_balance(&mut context, &buyer.pubkey(), &test_metadata.mint.pubkey()).await,
+        1
+    );
+
+    let (purchase_receipt, _) =
+        find_purchase_receipt_address(&fill.listing.trade_state, &fill.bid.trade_state);
+    let data = account_data(&mut context, &purchase_receipt).await;
+    let purchase_receipt = PurchaseReceipt::try_deserialize(&mut data.as_ref()).unwrap();
+    assert_eq!(purchase_receipt.buyer, buyer.pubkey());
+    assert_eq!(purchase_receipt.price, 100_000_000);
+}
+
+#[tokio::test]
+async fn match_partial_fills_success() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    airdrop(&mut context, &ah.auction_house_fee_account, TEN_SOL)
+        .await
+        .unwrap();
+    let mut engine = MatchingEngine::new();
+    engine.add_auction_house(ahkey, ah.clone());
+
+    let test_metadata =
+        ingested_listing(&mut context, &mut engine, &ahkey, &ah, 600_000_000, 6).await;
+    let first_buyer = place_bid(
+        &mut context,
+        &mut engine,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        300_000_000,
+        3,
+    )
+    .await;
+    let second_buyer = place_bid(
+        &mut context,
+        &mut engine,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        200_000_000,
+        2,
+    )
+    .await;
+    // Only one token is left once the first two bids are filled.
+    let _third_buyer = place_bid(
+        &mut context,
+        &mut engine,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        200_000_000,
+        2,
+    )
+    .await;
+
+    let now = now(&mut context).await;
+    let fills = engine.match_orders(now);
+    assert_eq!(fills.len(), 2);
+    assert!(fills.iter().all(|fill| fill.is_partial()));
+    assert_eq!(fills[0].bid.buyer, first_buyer.pubkey());
+    assert_eq!(fills[1].bid.buyer, second_buyer.pubkey());
+
+    let book = engine.book(&ahkey, &test_metadata.mint.pubkey()).unwrap();
+    assert_eq!(book.listings()[0].remaining, 1);
+    assert_eq!(book.bids().len(), 1);
+
+    for fill in &fills {
+        let instructions = engine.instructions(fill, &authority.pubkey()).unwrap();
+        assert_eq!(instructions.len(), 1);
+        let tx = Transaction::new_signed_with_payer(
+            &instructions,
+            Some(&authority.pubkey()),
+            &[&authority],
+            context.last_blockhash,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+    }
+
+    let mint = test_metadata.mint.pubkey();
+    assert_eq!(
+        token_balance(&mut context, &first_buyer.pubkey(), &mint).await,
+        3
+    );
+    assert_eq!(
+        token_balance(&mut context, &second_buyer.pubkey(), &mint).await,
+        2
+    );
+    assert_eq!(
+        token_balance(&mut context, &test_metadata.token.pubkey(), &mint).await,
+        1
+    );
+}
+
+#[tokio::test]
+async fn match_with_creators_success() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    airdrop(&mut context, &ah.auction_house_fee_account, TEN_SOL)
+        .await
+        .unwrap();
+    let mut engine = MatchingEngine::new();
+    engine.add_auction_house(ahkey, ah.clone());
+
+    let creators = [Pubkey::new_unique(), Pubkey::new_unique()];
+    for creator in &creators {
+        airdrop(&mut context, creator, 100_000_000).await.unwrap();
+    }
+    let test_metadata = Metadata::new();
+    airdrop(&mut context, &test_metadata.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            Some(
+                creators
+                    .iter()
+                    .zip([25, 75])
+                    .map(|(address, share)| Creator {
+                        address: *address,
+                        verified: false,
+                        share,
+                    })
+                    .collect(),
+            ),
+            1_000,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+    let (sell_accounts, sell_tx) = sell(&mut context, &ahkey, &ah, &test_metadata, 100_000_000, 1);
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+    ingest_listing(&mut context, &mut engine, &test_metadata, &sell_accounts).await;
+    let buyer = place_bid(
+        &mut context,
+        &mut engine,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        100_000_000,
+        1,
+    )
+    .await;
+
+    let now = now(&mut context).await;
+    let fills = engine.match_orders(now);
+    assert_eq!(fills.len(), 1);
+    assert_eq!(fills[0].listing.creators, creators);
+
+    let mut creators_before = vec![];
+    for creator in &creators {
+        creators_before.push(context.banks_client.get_balance(*creator).await.unwrap());
+    }
+
+    let instructions = engine.instructions(&fills[0], &authority.pubkey()).unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&authority.pubkey()),
+        &[&authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    assert_eq!(
+        token_balance(&mut context, &buyer.pubkey(), &test_metadata.mint.pubkey()).await,
+        1
+    );
+    // 10% royalties split 25/75 between the creators.
+    for (creator, (before, share)) in creators
+        .iter()
+        .zip(creators_before.into_iter().zip([2_500_000, 7_500_000]))
+    {
+        assert_eq!(
+            context.banks_client.get_balance(*creator).await.unwrap(),
+            before + share
+        );
+    }
+}
+
+#[tokio::test]
+async fn match_scheduled_listing_once_started() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    airdrop(&mut context, &ah.auction_house_fee_account, TEN_SOL)
+        .await
+        .unwrap();
+    let mut engine = MatchingEngine::new();
+    engine.add_auction_house(ahkey, ah.clone());
+
+    let test_metadata = Metadata::new();
+    airdrop(&mut context, &test_metadata.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+    let start_time = now(&mut context).await + 60;
+    let (sell_accounts, sell_tx) = scheduled_sell(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        100_000_000,
+        1,
+        start_time,
+    );
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+    ingest_listing(&mut context, &mut engine, &test_metadata, &sell_accounts).await;
+    let buyer = place_bid(
+        &mut context,
+        &mut engine,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        100_000_000,
+        1,
+    )
+    .await;
+
+    let book = engine.book(&ahkey, &test_metadata.mint.pubkey()).unwrap();
+    assert_eq!(book.listings()[0].start_time, Some(start_time));
+    assert!(engine.match_orders(start_time - 1).is_empty());
+
+    warp_forward(&mut context, 60).await;
+    let now = now(&mut context).await;
+    let fills = engine.match_orders(now);
+    assert_eq!(fills.len(), 1);
+
+    let instructions = engine.instructions(&fills[0], &authority.pubkey()).unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&authority.pubkey()),
+        &[&authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    assert_eq!(
+        token_balance(&mut context, &buyer.pubkey(), &test_metadata.mint.pubkey()).await,
+        1
+    );
+}
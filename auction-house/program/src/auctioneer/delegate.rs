@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
 
-use crate::{constants::*, errors::AuctionHouseError, AuctionHouse, Auctioneer, AuthorityScope};
+use crate::{
+    constants::*, errors::AuctionHouseError, governance::consume_instruction_change, AuctionHouse,
+    Auctioneer, AuthorityScope,
+};
 
 /// Accounts for the [`delegate_auctioneer` handler](auction_house/fn.delegate_auctioneer.html).
 #[derive(Accounts)]
@@ -50,21 +53,37 @@ pub fn delegate_auctioneer<'info>(
         return Err(AuctionHouseError::TooManyScopes.into());
     }
 
+    if ctx.accounts.auction_house.has_governance {
+        let auctioneer_authority = ctx.accounts.auctioneer_authority.key();
+        consume_instruction_change(
+            &ctx.accounts.auction_house,
+            ctx.remaining_accounts,
+            |change| {
+                matches!(
+                    change.auctioneer_change(&auctioneer_authority),
+                    Some(auctioneer) if auctioneer.scopes.as_ref() == Some(&scopes)
+                )
+            },
+        )?;
+    }
+
     let auction_house = &mut ctx.accounts.auction_house;
 
-    if auction_house.has_auctioneer {
+    // Delegations made before several auctioneers were supported have to be migrated through
+    // `update_auctioneer` first so their scopes are stored on their own PDA.
+    if auction_house.has_auctioneer && auction_house.auctioneer_count == 0 {
         return Err(AuctionHouseError::AuctionHouseAlreadyDelegated.into());
     }
 
-    auction_house.has_auctioneer = true;
-    auction_house.auctioneer_address = ctx.accounts.ah_auctioneer_pda.key();
-
-    // Set all scopes false and then update as true the ones passed into the handler.
-    auction_house.scopes = [false; MAX_NUM_SCOPES];
+    let mut delegated_scopes = [false; MAX_NUM_SCOPES];
     for scope in scopes {
-        auction_house.scopes[scope as usize] = true;
+        delegated_scopes[scope as usize] = true;
     }
 
+    auction_house.add_delegation(&delegated_scopes)?;
+    // Kept for clients reading the most recent delegation.
+    auction_house.auctioneer_address = ctx.accounts.ah_auctioneer_pda.key();
+
     let auctioneer = &mut ctx.accounts.ah_auctioneer_pda;
     auctioneer.auctioneer_authority = ctx.accounts.auctioneer_authority.key();
     auctioneer.auction_house = ctx.accounts.auction_house.key();
@@ -72,6 +91,7 @@ pub fn delegate_auctioneer<'info>(
         .bumps
         .get("ah_auctioneer_pda")
         .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+    auctioneer.scopes = delegated_scopes;
 
     Ok(())
 }
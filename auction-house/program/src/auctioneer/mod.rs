@@ -1,4 +1,6 @@
 pub mod delegate;
+pub mod revoke;
 pub mod update;
 pub use delegate::*;
+pub use revoke::*;
 pub use update::*;
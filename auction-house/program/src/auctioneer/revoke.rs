@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*, errors::AuctionHouseError, governance::consume_instruction_change, AuctionHouse,
+    Auctioneer,
+};
+
+/// Accounts for the [`revoke_auctioneer` handler](auction_house/fn.revoke_auctioneer.html).
+#[derive(Accounts)]
+pub struct RevokeAuctioneer<'info> {
+    // Auction House instance PDA account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=authority
+    )]
+    pub auction_house: Account<'info, AuctionHouse>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Verified through the `has_one` constraint on the auctioneer PDA.
+    /// The auctioneer authority being revoked.
+    pub auctioneer_authority: UncheckedAccount<'info>,
+
+    /// The auctioneer PDA owned by Auction House, closed to the authority.
+    #[account(
+        mut,
+        close = authority,
+        seeds = [
+            AUCTIONEER.as_bytes(),
+            auction_house.key().as_ref(),
+            auctioneer_authority.key().as_ref()
+        ],
+        bump=ah_auctioneer_pda.bump,
+        has_one=auctioneer_authority
+    )]
+    pub ah_auctioneer_pda: Account<'info, Auctioneer>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn revoke_auctioneer<'info>(
+    ctx: Context<'_, '_, '_, 'info, RevokeAuctioneer<'info>>,
+) -> Result<()> {
+    if ctx.accounts.auction_house.has_governance {
+        let auctioneer_authority = ctx.accounts.auctioneer_authority.key();
+        consume_instruction_change(
+            &ctx.accounts.auction_house,
+            ctx.remaining_accounts,
+            |change| {
+                matches!(
+                    change.auctioneer_change(&auctioneer_authority),
+                    Some(auctioneer) if auctioneer.revoke
+                )
+            },
+        )?;
+    }
+
+    let auction_house = &mut ctx.accounts.auction_house;
+    if !auction_house.has_auctioneer {
+        return Err(AuctionHouseError::AuctionHouseNotDelegated.into());
+    }
+
+    let auctioneer = &ctx.accounts.ah_auctioneer_pda;
+    let delegated_scopes = auctioneer.delegated_scopes(auction_house);
+    auction_house.sync_legacy_delegation();
+    auction_house.remove_delegation(&delegated_scopes);
+
+    if auction_house.auctioneer_address == auctioneer.key() {
+        auction_house.auctioneer_address = Pubkey::default();
+    }
+
+    Ok(())
+}
@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
 
-use crate::{constants::*, errors::AuctionHouseError, AuctionHouse, Auctioneer, AuthorityScope};
+use crate::{
+    constants::*, errors::AuctionHouseError, governance::consume_instruction_change, AuctionHouse,
+    Auctioneer, AuctioneerLimits, AuthorityScope,
+};
 
 #[derive(Accounts)]
 pub struct UpdateAuctioneer<'info> {
@@ -48,20 +51,84 @@ pub fn update_auctioneer<'info>(
         return Err(AuctionHouseError::TooManyScopes.into());
     }
 
+    if ctx.accounts.auction_house.has_governance {
+        let auctioneer_authority = ctx.accounts.auctioneer_authority.key();
+        consume_instruction_change(
+            &ctx.accounts.auction_house,
+            ctx.remaining_accounts,
+            |change| {
+                matches!(
+                    change.auctioneer_change(&auctioneer_authority),
+                    Some(auctioneer) if auctioneer.scopes.as_ref() == Some(&scopes)
+                )
+            },
+        )?;
+    }
+
     let auction_house = &mut ctx.accounts.auction_house;
     if !auction_house.has_auctioneer {
         return Err(AuctionHouseError::AuctionHouseNotDelegated.into());
     }
 
-    // Set all scopes false and then update as true the ones passed into the handler.
-    auction_house.scopes = [false; MAX_NUM_SCOPES];
+    let mut delegated_scopes = [false; MAX_NUM_SCOPES];
+    for scope in scopes {
+        delegated_scopes[scope as usize] = true;
+    }
+
+    // Swap the previous scopes of this auctioneer for the new ones in the auction house counts.
+    let auctioneer = &mut ctx.accounts.ah_auctioneer_pda;
+    let previous_scopes = auctioneer.delegated_scopes(auction_house);
+    auction_house.sync_legacy_delegation();
+    auction_house.remove_scopes(&previous_scopes);
+    auction_house.add_scopes(&delegated_scopes)?;
+
+    auctioneer.scopes = delegated_scopes;
+
+    Ok(())
+}
+
+/// Replace the limits of an Auctioneer delegation and the scopes they apply to, resetting its
+/// count of executed sales.
+pub fn set_auctioneer_limits<'info>(
+    ctx: Context<'_, '_, '_, 'info, UpdateAuctioneer<'info>>,
+    scopes: Vec<AuthorityScope>,
+    limits: AuctioneerLimits,
+) -> Result<()> {
+    if scopes.len() > MAX_NUM_SCOPES {
+        return Err(AuctionHouseError::TooManyScopes.into());
+    }
+
+    if ctx.accounts.auction_house.has_governance {
+        let auctioneer_authority = ctx.accounts.auctioneer_authority.key();
+        consume_instruction_change(
+            &ctx.accounts.auction_house,
+            ctx.remaining_accounts,
+            |change| {
+                matches!(
+                    change
+                        .auctioneer_change(&auctioneer_authority)
+                        .and_then(|auctioneer| auctioneer.limits.as_ref()),
+                    Some(scope_limits)
+                        if scope_limits.scopes == scopes && scope_limits.limits == limits
+                )
+            },
+        )?;
+    }
+
+    let auction_house = &ctx.accounts.auction_house;
+    if !auction_house.has_auctioneer {
+        return Err(AuctionHouseError::AuctionHouseNotDelegated.into());
+    }
+
+    let mut limited_scopes = [false; MAX_NUM_SCOPES];
     for scope in scopes {
-        auction_house.scopes[scope as usize] = true;
+        limited_scopes[scope as usize] = true;
     }
 
     let auctioneer = &mut ctx.accounts.ah_auctioneer_pda;
-    auctioneer.auctioneer_authority = ctx.accounts.auctioneer_authority.key();
-    auctioneer.auction_house = ctx.accounts.auction_house.key();
+    auctioneer.limits = limits;
+    auctioneer.limited_scopes = limited_scopes;
+    auctioneer.sales_count = 0;
 
     Ok(())
 }
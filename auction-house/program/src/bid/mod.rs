@@ -3,7 +3,7 @@
 
 use anchor_lang::{
     prelude::*,
-    solana_program::{program::invoke, system_instruction},
+    solana_program::{program::invoke, program_option::COption, system_instruction},
     AnchorDeserialize,
 };
 use anchor_spl::token::{Mint, Token, TokenAccount};
@@ -11,7 +11,7 @@ use solana_program::program_memory::sol_memset;
 
 use crate::{
     constants::*, errors::AuctionHouseError, utils::*, AuctionHouse, Auctioneer, AuthorityScope,
-    TRADE_STATE_SIZE,
+    DELEGATED_TRADE_STATE_SIZE, PARTIAL_BID_TRADE_STATE_SIZE, TRADE_STATE_SIZE,
 };
 
 /// Accounts for the [`public_bid` handler](fn.public_bid.html).
@@ -129,6 +129,47 @@ pub fn public_bid(
         buyer_price,
         token_size,
         true,
+        false,
+        *ctx.bumps
+            .get("escrow_payment_account")
+            .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?,
+        *ctx.bumps
+            .get("buyer_trade_state")
+            .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?,
+    )
+}
+
+/// Create a public bid that sellers can fill in parts, e.g. a bid for 100 units of an SFT filled by
+/// several sellers holding fewer. The trade state tracks the tokens left to buy and the escrow
+/// amount reserved for them, and stays open until the whole bid is filled or canceled.
+pub fn partial_public_bid(
+    ctx: Context<PublicBuy>,
+    trade_state_bump: u8,
+    escrow_payment_bump: u8,
+    buyer_price: u64,
+    token_size: u64,
+) -> Result<()> {
+    bid_logic(
+        ctx.accounts.wallet.to_owned(),
+        ctx.accounts.payment_account.to_owned(),
+        ctx.accounts.transfer_authority.to_owned(),
+        *ctx.accounts.treasury_mint.to_owned(),
+        *ctx.accounts.token_account.to_owned(),
+        ctx.accounts.metadata.to_owned(),
+        ctx.accounts.escrow_payment_account.to_owned(),
+        ctx.accounts.authority.to_owned(),
+        *ctx.accounts.auction_house.to_owned(),
+        ctx.accounts.auction_house_fee_account.to_owned(),
+        ctx.accounts.buyer_trade_state.to_owned(),
+        ctx.accounts.token_program.to_owned(),
+        ctx.accounts.system_program.to_owned(),
+        ctx.accounts.rent.to_owned(),
+        trade_state_bump,
+        escrow_payment_bump,
+        buyer_price,
+        token_size,
+        true,
+        true,
         *ctx.bumps
             .get("escrow_payment_account")
             .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?,
@@ -406,6 +447,7 @@ pub fn private_bid<'info>(
         buyer_price,
         token_size,
         false,
+        false,
         *ctx.bumps
             .get("escrow_payment_account")
             .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?,
@@ -586,6 +628,7 @@ pub fn bid_logic<'info>(
     buyer_price: u64,
     token_size: u64,
     public: bool,
+    partial: bool,
     escrow_canonical_bump: u8,
     trade_state_canonical_bump: u8,
 ) -> Result<()> {
@@ -614,6 +657,17 @@ pub fn bid_logic<'info>(
         return Err(AuctionHouseError::BumpSeedNotInHashMap.into());
     }
 
+    let ts_info = buyer_trade_state.to_account_info();
+    let trade_state_size = if partial {
+        PARTIAL_BID_TRADE_STATE_SIZE
+    } else {
+        TRADE_STATE_SIZE
+    };
+    // A bid at the same price can't switch between being filled at once and in parts.
+    if !ts_info.data_is_empty() && (ts_info.data_len() == PARTIAL_BID_TRADE_STATE_SIZE) != partial {
+        return Err(AuctionHouseError::TradeStateIsNotEmpty.into());
+    }
+
     let auction_house_key = auction_house.key();
     let seeds = [
         PREFIX.as_bytes(),
@@ -651,15 +705,18 @@ pub fn bid_logic<'info>(
         fee_seeds,
         is_native,
     )?;
+    // Sending a partial bid again only tops the escrow up to the amount still reserved for the
+    // tokens left on it.
+    let escrow_amount = get_partial_bid(&ts_info)?.map_or(buyer_price, |(_, reserved)| reserved);
     if is_native {
         assert_keys_equal(wallet.key(), payment_account.key())?;
 
         if escrow_payment_account.lamports()
-            < buyer_price
+            < escrow_amount
                 .checked_add(rent.minimum_balance(escrow_payment_account.data_len()))
                 .ok_or(AuctionHouseError::NumericalOverflow)?
         {
-            let diff = buyer_price
+            let diff = escrow_amount
                 .checked_add(rent.minimum_balance(escrow_payment_account.data_len()))
                 .ok_or(AuctionHouseError::NumericalOverflow)?
                 .checked_sub(escrow_payment_account.lamports())
@@ -682,8 +739,8 @@ pub fn bid_logic<'info>(
         let escrow_payment_loaded: spl_token::state::Account =
             assert_initialized(&escrow_payment_account)?;
 
-        if escrow_payment_loaded.amount < buyer_price {
-            let diff = buyer_price
+        if escrow_payment_loaded.amount < escrow_amount {
+            let diff = escrow_amount
                 .checked_sub(escrow_payment_loaded.amount)
                 .ok_or(AuctionHouseError::NumericalOverflow)?;
             invoke(
@@ -706,7 +763,6 @@ pub fn bid_logic<'info>(
     }
     assert_metadata_valid(&metadata, &token_account)?;
 
-    let ts_info = buyer_trade_state.to_account_info();
     if ts_info.data_is_empty() {
         let wallet_key = wallet.key();
         let token_account_key = token_account.key();
@@ -717,7 +773,7 @@ pub fn bid_logic<'info>(
                 &rent.to_account_info(),
                 &system_program,
                 &fee_payer,
-                TRADE_STATE_SIZE,
+                trade_state_size,
                 fee_seeds,
                 &[
                     PREFIX.as_bytes(),
@@ -737,7 +793,7 @@ pub fn bid_logic<'info>(
                 &rent.to_account_info(),
                 &system_program,
                 &fee_payer,
-                TRADE_STATE_SIZE,
+                trade_state_size,
                 fee_seeds,
                 &[
                     PREFIX.as_bytes(),
@@ -757,8 +813,12 @@ pub fn bid_logic<'info>(
         sol_memset(
             *ts_info.try_borrow_mut_data()?,
             trade_state_bump,
-            TRADE_STATE_SIZE,
+            trade_state_size,
         );
+
+        if partial {
+            set_partial_bid(&ts_info, token_size, buyer_price)?;
+        }
     }
     // Allow The same bid to be sent with no issues
     Ok(())
@@ -800,6 +860,7 @@ pub fn auctioneer_bid_logic<'info>(
         &auctioneer_authority.key(),
         &ah_auctioneer_pda,
         AuthorityScope::Buy,
+        Some(buyer_price),
     )?;
 
     if (escrow_canonical_bump != escrow_payment_bump)
@@ -966,3 +1027,453 @@ pub fn auctioneer_bid_logic<'info>(
     // Allow The same bid to be sent with no issues
     Ok(())
 }
+
+/// Accounts for the [`delegated_public_bid` handler](fn.delegated_public_bid.html).
+#[derive(Accounts)]
+#[instruction(
+    trade_state_bump: u8,
+    escrow_payment_bump: u8,
+    buyer_price: u64,
+    token_size: u64
+)]
+pub struct DelegatedPublicBuy<'info> {
+    /// User wallet account.
+    wallet: Signer<'info>,
+
+    /// CHECK: Validated in delegated_bid_logic.
+    /// User SPL account delegating the bid price to the program as signer.
+    #[account(mut)]
+    payment_account: UncheckedAccount<'info>,
+
+    /// Auction House instance treasury mint account.
+    treasury_mint: Box<Account<'info, Mint>>,
+
+    /// SPL token account.
+    token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Validated in delegated_bid_logic.
+    /// SPL token account metadata.
+    metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Buyer escrow payment account PDA, funded from the payment account at settlement.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            wallet.key().as_ref()
+        ],
+        bump
+    )]
+    escrow_payment_account: UncheckedAccount<'info>,
+
+    /// CHECK: Verified with has_one constraint on auction house account.
+    /// Auction House instance authority account.
+    authority: UncheckedAccount<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump = auction_house.bump,
+        has_one = authority,
+        has_one = treasury_mint,
+        has_one = auction_house_fee_account
+    )]
+    auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Auction House instance fee account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            FEE_PAYER.as_bytes()
+        ],
+        bump = auction_house.fee_payer_bump
+    )]
+    auction_house_fee_account: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Buyer trade state PDA.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            wallet.key().as_ref(),
+            auction_house.key().as_ref(),
+            treasury_mint.key().as_ref(),
+            token_account.mint.as_ref(),
+            buyer_price.to_le_bytes().as_ref(),
+            token_size.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    buyer_trade_state: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    #[account(seeds = [PREFIX.as_bytes(), SIGNER.as_bytes()], bump)]
+    program_as_signer: UncheckedAccount<'info>,
+
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+    rent: Sysvar<'info, Rent>,
+}
+
+/// Create a public bid funded at settlement from a payment account delegated to the program as
+/// signer, instead of from funds locked in the buyer escrow. Only available for SPL treasury mints.
+pub fn delegated_public_bid<'info>(
+    ctx: Context<'_, '_, '_, 'info, DelegatedPublicBuy<'info>>,
+    trade_state_bump: u8,
+    escrow_payment_bump: u8,
+    buyer_price: u64,
+    token_size: u64,
+) -> Result<()> {
+    delegated_bid_logic(
+        ctx.accounts.wallet.to_owned(),
+        ctx.accounts.payment_account.to_owned(),
+        *ctx.accounts.treasury_mint.to_owned(),
+        *ctx.accounts.token_account.to_owned(),
+        ctx.accounts.metadata.to_owned(),
+        ctx.accounts.escrow_payment_account.to_owned(),
+        ctx.accounts.authority.to_owned(),
+        *ctx.accounts.auction_house.to_owned(),
+        ctx.accounts.auction_house_fee_account.to_owned(),
+        ctx.accounts.buyer_trade_state.to_owned(),
+        ctx.accounts.program_as_signer.to_owned(),
+        ctx.accounts.token_program.to_owned(),
+        ctx.accounts.system_program.to_owned(),
+        ctx.accounts.rent.to_owned(),
+        trade_state_bump,
+        escrow_payment_bump,
+        buyer_price,
+        token_size,
+        true,
+        *ctx.bumps
+            .get("escrow_payment_account")
+            .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?,
+        *ctx.bumps
+            .get("buyer_trade_state")
+            .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?,
+    )
+}
+
+/// Accounts for the [`delegated_private_bid` handler](fn.delegated_private_bid.html).
+#[derive(Accounts)]
+#[instruction(
+    trade_state_bump: u8,
+    escrow_payment_bump: u8,
+    buyer_price: u64,
+    token_size: u64
+)]
+pub struct DelegatedBuy<'info> {
+    /// User wallet account.
+    wallet: Signer<'info>,
+
+    /// CHECK: Validated in delegated_bid_logic.
+    /// User SPL account delegating the bid price to the program as signer.
+    #[account(mut)]
+    payment_account: UncheckedAccount<'info>,
+
+    /// Auction House instance treasury mint account.
+    treasury_mint: Box<Account<'info, Mint>>,
+
+    /// SPL token account.
+    token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Validated in delegated_bid_logic.
+    /// SPL token account metadata.
+    metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Buyer escrow payment account PDA, funded from the payment account at settlement.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            wallet.key().as_ref()
+        ],
+        bump
+    )]
+    escrow_payment_account: UncheckedAccount<'info>,
+
+    /// CHECK: Verified with has_one constraint on auction house account.
+    /// Auction House instance authority account.
+    authority: UncheckedAccount<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump = auction_house.bump,
+        has_one = authority,
+        has_one = treasury_mint,
+        has_one = auction_house_fee_account
+    )]
+    auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Auction House instance fee account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            FEE_PAYER.as_bytes()
+        ],
+        bump = auction_house.fee_payer_bump
+    )]
+    auction_house_fee_account: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Buyer trade state PDA.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            wallet.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            treasury_mint.key().as_ref(),
+            token_account.mint.as_ref(),
+            buyer_price.to_le_bytes().as_ref(),
+            token_size.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    buyer_trade_state: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    #[account(seeds = [PREFIX.as_bytes(), SIGNER.as_bytes()], bump)]
+    program_as_signer: UncheckedAccount<'info>,
+
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+    rent: Sysvar<'info, Rent>,
+}
+
+/// Create a private bid on a specific SPL token that is *held by a specific wallet*, funded at
+/// settlement from a payment account delegated to the program as signer.
+pub fn delegated_private_bid<'info>(
+    ctx: Context<'_, '_, '_, 'info, DelegatedBuy<'info>>,
+    trade_state_bump: u8,
+    escrow_payment_bump: u8,
+    buyer_price: u64,
+    token_size: u64,
+) -> Result<()> {
+    delegated_bid_logic(
+        ctx.accounts.wallet.to_owned(),
+        ctx.accounts.payment_account.to_owned(),
+        *ctx.accounts.treasury_mint.to_owned(),
+        *ctx.accounts.token_account.to_owned(),
+        ctx.accounts.metadata.to_owned(),
+        ctx.accounts.escrow_payment_account.to_owned(),
+        ctx.accounts.authority.to_owned(),
+        *ctx.accounts.auction_house.to_owned(),
+        ctx.accounts.auction_house_fee_account.to_owned(),
+        ctx.accounts.buyer_trade_state.to_owned(),
+        ctx.accounts.program_as_signer.to_owned(),
+        ctx.accounts.token_program.to_owned(),
+        ctx.accounts.system_program.to_owned(),
+        ctx.accounts.rent.to_owned(),
+        trade_state_bump,
+        escrow_payment_bump,
+        buyer_price,
+        token_size,
+        false,
+        *ctx.bumps
+            .get("escrow_payment_account")
+            .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?,
+        *ctx.bumps
+            .get("buyer_trade_state")
+            .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?,
+    )
+}
+
+/// Handles the bid logic for both private and public delegated bids.
+///
+/// Instead of moving funds into the escrow, the bid price is added to the allowance of the program
+/// as signer on the payment account. `execute_sale` pulls the funds when the bid is matched.
+#[allow(clippy::too_many_arguments)]
+pub fn delegated_bid_logic<'info>(
+    wallet: Signer<'info>,
+    payment_account: UncheckedAccount<'info>,
+    treasury_mint: Account<'info, Mint>,
+    token_account: Account<'info, TokenAccount>,
+    metadata: UncheckedAccount<'info>,
+    escrow_payment_account: UncheckedAccount<'info>,
+    authority: UncheckedAccount<'info>,
+    auction_house: Account<'info, AuctionHouse>,
+    auction_house_fee_account: UncheckedAccount<'info>,
+    buyer_trade_state: UncheckedAccount<'info>,
+    program_as_signer: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+    rent: Sysvar<'info, Rent>,
+    trade_state_bump: u8,
+    escrow_payment_bump: u8,
+    buyer_price: u64,
+    token_size: u64,
+    public: bool,
+    escrow_canonical_bump: u8,
+    trade_state_canonical_bump: u8,
+) -> Result<()> {
+    // If it has an auctioneer authority delegated must use auctioneer_* handler.
+    if (auction_house.scopes[AuthorityScope::PublicBuy as usize] || !public)
+        && (auction_house.scopes[AuthorityScope::Buy as usize] || public)
+        && auction_house.has_auctioneer
+    {
+        return Err(AuctionHouseError::MustUseAuctioneerHandler.into());
+    }
+
+    if treasury_mint.key() == spl_token::native_mint::id() {
+        return Err(AuctionHouseError::DelegatedBidRequiresSplTreasuryMint.into());
+    }
+
+    assert_valid_trade_state(
+        &wallet.key(),
+        &auction_house,
+        buyer_price,
+        token_size,
+        &buyer_trade_state,
+        &token_account.mint.key(),
+        &token_account.key(),
+        trade_state_bump,
+    )?;
+
+    if (escrow_canonical_bump != escrow_payment_bump)
+        || (trade_state_canonical_bump != trade_state_bump)
+    {
+        return Err(AuctionHouseError::BumpSeedNotInHashMap.into());
+    }
+
+    let ts_info = buyer_trade_state.to_account_info();
+    if !ts_info.data_is_empty() {
+        // An escrow backed bid at the same price can't be turned into a delegated one.
+        if ts_info.data_len() != DELEGATED_TRADE_STATE_SIZE {
+            return Err(AuctionHouseError::TradeStateIsNotEmpty.into());
+        }
+
+        // Allow The same bid to be sent with no issues
+        return Ok(());
+    }
+
+    let auction_house_key = auction_house.key();
+    let seeds = [
+        PREFIX.as_bytes(),
+        auction_house_key.as_ref(),
+        FEE_PAYER.as_bytes(),
+        &[auction_house.fee_payer_bump],
+    ];
+    let (fee_payer, fee_seeds) = get_fee_payer(
+        &authority,
+        &auction_house,
+        wallet.to_account_info(),
+        auction_house_fee_account.to_account_info(),
+        &seeds,
+    )?;
+
+    // The escrow stays empty until settlement, but has to exist to receive the funds.
+    let wallet_key = wallet.key();
+    let escrow_signer_seeds = [
+        PREFIX.as_bytes(),
+        auction_house_key.as_ref(),
+        wallet_key.as_ref(),
+        &[escrow_payment_bump],
+    ];
+    create_program_token_account_if_not_present(
+        &escrow_payment_account,
+        &system_program,
+        &fee_payer,
+        &token_program,
+        &treasury_mint,
+        &auction_house.to_account_info(),
+        &rent,
+        &escrow_signer_seeds,
+        fee_seeds,
+        false,
+    )?;
+
+    assert_owned_by(&payment_account, &spl_token::id())?;
+    let payment_account_data: spl_token::state::Account = assert_initialized(&payment_account)?;
+    assert_keys_equal(payment_account_data.owner, wallet.key())?;
+    assert_keys_equal(payment_account_data.mint, treasury_mint.key())?;
+
+    // A token account has a single delegate, so the allowance covers all open delegated bids and
+    // is lowered again when one of them is canceled. A delegation to anyone else is kept.
+    let allowance = match payment_account_data.delegate {
+        COption::Some(delegate) if delegate == program_as_signer.key() => payment_account_data
+            .delegated_amount
+            .checked_add(buyer_price)
+            .ok_or(AuctionHouseError::NumericalOverflow)?,
+        COption::Some(_) => return Err(AuctionHouseError::PaymentAccountDelegated.into()),
+        COption::None => buyer_price,
+    };
+    invoke(
+        &spl_token::instruction::approve(
+            &token_program.key(),
+            &payment_account.key(),
+            &program_as_signer.key(),
+            &wallet.key(),
+            &[],
+            allowance,
+        )?,
+        &[
+            payment_account.to_account_info(),
+            program_as_signer.to_account_info(),
+            wallet.to_account_info(),
+            token_program.to_account_info(),
+        ],
+    )?;
+
+    assert_metadata_valid(&metadata, &token_account)?;
+
+    let token_account_key = token_account.key();
+    let mut trade_state_seeds = vec![
+        PREFIX.as_bytes(),
+        wallet_key.as_ref(),
+        auction_house_key.as_ref(),
+    ];
+    if !public {
+        trade_state_seeds.push(token_account_key.as_ref());
+    }
+    let buyer_price_bytes = buyer_price.to_le_bytes();
+    let token_size_bytes = token_size.to_le_bytes();
+    let trade_state_bump_bytes = [trade_state_bump];
+    trade_state_seeds.extend([
+        auction_house.treasury_mint.as_ref(),
+        token_account.mint.as_ref(),
+        &buyer_price_bytes,
+        &token_size_bytes,
+        &trade_state_bump_bytes,
+    ]);
+
+    create_or_allocate_account_raw(
+        crate::id(),
+        &ts_info,
+        &rent.to_account_info(),
+        &system_program,
+        &fee_payer,
+        DELEGATED_TRADE_STATE_SIZE,
+        fee_seeds,
+        &trade_state_seeds,
+    )?;
+
+    #[allow(clippy::explicit_auto_deref)]
+    sol_memset(
+        *ts_info.try_borrow_mut_data()?,
+        trade_state_bump,
+        DELEGATED_TRADE_STATE_SIZE,
+    );
+
+    Ok(())
+}
@@ -213,6 +213,7 @@ pub fn auctioneer_cancel<'info>(
         &auctioneer_authority.key(),
         ah_auctioneer_pda,
         AuthorityScope::Cancel,
+        None,
     )?;
 
     let mut accounts: Cancel<'info> = (*ctx.accounts).clone().into();
@@ -275,7 +276,22 @@ fn cancel_logic<'c, 'info>(
 
     let remaining_accounts = &mut remaining_accounts.iter();
 
-    if token_account.owner == wallet.key() && wallet.is_signer {
+    if trade_state.data_len() == DELEGATED_TRADE_STATE_SIZE {
+        // Only the buyer can lower the allowance backing a delegated bid, passing the payment
+        // account and the program as signer. The authority canceling the bid leaves it in place.
+        if wallet.is_signer {
+            let payment_account = next_account_info(remaining_accounts)?;
+            let program_as_signer = next_account_info(remaining_accounts)?;
+            release_delegated_bid_funds(
+                payment_account,
+                &wallet.to_account_info(),
+                &auction_house.treasury_mint,
+                program_as_signer,
+                &token_program.to_account_info(),
+                buyer_price,
+            )?;
+        }
+    } else if token_account.owner == wallet.key() && wallet.is_signer {
         match next_account_info(remaining_accounts) {
             Ok(metadata_program) => {
                 require!(
@@ -361,8 +377,10 @@ fn cancel_logic<'c, 'info>(
         .checked_add(curr_lamp)
         .ok_or(AuctionHouseError::NumericalOverflow)?;
 
+    // Delegated bid trade states are shorter than the others.
+    let trade_state_size = trade_state.data_len().min(TRADE_STATE_SIZE);
     #[allow(clippy::explicit_auto_deref)]
-    sol_memset(*trade_state.try_borrow_mut_data()?, 0, TRADE_STATE_SIZE);
+    sol_memset(*trade_state.try_borrow_mut_data()?, 0, trade_state_size);
 
     Ok(())
 }
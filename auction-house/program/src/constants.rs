@@ -6,13 +6,30 @@ pub const PURCHASE_RECEIPT_PREFIX: &str = "purchase_receipt";
 pub const BID_RECEIPT_PREFIX: &str = "bid_receipt";
 pub const LISTING_RECEIPT_PREFIX: &str = "listing_receipt";
 pub const AUCTIONEER: &str = "auctioneer";
+pub const GOVERNANCE: &str = "governance";
+pub const PENDING_CHANGE: &str = "pending_change";
+pub const COLLECTION_STATS: &str = "collection_stats";
+pub const FEE_DISCOUNT_SCHEDULE: &str = "fee_discount_schedule";
+pub const COUNTER_OFFER: &str = "counter_offer";
 pub const TRADE_STATE_SIZE: usize = 1;
+// Delegated bids use a larger trade state so settlement knows to pull funds from the buyer.
+pub const DELEGATED_TRADE_STATE_SIZE: usize = 2;
+// Scheduled listings store the time they become buyable after the trade state bump.
+pub const SCHEDULED_TRADE_STATE_SIZE: usize = 1 + 8;
+// Partially fillable bids store the tokens left to buy and the escrow amount reserved for them.
+pub const PARTIAL_BID_TRADE_STATE_SIZE: usize = 1 + 8 + 8;
 pub const MAX_NUM_SCOPES: usize = 7;
 pub const AUCTIONEER_SIZE: usize = 8 +                      // Anchor discriminator/sighash
 32 +                                                        // Auctioneer authority
 32 +                                                        // Auction house instance
 1 +                                                         // bump
-63                                                          // Padding
+1 + 8 +                                                     // limits.expires_at
+1 + 8 +                                                     // limits.max_sale_price
+1 + 8 +                                                     // limits.max_sales
+8 +                                                         // sales count
+MAX_NUM_SCOPES +                                            // Array of AuthorityScope bools
+MAX_NUM_SCOPES +                                            // Array of limited AuthorityScope bools
+14                                                          // Padding
 ;
 
 pub const AUCTION_HOUSE_SIZE: usize = 8 +                   // key
@@ -33,5 +50,10 @@ pub const AUCTION_HOUSE_SIZE: usize = 8 +                   // key
 1 +                                                         // has external auctioneer program as an authority
 32 +                                                         // auctioneer address
 MAX_NUM_SCOPES +                                            // Array of AuthorityScope bools
-172                                                         // padding
+2 +                                                         // max referral basis points
+1 +                                                         // has governance
+8 +                                                         // receipt retention period
+1 +                                                         // auctioneer count
+MAX_NUM_SCOPES +                                            // Auctioneers delegated each AuthorityScope
+153                                                         // padding
 ;
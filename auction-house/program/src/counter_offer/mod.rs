@@ -0,0 +1,417 @@
+//! Counter-offers from sellers to bids.
+//!
+//! A seller answers a bid receipt by listing the item at a counter price and recording the
+//! counter-offer in a `counter_offer` PDA. The bidder accepts it by topping up their escrow and
+//! buying the listing in the same instruction, while either side can withdraw it. Every
+//! counter-offer keeps a link to the bid receipt it answers so indexers can follow the chain.
+//!
+//! Accepting a counter-offer records a purchase receipt for the sale and links it from the bid
+//! receipt. The `counter_offer` account is closed back to the seller once it is accepted or
+//! withdrawn, and anyone can close it after the listing it points to has been canceled or sold.
+use crate::{
+    constants::*,
+    errors::AuctionHouseError,
+    receipt::{BidReceipt, PurchaseReceipt, PURCHASE_RECEIPT_SIZE},
+    sweep::*,
+    utils::*,
+    AuctionHouse, AuthorityScope,
+};
+use anchor_lang::{
+    prelude::*,
+    solana_program::{program::invoke, program_pack::Pack, system_instruction},
+    AnchorDeserialize,
+};
+use spl_token::state::Account as SplAccount;
+
+pub const COUNTER_OFFER_SIZE: usize = 8 + // key
+32 + // auction_house
+32 + // bid_receipt
+32 + // buyer_trade_state
+32 + // buyer
+32 + // seller
+32 + // metadata
+32 + // seller_trade_state
+8 + // bid_price
+8 + // price
+8 + // token_size
+8 + // created_at
+1; // bump
+
+/// Counter price proposed by a seller in response to a bid.
+#[account]
+pub struct CounterOffer {
+    pub auction_house: Pubkey,
+    pub bid_receipt: Pubkey,
+    pub buyer_trade_state: Pubkey,
+    pub buyer: Pubkey,
+    pub seller: Pubkey,
+    pub metadata: Pubkey,
+    pub seller_trade_state: Pubkey,
+    pub bid_price: u64,
+    pub price: u64,
+    pub token_size: u64,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+/// Accounts for the [`make_counter_offer` handler](auction_house/fn.make_counter_offer.html).
+#[derive(Accounts)]
+pub struct MakeCounterOffer<'info> {
+    /// Seller user wallet account.
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// Receipt of the bid to counter.
+    #[account(constraint = bid_receipt.auction_house == auction_house.key() @ AuctionHouseError::PublicKeyMismatch)]
+    pub bid_receipt: Box<Account<'info, BidReceipt>>,
+
+    /// CHECK: Validated in make_counter_offer.
+    /// Seller token account holding the item.
+    pub token_account: UncheckedAccount<'info>,
+
+    /// CHECK: Validated in make_counter_offer.
+    /// Seller trade state listing the item at the counter price.
+    pub seller_trade_state: UncheckedAccount<'info>,
+
+    /// Counter-offer PDA account.
+    #[account(
+        init,
+        payer = seller,
+        space = COUNTER_OFFER_SIZE,
+        seeds = [
+            COUNTER_OFFER.as_bytes(),
+            bid_receipt.key().as_ref(),
+            seller_trade_state.key().as_ref()
+        ],
+        bump
+    )]
+    pub counter_offer: Account<'info, CounterOffer>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for the [`accept_counter_offer` handler](auction_house/fn.accept_counter_offer.html).
+#[derive(Accounts)]
+pub struct AcceptCounterOffer<'info> {
+    /// Accounts buying the counter-offer listing from the buyer escrow.
+    pub sweep: Sweep<'info>,
+
+    /// Counter-offer PDA account, closed once the counter-offer is accepted.
+    #[account(
+        mut,
+        close = seller,
+        seeds = [
+            COUNTER_OFFER.as_bytes(),
+            counter_offer.bid_receipt.as_ref(),
+            counter_offer.seller_trade_state.as_ref()
+        ],
+        bump=counter_offer.bump,
+        constraint = counter_offer.buyer == sweep.buyer.key() @ AuctionHouseError::PublicKeyMismatch,
+        constraint = counter_offer.auction_house == sweep.auction_house.key() @ AuctionHouseError::PublicKeyMismatch
+    )]
+    pub counter_offer: Account<'info, CounterOffer>,
+
+    /// Receipt of the countered bid, linked to the purchase receipt once the counter-offer is accepted.
+    #[account(mut, address = counter_offer.bid_receipt)]
+    pub bid_receipt: Box<Account<'info, BidReceipt>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Purchase receipt PDA account recording the accepted counter-offer.
+    #[account(
+        mut,
+        seeds = [
+            PURCHASE_RECEIPT_PREFIX.as_bytes(),
+            counter_offer.seller_trade_state.as_ref(),
+            counter_offer.buyer_trade_state.as_ref()
+        ],
+        bump
+    )]
+    pub purchase_receipt: UncheckedAccount<'info>,
+
+    /// CHECK: Validated against the counter-offer.
+    /// Seller wallet receiving the counter-offer rent.
+    #[account(mut, address = counter_offer.seller)]
+    pub seller: UncheckedAccount<'info>,
+
+    /// CHECK: Validated against the counter-offer.
+    /// Buyer trade state of the countered bid, closed once the counter-offer is accepted.
+    #[account(mut, address = counter_offer.buyer_trade_state)]
+    pub buyer_trade_state: UncheckedAccount<'info>,
+
+    /// CHECK: Validated in accept_counter_offer.
+    /// Buyer SOL or SPL account topping up the escrow.
+    #[account(mut)]
+    pub payment_account: UncheckedAccount<'info>,
+    // The remaining accounts hold the listing group of the counter-offer, laid out as in `sweep`.
+}
+
+/// Accounts for the [`withdraw_counter_offer` handler](auction_house/fn.withdraw_counter_offer.html).
+#[derive(Accounts)]
+pub struct WithdrawCounterOffer<'info> {
+    /// Buyer or seller of the counter-offer, or anyone once the counter-offer has expired.
+    pub wallet: Signer<'info>,
+
+    /// Counter-offer PDA account.
+    #[account(
+        mut,
+        close = seller,
+        seeds = [
+            COUNTER_OFFER.as_bytes(),
+            counter_offer.bid_receipt.as_ref(),
+            counter_offer.seller_trade_state.as_ref()
+        ],
+        bump=counter_offer.bump
+    )]
+    pub counter_offer: Account<'info, CounterOffer>,
+
+    /// CHECK: Validated against the counter-offer.
+    /// Seller wallet receiving the counter-offer rent.
+    #[account(mut, address = counter_offer.seller)]
+    pub seller: UncheckedAccount<'info>,
+
+    /// CHECK: Validated against the counter-offer.
+    /// Seller trade state of the counter-offer listing, empty once the listing is canceled or sold.
+    #[account(address = counter_offer.seller_trade_state)]
+    pub seller_trade_state: UncheckedAccount<'info>,
+}
+
+pub fn make_counter_offer<'info>(
+    ctx: Context<'_, '_, '_, 'info, MakeCounterOffer<'info>>,
+    price: u64,
+) -> Result<()> {
+    let seller = &ctx.accounts.seller;
+    let auction_house = &ctx.accounts.auction_house;
+    let bid_receipt = &ctx.accounts.bid_receipt;
+    let token_account = &ctx.accounts.token_account;
+    let seller_trade_state = &ctx.accounts.seller_trade_state;
+
+    if bid_receipt.canceled_at.is_some() || bid_receipt.purchase_receipt.is_some() {
+        return Err(AuctionHouseError::BidReceiptClosed.into());
+    }
+
+    if price == 0 || price == bid_receipt.price {
+        return Err(AuctionHouseError::InvalidCounterOfferPrice.into());
+    }
+
+    // Private bids can only be countered for the token account they were made on.
+    if let Some(bid_token_account) = bid_receipt.token_account {
+        assert_keys_equal(bid_token_account, token_account.key())?;
+    }
+
+    assert_owned_by(token_account, &spl_token::id())?;
+    let token_account_data: SplAccount = assert_initialized(token_account)?;
+    assert_keys_equal(token_account_data.owner, seller.key())?;
+    let (metadata, _) = mpl_token_metadata::pda::find_metadata_account(&token_account_data.mint);
+    assert_keys_equal(metadata, bid_receipt.metadata)?;
+
+    if seller_trade_state.data_is_empty() {
+        return Err(AuctionHouseError::TradeStateDoesntExist.into());
+    }
+    let auction_house_key = auction_house.key();
+    let seller_trade_state_key = Pubkey::create_program_address(
+        &[
+            PREFIX.as_bytes(),
+            seller.key.as_ref(),
+            auction_house_key.as_ref(),
+            token_account.key.as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_account_data.mint.as_ref(),
+            &price.to_le_bytes(),
+            &bid_receipt.token_size.to_le_bytes(),
+            &[seller_trade_state.try_borrow_data()?[0]],
+        ],
+        &crate::id(),
+    )
+    .map_err(|_| AuctionHouseError::DerivedKeyInvalid)?;
+    assert_keys_equal(seller_trade_state_key, seller_trade_state.key())?;
+
+    let counter_offer = &mut ctx.accounts.counter_offer;
+    counter_offer.auction_house = auction_house_key;
+    counter_offer.bid_receipt = bid_receipt.key();
+    counter_offer.buyer_trade_state = bid_receipt.trade_state;
+    counter_offer.buyer = bid_receipt.buyer;
+    counter_offer.seller = seller.key();
+    counter_offer.metadata = metadata;
+    counter_offer.seller_trade_state = seller_trade_state.key();
+    counter_offer.bid_price = bid_receipt.price;
+    counter_offer.price = price;
+    counter_offer.token_size = bid_receipt.token_size;
+    counter_offer.created_at = Clock::get()?.unix_timestamp;
+    counter_offer.bump = *ctx
+        .bumps
+        .get("counter_offer")
+        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+
+    Ok(())
+}
+
+pub fn accept_counter_offer<'info>(
+    ctx: Context<'_, '_, '_, 'info, AcceptCounterOffer<'info>>,
+) -> Result<()> {
+    let accounts = &ctx.accounts.sweep;
+    let counter_offer = &ctx.accounts.counter_offer;
+    let payment_account = &ctx.accounts.payment_account;
+    let auction_house = &accounts.auction_house;
+    let buyer = &accounts.buyer;
+    let escrow_payment_account = &accounts.escrow_payment_account;
+
+    // If it has an auctioneer authority delegated must use auctioneer_* handler.
+    if auction_house.has_auctioneer && auction_house.scopes[AuthorityScope::ExecuteSale as usize] {
+        return Err(AuctionHouseError::MustUseAuctioneerHandler.into());
+    }
+
+    match ctx.remaining_accounts.first() {
+        Some(seller_trade_state) => {
+            assert_keys_equal(seller_trade_state.key(), counter_offer.seller_trade_state)?
+        }
+        None => return Err(ErrorCode::AccountNotEnoughKeys.into()),
+    }
+
+    let escrow_payment_bump = *ctx
+        .bumps
+        .get("escrow_payment_account")
+        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+    let program_as_signer_bump = *ctx
+        .bumps
+        .get("program_as_signer")
+        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+
+    // Top up the escrow so it covers the counter price on top of its rent exemption.
+    let is_native = accounts.treasury_mint.key() == spl_token::native_mint::id();
+    let available = if is_native {
+        escrow_payment_account
+            .lamports()
+            .saturating_sub(Rent::get()?.minimum_balance(escrow_payment_account.data_len()))
+    } else if escrow_payment_account.data_is_empty() {
+        0
+    } else {
+        SplAccount::unpack(&escrow_payment_account.try_borrow_data()?)?.amount
+    };
+    let top_up = counter_offer.price.saturating_sub(available);
+
+    if top_up > 0 {
+        if is_native {
+            assert_keys_equal(payment_account.key(), buyer.key())?;
+            invoke(
+                &system_instruction::transfer(
+                    &payment_account.key(),
+                    &escrow_payment_account.key(),
+                    top_up,
+                ),
+                &[
+                    payment_account.to_account_info(),
+                    escrow_payment_account.to_account_info(),
+                    accounts.system_program.to_account_info(),
+                ],
+            )?;
+        } else {
+            assert_is_ata(payment_account, &buyer.key(), &accounts.treasury_mint.key())?;
+            invoke(
+                &spl_token::instruction::transfer(
+                    &accounts.token_program.key(),
+                    &payment_account.key(),
+                    &escrow_payment_account.key(),
+                    &buyer.key(),
+                    &[],
+                    top_up,
+                )?,
+                &[
+                    payment_account.to_account_info(),
+                    escrow_payment_account.to_account_info(),
+                    accounts.token_program.to_account_info(),
+                    buyer.to_account_info(),
+                ],
+            )?;
+        }
+    }
+
+    let remaining_accounts = &mut ctx.remaining_accounts.iter();
+    sweep_listing(
+        accounts,
+        remaining_accounts,
+        escrow_payment_bump,
+        program_as_signer_bump,
+        &SweepListing {
+            price: counter_offer.price,
+            token_size: counter_offer.token_size,
+        },
+        false,
+        None,
+    )?;
+
+    // The countered bid is superseded by the purchase.
+    let buyer_trade_state = &ctx.accounts.buyer_trade_state;
+    if buyer_trade_state.owner == &crate::id() && buyer_trade_state.lamports() > 0 {
+        close_account(buyer_trade_state, &buyer.to_account_info())?;
+    }
+
+    // Record the sale; the counter-offer account itself is closed on exit.
+    let purchase_receipt = &ctx.accounts.purchase_receipt;
+    let purchase_receipt_bump = *ctx
+        .bumps
+        .get("purchase_receipt")
+        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+
+    if purchase_receipt.data_is_empty() {
+        let purchase_receipt_seeds = [
+            PURCHASE_RECEIPT_PREFIX.as_bytes(),
+            counter_offer.seller_trade_state.as_ref(),
+            counter_offer.buyer_trade_state.as_ref(),
+            &[purchase_receipt_bump],
+        ];
+
+        create_or_allocate_account_raw(
+            crate::id(),
+            &purchase_receipt.to_account_info(),
+            &accounts.rent.to_account_info(),
+            &accounts.system_program.to_account_info(),
+            &buyer.to_account_info(),
+            PURCHASE_RECEIPT_SIZE,
+            &[],
+            &purchase_receipt_seeds,
+        )?;
+    }
+
+    let purchase = PurchaseReceipt {
+        buyer: buyer.key(),
+        seller: counter_offer.seller,
+        auction_house: auction_house.key(),
+        metadata: counter_offer.metadata,
+        bookkeeper: buyer.key(),
+        bump: purchase_receipt_bump,
+        price: counter_offer.price,
+        token_size: counter_offer.token_size,
+        created_at: Clock::get()?.unix_timestamp,
+    };
+    purchase.try_serialize(&mut *purchase_receipt.try_borrow_mut_data()?)?;
+
+    ctx.accounts.bid_receipt.purchase_receipt = Some(purchase_receipt.key());
+
+    Ok(())
+}
+
+pub fn withdraw_counter_offer<'info>(
+    ctx: Context<'_, '_, '_, 'info, WithdrawCounterOffer<'info>>,
+) -> Result<()> {
+    let wallet = ctx.accounts.wallet.key();
+    let expired = ctx.accounts.seller_trade_state.data_is_empty();
+    let counter_offer = &ctx.accounts.counter_offer;
+
+    if !expired && wallet != counter_offer.buyer && wallet != counter_offer.seller {
+        return Err(AuctionHouseError::NoValidSignerPresent.into());
+    }
+
+    // Closing the counter-offer account on exit withdraws it.
+    Ok(())
+}
@@ -214,6 +214,7 @@ pub fn auctioneer_deposit<'info>(
         &auctioneer_authority.key(),
         ah_auctioneer_pda,
         AuthorityScope::Deposit,
+        None,
     )?;
 
     if escrow_payment_bump
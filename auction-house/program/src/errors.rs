@@ -181,4 +181,136 @@ pub enum AuctionHouseError {
     // 6044
     #[msg("This sale requires exactly one signer: either the seller or the authority.")]
     SaleRequiresExactlyOneSigner,
+
+    // 6045
+    #[msg("Referral basis points exceed the maximum allowed by the Auction House.")]
+    InvalidReferralBasisPoints,
+
+    // 6046
+    #[msg("Auction House is governed, changes must go through a governance proposal.")]
+    GovernanceEnabled,
+
+    // 6047
+    #[msg("Too many governance signers.")]
+    TooManyGovernanceSigners,
+
+    // 6048
+    #[msg("Governance threshold must be between one and the number of signers.")]
+    InvalidGovernanceThreshold,
+
+    // 6049
+    #[msg("Governance delay cannot be negative.")]
+    InvalidGovernanceDelay,
+
+    // 6050
+    #[msg("Signer is not part of the Auction House governance.")]
+    NotAGovernanceSigner,
+
+    // 6051
+    #[msg("Change already approved by this signer.")]
+    ChangeAlreadyApproved,
+
+    // 6052
+    #[msg("Change has not been approved by enough governance signers.")]
+    ChangeNotApproved,
+
+    // 6053
+    #[msg("Change is still timelocked.")]
+    ChangeTimelocked,
+
+    // 6054
+    #[msg("Listing is no longer available for purchase.")]
+    ListingUnavailable,
+
+    // 6055
+    #[msg("Delegated bids are only available for SPL treasury mints.")]
+    DelegatedBidRequiresSplTreasuryMint,
+
+    // 6056
+    #[msg("Payment account allowance for the Auction House does not cover the bid price.")]
+    InsufficientDelegatedFunds,
+
+    // 6057
+    #[msg("Metadata does not belong to a verified collection.")]
+    CollectionNotVerified,
+
+    // 6058
+    #[msg("Too many fee discounts.")]
+    TooManyFeeDiscounts,
+
+    // 6059
+    #[msg("Fee discount proof is not a discounted NFT held by the buyer or seller.")]
+    InvalidFeeDiscountProof,
+
+    // 6060
+    #[msg("Counter offer was already accepted or withdrawn.")]
+    CounterOfferClosed,
+
+    // 6061
+    #[msg("Bid was canceled or already filled.")]
+    BidReceiptClosed,
+
+    // 6062
+    #[msg("Counter offer price must be non-zero and differ from the bid price.")]
+    InvalidCounterOfferPrice,
+
+    // 6063
+    #[msg("Receipt must be canceled or fulfilled before it can be closed.")]
+    ReceiptIsActive,
+
+    // 6064
+    #[msg("Receipt is still within the Auction House retention period.")]
+    ReceiptRetentionPeriodActive,
+
+    // 6065
+    #[msg("Receipt retention period cannot be negative.")]
+    InvalidReceiptRetentionPeriod,
+
+    // 6066
+    #[msg("Auctioneer delegation has expired.")]
+    AuctioneerDelegationExpired,
+
+    // 6067
+    #[msg("Price is above the Auctioneer delegation limit.")]
+    AuctioneerPriceLimitExceeded,
+
+    // 6068
+    #[msg("Auctioneer delegation has reached its maximum number of sales.")]
+    AuctioneerSaleLimitReached,
+
+    // 6069
+    #[msg("Listing cannot be bought before its start time.")]
+    ListingNotStarted,
+
+    // 6070
+    #[msg("Sale exceeds the tokens left on the bid.")]
+    PartialBidOverfilled,
+
+    // 6071
+    #[msg("Partially fillable bids can't be settled by an auctioneer.")]
+    PartialBidNotSupported,
+
+    // 6072
+    #[msg("Change authorizes an instruction and can only be executed by it.")]
+    GovernedInstructionChange,
+
+    // 6073
+    #[msg("A change authorizing an instruction can't contain any other change.")]
+    InvalidGovernedInstructionChange,
+
+    // 6074
+    #[msg("Pending change does not authorize this instruction.")]
+    GovernedChangeMismatch,
+
+    // 6075
+    #[msg("Programmable NFTs are not supported by this instruction.")]
+    ProgrammableNftNotSupported,
+
+    // 6076
+    #[msg("Payment account is already delegated to another account.")]
+    PaymentAccountDelegated,
+
+    // 6077
+    #[msg("Sale exceeds the escrow amount reserved for the bid.")]
+    PartialBidReservationExceeded,
 }
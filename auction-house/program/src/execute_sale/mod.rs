@@ -1,4 +1,7 @@
-use crate::{constants::*, errors::*, utils::*, AuctionHouse, Auctioneer, AuthorityScope, *};
+use crate::{
+    constants::*, errors::*, fee_discount::get_fee_discount, stats::update_collection_stats,
+    utils::*, AuctionHouse, Auctioneer, AuthorityScope, *,
+};
 use anchor_lang::{prelude::*, solana_program::program_pack::Pack, AnchorDeserialize};
 use mpl_token_auth_rules::payload::{Payload, PayloadType, SeedsVec};
 use mpl_token_metadata::{
@@ -6,6 +9,7 @@ use mpl_token_metadata::{
     processor::AuthorizationData,
 };
 use spl_token::state::Account as SplAccount;
+use std::slice::Iter;
 
 /// Accounts for the [`execute_sale` handler](auction_house/fn.execute_sale.html).
 #[derive(Accounts)]
@@ -240,6 +244,7 @@ pub fn execute_sale<'info>(
     program_as_signer_bump: u8,
     buyer_price: u64,
     token_size: u64,
+    referral_basis_points: Option<u16>,
 ) -> Result<()> {
     let auction_house = &ctx.accounts.auction_house;
 
@@ -278,6 +283,7 @@ pub fn execute_sale<'info>(
         token_size,
         None,
         None,
+        referral_basis_points,
     )
 }
 
@@ -477,6 +483,7 @@ pub fn execute_partial_sale<'info>(
     token_size: u64,
     partial_order_size: Option<u64>,
     partial_order_price: Option<u64>,
+    referral_basis_points: Option<u16>,
 ) -> Result<()> {
     let auction_house = &ctx.accounts.auction_house;
 
@@ -517,6 +524,7 @@ pub fn execute_partial_sale<'info>(
         token_size,
         partial_order_size,
         partial_order_price,
+        referral_basis_points,
     )
 }
 
@@ -673,8 +681,9 @@ pub struct AuctioneerExecuteSale<'info> {
     pub free_trade_state: UncheckedAccount<'info>,
 
     /// CHECK: Not dangerous. Account seeds checked in constraint.
-    /// The auctioneer PDA owned by Auction House storing scopes.
+    /// The auctioneer PDA owned by Auction House storing scopes and counting sales.
     #[account(
+        mut,
         seeds = [
             AUCTIONEER.as_bytes(),
             auction_house.key().as_ref(),
@@ -723,6 +732,7 @@ pub fn auctioneer_execute_sale<'info>(
         &auctioneer_authority.key(),
         ah_auctioneer_pda,
         AuthorityScope::ExecuteSale,
+        Some(buyer_price),
     )?;
 
     let escrow_canonical_bump = *ctx
@@ -756,7 +766,13 @@ pub fn auctioneer_execute_sale<'info>(
         token_size,
         None,
         None,
-    )
+        None,
+    )?;
+
+    let ah_auctioneer_pda = &mut ctx.accounts.ah_auctioneer_pda;
+    ah_auctioneer_pda.sales_count = ah_auctioneer_pda.sales_count.saturating_add(1);
+
+    Ok(())
 }
 
 #[derive(Accounts, Clone)]
@@ -911,8 +927,9 @@ pub struct AuctioneerExecutePartialSale<'info> {
     pub free_trade_state: UncheckedAccount<'info>,
 
     /// CHECK: Not dangerous. Account seeds checked in constraint.
-    /// The auctioneer PDA owned by Auction House storing scopes.
+    /// The auctioneer PDA owned by Auction House storing scopes and counting sales.
     #[account(
+        mut,
         seeds = [
             AUCTIONEER.as_bytes(),
             auction_house.key().as_ref(),
@@ -974,6 +991,57 @@ pub fn auctioneer_execute_partial_sale<'info>(
     token_size: u64,
     partial_order_size: Option<u64>,
     partial_order_price: Option<u64>,
+) -> Result<()> {
+    auctioneer_partial_sale(
+        ctx,
+        escrow_payment_bump,
+        free_trade_state_bump,
+        program_as_signer_bump,
+        buyer_price,
+        token_size,
+        partial_order_size,
+        partial_order_price,
+        None,
+    )
+}
+
+/// Execute a partial sale of an auctioneer listing for `settlement_price` instead of the full
+/// price of the bid, leaving the difference in the buyer escrow. Used by auctioneers settling
+/// several winners at a uniform price.
+pub fn auctioneer_execute_partial_sale_at_price<'info>(
+    ctx: Context<'_, '_, '_, 'info, AuctioneerExecutePartialSale<'info>>,
+    escrow_payment_bump: u8,
+    free_trade_state_bump: u8,
+    program_as_signer_bump: u8,
+    buyer_price: u64,
+    token_size: u64,
+    partial_order_size: u64,
+    partial_order_price: u64,
+    settlement_price: u64,
+) -> Result<()> {
+    auctioneer_partial_sale(
+        ctx,
+        escrow_payment_bump,
+        free_trade_state_bump,
+        program_as_signer_bump,
+        buyer_price,
+        token_size,
+        Some(partial_order_size),
+        Some(partial_order_price),
+        Some(settlement_price),
+    )
+}
+
+fn auctioneer_partial_sale<'info>(
+    ctx: Context<'_, '_, '_, 'info, AuctioneerExecutePartialSale<'info>>,
+    escrow_payment_bump: u8,
+    free_trade_state_bump: u8,
+    program_as_signer_bump: u8,
+    buyer_price: u64,
+    token_size: u64,
+    partial_order_size: Option<u64>,
+    partial_order_price: Option<u64>,
+    settlement_price: Option<u64>,
 ) -> Result<()> {
     let auction_house = &ctx.accounts.auction_house;
     let auctioneer_authority = &ctx.accounts.auctioneer_authority;
@@ -988,6 +1056,11 @@ pub fn auctioneer_execute_partial_sale<'info>(
         &auctioneer_authority.key(),
         ah_auctioneer_pda,
         AuthorityScope::ExecuteSale,
+        Some(
+            settlement_price
+                .or(partial_order_price)
+                .unwrap_or(buyer_price),
+        ),
     )?;
 
     let escrow_canonical_bump = *ctx
@@ -1023,7 +1096,13 @@ pub fn auctioneer_execute_partial_sale<'info>(
         token_size,
         partial_order_size,
         partial_order_price,
-    )
+        settlement_price,
+    )?;
+
+    let ah_auctioneer_pda = &mut ctx.accounts.ah_auctioneer_pda;
+    ah_auctioneer_pda.sales_count = ah_auctioneer_pda.sales_count.saturating_add(1);
+
+    Ok(())
 }
 
 /// Execute sale between provided buyer and seller trade state accounts transferring funds to seller wallet and token to buyer wallet.
@@ -1038,6 +1117,7 @@ fn auctioneer_execute_sale_logic<'c, 'info>(
     token_size: u64,
     partial_order_size: Option<u64>,
     partial_order_price: Option<u64>,
+    settlement_price: Option<u64>,
 ) -> Result<()> {
     let buyer = &accounts.buyer;
     let seller = &accounts.seller;
@@ -1061,20 +1141,12 @@ fn auctioneer_execute_sale_logic<'c, 'info>(
     let program_as_signer = &accounts.program_as_signer;
     let rent = &accounts.rent;
 
-    let metadata_clone = metadata.to_account_info();
     let escrow_clone = escrow_payment_account.to_account_info();
-    let auction_house_clone = auction_house.to_account_info();
-    let ata_clone = ata_program.to_account_info();
     let token_clone = token_program.to_account_info();
-    let sys_clone = system_program.to_account_info();
     let rent_clone = rent.to_account_info();
-    let treasury_clone = auction_house_treasury.to_account_info();
     let authority_clone = authority.to_account_info();
-    let buyer_receipt_clone = buyer_receipt_token_account.to_account_info();
     let token_account_clone = token_account.to_account_info();
 
-    let is_native = treasury_mint.key() == spl_token::native_mint::id();
-
     if buyer_price == 0 && !authority_clone.is_signer && !seller.is_signer {
         return Err(
             AuctionHouseError::CannotMatchFreeSalesWithoutAuctionHouseOrSellerSignoff.into(),
@@ -1105,6 +1177,12 @@ fn auctioneer_execute_sale_logic<'c, 'info>(
         return Err(AuctionHouseError::BothPartiesNeedToAgreeToSale.into());
     }
 
+    assert_listing_started(seller_trade_state)?;
+
+    if buyer_trade_state.data_len() == PARTIAL_BID_TRADE_STATE_SIZE {
+        return Err(AuctionHouseError::PartialBidNotSupported.into());
+    }
+
     let token_account_data = SplAccount::unpack(&token_account.data.borrow())?;
 
     let (size, price): (u64, u64) = match (partial_order_size, partial_order_price) {
@@ -1120,10 +1198,19 @@ fn auctioneer_execute_sale_logic<'c, 'info>(
                 ts_bump,
             )?;
 
-            if ((buyer_price / token_size) * size) != price {
+            // Auctioneer listings are posted at `u64::MAX` and priced by the auctioneer program.
+            if buyer_price != u64::MAX && ((buyer_price / token_size) * size) != price {
                 return Err(AuctionHouseError::PartialPriceMismatch.into());
             }
 
+            let price = match settlement_price {
+                Some(settlement_price) if settlement_price > price => {
+                    return Err(AuctionHouseError::PartialPriceMismatch.into());
+                }
+                Some(settlement_price) => settlement_price,
+                None => price,
+            };
+
             if token_account_data.amount < size {
                 return Err(AuctionHouseError::NotEnoughTokensAvailableForPurchase.into());
             };
@@ -1174,7 +1261,6 @@ fn auctioneer_execute_sale_logic<'c, 'info>(
         auction_house_fee_account.to_account_info(),
         &seeds,
     )?;
-    let fee_payer_clone = fee_payer.to_account_info();
 
     assert_is_ata(
         &token_account.to_account_info(),
@@ -1191,165 +1277,31 @@ fn auctioneer_execute_sale_logic<'c, 'info>(
         ],
     )?;
 
-    // For native purchases, verify that the amount in escrow is sufficient to actually purchase the
-    // token.  This is intended to cover the migration from pre-rent-exemption checked accounts to
-    // rent-exemption checked accounts.  The fee payer makes up the shortfall up to the amount of
-    // rent for an empty account.
-    if is_native {
-        let rent_shortfall =
-            verify_withdrawal(escrow_payment_account.to_account_info(), buyer_price)?;
-        if rent_shortfall > 0 {
-            invoke_signed(
-                &system_instruction::transfer(
-                    fee_payer.key,
-                    escrow_payment_account.key,
-                    rent_shortfall,
-                ),
-                &[
-                    fee_payer.to_account_info(),
-                    escrow_payment_account.to_account_info(),
-                    system_program.to_account_info(),
-                ],
-                &[fee_payer_seeds],
-            )?;
-        }
-    }
-
     if metadata.data_is_empty() {
         return Err(AuctionHouseError::MetadataDoesntExist.into());
     }
 
-    let auction_house_key = auction_house.key();
-    let wallet_key = buyer.key();
-    let escrow_signer_seeds = [
-        PREFIX.as_bytes(),
-        auction_house_key.as_ref(),
-        wallet_key.as_ref(),
-        &[escrow_payment_bump],
-    ];
-
-    let ah_seeds = [
-        PREFIX.as_bytes(),
-        auction_house.creator.as_ref(),
-        auction_house.treasury_mint.as_ref(),
-        &[auction_house.bump],
-    ];
-
-    // with the native account, the escrow is its own owner,
-    // whereas with token, it is the auction house that is owner.
-    let signer_seeds_for_royalties = if is_native {
-        escrow_signer_seeds
-    } else {
-        ah_seeds
-    };
-
     let remaining_accounts = &mut remaining_accounts.iter();
 
-    let buyer_leftover_after_royalties = pay_creator_fees(
-        remaining_accounts,
-        &metadata_clone,
-        &escrow_clone,
-        &auction_house_clone,
-        &fee_payer_clone,
-        treasury_mint,
-        &ata_clone,
-        &token_clone,
-        &sys_clone,
-        &rent_clone,
-        &signer_seeds_for_royalties,
-        fee_payer_seeds,
-        price,
-        is_native,
-    )?;
-
-    let auction_house_fee_paid = pay_auction_house_fees(
-        auction_house,
-        &treasury_clone,
-        &escrow_clone,
-        &token_clone,
-        &sys_clone,
-        &signer_seeds_for_royalties,
-        price,
-        is_native,
-    )?;
-
-    let buyer_leftover_after_royalties_and_house_fee = buyer_leftover_after_royalties
-        .checked_sub(auction_house_fee_paid)
-        .ok_or(AuctionHouseError::NumericalOverflow)?;
-
-    if !is_native {
-        if seller_payment_receipt_account.data_is_empty() {
-            make_ata(
-                seller_payment_receipt_account.to_account_info(),
-                seller.to_account_info(),
-                treasury_mint.to_account_info(),
-                fee_payer.to_account_info(),
-                ata_program.to_account_info(),
-                token_program.to_account_info(),
-                system_program.to_account_info(),
-                rent.to_account_info(),
-                fee_payer_seeds,
-            )?;
-        }
-
-        let seller_rec_acct = assert_is_ata(
-            &seller_payment_receipt_account.to_account_info(),
-            &seller.key(),
+    // Delegated bids pull the price from the buyer payment account, passed before the creators.
+    if buyer_trade_state.data_len() == DELEGATED_TRADE_STATE_SIZE {
+        transfer_delegated_bid_funds(
+            next_account_info(remaining_accounts)?,
+            &escrow_clone,
+            &buyer.key(),
             &treasury_mint.key(),
-        )?;
-
-        // make sure you cant get rugged
-        if seller_rec_acct.delegate.is_some() {
-            return Err(AuctionHouseError::SellerATACannotHaveDelegate.into());
-        }
-
-        invoke_signed(
-            &spl_token::instruction::transfer(
-                token_program.key,
-                &escrow_payment_account.key(),
-                &seller_payment_receipt_account.key(),
-                &auction_house.key(),
-                &[],
-                buyer_leftover_after_royalties_and_house_fee,
-            )?,
-            &[
-                escrow_payment_account.to_account_info(),
-                seller_payment_receipt_account.to_account_info(),
-                token_program.to_account_info(),
-                auction_house.to_account_info(),
-            ],
-            &[&ah_seeds],
-        )?;
-    } else {
-        assert_keys_equal(seller_payment_receipt_account.key(), seller.key())?;
-        invoke_signed(
-            &system_instruction::transfer(
-                escrow_payment_account.key,
-                seller_payment_receipt_account.key,
-                buyer_leftover_after_royalties_and_house_fee,
-            ),
+            &program_as_signer.to_account_info(),
+            &token_clone,
             &[
-                escrow_payment_account.to_account_info(),
-                seller_payment_receipt_account.to_account_info(),
-                system_program.to_account_info(),
+                PREFIX.as_bytes(),
+                SIGNER.as_bytes(),
+                &[program_as_signer_bump],
             ],
-            &[&escrow_signer_seeds],
+            price,
         )?;
     }
 
-    if buyer_receipt_token_account.data_is_empty() {
-        make_ata(
-            buyer_receipt_token_account.to_account_info(),
-            buyer.to_account_info(),
-            token_mint.to_account_info(),
-            fee_payer.to_account_info(),
-            ata_program.to_account_info(),
-            token_program.to_account_info(),
-            system_program.to_account_info(),
-            rent.to_account_info(),
-            fee_payer_seeds,
-        )?;
-    } else {
+    if !buyer_receipt_token_account.data_is_empty() {
         let data = buyer_receipt_token_account.try_borrow_data()?;
         let token_account = TokenAccount::try_deserialize(&mut data.as_ref())?;
         if &token_account.owner != buyer.key {
@@ -1357,122 +1309,39 @@ fn auctioneer_execute_sale_logic<'c, 'info>(
         }
     }
 
-    let buyer_rec_acct = assert_is_ata(&buyer_receipt_clone, &buyer.key(), &token_mint.key())?;
-
-    // make sure you cant get rugged
-    if buyer_rec_acct.delegate.is_some() {
-        return Err(AuctionHouseError::BuyerATACannotHaveDelegate.into());
-    }
-
-    let program_as_signer_seeds = [
-        PREFIX.as_bytes(),
-        SIGNER.as_bytes(),
-        &[program_as_signer_bump],
-    ];
-
-    match next_account_info(remaining_accounts) {
-        Ok(metadata_program) => {
-            require!(
-                metadata_program.key() == mpl_token_metadata::ID,
-                AuctionHouseError::PublicKeyMismatch
-            );
-
-            let edition = next_account_info(remaining_accounts)?;
-            let owner_tr = next_account_info(remaining_accounts)?;
-            let destination_tr = next_account_info(remaining_accounts)?;
-            let auth_rules_program = next_account_info(remaining_accounts)?;
-            let auth_rules = next_account_info(remaining_accounts)?;
-            let sysvar_instructions = next_account_info(remaining_accounts)?;
-
-            let mpl_transfer = TransferBuilder::new()
-                .token(*token_account.key)
-                .token_owner(*seller.key)
-                .destination(*buyer_receipt_token_account.key)
-                .destination_owner(*buyer.key)
-                .mint(*token_mint.key)
-                .metadata(*metadata.key)
-                .edition(*edition.key)
-                .owner_token_record(*owner_tr.key)
-                .destination_token_record(*destination_tr.key)
-                .authority(program_as_signer.key())
-                .payer(*fee_payer.key)
-                .system_program(*system_program.key)
-                .sysvar_instructions(*sysvar_instructions.key)
-                .spl_token_program(*token_program.key)
-                .spl_ata_program(*ata_program.key)
-                .authorization_rules_program(*auth_rules_program.key)
-                .authorization_rules(*auth_rules.key)
-                .build(TransferArgs::V1 {
-                    amount: size,
-                    authorization_data: Some(AuthorizationData {
-                        payload: Payload::from([
-                            ("Amount".to_string(), PayloadType::Number(size)),
-                            (
-                                "Authority".to_string(),
-                                PayloadType::Pubkey(*program_as_signer.key),
-                            ),
-                            (
-                                "AuthoritySeeds".to_string(),
-                                PayloadType::Seeds(SeedsVec {
-                                    seeds: vec![
-                                        PREFIX.as_bytes().to_vec(),
-                                        SIGNER.as_bytes().to_vec(),
-                                    ],
-                                }),
-                            ),
-                        ]),
-                    }),
-                })
-                .unwrap()
-                .instruction();
-
-            let mpl_transfer_accounts = [
-                metadata_program.clone(),
-                token_account_clone,
-                seller.to_account_info(),
-                buyer_receipt_token_account.to_account_info(),
-                buyer.to_account_info(),
-                token_mint.to_account_info(),
-                metadata.to_account_info(),
-                edition.to_account_info(),
-                owner_tr.to_account_info(),
-                destination_tr.to_account_info(),
-                program_as_signer.to_account_info(),
-                fee_payer.to_account_info(),
-                system_program.to_account_info(),
-                sysvar_instructions.to_account_info(),
-                token_program.to_account_info(),
-                ata_program.to_account_info(),
-                auth_rules_program.to_account_info(),
-                auth_rules.to_account_info(),
-            ];
+    let settlement = SaleSettlement {
+        auction_house,
+        auction_house_treasury,
+        escrow_payment_account,
+        treasury_mint,
+        buyer,
+        seller,
+        seller_payment_receipt_account,
+        metadata,
+        token_account,
+        token_mint,
+        buyer_receipt_token_account,
+        fee_payer: &fee_payer,
+        program_as_signer,
+        token_program,
+        system_program,
+        ata_program,
+        rent: &rent_clone,
+    };
+    settlement.pay(
+        remaining_accounts,
+        escrow_payment_bump,
+        fee_payer_seeds,
+        price,
+        None,
+    )?;
+    settlement.deliver(
+        remaining_accounts,
+        fee_payer_seeds,
+        program_as_signer_bump,
+        size,
+    )?;
 
-            invoke_signed(
-                &mpl_transfer,
-                &mpl_transfer_accounts,
-                &[&program_as_signer_seeds, fee_payer_seeds],
-            )?;
-        }
-        Err(_) => {
-            invoke_signed(
-                &spl_token::instruction::transfer(
-                    token_program.key,
-                    &token_account.key(),
-                    &buyer_receipt_token_account.key(),
-                    &program_as_signer.key(),
-                    &[],
-                    size,
-                )?,
-                &[
-                    token_account.to_account_info(),
-                    buyer_receipt_clone,
-                    program_as_signer.to_account_info(),
-                    token_clone,
-                ],
-                &[&program_as_signer_seeds],
-            )?;
-        }
-    }
     // Close the buyer trade state account if the rest of execute sale was successful.
     close_account(
         &buyer_trade_state.to_account_info(),
@@ -1508,6 +1377,7 @@ fn execute_sale_logic<'c, 'info>(
     token_size: u64,
     partial_order_size: Option<u64>,
     partial_order_price: Option<u64>,
+    referral_basis_points: Option<u16>,
 ) -> Result<()> {
     let buyer = &accounts.buyer;
     let seller = &accounts.seller;
@@ -1531,20 +1401,12 @@ fn execute_sale_logic<'c, 'info>(
     let program_as_signer = &accounts.program_as_signer;
     let rent = &accounts.rent;
 
-    let metadata_clone = metadata.to_account_info();
     let escrow_clone = escrow_payment_account.to_account_info();
-    let auction_house_clone = auction_house.to_account_info();
-    let ata_clone = ata_program.to_account_info();
     let token_clone = token_program.to_account_info();
-    let sys_clone = system_program.to_account_info();
     let rent_clone = rent.to_account_info();
-    let treasury_clone = auction_house_treasury.to_account_info();
     let authority_clone = authority.to_account_info();
-    let buyer_receipt_clone = buyer_receipt_token_account.to_account_info();
     let token_account_clone = token_account.to_account_info();
 
-    let is_native = treasury_mint.key() == spl_token::native_mint::id();
-
     if buyer_price == 0 && !authority_clone.is_signer && !seller.is_signer {
         return Err(
             AuctionHouseError::CannotMatchFreeSalesWithoutAuctionHouseOrSellerSignoff.into(),
@@ -1575,10 +1437,47 @@ fn execute_sale_logic<'c, 'info>(
         return Err(AuctionHouseError::BothPartiesNeedToAgreeToSale.into());
     }
 
+    assert_listing_started(seller_trade_state)?;
+
     let token_account_data = SplAccount::unpack(&token_account.data.borrow())?;
 
-    let (size, price): (u64, u64) = match (partial_order_size, partial_order_price) {
-        (Some(size), Some(price)) => {
+    let partial_bid = get_partial_bid(buyer_trade_state)?;
+
+    let (size, price): (u64, u64) = match (partial_order_size, partial_order_price, partial_bid) {
+        // Bids created by `partial_public_buy` take as many tokens as both the listing and the bid
+        // have left, at the unit price both sides agreed on.
+        (Some(bid_size), Some(bid_price), Some((remaining, _))) => {
+            assert_valid_trade_state(
+                &buyer.key(),
+                auction_house,
+                bid_price,
+                bid_size,
+                buyer_trade_state,
+                &token_mint.key(),
+                &token_account.key(),
+                ts_bump,
+            )?;
+
+            let unit_price = bid_price / bid_size;
+            if buyer_price / token_size != unit_price {
+                return Err(AuctionHouseError::PartialPriceMismatch.into());
+            }
+
+            let size = remaining
+                .min(token_size)
+                .min(token_account_data.amount)
+                .min(token_account_data.delegated_amount);
+            if size == 0 {
+                return Err(AuctionHouseError::NotEnoughTokensAvailableForPurchase.into());
+            }
+
+            let price = unit_price
+                .checked_mul(size)
+                .ok_or(AuctionHouseError::NumericalOverflow)?;
+
+            (size, price)
+        }
+        (Some(size), Some(price), None) => {
             assert_valid_trade_state(
                 &buyer.key(),
                 auction_house,
@@ -1604,7 +1503,7 @@ fn execute_sale_logic<'c, 'info>(
 
             (size, price)
         }
-        (None, None) => {
+        (None, None, _) => {
             assert_valid_trade_state(
                 &buyer.key(),
                 auction_house,
@@ -1627,6 +1526,15 @@ fn execute_sale_logic<'c, 'info>(
         }
     };
 
+    if let Some((remaining, reserved)) = partial_bid {
+        if size > remaining {
+            return Err(AuctionHouseError::PartialBidOverfilled.into());
+        }
+        if price > reserved {
+            return Err(AuctionHouseError::PartialBidReservationExceeded.into());
+        }
+    }
+
     let auction_house_key = auction_house.key();
     let seeds = [
         PREFIX.as_bytes(),
@@ -1644,7 +1552,6 @@ fn execute_sale_logic<'c, 'info>(
         auction_house_fee_account.to_account_info(),
         &seeds,
     )?;
-    let fee_payer_clone = fee_payer.to_account_info();
 
     assert_is_ata(
         &token_account.to_account_info(),
@@ -1661,302 +1568,458 @@ fn execute_sale_logic<'c, 'info>(
         ],
     )?;
 
-    // For native purchases, verify that the amount in escrow is sufficient to actually purchase the
-    // token.  This is intended to cover the migration from pre-rent-exemption checked accounts to
-    // rent-exemption checked accounts.  The fee payer makes up the shortfall up to the amount of
-    // rent for an empty account.
-    if is_native {
-        let rent_shortfall = verify_withdrawal(escrow_payment_account.to_account_info(), price)?;
-        if rent_shortfall > 0 {
-            invoke_signed(
-                &system_instruction::transfer(
-                    fee_payer.key,
-                    escrow_payment_account.key,
-                    rent_shortfall,
-                ),
-                &[
-                    fee_payer.to_account_info(),
-                    escrow_payment_account.to_account_info(),
-                    system_program.to_account_info(),
-                ],
-                &[fee_payer_seeds],
-            )?;
-        }
-    }
-
     if metadata.data_is_empty() {
         return Err(AuctionHouseError::MetadataDoesntExist.into());
     }
 
-    let auction_house_key = auction_house.key();
-    let wallet_key = buyer.key();
-    let escrow_signer_seeds = [
-        PREFIX.as_bytes(),
-        auction_house_key.as_ref(),
-        wallet_key.as_ref(),
-        &[escrow_payment_bump],
-    ];
+    let remaining_accounts = &mut remaining_accounts.iter();
 
-    let ah_seeds = [
-        PREFIX.as_bytes(),
-        auction_house.creator.as_ref(),
-        auction_house.treasury_mint.as_ref(),
-        &[auction_house.bump],
-    ];
+    // Delegated bids pull the price from the buyer payment account, passed before the creators.
+    if buyer_trade_state.data_len() == DELEGATED_TRADE_STATE_SIZE {
+        transfer_delegated_bid_funds(
+            next_account_info(remaining_accounts)?,
+            &escrow_clone,
+            &buyer.key(),
+            &treasury_mint.key(),
+            &program_as_signer.to_account_info(),
+            &token_clone,
+            &[
+                PREFIX.as_bytes(),
+                SIGNER.as_bytes(),
+                &[program_as_signer_bump],
+            ],
+            price,
+        )?;
+    }
 
-    // with the native account, the escrow is its own owner,
-    // whereas with token, it is the auction house that is owner.
-    let signer_seeds_for_royalties = if is_native {
-        escrow_signer_seeds
-    } else {
-        ah_seeds
+    let settlement = SaleSettlement {
+        auction_house,
+        auction_house_treasury,
+        escrow_payment_account,
+        treasury_mint,
+        buyer,
+        seller,
+        seller_payment_receipt_account,
+        metadata,
+        token_account,
+        token_mint,
+        buyer_receipt_token_account,
+        fee_payer: &fee_payer,
+        program_as_signer,
+        token_program,
+        system_program,
+        ata_program,
+        rent: &rent_clone,
     };
-
-    let remaining_accounts = &mut remaining_accounts.iter();
-
-    let buyer_leftover_after_royalties = pay_creator_fees(
+    settlement.pay(
         remaining_accounts,
-        &metadata_clone,
-        &escrow_clone,
-        &auction_house_clone,
-        &fee_payer_clone,
-        treasury_mint,
-        &ata_clone,
-        &token_clone,
-        &sys_clone,
-        &rent_clone,
-        &signer_seeds_for_royalties,
+        escrow_payment_bump,
         fee_payer_seeds,
         price,
-        is_native,
+        referral_basis_points,
     )?;
-
-    let auction_house_fee_paid = pay_auction_house_fees(
-        auction_house,
-        &treasury_clone,
-        &escrow_clone,
-        &token_clone,
-        &sys_clone,
-        &signer_seeds_for_royalties,
-        price,
-        is_native,
+    settlement.deliver(
+        remaining_accounts,
+        fee_payer_seeds,
+        program_as_signer_bump,
+        size,
     )?;
 
-    let buyer_leftover_after_royalties_and_house_fee = buyer_leftover_after_royalties
-        .checked_sub(auction_house_fee_paid)
-        .ok_or(AuctionHouseError::NumericalOverflow)?;
-
-    if !is_native {
-        if seller_payment_receipt_account.data_is_empty() {
-            make_ata(
-                seller_payment_receipt_account.to_account_info(),
-                seller.to_account_info(),
-                treasury_mint.to_account_info(),
-                fee_payer.to_account_info(),
-                ata_program.to_account_info(),
-                token_program.to_account_info(),
-                system_program.to_account_info(),
-                rent.to_account_info(),
-                fee_payer_seeds,
+    // Close the buyer trade state account if the rest of execute sale was successful, unless the bid
+    // has tokens left to buy.
+    match partial_bid {
+        Some((remaining, reserved)) if remaining > size => {
+            set_partial_bid(
+                &buyer_trade_state.to_account_info(),
+                remaining - size,
+                reserved - price,
             )?;
         }
+        _ => {
+            close_account(
+                &buyer_trade_state.to_account_info(),
+                &fee_payer.to_account_info(),
+            )?;
+        }
+    }
 
-        let seller_rec_acct = assert_is_ata(
-            &seller_payment_receipt_account.to_account_info(),
-            &seller.key(),
-            &treasury_mint.key(),
+    let token_account_data = SplAccount::unpack(&token_account.data.borrow())?;
+    if token_account_data.delegated_amount == 0 {
+        close_account(
+            &seller_trade_state.to_account_info(),
+            &fee_payer.to_account_info(),
         )?;
 
-        // make sure you cant get rugged
-        if seller_rec_acct.delegate.is_some() {
-            return Err(AuctionHouseError::SellerATACannotHaveDelegate.into());
+        if free_trade_state.lamports() > 0 {
+            close_account(
+                &free_trade_state.to_account_info(),
+                &fee_payer.to_account_info(),
+            )?;
         }
-
-        invoke_signed(
-            &spl_token::instruction::transfer(
-                token_program.key,
-                &escrow_payment_account.key(),
-                &seller_payment_receipt_account.key(),
-                &auction_house.key(),
-                &[],
-                buyer_leftover_after_royalties_and_house_fee,
-            )?,
-            &[
-                escrow_payment_account.to_account_info(),
-                seller_payment_receipt_account.to_account_info(),
-                token_program.to_account_info(),
-                auction_house.to_account_info(),
-            ],
-            &[&ah_seeds],
-        )?;
-    } else {
-        assert_keys_equal(seller_payment_receipt_account.key(), seller.key())?;
-        invoke_signed(
-            &system_instruction::transfer(
-                escrow_payment_account.key,
-                seller_payment_receipt_account.key,
-                buyer_leftover_after_royalties_and_house_fee,
-            ),
-            &[
-                escrow_payment_account.to_account_info(),
-                seller_payment_receipt_account.to_account_info(),
-                system_program.to_account_info(),
-            ],
-            &[&escrow_signer_seeds],
-        )?;
     }
 
-    if buyer_receipt_token_account.data_is_empty() {
-        make_ata(
-            buyer_receipt_token_account.to_account_info(),
-            buyer.to_account_info(),
-            token_mint.to_account_info(),
-            fee_payer.to_account_info(),
-            ata_program.to_account_info(),
-            token_program.to_account_info(),
-            system_program.to_account_info(),
-            rent.to_account_info(),
+    Ok(())
+}
+
+/// Accounts moving funds and tokens when a sale settles, shared by every sale handler.
+pub(crate) struct SaleSettlement<'a, 'info> {
+    pub auction_house: &'a Account<'info, AuctionHouse>,
+    pub auction_house_treasury: &'a AccountInfo<'info>,
+    pub escrow_payment_account: &'a AccountInfo<'info>,
+    pub treasury_mint: &'a AccountInfo<'info>,
+    pub buyer: &'a AccountInfo<'info>,
+    pub seller: &'a AccountInfo<'info>,
+    pub seller_payment_receipt_account: &'a AccountInfo<'info>,
+    pub metadata: &'a AccountInfo<'info>,
+    pub token_account: &'a AccountInfo<'info>,
+    pub token_mint: &'a AccountInfo<'info>,
+    pub buyer_receipt_token_account: &'a AccountInfo<'info>,
+    pub fee_payer: &'a AccountInfo<'info>,
+    pub program_as_signer: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub ata_program: &'a AccountInfo<'info>,
+    pub rent: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> SaleSettlement<'a, 'info> {
+    /// Pay `price` out of the buyer escrow to the creators, the referrer, the Auction House and
+    /// the seller, recording the sale in the collection stats. The remaining accounts hold the
+    /// creators, then the referrer when `referral_basis_points` is set, then the optional
+    /// collection stats and fee discount proof.
+    #[inline(never)]
+    pub fn pay<'c>(
+        &self,
+        remaining_accounts: &mut Iter<'c, AccountInfo<'info>>,
+        escrow_payment_bump: u8,
+        fee_payer_seeds: &[&[u8]],
+        price: u64,
+        referral_basis_points: Option<u16>,
+    ) -> Result<()> {
+        let auction_house = self.auction_house;
+        let escrow_payment_account = self.escrow_payment_account;
+        let seller = self.seller;
+        let seller_payment_receipt_account = self.seller_payment_receipt_account;
+        let treasury_mint = self.treasury_mint;
+
+        let is_native = treasury_mint.key() == spl_token::native_mint::id();
+
+        // For native purchases, verify that the amount in escrow is sufficient to actually purchase
+        // the token.  This is intended to cover the migration from pre-rent-exemption checked
+        // accounts to rent-exemption checked accounts.  The fee payer makes up the shortfall up to
+        // the amount of rent for an empty account.
+        if is_native {
+            let rent_shortfall = verify_withdrawal(escrow_payment_account.clone(), price)?;
+            if rent_shortfall > 0 {
+                invoke_signed(
+                    &system_instruction::transfer(
+                        self.fee_payer.key,
+                        escrow_payment_account.key,
+                        rent_shortfall,
+                    ),
+                    &[
+                        self.fee_payer.clone(),
+                        escrow_payment_account.clone(),
+                        self.system_program.clone(),
+                    ],
+                    &[fee_payer_seeds],
+                )?;
+            }
+        }
+
+        let auction_house_key = auction_house.key();
+        let wallet_key = self.buyer.key();
+        let escrow_signer_seeds = [
+            PREFIX.as_bytes(),
+            auction_house_key.as_ref(),
+            wallet_key.as_ref(),
+            &[escrow_payment_bump],
+        ];
+
+        let ah_seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            &[auction_house.bump],
+        ];
+
+        // with the native account, the escrow is its own owner,
+        // whereas with token, it is the auction house that is owner.
+        let signer_seeds_for_royalties = if is_native {
+            escrow_signer_seeds
+        } else {
+            ah_seeds
+        };
+
+        let buyer_leftover_after_royalties = pay_creator_fees(
+            remaining_accounts,
+            self.metadata,
+            escrow_payment_account,
+            &auction_house.to_account_info(),
+            self.fee_payer,
+            treasury_mint,
+            self.ata_program,
+            self.token_program,
+            self.system_program,
+            self.rent,
+            &signer_seeds_for_royalties,
             fee_payer_seeds,
+            price,
+            is_native,
         )?;
-    }
 
-    let buyer_rec_acct = assert_is_ata(&buyer_receipt_clone, &buyer.key(), &token_mint.key())?;
+        // The referrer, if any, follows the creator accounts.
+        let referral = get_referral(
+            remaining_accounts,
+            auction_house,
+            referral_basis_points,
+            is_native,
+        )?;
 
-    // make sure you cant get rugged
-    if buyer_rec_acct.delegate.is_some() {
-        return Err(AuctionHouseError::BuyerATACannotHaveDelegate.into());
-    }
+        update_collection_stats(remaining_accounts, &auction_house_key, self.metadata, price)?;
 
-    let program_as_signer_seeds = [
-        PREFIX.as_bytes(),
-        SIGNER.as_bytes(),
-        &[program_as_signer_bump],
-    ];
+        let fee_discount = get_fee_discount(
+            remaining_accounts,
+            &auction_house_key,
+            &wallet_key,
+            &seller.key(),
+        )?;
 
-    match next_account_info(remaining_accounts) {
-        Ok(metadata_program) => {
-            require!(
-                metadata_program.key() == mpl_token_metadata::ID,
-                AuctionHouseError::PublicKeyMismatch
-            );
-
-            let edition = next_account_info(remaining_accounts)?;
-            let owner_tr = next_account_info(remaining_accounts)?;
-            let destination_tr = next_account_info(remaining_accounts)?;
-            let auth_rules_program = next_account_info(remaining_accounts)?;
-            let auth_rules = next_account_info(remaining_accounts)?;
-            let sysvar_instructions = next_account_info(remaining_accounts)?;
-
-            let mpl_transfer = TransferBuilder::new()
-                .token(*token_account.key)
-                .token_owner(*seller.key)
-                .destination(*buyer_receipt_token_account.key)
-                .destination_owner(*buyer.key)
-                .mint(*token_mint.key)
-                .metadata(*metadata.key)
-                .edition(*edition.key)
-                .owner_token_record(*owner_tr.key)
-                .destination_token_record(*destination_tr.key)
-                .authority(program_as_signer.key())
-                .payer(*fee_payer.key)
-                .system_program(*system_program.key)
-                .sysvar_instructions(*sysvar_instructions.key)
-                .spl_token_program(*token_program.key)
-                .spl_ata_program(*ata_program.key)
-                .authorization_rules_program(*auth_rules_program.key)
-                .authorization_rules(*auth_rules.key)
-                .build(TransferArgs::V1 {
-                    amount: size,
-                    authorization_data: Some(AuthorizationData {
-                        payload: Payload::from([
-                            ("Amount".to_string(), PayloadType::Number(size)),
-                            (
-                                "Authority".to_string(),
-                                PayloadType::Pubkey(*program_as_signer.key),
-                            ),
-                            (
-                                "AuthoritySeeds".to_string(),
-                                PayloadType::Seeds(SeedsVec {
-                                    seeds: vec![
-                                        PREFIX.as_bytes().to_vec(),
-                                        SIGNER.as_bytes().to_vec(),
-                                    ],
-                                }),
-                            ),
-                        ]),
-                    }),
-                })
-                .unwrap()
-                .instruction();
-
-            let mpl_transfer_accounts = [
-                metadata_program.clone(),
-                token_account_clone,
-                seller.to_account_info(),
-                buyer_receipt_token_account.to_account_info(),
-                buyer.to_account_info(),
-                fee_payer.to_account_info(),
-                token_mint.to_account_info(),
-                metadata.to_account_info(),
-                edition.to_account_info(),
-                owner_tr.to_account_info(),
-                destination_tr.to_account_info(),
-                program_as_signer.to_account_info(),
-                system_program.to_account_info(),
-                sysvar_instructions.to_account_info(),
-                token_program.to_account_info(),
-                ata_program.to_account_info(),
-                auth_rules_program.to_account_info(),
-                auth_rules.to_account_info(),
-            ];
+        let auction_house_fee_paid = pay_auction_house_fees(
+            auction_house,
+            self.auction_house_treasury,
+            escrow_payment_account,
+            self.token_program,
+            self.system_program,
+            &signer_seeds_for_royalties,
+            price,
+            is_native,
+            referral,
+            fee_discount,
+        )?;
 
-            invoke_signed(
-                &mpl_transfer,
-                &mpl_transfer_accounts,
-                &[&program_as_signer_seeds, fee_payer_seeds],
+        let buyer_leftover_after_royalties_and_house_fee = buyer_leftover_after_royalties
+            .checked_sub(auction_house_fee_paid)
+            .ok_or(AuctionHouseError::NumericalOverflow)?;
+
+        if !is_native {
+            if seller_payment_receipt_account.data_is_empty() {
+                make_ata(
+                    seller_payment_receipt_account.clone(),
+                    seller.clone(),
+                    treasury_mint.clone(),
+                    self.fee_payer.clone(),
+                    self.ata_program.clone(),
+                    self.token_program.clone(),
+                    self.system_program.clone(),
+                    self.rent.clone(),
+                    fee_payer_seeds,
+                )?;
+            }
+
+            let seller_rec_acct = assert_is_ata(
+                seller_payment_receipt_account,
+                seller.key,
+                treasury_mint.key,
             )?;
-        }
-        Err(_) => {
+
+            // make sure you cant get rugged
+            if seller_rec_acct.delegate.is_some() {
+                return Err(AuctionHouseError::SellerATACannotHaveDelegate.into());
+            }
+
             invoke_signed(
                 &spl_token::instruction::transfer(
-                    token_program.key,
-                    &token_account.key(),
-                    &buyer_receipt_token_account.key(),
-                    &program_as_signer.key(),
+                    self.token_program.key,
+                    escrow_payment_account.key,
+                    seller_payment_receipt_account.key,
+                    &auction_house_key,
                     &[],
-                    size,
+                    buyer_leftover_after_royalties_and_house_fee,
                 )?,
                 &[
-                    token_account.to_account_info(),
-                    buyer_receipt_clone,
-                    program_as_signer.to_account_info(),
-                    token_clone,
+                    escrow_payment_account.clone(),
+                    seller_payment_receipt_account.clone(),
+                    self.token_program.clone(),
+                    auction_house.to_account_info(),
                 ],
-                &[&program_as_signer_seeds],
+                &[&ah_seeds],
+            )?;
+        } else {
+            assert_keys_equal(seller_payment_receipt_account.key(), seller.key())?;
+            invoke_signed(
+                &system_instruction::transfer(
+                    escrow_payment_account.key,
+                    seller_payment_receipt_account.key,
+                    buyer_leftover_after_royalties_and_house_fee,
+                ),
+                &[
+                    escrow_payment_account.clone(),
+                    seller_payment_receipt_account.clone(),
+                    self.system_program.clone(),
+                ],
+                &[&escrow_signer_seeds],
             )?;
         }
+
+        Ok(())
     }
 
-    // Close the buyer trade state account if the rest of execute sale was successful.
-    close_account(
-        &buyer_trade_state.to_account_info(),
-        &fee_payer.to_account_info(),
-    )?;
+    /// Transfer `size` tokens from the seller to the buyer receipt token account, creating it if
+    /// needed. Programmable NFTs go through Token Metadata when the remaining accounts continue
+    /// with its program, the edition, both token records, the rule set program, the rule set and
+    /// the instructions sysvar.
+    #[inline(never)]
+    pub fn deliver<'c>(
+        &self,
+        remaining_accounts: &mut Iter<'c, AccountInfo<'info>>,
+        fee_payer_seeds: &[&[u8]],
+        program_as_signer_bump: u8,
+        size: u64,
+    ) -> Result<()> {
+        let buyer = self.buyer;
+        let seller = self.seller;
+        let token_account = self.token_account;
+        let token_mint = self.token_mint;
+        let metadata = self.metadata;
+        let buyer_receipt_token_account = self.buyer_receipt_token_account;
+        let fee_payer = self.fee_payer;
+        let program_as_signer = self.program_as_signer;
+        let token_program = self.token_program;
+        let system_program = self.system_program;
+        let ata_program = self.ata_program;
+
+        if buyer_receipt_token_account.data_is_empty() {
+            make_ata(
+                buyer_receipt_token_account.clone(),
+                buyer.clone(),
+                token_mint.clone(),
+                fee_payer.clone(),
+                ata_program.clone(),
+                token_program.clone(),
+                system_program.clone(),
+                self.rent.clone(),
+                fee_payer_seeds,
+            )?;
+        }
 
-    let token_account_data = SplAccount::unpack(&token_account.data.borrow())?;
-    if token_account_data.delegated_amount == 0 {
-        close_account(
-            &seller_trade_state.to_account_info(),
-            &fee_payer.to_account_info(),
-        )?;
+        let buyer_rec_acct = assert_is_ata(buyer_receipt_token_account, buyer.key, token_mint.key)?;
 
-        if free_trade_state.lamports() > 0 {
-            close_account(
-                &free_trade_state.to_account_info(),
-                &fee_payer.to_account_info(),
-            )?;
+        // make sure you cant get rugged
+        if buyer_rec_acct.delegate.is_some() {
+            return Err(AuctionHouseError::BuyerATACannotHaveDelegate.into());
         }
-    }
 
-    Ok(())
+        let program_as_signer_seeds = [
+            PREFIX.as_bytes(),
+            SIGNER.as_bytes(),
+            &[program_as_signer_bump],
+        ];
+
+        match next_account_info(remaining_accounts) {
+            Ok(metadata_program) => {
+                require!(
+                    metadata_program.key() == mpl_token_metadata::ID,
+                    AuctionHouseError::PublicKeyMismatch
+                );
+
+                let edition = next_account_info(remaining_accounts)?;
+                let owner_tr = next_account_info(remaining_accounts)?;
+                let destination_tr = next_account_info(remaining_accounts)?;
+                let auth_rules_program = next_account_info(remaining_accounts)?;
+                let auth_rules = next_account_info(remaining_accounts)?;
+                let sysvar_instructions = next_account_info(remaining_accounts)?;
+
+                let mpl_transfer = TransferBuilder::new()
+                    .token(*token_account.key)
+                    .token_owner(*seller.key)
+                    .destination(*buyer_receipt_token_account.key)
+                    .destination_owner(*buyer.key)
+                    .mint(*token_mint.key)
+                    .metadata(*metadata.key)
+                    .edition(*edition.key)
+                    .owner_token_record(*owner_tr.key)
+                    .destination_token_record(*destination_tr.key)
+                    .authority(*program_as_signer.key)
+                    .payer(*fee_payer.key)
+                    .system_program(*system_program.key)
+                    .sysvar_instructions(*sysvar_instructions.key)
+                    .spl_token_program(*token_program.key)
+                    .spl_ata_program(*ata_program.key)
+                    .authorization_rules_program(*auth_rules_program.key)
+                    .authorization_rules(*auth_rules.key)
+                    .build(TransferArgs::V1 {
+                        amount: size,
+                        authorization_data: Some(AuthorizationData {
+                            payload: Payload::from([
+                                ("Amount".to_string(), PayloadType::Number(size)),
+                                (
+                                    "Authority".to_string(),
+                                    PayloadType::Pubkey(*program_as_signer.key),
+                                ),
+                                (
+                                    "AuthoritySeeds".to_string(),
+                                    PayloadType::Seeds(SeedsVec {
+                                        seeds: vec![
+                                            PREFIX.as_bytes().to_vec(),
+                                            SIGNER.as_bytes().to_vec(),
+                                        ],
+                                    }),
+                                ),
+                            ]),
+                        }),
+                    })
+                    .unwrap()
+                    .instruction();
+
+                let mpl_transfer_accounts = [
+                    metadata_program.clone(),
+                    token_account.clone(),
+                    seller.clone(),
+                    buyer_receipt_token_account.clone(),
+                    buyer.clone(),
+                    fee_payer.clone(),
+                    token_mint.clone(),
+                    metadata.clone(),
+                    edition.clone(),
+                    owner_tr.clone(),
+                    destination_tr.clone(),
+                    program_as_signer.clone(),
+                    system_program.clone(),
+                    sysvar_instructions.clone(),
+                    token_program.clone(),
+                    ata_program.clone(),
+                    auth_rules_program.clone(),
+                    auth_rules.clone(),
+                ];
+
+                invoke_signed(
+                    &mpl_transfer,
+                    &mpl_transfer_accounts,
+                    &[&program_as_signer_seeds, fee_payer_seeds],
+                )?;
+            }
+            Err(_) => {
+                invoke_signed(
+                    &spl_token::instruction::transfer(
+                        token_program.key,
+                        token_account.key,
+                        buyer_receipt_token_account.key,
+                        program_as_signer.key,
+                        &[],
+                        size,
+                    )?,
+                    &[
+                        token_account.clone(),
+                        buyer_receipt_token_account.clone(),
+                        program_as_signer.clone(),
+                        token_program.clone(),
+                    ],
+                    &[&program_as_signer_seeds],
+                )?;
+            }
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,226 @@
+//! Reduced Auction House fees for holders of NFTs from selected collections.
+//!
+//! The Auction House authority maintains a schedule mapping verified collection mints to
+//! discounted fee basis points. `execute_sale` applies the lowest of the Auction House fee and
+//! the discount when the buyer or seller proves they hold an NFT from a scheduled collection.
+//! On a governed Auction House, the schedule is only set through an approved change.
+use crate::{
+    constants::*, errors::AuctionHouseError, governance::consume_instruction_change, utils::*,
+    AuctionHouse,
+};
+use anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize, Discriminator};
+use mpl_token_metadata::state::{Metadata, TokenMetadataAccount};
+use spl_token::state::Account as SplAccount;
+use std::slice::Iter;
+
+pub const MAX_FEE_DISCOUNTS: usize = 10;
+
+pub const FEE_DISCOUNT_SCHEDULE_SIZE: usize = 8 + // key
+32 + // auction_house
+4 + (32 + 2) * MAX_FEE_DISCOUNTS + // discounts
+1 + // bump
+64; // padding
+
+/// Fee charged on sales where the buyer or seller holds an NFT from `collection_mint`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct FeeDiscount {
+    pub collection_mint: Pubkey,
+    pub seller_fee_basis_points: u16,
+}
+
+/// Holder fee discounts of an Auction House.
+#[account]
+pub struct FeeDiscountSchedule {
+    pub auction_house: Pubkey,
+    pub discounts: Vec<FeeDiscount>,
+    pub bump: u8,
+}
+
+impl FeeDiscountSchedule {
+    /// Discounted basis points for holders of `collection_mint`, if it is scheduled.
+    pub fn basis_points_for(&self, collection_mint: &Pubkey) -> Option<u16> {
+        self.discounts
+            .iter()
+            .find(|discount| discount.collection_mint == *collection_mint)
+            .map(|discount| discount.seller_fee_basis_points)
+    }
+}
+
+pub(crate) fn assert_valid_fee_discounts(discounts: &[FeeDiscount]) -> Result<()> {
+    if discounts.len() > MAX_FEE_DISCOUNTS {
+        return Err(AuctionHouseError::TooManyFeeDiscounts.into());
+    }
+
+    for (i, discount) in discounts.iter().enumerate() {
+        if discount.seller_fee_basis_points > 10000 {
+            return Err(AuctionHouseError::InvalidBasisPoints.into());
+        }
+
+        if discounts[..i]
+            .iter()
+            .any(|other| other.collection_mint == discount.collection_mint)
+        {
+            return Err(AuctionHouseError::PublicKeysShouldBeUnique.into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Accounts for the [`create_fee_discount_schedule` handler](auction_house/fn.create_fee_discount_schedule.html).
+#[derive(Accounts)]
+pub struct CreateFeeDiscountSchedule<'info> {
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=authority
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// Authority key for the Auction House.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Fee discount schedule PDA account.
+    #[account(
+        init,
+        payer = authority,
+        space = FEE_DISCOUNT_SCHEDULE_SIZE,
+        seeds = [
+            FEE_DISCOUNT_SCHEDULE.as_bytes(),
+            auction_house.key().as_ref()
+        ],
+        bump
+    )]
+    pub fee_discount_schedule: Account<'info, FeeDiscountSchedule>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for the [`update_fee_discount_schedule` handler](auction_house/fn.update_fee_discount_schedule.html).
+#[derive(Accounts)]
+pub struct UpdateFeeDiscountSchedule<'info> {
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=authority
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// Authority key for the Auction House.
+    pub authority: Signer<'info>,
+
+    /// Fee discount schedule PDA account.
+    #[account(
+        mut,
+        seeds = [
+            FEE_DISCOUNT_SCHEDULE.as_bytes(),
+            auction_house.key().as_ref()
+        ],
+        bump=fee_discount_schedule.bump
+    )]
+    pub fee_discount_schedule: Account<'info, FeeDiscountSchedule>,
+}
+
+pub fn create_fee_discount_schedule<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateFeeDiscountSchedule<'info>>,
+    discounts: Vec<FeeDiscount>,
+) -> Result<()> {
+    // Fee changes of a governed Auction House go through proposals.
+    if ctx.accounts.auction_house.has_governance {
+        consume_instruction_change(
+            &ctx.accounts.auction_house,
+            ctx.remaining_accounts,
+            |change| change.fee_discounts.as_ref() == Some(&discounts),
+        )?;
+    }
+    assert_valid_fee_discounts(&discounts)?;
+
+    let fee_discount_schedule = &mut ctx.accounts.fee_discount_schedule;
+    fee_discount_schedule.auction_house = ctx.accounts.auction_house.key();
+    fee_discount_schedule.discounts = discounts;
+    fee_discount_schedule.bump = *ctx
+        .bumps
+        .get("fee_discount_schedule")
+        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+
+    Ok(())
+}
+
+pub fn update_fee_discount_schedule<'info>(
+    ctx: Context<'_, '_, '_, 'info, UpdateFeeDiscountSchedule<'info>>,
+    discounts: Vec<FeeDiscount>,
+) -> Result<()> {
+    if ctx.accounts.auction_house.has_governance {
+        consume_instruction_change(
+            &ctx.accounts.auction_house,
+            ctx.remaining_accounts,
+            |change| change.fee_discounts.as_ref() == Some(&discounts),
+        )?;
+    }
+    assert_valid_fee_discounts(&discounts)?;
+
+    ctx.accounts.fee_discount_schedule.discounts = discounts;
+
+    Ok(())
+}
+
+/// Read the optional fee discount proof from the remaining accounts: the fee discount schedule,
+/// then a token account of the buyer or seller holding an NFT and that NFT's metadata. The
+/// iterator is left untouched when the next account is not the fee discount schedule.
+pub fn get_fee_discount<'c, 'info>(
+    remaining_accounts: &mut Iter<'c, AccountInfo<'info>>,
+    auction_house: &Pubkey,
+    buyer: &Pubkey,
+    seller: &Pubkey,
+) -> Result<Option<u16>> {
+    let schedule_info = match remaining_accounts.as_slice().first() {
+        Some(account) if is_fee_discount_schedule(account) => account,
+        _ => return Ok(None),
+    };
+    remaining_accounts.next();
+    let holder_token_account = next_account_info(remaining_accounts)?;
+    let holder_metadata = next_account_info(remaining_accounts)?;
+
+    let schedule: Account<FeeDiscountSchedule> = Account::try_from(schedule_info)?;
+    assert_keys_equal(schedule.auction_house, *auction_house)?;
+
+    assert_owned_by(holder_token_account, &spl_token::id())?;
+    let token_account: SplAccount = assert_initialized(holder_token_account)?;
+    if token_account.owner != *buyer && token_account.owner != *seller {
+        return Err(AuctionHouseError::InvalidFeeDiscountProof.into());
+    }
+    if token_account.amount == 0 {
+        return Err(AuctionHouseError::InvalidFeeDiscountProof.into());
+    }
+
+    assert_owned_by(holder_metadata, &mpl_token_metadata::id())?;
+    let metadata = Metadata::from_account_info(holder_metadata)?;
+    assert_keys_equal(metadata.mint, token_account.mint)?;
+
+    match metadata.collection {
+        Some(collection) if collection.verified => schedule
+            .basis_points_for(&collection.key)
+            .map(Some)
+            .ok_or_else(|| AuctionHouseError::InvalidFeeDiscountProof.into()),
+        _ => Err(AuctionHouseError::InvalidFeeDiscountProof.into()),
+    }
+}
+
+pub(crate) fn is_fee_discount_schedule(account: &AccountInfo) -> bool {
+    *account.owner == crate::id()
+        && account
+            .try_borrow_data()
+            .map(|data| data.starts_with(&FeeDiscountSchedule::discriminator()))
+            .unwrap_or(false)
+}
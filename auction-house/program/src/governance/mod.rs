@@ -0,0 +1,685 @@
+//! Time-locked, multi-signer governance of Auction House parameters.
+//!
+//! Once governance is enabled, `update_auction_house` is disabled and changes must be proposed
+//! into a pending change PDA, approved by `threshold` of the configured signers and can only be
+//! executed after `delay` seconds have passed since the threshold was reached.
+//!
+//! Treasury withdrawals, Auctioneer delegation changes and fee discount schedules are authorized
+//! the same way: the approved change is passed to `withdraw_from_treasury`, the auctioneer
+//! instruction or the fee discount schedule instruction, which consumes it.
+use crate::{
+    constants::*,
+    errors::AuctionHouseError,
+    fee_discount::{assert_valid_fee_discounts, FeeDiscount, MAX_FEE_DISCOUNTS},
+    utils::*,
+    AuctionHouse, AuctioneerLimits, AuthorityScope,
+};
+use anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize};
+use anchor_spl::token::Mint;
+use spl_token::state::Account as SplAccount;
+
+pub const MAX_GOVERNANCE_SIGNERS: usize = 10;
+
+pub const GOVERNANCE_SIZE: usize = 8 + // key
+32 + // auction_house
+4 + 32 * MAX_GOVERNANCE_SIGNERS + // signers
+1 + // threshold
+8 + // delay
+8 + // proposal_count
+1 + // bump
+64; // padding
+
+pub const AUCTION_HOUSE_CHANGE_SIZE: usize = 1 + 2 + // seller_fee_basis_points
+1 + 1 + // requires_sign_off
+1 + 1 + // can_change_sale_price
+1 + 2 + // max_referral_basis_points
+1 + 8 + // receipt_retention_period
+1 + 32 + // authority
+1 + 32 + // treasury_withdrawal_destination
+1 + 32 + // fee_withdrawal_destination
+1 + 4 + 32 * MAX_GOVERNANCE_SIGNERS + // signers
+1 + 1 + // threshold
+1 + 8 + // delay
+1 + 8 + // treasury_withdrawal
+1 + AUCTIONEER_CHANGE_SIZE + // auctioneer
+1 + 4 + (32 + 2) * MAX_FEE_DISCOUNTS; // fee_discounts
+
+pub const AUCTIONEER_CHANGE_SIZE: usize = 32 + // auctioneer_authority
+1 + 4 + MAX_NUM_SCOPES + // scopes
+1 + 4 + MAX_NUM_SCOPES + 3 * (1 + 8) + // limits
+1; // revoke
+
+pub const PENDING_CHANGE_SIZE: usize = 8 + // key
+32 + // auction_house
+32 + // proposer
+8 + // index
+AUCTION_HOUSE_CHANGE_SIZE + // change
+4 + 32 * MAX_GOVERNANCE_SIGNERS + // approvals
+8 + // created_at
+1 + 8 + // approved_at
+1; // bump
+
+/// Signer set and delay governing an Auction House.
+#[account]
+pub struct AuctionHouseGovernance {
+    pub auction_house: Pubkey,
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+    pub delay: i64,
+    pub proposal_count: u64,
+    pub bump: u8,
+}
+
+impl AuctionHouseGovernance {
+    pub fn assert_signer(&self, signer: &Pubkey) -> Result<()> {
+        if self.signers.contains(signer) {
+            Ok(())
+        } else {
+            Err(AuctionHouseError::NotAGovernanceSigner.into())
+        }
+    }
+
+    /// Number of approvals given by the current signer set.
+    pub fn count_approvals(&self, approvals: &[Pubkey]) -> usize {
+        approvals
+            .iter()
+            .filter(|approval| self.signers.contains(approval))
+            .count()
+    }
+}
+
+/// Parameter changes to apply to an Auction House, `None` values are left untouched.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, PartialEq, Eq, Debug)]
+pub struct AuctionHouseChange {
+    pub seller_fee_basis_points: Option<u16>,
+    pub requires_sign_off: Option<bool>,
+    pub can_change_sale_price: Option<bool>,
+    pub max_referral_basis_points: Option<u16>,
+    pub receipt_retention_period: Option<i64>,
+    pub authority: Option<Pubkey>,
+    pub treasury_withdrawal_destination: Option<Pubkey>,
+    pub fee_withdrawal_destination: Option<Pubkey>,
+    pub signers: Option<Vec<Pubkey>>,
+    pub threshold: Option<u8>,
+    pub delay: Option<i64>,
+    /// Amount `withdraw_from_treasury` is allowed to withdraw.
+    pub treasury_withdrawal: Option<u64>,
+    /// Delegation change the auctioneer instructions are allowed to make.
+    pub auctioneer: Option<AuctioneerChange>,
+    /// Discounts `create_fee_discount_schedule` or `update_fee_discount_schedule` is allowed to
+    /// set.
+    pub fee_discounts: Option<Vec<FeeDiscount>>,
+}
+
+/// Delegation change of a single Auctioneer, exactly one of `scopes`, `limits` and `revoke`
+/// is set.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, PartialEq, Eq, Debug)]
+pub struct AuctioneerChange {
+    pub auctioneer_authority: Pubkey,
+    /// Scopes for `delegate_auctioneer` or `update_auctioneer`.
+    pub scopes: Option<Vec<AuthorityScope>>,
+    /// Limits for `set_auctioneer_limits`.
+    pub limits: Option<AuctioneerScopeLimits>,
+    /// Authorizes `revoke_auctioneer`.
+    pub revoke: bool,
+}
+
+/// Arguments of `set_auctioneer_limits`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, PartialEq, Eq, Debug)]
+pub struct AuctioneerScopeLimits {
+    pub scopes: Vec<AuthorityScope>,
+    pub limits: AuctioneerLimits,
+}
+
+/// A proposed change waiting for approvals and for its delay to pass.
+#[account]
+pub struct PendingAuctionHouseChange {
+    pub auction_house: Pubkey,
+    pub proposer: Pubkey,
+    pub index: u64,
+    pub change: AuctionHouseChange,
+    pub approvals: Vec<Pubkey>,
+    pub created_at: i64,
+    pub approved_at: Option<i64>,
+    pub bump: u8,
+}
+
+impl PendingAuctionHouseChange {
+    /// Record an approval, starting the delay once the threshold is first reached.
+    pub fn approve(
+        &mut self,
+        governance: &AuctionHouseGovernance,
+        signer: &Pubkey,
+        now: i64,
+    ) -> Result<()> {
+        governance.assert_signer(signer)?;
+
+        // Approvals of removed signers no longer count, dropping them keeps the list within
+        // the size of the signer set.
+        self.approvals
+            .retain(|approval| governance.signers.contains(approval));
+
+        if self.approvals.contains(signer) {
+            return Err(AuctionHouseError::ChangeAlreadyApproved.into());
+        }
+        self.approvals.push(*signer);
+
+        if self.approved_at.is_none()
+            && governance.count_approvals(&self.approvals) >= governance.threshold as usize
+        {
+            self.approved_at = Some(now);
+        }
+
+        Ok(())
+    }
+
+    pub fn assert_executable(&self, governance: &AuctionHouseGovernance, now: i64) -> Result<()> {
+        let approved_at = match self.approved_at {
+            Some(approved_at) => approved_at,
+            None => return Err(AuctionHouseError::ChangeNotApproved.into()),
+        };
+
+        // The signer set may have changed since the threshold was reached.
+        if governance.count_approvals(&self.approvals) < governance.threshold as usize {
+            return Err(AuctionHouseError::ChangeNotApproved.into());
+        }
+
+        let executable_at = approved_at
+            .checked_add(governance.delay)
+            .ok_or(AuctionHouseError::NumericalOverflow)?;
+        if now < executable_at {
+            return Err(AuctionHouseError::ChangeTimelocked.into());
+        }
+
+        Ok(())
+    }
+}
+
+fn assert_valid_governance(signers: &[Pubkey], threshold: u8, delay: i64) -> Result<()> {
+    if signers.len() > MAX_GOVERNANCE_SIGNERS {
+        return Err(AuctionHouseError::TooManyGovernanceSigners.into());
+    }
+
+    for (i, signer) in signers.iter().enumerate() {
+        if signers[..i].contains(signer) {
+            return Err(AuctionHouseError::PublicKeysShouldBeUnique.into());
+        }
+    }
+
+    if threshold == 0 || threshold as usize > signers.len() {
+        return Err(AuctionHouseError::InvalidGovernanceThreshold.into());
+    }
+
+    if delay < 0 {
+        return Err(AuctionHouseError::InvalidGovernanceDelay.into());
+    }
+
+    Ok(())
+}
+
+impl AuctionHouseChange {
+    /// Whether the change authorizes an instruction instead of updating parameters.
+    pub fn is_instruction_change(&self) -> bool {
+        self.treasury_withdrawal.is_some()
+            || self.auctioneer.is_some()
+            || self.fee_discounts.is_some()
+    }
+
+    /// Delegation change authorized for the Auctioneer of `auctioneer_authority`.
+    pub fn auctioneer_change(&self, auctioneer_authority: &Pubkey) -> Option<&AuctioneerChange> {
+        self.auctioneer
+            .as_ref()
+            .filter(|auctioneer| auctioneer.auctioneer_authority == *auctioneer_authority)
+    }
+
+    pub fn assert_valid(&self, governance: &AuctionHouseGovernance) -> Result<()> {
+        if self.is_instruction_change() {
+            // The instruction only applies its own change, anything else would be dropped.
+            let instruction_change = AuctionHouseChange {
+                treasury_withdrawal: self.treasury_withdrawal,
+                auctioneer: self.auctioneer.clone(),
+                fee_discounts: self.fee_discounts.clone(),
+                ..AuctionHouseChange::default()
+            };
+            let instructions = [
+                self.treasury_withdrawal.is_some(),
+                self.auctioneer.is_some(),
+                self.fee_discounts.is_some(),
+            ];
+            if *self != instruction_change || instructions.iter().filter(|set| **set).count() != 1 {
+                return Err(AuctionHouseError::InvalidGovernedInstructionChange.into());
+            }
+
+            if let Some(fee_discounts) = &self.fee_discounts {
+                assert_valid_fee_discounts(fee_discounts)?;
+            }
+
+            if let Some(auctioneer) = &self.auctioneer {
+                let actions = [
+                    auctioneer.scopes.is_some(),
+                    auctioneer.limits.is_some(),
+                    auctioneer.revoke,
+                ];
+                if actions.iter().filter(|set| **set).count() != 1 {
+                    return Err(AuctionHouseError::InvalidGovernedInstructionChange.into());
+                }
+                if auctioneer.scopes.as_ref().map_or(0, Vec::len) > MAX_NUM_SCOPES
+                    || auctioneer
+                        .limits
+                        .as_ref()
+                        .map_or(0, |limits| limits.scopes.len())
+                        > MAX_NUM_SCOPES
+                {
+                    return Err(AuctionHouseError::TooManyScopes.into());
+                }
+            }
+
+            return Ok(());
+        }
+
+        for bp in [self.seller_fee_basis_points, self.max_referral_basis_points]
+            .iter()
+            .flatten()
+        {
+            if *bp > 10000 {
+                return Err(AuctionHouseError::InvalidBasisPoints.into());
+            }
+        }
+
+        if matches!(self.receipt_retention_period, Some(rrp) if rrp < 0) {
+            return Err(AuctionHouseError::InvalidReceiptRetentionPeriod.into());
+        }
+
+        if self.signers.is_some() || self.threshold.is_some() || self.delay.is_some() {
+            assert_valid_governance(
+                self.signers.as_ref().unwrap_or(&governance.signers),
+                self.threshold.unwrap_or(governance.threshold),
+                self.delay.unwrap_or(governance.delay),
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Accounts for the [`enable_governance` handler](auction_house/fn.enable_governance.html).
+#[derive(Accounts)]
+pub struct EnableGovernance<'info> {
+    /// Auction House instance PDA account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=authority
+    )]
+    pub auction_house: Account<'info, AuctionHouse>,
+
+    /// Authority key for the Auction House.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Governance PDA storing the signer set.
+    #[account(
+        init,
+        payer = authority,
+        space = GOVERNANCE_SIZE,
+        seeds = [
+            GOVERNANCE.as_bytes(),
+            auction_house.key().as_ref()
+        ],
+        bump
+    )]
+    pub governance: Account<'info, AuctionHouseGovernance>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn enable_governance<'info>(
+    ctx: Context<'_, '_, '_, 'info, EnableGovernance<'info>>,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+    delay: i64,
+) -> Result<()> {
+    assert_valid_governance(&signers, threshold, delay)?;
+
+    let auction_house = &mut ctx.accounts.auction_house;
+    if auction_house.has_governance {
+        return Err(AuctionHouseError::GovernanceEnabled.into());
+    }
+    auction_house.has_governance = true;
+
+    let governance = &mut ctx.accounts.governance;
+    governance.auction_house = auction_house.key();
+    governance.signers = signers;
+    governance.threshold = threshold;
+    governance.delay = delay;
+    governance.proposal_count = 0;
+    governance.bump = *ctx
+        .bumps
+        .get("governance")
+        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+
+    Ok(())
+}
+
+/// Accounts for the [`propose_auction_house_change` handler](auction_house/fn.propose_auction_house_change.html).
+#[derive(Accounts)]
+pub struct ProposeAuctionHouseChange<'info> {
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump
+    )]
+    pub auction_house: Account<'info, AuctionHouse>,
+
+    /// Governance PDA storing the signer set.
+    #[account(
+        mut,
+        seeds = [
+            GOVERNANCE.as_bytes(),
+            auction_house.key().as_ref()
+        ],
+        bump=governance.bump,
+        has_one=auction_house
+    )]
+    pub governance: Account<'info, AuctionHouseGovernance>,
+
+    /// Pending change PDA, indexed by the governance proposal count.
+    #[account(
+        init,
+        payer = proposer,
+        space = PENDING_CHANGE_SIZE,
+        seeds = [
+            PENDING_CHANGE.as_bytes(),
+            auction_house.key().as_ref(),
+            &governance.proposal_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub pending_change: Account<'info, PendingAuctionHouseChange>,
+
+    /// Governance signer proposing the change, its approval is recorded with the proposal.
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn propose_auction_house_change<'info>(
+    ctx: Context<'_, '_, '_, 'info, ProposeAuctionHouseChange<'info>>,
+    change: AuctionHouseChange,
+) -> Result<()> {
+    let governance = &mut ctx.accounts.governance;
+    let proposer = &ctx.accounts.proposer;
+    let clock = Clock::get()?;
+
+    governance.assert_signer(proposer.key)?;
+    change.assert_valid(governance)?;
+
+    let pending_change = &mut ctx.accounts.pending_change;
+    pending_change.auction_house = ctx.accounts.auction_house.key();
+    pending_change.proposer = proposer.key();
+    pending_change.index = governance.proposal_count;
+    pending_change.change = change;
+    pending_change.approvals = vec![];
+    pending_change.created_at = clock.unix_timestamp;
+    pending_change.approved_at = None;
+    pending_change.bump = *ctx
+        .bumps
+        .get("pending_change")
+        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+    pending_change.approve(governance, proposer.key, clock.unix_timestamp)?;
+
+    governance.proposal_count = governance
+        .proposal_count
+        .checked_add(1)
+        .ok_or(AuctionHouseError::NumericalOverflow)?;
+
+    Ok(())
+}
+
+/// Accounts for the [`approve_auction_house_change` handler](auction_house/fn.approve_auction_house_change.html).
+#[derive(Accounts)]
+pub struct ApproveAuctionHouseChange<'info> {
+    /// Governance PDA storing the signer set.
+    #[account(
+        seeds = [
+            GOVERNANCE.as_bytes(),
+            pending_change.auction_house.as_ref()
+        ],
+        bump=governance.bump
+    )]
+    pub governance: Account<'info, AuctionHouseGovernance>,
+
+    /// Pending change PDA.
+    #[account(
+        mut,
+        seeds = [
+            PENDING_CHANGE.as_bytes(),
+            pending_change.auction_house.as_ref(),
+            &pending_change.index.to_le_bytes()
+        ],
+        bump=pending_change.bump
+    )]
+    pub pending_change: Account<'info, PendingAuctionHouseChange>,
+
+    /// Governance signer approving the change.
+    pub signer: Signer<'info>,
+}
+
+pub fn approve_auction_house_change<'info>(
+    ctx: Context<'_, '_, '_, 'info, ApproveAuctionHouseChange<'info>>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    ctx.accounts.pending_change.approve(
+        &ctx.accounts.governance,
+        ctx.accounts.signer.key,
+        clock.unix_timestamp,
+    )
+}
+
+/// Accounts for the [`execute_auction_house_change` handler](auction_house/fn.execute_auction_house_change.html).
+#[derive(Accounts)]
+pub struct ExecuteAuctionHouseChange<'info> {
+    /// Treasury mint account, either native SOL mint or a SPL token mint.
+    pub treasury_mint: Account<'info, Mint>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            treasury_mint.key().as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=treasury_mint
+    )]
+    pub auction_house: Account<'info, AuctionHouse>,
+
+    /// Governance PDA storing the signer set.
+    #[account(
+        mut,
+        seeds = [
+            GOVERNANCE.as_bytes(),
+            auction_house.key().as_ref()
+        ],
+        bump=governance.bump,
+        has_one=auction_house
+    )]
+    pub governance: Account<'info, AuctionHouseGovernance>,
+
+    /// Pending change PDA, closed back to the proposer once applied.
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [
+            PENDING_CHANGE.as_bytes(),
+            auction_house.key().as_ref(),
+            &pending_change.index.to_le_bytes()
+        ],
+        bump=pending_change.bump,
+        has_one=auction_house,
+        has_one=proposer
+    )]
+    pub pending_change: Account<'info, PendingAuctionHouseChange>,
+
+    /// CHECK: Checked by the has_one constraint on the pending change.
+    /// Wallet that proposed the change and paid for the pending change account.
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+    // When the change sets a new treasury withdrawal destination for a SPL treasury mint, that
+    // token account must be passed as the first remaining account.
+}
+
+/// Apply an approved change once its delay has passed. Anyone can execute it.
+pub fn execute_auction_house_change<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteAuctionHouseChange<'info>>,
+) -> Result<()> {
+    let treasury_mint = &ctx.accounts.treasury_mint;
+    let auction_house = &mut ctx.accounts.auction_house;
+    let governance = &mut ctx.accounts.governance;
+    let pending_change = &ctx.accounts.pending_change;
+    let clock = Clock::get()?;
+
+    pending_change.assert_executable(governance, clock.unix_timestamp)?;
+
+    let change = &pending_change.change;
+    if change.is_instruction_change() {
+        return Err(AuctionHouseError::GovernedInstructionChange.into());
+    }
+    // Governance may have changed since the proposal, so check again against the current state.
+    change.assert_valid(governance)?;
+
+    if let Some(sfbp) = change.seller_fee_basis_points {
+        auction_house.seller_fee_basis_points = sfbp;
+    }
+    if let Some(rqf) = change.requires_sign_off {
+        auction_house.requires_sign_off = rqf;
+    }
+    if let Some(chsp) = change.can_change_sale_price {
+        auction_house.can_change_sale_price = chsp;
+    }
+    if let Some(mrbp) = change.max_referral_basis_points {
+        auction_house.max_referral_basis_points = mrbp;
+    }
+    if let Some(rrp) = change.receipt_retention_period {
+        auction_house.receipt_retention_period = rrp;
+    }
+    if let Some(authority) = change.authority {
+        auction_house.authority = authority;
+    }
+    if let Some(fee_withdrawal_destination) = change.fee_withdrawal_destination {
+        auction_house.fee_withdrawal_destination = fee_withdrawal_destination;
+    }
+    if let Some(treasury_withdrawal_destination) = change.treasury_withdrawal_destination {
+        if treasury_mint.key() != spl_token::native_mint::id() {
+            let destination = ctx
+                .remaining_accounts
+                .first()
+                .ok_or(AuctionHouseError::PublicKeyMismatch)?;
+            assert_keys_equal(destination.key(), treasury_withdrawal_destination)?;
+            assert_owned_by(destination, &spl_token::id())?;
+            let destination_account: SplAccount = assert_initialized(destination)?;
+            assert_keys_equal(destination_account.mint, treasury_mint.key())?;
+        }
+        auction_house.treasury_withdrawal_destination = treasury_withdrawal_destination;
+    }
+
+    if let Some(signers) = &change.signers {
+        governance.signers = signers.clone();
+    }
+    if let Some(threshold) = change.threshold {
+        governance.threshold = threshold;
+    }
+    if let Some(delay) = change.delay {
+        governance.delay = delay;
+    }
+
+    Ok(())
+}
+
+/// Accounts for the [`cancel_auction_house_change` handler](auction_house/fn.cancel_auction_house_change.html).
+#[derive(Accounts)]
+pub struct CancelAuctionHouseChange<'info> {
+    /// Governance PDA storing the signer set.
+    #[account(
+        seeds = [
+            GOVERNANCE.as_bytes(),
+            pending_change.auction_house.as_ref()
+        ],
+        bump=governance.bump
+    )]
+    pub governance: Account<'info, AuctionHouseGovernance>,
+
+    /// Pending change PDA, closed back to the proposer.
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [
+            PENDING_CHANGE.as_bytes(),
+            pending_change.auction_house.as_ref(),
+            &pending_change.index.to_le_bytes()
+        ],
+        bump=pending_change.bump,
+        has_one=proposer
+    )]
+    pub pending_change: Account<'info, PendingAuctionHouseChange>,
+
+    /// CHECK: Checked by the has_one constraint on the pending change.
+    /// Wallet that proposed the change and paid for the pending change account.
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+
+    /// The proposer or any governance signer.
+    pub signer: Signer<'info>,
+}
+
+pub fn cancel_auction_house_change<'info>(
+    ctx: Context<'_, '_, '_, 'info, CancelAuctionHouseChange<'info>>,
+) -> Result<()> {
+    let signer = &ctx.accounts.signer;
+
+    if signer.key() != ctx.accounts.proposer.key() {
+        ctx.accounts.governance.assert_signer(signer.key)?;
+    }
+
+    Ok(())
+}
+
+/// Consume the pending change authorizing an instruction of a governed Auction House. The
+/// governance PDA, the pending change and its proposer are read from the first remaining
+/// accounts, and the pending change is closed back to the proposer once `authorizes` accepts it.
+pub fn consume_instruction_change<'info>(
+    auction_house: &Account<'info, AuctionHouse>,
+    remaining_accounts: &[AccountInfo<'info>],
+    authorizes: impl FnOnce(&AuctionHouseChange) -> bool,
+) -> Result<()> {
+    let (governance, pending_change, proposer) = match remaining_accounts {
+        [governance, pending_change, proposer, ..] => (governance, pending_change, proposer),
+        _ => return Err(AuctionHouseError::GovernanceEnabled.into()),
+    };
+
+    let governance: Account<AuctionHouseGovernance> = Account::try_from(governance)?;
+    assert_keys_equal(governance.auction_house, auction_house.key())?;
+
+    let pending_change: Account<PendingAuctionHouseChange> = Account::try_from(pending_change)?;
+    assert_keys_equal(pending_change.auction_house, auction_house.key())?;
+    assert_keys_equal(pending_change.proposer, proposer.key())?;
+
+    pending_change.assert_executable(&governance, Clock::get()?.unix_timestamp)?;
+    if !authorizes(&pending_change.change) {
+        return Err(AuctionHouseError::GovernedChangeMismatch.into());
+    }
+
+    pending_change.close(proposer.clone())
+}
@@ -9,21 +9,27 @@ pub mod auctioneer;
 pub mod bid;
 pub mod cancel;
 pub mod constants;
+pub mod counter_offer;
 pub mod deposit;
 pub mod errors;
 pub mod execute_sale;
+pub mod fee_discount;
+pub mod governance;
 pub mod pda;
 pub mod receipt;
 pub mod sell;
 pub mod state;
+pub mod stats;
+pub mod sweep;
 pub mod utils;
 pub mod withdraw;
 
 pub use state::*;
 
 use crate::{
-    auctioneer::*, bid::*, cancel::*, constants::*, deposit::*, errors::AuctionHouseError,
-    execute_sale::*, receipt::*, sell::*, utils::*, withdraw::*,
+    auctioneer::*, bid::*, cancel::*, constants::*, counter_offer::*, deposit::*,
+    errors::AuctionHouseError, execute_sale::*, fee_discount::*, governance::*, receipt::*,
+    sell::*, stats::*, sweep::*, utils::*, withdraw::*,
 };
 
 use anchor_lang::{
@@ -90,6 +96,14 @@ pub mod auction_house {
         let token_program = &ctx.accounts.token_program;
         let system_program = &ctx.accounts.system_program;
 
+        if auction_house.has_governance {
+            governance::consume_instruction_change(
+                auction_house,
+                ctx.remaining_accounts,
+                |change| change.treasury_withdrawal == Some(amount),
+            )?;
+        }
+
         let is_native = treasury_mint.key() == spl_token::native_mint::id();
         let auction_house_seeds = [
             PREFIX.as_bytes(),
@@ -163,6 +177,10 @@ pub mod auction_house {
         let rent = &ctx.accounts.rent;
         let is_native = treasury_mint.key() == spl_token::native_mint::id();
 
+        if auction_house.has_governance {
+            return Err(AuctionHouseError::GovernanceEnabled.into());
+        }
+
         if let Some(sfbp) = seller_fee_basis_points {
             if sfbp > 10000 {
                 return Err(AuctionHouseError::InvalidBasisPoints.into());
@@ -212,6 +230,46 @@ pub mod auction_house {
         Ok(())
     }
 
+    /// Set the maximum share of the Auction House fee, in basis points, that a sale can pay to a referrer.
+    pub fn set_max_referral_basis_points<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateAuctionHouseSetting<'info>>,
+        max_referral_basis_points: u16,
+    ) -> Result<()> {
+        let auction_house = &mut ctx.accounts.auction_house;
+
+        if auction_house.has_governance {
+            return Err(AuctionHouseError::GovernanceEnabled.into());
+        }
+
+        if max_referral_basis_points > 10000 {
+            return Err(AuctionHouseError::InvalidBasisPoints.into());
+        }
+
+        auction_house.max_referral_basis_points = max_referral_basis_points;
+
+        Ok(())
+    }
+
+    /// Set how many seconds settled receipts are kept before their bookkeeper can close them.
+    pub fn set_receipt_retention_period<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateAuctionHouseSetting<'info>>,
+        receipt_retention_period: i64,
+    ) -> Result<()> {
+        let auction_house = &mut ctx.accounts.auction_house;
+
+        if auction_house.has_governance {
+            return Err(AuctionHouseError::GovernanceEnabled.into());
+        }
+
+        if receipt_retention_period < 0 {
+            return Err(AuctionHouseError::InvalidReceiptRetentionPeriod.into());
+        }
+
+        auction_house.receipt_retention_period = receipt_retention_period;
+
+        Ok(())
+    }
+
     /// Create a new Auction House instance.
     pub fn create_auction_house<'info>(
         ctx: Context<'_, '_, '_, 'info, CreateAuctionHouse<'info>>,
@@ -381,6 +439,23 @@ pub mod auction_house {
         )
     }
 
+    /// Create a public buy bid that several sellers can fill in parts through `execute_partial_sale`, passing the bid price and size as the partial order. The `public_buyer_trade_state` tracks the tokens left to buy and the escrow amount reserved for them. Sending the bid again only tops the escrow up to the amount still reserved, and a sale can't take more than it.
+    pub fn partial_public_buy<'info>(
+        ctx: Context<'_, '_, '_, 'info, PublicBuy<'info>>,
+        trade_state_bump: u8,
+        escrow_payment_bump: u8,
+        buyer_price: u64,
+        token_size: u64,
+    ) -> Result<()> {
+        bid::partial_public_bid(
+            ctx,
+            trade_state_bump,
+            escrow_payment_bump,
+            buyer_price,
+            token_size,
+        )
+    }
+
     /// Create a public buy bid by creating a `public_buyer_trade_state` account and an `escrow_payment` account and funding the escrow with the necessary SOL or SPL token amount.
     pub fn auctioneer_public_buy<'info>(
         ctx: Context<'_, '_, '_, 'info, AuctioneerPublicBuy<'info>>,
@@ -398,6 +473,40 @@ pub mod auction_house {
         )
     }
 
+    /// Create a private buy bid backed by a token delegation: the bid price is approved to the `program_as_signer` on the buyer SPL payment account instead of being locked in the escrow, and is pulled at `execute_sale`. Canceling the bid lowers the allowance again.
+    pub fn delegated_buy<'info>(
+        ctx: Context<'_, '_, '_, 'info, DelegatedBuy<'info>>,
+        trade_state_bump: u8,
+        escrow_payment_bump: u8,
+        buyer_price: u64,
+        token_size: u64,
+    ) -> Result<()> {
+        bid::delegated_private_bid(
+            ctx,
+            trade_state_bump,
+            escrow_payment_bump,
+            buyer_price,
+            token_size,
+        )
+    }
+
+    /// Create a public buy bid backed by a token delegation on the buyer SPL payment account, see `delegated_buy`.
+    pub fn delegated_public_buy<'info>(
+        ctx: Context<'_, '_, '_, 'info, DelegatedPublicBuy<'info>>,
+        trade_state_bump: u8,
+        escrow_payment_bump: u8,
+        buyer_price: u64,
+        token_size: u64,
+    ) -> Result<()> {
+        bid::delegated_public_bid(
+            ctx,
+            trade_state_bump,
+            escrow_payment_bump,
+            buyer_price,
+            token_size,
+        )
+    }
+
     /// Cancel a bid or ask by revoking the token delegate, transferring all lamports from the trade state account to the fee payer, and setting the trade state account data to zero so it can be garbage collected.
     pub fn cancel<'info>(
         ctx: Context<'_, '_, '_, 'info, Cancel<'info>>,
@@ -449,6 +558,29 @@ pub mod auction_house {
             program_as_signer_bump,
             buyer_price,
             token_size,
+            None,
+        )
+    }
+
+    /// Execute a sale, paying `referral_basis_points` of the Auction House fee to the referrer
+    /// passed after the creators in the remaining accounts.
+    pub fn execute_sale_with_referral<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteSale<'info>>,
+        escrow_payment_bump: u8,
+        _free_trade_state_bump: u8,
+        program_as_signer_bump: u8,
+        buyer_price: u64,
+        token_size: u64,
+        referral_basis_points: u16,
+    ) -> Result<()> {
+        execute_sale::execute_sale(
+            ctx,
+            escrow_payment_bump,
+            _free_trade_state_bump,
+            program_as_signer_bump,
+            buyer_price,
+            token_size,
+            Some(referral_basis_points),
         )
     }
 
@@ -471,6 +603,33 @@ pub mod auction_house {
             token_size,
             partial_order_size,
             partial_order_price,
+            None,
+        )
+    }
+
+    /// Execute a partial sale, paying `referral_basis_points` of the Auction House fee to the
+    /// referrer passed after the creators in the remaining accounts.
+    pub fn execute_partial_sale_with_referral<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecutePartialSale<'info>>,
+        escrow_payment_bump: u8,
+        _free_trade_state_bump: u8,
+        program_as_signer_bump: u8,
+        buyer_price: u64,
+        token_size: u64,
+        partial_order_size: Option<u64>,
+        partial_order_price: Option<u64>,
+        referral_basis_points: u16,
+    ) -> Result<()> {
+        execute_sale::execute_partial_sale(
+            ctx,
+            escrow_payment_bump,
+            _free_trade_state_bump,
+            program_as_signer_bump,
+            buyer_price,
+            token_size,
+            partial_order_size,
+            partial_order_price,
+            Some(referral_basis_points),
         )
     }
 
@@ -514,6 +673,66 @@ pub mod auction_house {
         )
     }
 
+    /// Partial sale of an auctioneer listing paid at `settlement_price`, which can be lower than
+    /// the bid. The rest of the bid stays in the buyer escrow.
+    pub fn auctioneer_execute_partial_sale_at_price<'info>(
+        ctx: Context<'_, '_, '_, 'info, AuctioneerExecutePartialSale<'info>>,
+        escrow_payment_bump: u8,
+        _free_trade_state_bump: u8,
+        program_as_signer_bump: u8,
+        buyer_price: u64,
+        token_size: u64,
+        partial_order_size: u64,
+        partial_order_price: u64,
+        settlement_price: u64,
+    ) -> Result<()> {
+        execute_sale::auctioneer_execute_partial_sale_at_price(
+            ctx,
+            escrow_payment_bump,
+            _free_trade_state_bump,
+            program_as_signer_bump,
+            buyer_price,
+            token_size,
+            partial_order_size,
+            partial_order_price,
+            settlement_price,
+        )
+    }
+
+    /// Buy many listings at once with the funds in the buyer escrow, optionally skipping listings
+    /// that are no longer available.
+    pub fn sweep<'info>(
+        ctx: Context<'_, '_, '_, 'info, Sweep<'info>>,
+        listings: Vec<SweepListing>,
+        skip_unavailable: bool,
+        referral_basis_points: Option<u16>,
+    ) -> Result<()> {
+        sweep::sweep(ctx, listings, skip_unavailable, referral_basis_points)
+    }
+
+    /// Counter a bid with a listing at a different price, recorded in a `counter_offer` account.
+    pub fn make_counter_offer<'info>(
+        ctx: Context<'_, '_, '_, 'info, MakeCounterOffer<'info>>,
+        price: u64,
+    ) -> Result<()> {
+        counter_offer::make_counter_offer(ctx, price)
+    }
+
+    /// Accept a counter-offer as the bidder, topping up the escrow and buying the listing.
+    pub fn accept_counter_offer<'info>(
+        ctx: Context<'_, '_, '_, 'info, AcceptCounterOffer<'info>>,
+    ) -> Result<()> {
+        counter_offer::accept_counter_offer(ctx)
+    }
+
+    /// Withdraw a counter-offer as either the bidder or the seller, or close it as anyone once its
+    /// listing has been canceled or sold.
+    pub fn withdraw_counter_offer<'info>(
+        ctx: Context<'_, '_, '_, 'info, WithdrawCounterOffer<'info>>,
+    ) -> Result<()> {
+        counter_offer::withdraw_counter_offer(ctx)
+    }
+
     pub fn sell<'info>(
         ctx: Context<'_, '_, '_, 'info, Sell<'info>>,
         trade_state_bump: u8,
@@ -532,6 +751,28 @@ pub mod auction_house {
         )
     }
 
+    /// Create a sell bid that can only be executed from `start_time` on, so a listing can be
+    /// published ahead of the time it becomes buyable.
+    pub fn scheduled_sell<'info>(
+        ctx: Context<'_, '_, '_, 'info, Sell<'info>>,
+        trade_state_bump: u8,
+        free_trade_state_bump: u8,
+        program_as_signer_bump: u8,
+        buyer_price: u64,
+        token_size: u64,
+        start_time: i64,
+    ) -> Result<()> {
+        sell::scheduled_sell(
+            ctx,
+            trade_state_bump,
+            free_trade_state_bump,
+            program_as_signer_bump,
+            buyer_price,
+            token_size,
+            start_time,
+        )
+    }
+
     pub fn auctioneer_sell<'info>(
         ctx: Context<'_, '_, '_, 'info, AuctioneerSell<'info>>,
         trade_state_bump: u8,
@@ -611,6 +852,86 @@ pub mod auction_house {
         auctioneer::update_auctioneer(ctx, scopes)
     }
 
+    /// Set an expiry and price and sale count limits on `scopes` of the delegated Auctioneer.
+    pub fn set_auctioneer_limits<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateAuctioneer<'info>>,
+        scopes: Vec<AuthorityScope>,
+        limits: AuctioneerLimits,
+    ) -> Result<()> {
+        auctioneer::set_auctioneer_limits(ctx, scopes, limits)
+    }
+
+    /// Revoke the delegated Auctioneer, closing its PDA and clearing its scopes.
+    pub fn revoke_auctioneer<'info>(
+        ctx: Context<'_, '_, '_, 'info, RevokeAuctioneer<'info>>,
+    ) -> Result<()> {
+        auctioneer::revoke_auctioneer(ctx)
+    }
+
+    /// Hand control of the Auction House parameters over to a set of signers. Once enabled,
+    /// `update_auction_house` is disabled and changes go through timelocked proposals.
+    pub fn enable_governance<'info>(
+        ctx: Context<'_, '_, '_, 'info, EnableGovernance<'info>>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+        delay: i64,
+    ) -> Result<()> {
+        governance::enable_governance(ctx, signers, threshold, delay)
+    }
+
+    /// Propose a change to the Auction House by creating a `pending_change` account.
+    pub fn propose_auction_house_change<'info>(
+        ctx: Context<'_, '_, '_, 'info, ProposeAuctionHouseChange<'info>>,
+        change: AuctionHouseChange,
+    ) -> Result<()> {
+        governance::propose_auction_house_change(ctx, change)
+    }
+
+    /// Approve a pending change as one of the governance signers.
+    pub fn approve_auction_house_change<'info>(
+        ctx: Context<'_, '_, '_, 'info, ApproveAuctionHouseChange<'info>>,
+    ) -> Result<()> {
+        governance::approve_auction_house_change(ctx)
+    }
+
+    /// Apply an approved pending change once its delay has passed.
+    pub fn execute_auction_house_change<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteAuctionHouseChange<'info>>,
+    ) -> Result<()> {
+        governance::execute_auction_house_change(ctx)
+    }
+
+    /// Cancel a pending change, closing the `pending_change` account.
+    pub fn cancel_auction_house_change<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelAuctionHouseChange<'info>>,
+    ) -> Result<()> {
+        governance::cancel_auction_house_change(ctx)
+    }
+
+    /// Create the schedule of discounted fees for holders of NFTs from selected collections.
+    pub fn create_fee_discount_schedule<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateFeeDiscountSchedule<'info>>,
+        discounts: Vec<FeeDiscount>,
+    ) -> Result<()> {
+        fee_discount::create_fee_discount_schedule(ctx, discounts)
+    }
+
+    /// Replace the discounts of the fee discount schedule.
+    pub fn update_fee_discount_schedule<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateFeeDiscountSchedule<'info>>,
+        discounts: Vec<FeeDiscount>,
+    ) -> Result<()> {
+        fee_discount::update_fee_discount_schedule(ctx, discounts)
+    }
+
+    /// Create the `collection_stats` account that `execute_sale` updates for sales of NFTs in a
+    /// verified collection. Stats are best effort: only sales passing the account are counted.
+    pub fn init_collection_stats<'info>(
+        ctx: Context<'_, '_, '_, 'info, InitCollectionStats<'info>>,
+    ) -> Result<()> {
+        stats::init_collection_stats(ctx)
+    }
+
     /// Create a listing receipt by creating a `listing_receipt` account.
     pub fn print_listing_receipt<'info>(
         ctx: Context<'_, '_, '_, 'info, PrintListingReceipt<'info>>,
@@ -649,6 +970,29 @@ pub mod auction_house {
         receipt::print_purchase_receipt(ctx, purchase_receipt_bump)
     }
 
+    /// Close a canceled or fulfilled listing receipt once the retention period has passed, counted
+    /// from the purchase for fulfilled receipts.
+    pub fn close_listing_receipt<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseListingReceipt<'info>>,
+    ) -> Result<()> {
+        receipt::close_listing_receipt(ctx)
+    }
+
+    /// Close a canceled or fulfilled bid receipt once the retention period has passed, counted from
+    /// the purchase for fulfilled receipts.
+    pub fn close_bid_receipt<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseBidReceipt<'info>>,
+    ) -> Result<()> {
+        receipt::close_bid_receipt(ctx)
+    }
+
+    /// Close a purchase receipt once the retention period has passed.
+    pub fn close_purchase_receipt<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClosePurchaseReceipt<'info>>,
+    ) -> Result<()> {
+        receipt::close_purchase_receipt(ctx)
+    }
+
     #[doc(hidden)]
     pub fn sell_remaining_accounts<'info>(
         _ctx: Context<'_, '_, '_, 'info, SellRemainingAccounts<'info>>,
@@ -760,6 +1104,17 @@ pub struct UpdateAuctionHouse<'info> {
     pub rent: Sysvar<'info, Rent>,
 }
 
+/// Accounts for the [`set_max_referral_basis_points`](auction_house/fn.set_max_referral_basis_points.html) and [`set_receipt_retention_period`](auction_house/fn.set_receipt_retention_period.html) handlers.
+#[derive(Accounts)]
+pub struct UpdateAuctionHouseSetting<'info> {
+    /// Authority key for the Auction House.
+    pub authority: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(mut, seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref(), auction_house.treasury_mint.as_ref()], bump=auction_house.bump, has_one=authority)]
+    pub auction_house: Account<'info, AuctionHouse>,
+}
+
 /// Accounts for the [`withdraw_from_treasury` handler](auction_house/fn.withdraw_from_treasury.html).
 #[derive(Accounts)]
 pub struct WithdrawFromTreasury<'info> {
@@ -161,3 +161,57 @@ pub fn find_auctioneer_trade_state_address(
         &id(),
     )
 }
+
+pub fn find_governance_address(auction_house: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[GOVERNANCE.as_bytes(), auction_house.as_ref()], &id())
+}
+
+/// Return pending change `Pubkey` address and bump seed for the proposal `index`.
+pub fn find_pending_change_address(auction_house: &Pubkey, index: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            PENDING_CHANGE.as_bytes(),
+            auction_house.as_ref(),
+            &index.to_le_bytes(),
+        ],
+        &id(),
+    )
+}
+
+/// Return collection stats `Pubkey` address and bump seed.
+pub fn find_collection_stats_address(
+    auction_house: &Pubkey,
+    collection_mint: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            COLLECTION_STATS.as_bytes(),
+            auction_house.as_ref(),
+            collection_mint.as_ref(),
+        ],
+        &id(),
+    )
+}
+
+/// Return fee discount schedule `Pubkey` address and bump seed.
+pub fn find_fee_discount_schedule_address(auction_house: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[FEE_DISCOUNT_SCHEDULE.as_bytes(), auction_house.as_ref()],
+        &id(),
+    )
+}
+
+/// Return counter-offer `Pubkey` address and bump seed.
+pub fn find_counter_offer_address(
+    bid_receipt: &Pubkey,
+    seller_trade_state: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            COUNTER_OFFER.as_bytes(),
+            bid_receipt.as_ref(),
+            seller_trade_state.as_ref(),
+        ],
+        &id(),
+    )
+}
@@ -5,6 +5,7 @@ use crate::{
     id,
     instruction::{Buy, ExecuteSale, Sell},
     utils::*,
+    AuctionHouse,
 };
 use anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize};
 use solana_program::{sysvar, sysvar::instructions::get_instruction_relative};
@@ -310,6 +311,7 @@ pub fn print_bid_receipt<'info>(
         BidType::PrivateSale => Some(token_account.pubkey),
         BidType::AuctioneerPrivateSale => Some(token_account.pubkey),
         BidType::PublicSale => None,
+        BidType::PartialPublicSale => None,
         BidType::AuctioneerPublicSale => None,
     };
 
@@ -572,3 +574,119 @@ pub fn print_purchase_receipt<'info>(
 
     Ok(())
 }
+
+/// Accounts for the [`close_listing_receipt` handler](fn.close_listing_receipt.html).
+#[derive(Accounts)]
+pub struct CloseListingReceipt<'info> {
+    #[account(mut, close = bookkeeper, has_one = bookkeeper, has_one = auction_house)]
+    receipt: Account<'info, ListingReceipt>,
+
+    #[account(mut)]
+    bookkeeper: Signer<'info>,
+
+    auction_house: Box<Account<'info, AuctionHouse>>,
+}
+
+/// Close a canceled or fulfilled listing receipt, returning its rent to the bookkeeper. A fulfilled
+/// receipt takes its purchase receipt as the first remaining account.
+pub fn close_listing_receipt<'info>(
+    ctx: Context<'_, '_, '_, 'info, CloseListingReceipt<'info>>,
+) -> Result<()> {
+    let receipt = &ctx.accounts.receipt;
+
+    let settled_at = match (receipt.canceled_at, receipt.purchase_receipt) {
+        (Some(canceled_at), _) => canceled_at,
+        (None, Some(purchase_receipt)) => {
+            purchase_created_at(purchase_receipt, ctx.remaining_accounts)?
+                .unwrap_or(receipt.created_at)
+        }
+        (None, None) => return Err(AuctionHouseError::ReceiptIsActive.into()),
+    };
+
+    assert_receipt_retention_elapsed(&ctx.accounts.auction_house, settled_at)
+}
+
+/// Accounts for the [`close_bid_receipt` handler](fn.close_bid_receipt.html).
+#[derive(Accounts)]
+pub struct CloseBidReceipt<'info> {
+    #[account(mut, close = bookkeeper, has_one = bookkeeper, has_one = auction_house)]
+    receipt: Account<'info, BidReceipt>,
+
+    #[account(mut)]
+    bookkeeper: Signer<'info>,
+
+    auction_house: Box<Account<'info, AuctionHouse>>,
+}
+
+/// Close a canceled or fulfilled bid receipt, returning its rent to the bookkeeper. A fulfilled
+/// receipt takes its purchase receipt as the first remaining account.
+pub fn close_bid_receipt<'info>(
+    ctx: Context<'_, '_, '_, 'info, CloseBidReceipt<'info>>,
+) -> Result<()> {
+    let receipt = &ctx.accounts.receipt;
+
+    let settled_at = match (receipt.canceled_at, receipt.purchase_receipt) {
+        (Some(canceled_at), _) => canceled_at,
+        (None, Some(purchase_receipt)) => {
+            purchase_created_at(purchase_receipt, ctx.remaining_accounts)?
+                .unwrap_or(receipt.created_at)
+        }
+        (None, None) => return Err(AuctionHouseError::ReceiptIsActive.into()),
+    };
+
+    assert_receipt_retention_elapsed(&ctx.accounts.auction_house, settled_at)
+}
+
+/// Accounts for the [`close_purchase_receipt` handler](fn.close_purchase_receipt.html).
+#[derive(Accounts)]
+pub struct ClosePurchaseReceipt<'info> {
+    #[account(mut, close = bookkeeper, has_one = bookkeeper, has_one = auction_house)]
+    receipt: Account<'info, PurchaseReceipt>,
+
+    #[account(mut)]
+    bookkeeper: Signer<'info>,
+
+    auction_house: Box<Account<'info, AuctionHouse>>,
+}
+
+/// Close a purchase receipt, returning its rent to the bookkeeper.
+pub fn close_purchase_receipt<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClosePurchaseReceipt<'info>>,
+) -> Result<()> {
+    assert_receipt_retention_elapsed(&ctx.accounts.auction_house, ctx.accounts.receipt.created_at)
+}
+
+/// Creation time of the purchase receipt that fulfilled a listing or bid receipt, or `None` if the
+/// purchase receipt has already been closed.
+fn purchase_created_at(
+    purchase_receipt: Pubkey,
+    remaining_accounts: &[AccountInfo],
+) -> Result<Option<i64>> {
+    let purchase_receipt_info = remaining_accounts
+        .first()
+        .ok_or(ErrorCode::AccountNotEnoughKeys)?;
+    assert_keys_equal(purchase_receipt_info.key(), purchase_receipt)?;
+
+    if purchase_receipt_info.data_is_empty() {
+        return Ok(None);
+    }
+
+    assert_owned_by(purchase_receipt_info, &id())?;
+    let purchase_receipt_data = purchase_receipt_info.try_borrow_data()?;
+    let purchase = PurchaseReceipt::try_deserialize(&mut purchase_receipt_data.as_ref())?;
+
+    Ok(Some(purchase.created_at))
+}
+
+/// Receipts are kept for the Auction House retention period after they were settled, which is
+/// the cancelation time or, for fulfilled receipts, the time of the purchase. Once the purchase
+/// receipt has been closed, the creation time of the fulfilled receipt is used instead.
+fn assert_receipt_retention_elapsed(auction_house: &AuctionHouse, settled_at: i64) -> Result<()> {
+    let closable_at = settled_at.saturating_add(auction_house.receipt_retention_period);
+
+    if Clock::get()?.unix_timestamp < closable_at {
+        return Err(AuctionHouseError::ReceiptRetentionPeriodActive.into());
+    }
+
+    Ok(())
+}
@@ -273,6 +273,47 @@ pub fn sell<'info>(
     program_as_signer_bump: u8,
     buyer_price: u64,
     token_size: u64,
+) -> Result<()> {
+    checked_sell(
+        ctx,
+        trade_state_bump,
+        free_trade_state_bump,
+        program_as_signer_bump,
+        buyer_price,
+        token_size,
+        None,
+    )
+}
+
+/// Create a sell bid like [`sell`] that can only be executed from `start_time` on.
+pub fn scheduled_sell<'info>(
+    ctx: Context<'_, '_, '_, 'info, Sell<'info>>,
+    trade_state_bump: u8,
+    free_trade_state_bump: u8,
+    program_as_signer_bump: u8,
+    buyer_price: u64,
+    token_size: u64,
+    start_time: i64,
+) -> Result<()> {
+    checked_sell(
+        ctx,
+        trade_state_bump,
+        free_trade_state_bump,
+        program_as_signer_bump,
+        buyer_price,
+        token_size,
+        Some(start_time),
+    )
+}
+
+fn checked_sell<'info>(
+    ctx: Context<'_, '_, '_, 'info, Sell<'info>>,
+    trade_state_bump: u8,
+    free_trade_state_bump: u8,
+    program_as_signer_bump: u8,
+    buyer_price: u64,
+    token_size: u64,
+    start_time: Option<i64>,
 ) -> Result<()> {
     let auction_house = &ctx.accounts.auction_house;
 
@@ -310,6 +351,7 @@ pub fn sell<'info>(
         program_as_signer_bump,
         buyer_price,
         token_size,
+        start_time,
     )
 }
 
@@ -334,6 +376,7 @@ pub fn auctioneer_sell<'info>(
         &auctioneer_authority.key(),
         ah_auctioneer_pda,
         AuthorityScope::Sell,
+        None,
     )?;
 
     let trade_state_canonical_bump = *ctx
@@ -367,6 +410,7 @@ pub fn auctioneer_sell<'info>(
         program_as_signer_bump,
         u64::MAX,
         token_size,
+        None,
     )
 }
 
@@ -380,6 +424,7 @@ fn sell_logic<'c, 'info>(
     _program_as_signer_bump: u8,
     buyer_price: u64,
     token_size: u64,
+    start_time: Option<i64>,
 ) -> Result<()> {
     let wallet = &accounts.wallet;
     let token_account = &accounts.token_account;
@@ -533,6 +578,11 @@ fn sell_logic<'c, 'info>(
         }
     }
 
+    let trade_state_size = match start_time {
+        Some(_) => SCHEDULED_TRADE_STATE_SIZE,
+        None => TRADE_STATE_SIZE,
+    };
+
     let ts_info = seller_trade_state.to_account_info();
     if ts_info.data_is_empty() {
         let token_account_key = token_account.key();
@@ -554,14 +604,20 @@ fn sell_logic<'c, 'info>(
             &rent.to_account_info(),
             system_program,
             &fee_payer,
-            TRADE_STATE_SIZE,
+            trade_state_size,
             fee_seeds,
             &ts_seeds,
         )?;
+    } else if ts_info.data_len() != trade_state_size {
+        // Listing again at the same price can't add or remove a start time.
+        return Err(AuctionHouseError::TradeStateIsNotEmpty.into());
     }
 
     let data = &mut ts_info.data.borrow_mut();
     data[0] = trade_state_bump;
+    if let Some(start_time) = start_time {
+        data[1..SCHEDULED_TRADE_STATE_SIZE].copy_from_slice(&start_time.to_le_bytes());
+    }
 
     Ok(())
 }
@@ -1,6 +1,6 @@
 use anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize};
 
-use crate::constants::*;
+use crate::{constants::*, errors::AuctionHouseError};
 
 #[account]
 pub struct AuctionHouse {
@@ -21,6 +21,70 @@ pub struct AuctionHouse {
     pub has_auctioneer: bool,
     pub auctioneer_address: Pubkey,
     pub scopes: [bool; MAX_NUM_SCOPES],
+    pub max_referral_basis_points: u16,
+    pub has_governance: bool,
+    pub receipt_retention_period: i64,
+    pub auctioneer_count: u8,
+    pub scope_delegations: [u8; MAX_NUM_SCOPES],
+}
+
+impl AuctionHouse {
+    /// Auction houses delegated before several auctioneers were supported only track the single
+    /// delegation through `has_auctioneer` and `scopes`, seed the counters from those.
+    pub fn sync_legacy_delegation(&mut self) {
+        if self.has_auctioneer && self.auctioneer_count == 0 {
+            self.auctioneer_count = 1;
+            for (delegations, scope) in self.scope_delegations.iter_mut().zip(self.scopes) {
+                *delegations = scope as u8;
+            }
+        }
+    }
+
+    /// Record a new auctioneer delegated `scopes`.
+    pub fn add_delegation(&mut self, scopes: &[bool; MAX_NUM_SCOPES]) -> Result<()> {
+        self.auctioneer_count = self
+            .auctioneer_count
+            .checked_add(1)
+            .ok_or(AuctionHouseError::NumericalOverflow)?;
+        self.add_scopes(scopes)
+    }
+
+    /// Remove an auctioneer delegated `scopes`.
+    pub fn remove_delegation(&mut self, scopes: &[bool; MAX_NUM_SCOPES]) {
+        self.auctioneer_count = self.auctioneer_count.saturating_sub(1);
+        self.remove_scopes(scopes);
+    }
+
+    pub fn add_scopes(&mut self, scopes: &[bool; MAX_NUM_SCOPES]) -> Result<()> {
+        for (delegations, scope) in self.scope_delegations.iter_mut().zip(scopes) {
+            if *scope {
+                *delegations = delegations
+                    .checked_add(1)
+                    .ok_or(AuctionHouseError::NumericalOverflow)?;
+            }
+        }
+        self.refresh_scopes();
+
+        Ok(())
+    }
+
+    pub fn remove_scopes(&mut self, scopes: &[bool; MAX_NUM_SCOPES]) {
+        for (delegations, scope) in self.scope_delegations.iter_mut().zip(scopes) {
+            if *scope {
+                *delegations = delegations.saturating_sub(1);
+            }
+        }
+        self.refresh_scopes();
+    }
+
+    /// `scopes` holds every scope delegated to at least one auctioneer, so the non-auctioneer
+    /// instructions stay disabled for them.
+    fn refresh_scopes(&mut self) {
+        self.has_auctioneer = self.auctioneer_count > 0;
+        for (scope, delegations) in self.scopes.iter_mut().zip(self.scope_delegations) {
+            *scope = delegations > 0;
+        }
+    }
 }
 
 #[account]
@@ -28,6 +92,66 @@ pub struct Auctioneer {
     pub auctioneer_authority: Pubkey,
     pub auction_house: Pubkey,
     pub bump: u8,
+    pub limits: AuctioneerLimits,
+    pub sales_count: u64,
+    pub scopes: [bool; MAX_NUM_SCOPES],
+    /// Scopes `limits` applies to.
+    pub limited_scopes: [bool; MAX_NUM_SCOPES],
+}
+
+impl Auctioneer {
+    /// Scopes delegated to this auctioneer. Delegations made before scopes were stored per
+    /// auctioneer are the only one of their auction house and use the auction house scopes.
+    pub fn delegated_scopes(&self, auction_house: &AuctionHouse) -> [bool; MAX_NUM_SCOPES] {
+        if auction_house.auctioneer_count == 0 {
+            auction_house.scopes
+        } else {
+            self.scopes
+        }
+    }
+
+    /// Check the delegation limits for an action in `scope`, `price` being the sale or bid price
+    /// of the action if it has one.
+    pub fn assert_within_limits(
+        &self,
+        scope: AuthorityScope,
+        price: Option<u64>,
+        now: i64,
+    ) -> Result<()> {
+        if !self.limited_scopes[scope.clone() as usize] {
+            return Ok(());
+        }
+
+        if matches!(self.limits.expires_at, Some(expires_at) if now >= expires_at) {
+            return Err(AuctionHouseError::AuctioneerDelegationExpired.into());
+        }
+
+        if let (Some(max_sale_price), Some(price)) = (self.limits.max_sale_price, price) {
+            if price > max_sale_price {
+                return Err(AuctionHouseError::AuctioneerPriceLimitExceeded.into());
+            }
+        }
+
+        if scope == AuthorityScope::ExecuteSale
+            && matches!(self.limits.max_sales, Some(max_sales) if self.sales_count >= max_sales)
+        {
+            return Err(AuctionHouseError::AuctioneerSaleLimitReached.into());
+        }
+
+        Ok(())
+    }
+}
+
+/// Optional restrictions on the limited scopes of an Auctioneer delegation, `None` values are
+/// unlimited.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, PartialEq, Eq, Debug)]
+pub struct AuctioneerLimits {
+    /// Unix timestamp after which the delegation can no longer be used.
+    pub expires_at: Option<i64>,
+    /// Highest price for bids and sales executed by the Auctioneer.
+    pub max_sale_price: Option<u64>,
+    /// Number of sales the Auctioneer can execute, if the `ExecuteSale` scope is limited.
+    pub max_sales: Option<u64>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
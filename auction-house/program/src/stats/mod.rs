@@ -0,0 +1,147 @@
+//! Per-collection sales statistics maintained by `execute_sale`.
+//!
+//! Stats accounts are opt-in: once initialized for an Auction House and a collection mint, they
+//! are updated by any sale whose remaining accounts include them after the creators and referrer.
+//!
+//! Stats are best effort. A sale that leaves the stats account out still settles and is not
+//! counted, so the figures are a lower bound fed by the clients that pass the account, not a
+//! complete record of the collection's sales. Indexers needing exact volumes should follow the
+//! sales themselves.
+use crate::{constants::*, errors::AuctionHouseError, AuctionHouse};
+use anchor_lang::{prelude::*, AnchorDeserialize, Discriminator};
+use anchor_spl::token::Mint;
+use mpl_token_metadata::state::{Metadata, TokenMetadataAccount};
+use std::slice::Iter;
+
+pub const COLLECTION_STATS_SIZE: usize = 8 + // key
+32 + // auction_house
+32 + // collection_mint
+8 + // volume
+8 + // sale_count
+8 + // last_sale_price
+8 + // last_sale_timestamp
+1 + // bump
+64; // padding
+
+/// Cumulative sales of a verified collection on an Auction House, counting only the sales that
+/// passed this account (see the module documentation).
+#[account]
+pub struct CollectionStats {
+    pub auction_house: Pubkey,
+    pub collection_mint: Pubkey,
+    pub volume: u64,
+    pub sale_count: u64,
+    pub last_sale_price: u64,
+    pub last_sale_timestamp: i64,
+    pub bump: u8,
+}
+
+impl CollectionStats {
+    /// Decode a `collection_stats` account from its raw data, checking the account discriminator.
+    pub fn from_account_data(data: &[u8]) -> Result<Self> {
+        let mut data = data;
+        Self::try_deserialize(&mut data)
+    }
+
+    fn record_sale(&mut self, price: u64, timestamp: i64) {
+        self.volume = self.volume.saturating_add(price);
+        self.sale_count = self.sale_count.saturating_add(1);
+        self.last_sale_price = price;
+        self.last_sale_timestamp = timestamp;
+    }
+}
+
+/// Accounts for the [`init_collection_stats` handler](auction_house/fn.init_collection_stats.html).
+#[derive(Accounts)]
+pub struct InitCollectionStats<'info> {
+    /// Account paying for the `collection_stats` account rent.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// Mint of the collection NFT.
+    pub collection_mint: Account<'info, Mint>,
+
+    /// Collection stats PDA account.
+    #[account(
+        init,
+        payer = payer,
+        space = COLLECTION_STATS_SIZE,
+        seeds = [
+            COLLECTION_STATS.as_bytes(),
+            auction_house.key().as_ref(),
+            collection_mint.key().as_ref()
+        ],
+        bump
+    )]
+    pub collection_stats: Account<'info, CollectionStats>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn init_collection_stats<'info>(
+    ctx: Context<'_, '_, '_, 'info, InitCollectionStats<'info>>,
+) -> Result<()> {
+    let collection_stats = &mut ctx.accounts.collection_stats;
+    collection_stats.auction_house = ctx.accounts.auction_house.key();
+    collection_stats.collection_mint = ctx.accounts.collection_mint.key();
+    collection_stats.bump = *ctx
+        .bumps
+        .get("collection_stats")
+        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+
+    Ok(())
+}
+
+/// Record a sale in the `collection_stats` account if it is the next remaining account, leaving
+/// the iterator untouched otherwise. Leaving the account out is not an error: stats are best
+/// effort.
+pub fn update_collection_stats<'c, 'info>(
+    remaining_accounts: &mut Iter<'c, AccountInfo<'info>>,
+    auction_house: &Pubkey,
+    metadata: &AccountInfo<'info>,
+    price: u64,
+) -> Result<()> {
+    let collection_stats_info = match remaining_accounts.as_slice().first() {
+        Some(account) if is_collection_stats(account) => account,
+        _ => return Ok(()),
+    };
+    remaining_accounts.next();
+
+    let metadata = Metadata::from_account_info(metadata)?;
+    let collection_mint = match metadata.collection {
+        Some(collection) if collection.verified => collection.key,
+        _ => return Err(AuctionHouseError::CollectionNotVerified.into()),
+    };
+
+    let mut collection_stats: Account<CollectionStats> = Account::try_from(collection_stats_info)?;
+    if collection_stats.auction_house != *auction_house
+        || collection_stats.collection_mint != collection_mint
+    {
+        return Err(AuctionHouseError::PublicKeyMismatch.into());
+    }
+    if !collection_stats_info.is_writable {
+        return Err(ErrorCode::AccountNotMutable.into());
+    }
+
+    collection_stats.record_sale(price, Clock::get()?.unix_timestamp);
+    collection_stats.exit(&crate::id())
+}
+
+pub(crate) fn is_collection_stats(account: &AccountInfo) -> bool {
+    *account.owner == crate::id()
+        && account
+            .try_borrow_data()
+            .map(|data| data.starts_with(&CollectionStats::discriminator()))
+            .unwrap_or(false)
+}
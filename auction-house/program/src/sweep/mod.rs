@@ -0,0 +1,357 @@
+use crate::{
+    constants::*, errors::*, execute_sale::SaleSettlement, fee_discount::is_fee_discount_schedule,
+    stats::is_collection_stats, utils::*, AuctionHouse, AuthorityScope, *,
+};
+use anchor_lang::{
+    prelude::*,
+    solana_program::{program_option::COption, program_pack::Pack},
+    AnchorDeserialize, AnchorSerialize,
+};
+use mpl_token_metadata::state::{Metadata, TokenMetadataAccount, TokenStandard};
+use spl_token::state::Account as SplAccount;
+use std::slice::Iter;
+
+/// Price and size of a listing to buy in a sweep, matching the seller trade state seeds.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SweepListing {
+    pub price: u64,
+    pub token_size: u64,
+}
+
+/// Accounts for the [`sweep` handler](auction_house/fn.sweep.html).
+#[derive(Accounts)]
+pub struct Sweep<'info> {
+    /// Buyer user wallet account, paying for every listing from its escrow.
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    /// CHECK: Verified through has_one constraint on auction_house.
+    /// Auction House treasury mint account.
+    pub treasury_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Buyer escrow payment account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            buyer.key().as_ref()
+        ],
+        bump
+    )]
+    pub escrow_payment_account: UncheckedAccount<'info>,
+
+    /// CHECK: Verified through has_one constraint on auction_house.
+    /// Auction House instance authority.
+    pub authority: UncheckedAccount<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=authority,
+        has_one=treasury_mint,
+        has_one=auction_house_treasury,
+        has_one=auction_house_fee_account
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Auction House instance fee account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            FEE_PAYER.as_bytes()
+        ],
+        bump=auction_house.fee_payer_bump
+    )]
+    pub auction_house_fee_account: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Auction House instance treasury account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            TREASURY.as_bytes()
+        ],
+        bump=auction_house.treasury_bump
+    )]
+    pub auction_house_treasury: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub ata_program: Program<'info, AssociatedToken>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    #[account(seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()], bump)]
+    pub program_as_signer: UncheckedAccount<'info>,
+
+    pub rent: Sysvar<'info, Rent>,
+    // Each listing is followed in the remaining accounts by a group of:
+    // seller trade state (mut), seller (mut), token account (mut), token mint, metadata,
+    // seller payment receipt account (mut), buyer receipt token account (mut), then the creator
+    // accounts of the metadata (mut), referrer, collection stats and fee discount proof, laid out
+    // as in `execute_sale`.
+}
+
+/// Buy every listing in `listings` with the funds in the buyer escrow, paying royalties and
+/// Auction House fees for each item. With `skip_unavailable`, listings that were canceled, sold
+/// or are no longer delegated to the Auction House are skipped instead of failing the sweep.
+/// `referral_basis_points` applies to every listing, each passing its own referrer.
+///
+/// Programmable NFTs are not supported and must be bought through `execute_sale`.
+pub fn sweep<'info>(
+    ctx: Context<'_, '_, '_, 'info, Sweep<'info>>,
+    listings: Vec<SweepListing>,
+    skip_unavailable: bool,
+    referral_basis_points: Option<u16>,
+) -> Result<()> {
+    let auction_house = &ctx.accounts.auction_house;
+
+    // If it has an auctioneer authority delegated must use auctioneer_* handler.
+    if auction_house.has_auctioneer && auction_house.scopes[AuthorityScope::ExecuteSale as usize] {
+        return Err(AuctionHouseError::MustUseAuctioneerHandler.into());
+    }
+
+    let escrow_payment_bump = *ctx
+        .bumps
+        .get("escrow_payment_account")
+        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+    let program_as_signer_bump = *ctx
+        .bumps
+        .get("program_as_signer")
+        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+
+    let remaining_accounts = &mut ctx.remaining_accounts.iter();
+    let mut purchased = 0;
+
+    for listing in listings.iter() {
+        if sweep_listing(
+            ctx.accounts,
+            remaining_accounts,
+            escrow_payment_bump,
+            program_as_signer_bump,
+            listing,
+            skip_unavailable,
+            referral_basis_points,
+        )? {
+            purchased += 1;
+        }
+    }
+
+    msg!("Purchased {} of {} listings", purchased, listings.len());
+
+    Ok(())
+}
+
+/// Check that the listing can still be filled. Invalid accounts are an error, while a listing that
+/// was canceled, sold or whose delegation was revoked returns `false`.
+fn is_listing_available<'info>(
+    accounts: &Sweep<'info>,
+    seller_trade_state: &AccountInfo<'info>,
+    seller: &AccountInfo<'info>,
+    token_account: &AccountInfo<'info>,
+    token_mint: &AccountInfo<'info>,
+    listing: &SweepListing,
+) -> Result<bool> {
+    if seller_trade_state.data_is_empty() || seller_trade_state.try_borrow_data()?[0] == 0 {
+        msg!("Listing {} was canceled or sold", seller_trade_state.key);
+        return Ok(false);
+    }
+
+    let auction_house_key = accounts.auction_house.key();
+    let seller_trade_state_key = Pubkey::create_program_address(
+        &[
+            PREFIX.as_bytes(),
+            seller.key.as_ref(),
+            auction_house_key.as_ref(),
+            token_account.key.as_ref(),
+            accounts.auction_house.treasury_mint.as_ref(),
+            token_mint.key.as_ref(),
+            &listing.price.to_le_bytes(),
+            &listing.token_size.to_le_bytes(),
+            &[seller_trade_state.try_borrow_data()?[0]],
+        ],
+        &crate::id(),
+    )
+    .map_err(|_| AuctionHouseError::DerivedKeyInvalid)?;
+    assert_keys_equal(seller_trade_state_key, seller_trade_state.key())?;
+
+    if let Some(start_time) = get_listing_start_time(seller_trade_state)? {
+        if Clock::get()?.unix_timestamp < start_time {
+            msg!("Listing {} is not buyable yet", seller_trade_state.key);
+            return Ok(false);
+        }
+    }
+
+    if token_account.data_is_empty() {
+        msg!("Token account {} was closed", token_account.key);
+        return Ok(false);
+    }
+
+    let token_account_data = assert_is_ata(token_account, seller.key, token_mint.key)?;
+
+    if token_account_data.amount < listing.token_size
+        || token_account_data.delegate != COption::Some(accounts.program_as_signer.key())
+        || token_account_data.delegated_amount < listing.token_size
+    {
+        msg!("Token account {} is no longer delegated", token_account.key);
+        return Ok(false);
+    }
+
+    Ok(true)
+}
+
+/// Buy a single listing of the sweep, returning whether it was purchased.
+#[inline(never)]
+pub fn sweep_listing<'c, 'info>(
+    accounts: &Sweep<'info>,
+    remaining_accounts: &mut Iter<'c, AccountInfo<'info>>,
+    escrow_payment_bump: u8,
+    program_as_signer_bump: u8,
+    listing: &SweepListing,
+    skip_unavailable: bool,
+    referral_basis_points: Option<u16>,
+) -> Result<bool> {
+    let seller_trade_state = next_account_info(remaining_accounts)?;
+    let seller = next_account_info(remaining_accounts)?;
+    let token_account = next_account_info(remaining_accounts)?;
+    let token_mint = next_account_info(remaining_accounts)?;
+    let metadata = next_account_info(remaining_accounts)?;
+    let seller_payment_receipt_account = next_account_info(remaining_accounts)?;
+    let buyer_receipt_token_account = next_account_info(remaining_accounts)?;
+
+    let auction_house = &accounts.auction_house;
+    let is_native = accounts.treasury_mint.key() == spl_token::native_mint::id();
+
+    // Free listings need the seller or the Auction House to sign off, see `execute_sale`.
+    if listing.price == 0 {
+        return Err(
+            AuctionHouseError::CannotMatchFreeSalesWithoutAuctionHouseOrSellerSignoff.into(),
+        );
+    }
+
+    assert_derivation(
+        &mpl_token_metadata::id(),
+        metadata,
+        &[
+            mpl_token_metadata::state::PREFIX.as_bytes(),
+            mpl_token_metadata::id().as_ref(),
+            token_mint.key.as_ref(),
+        ],
+    )?;
+
+    if metadata.data_is_empty() {
+        return Err(AuctionHouseError::MetadataDoesntExist.into());
+    }
+
+    // Programmable NFTs can only be moved by Token Metadata, which the sweep doesn't call.
+    let metadata_data = Metadata::from_account_info(metadata)?;
+    if metadata_data.token_standard == Some(TokenStandard::ProgrammableNonFungible) {
+        return Err(AuctionHouseError::ProgrammableNftNotSupported.into());
+    }
+
+    if !is_listing_available(
+        accounts,
+        seller_trade_state,
+        seller,
+        token_account,
+        token_mint,
+        listing,
+    )? {
+        if !skip_unavailable {
+            return Err(AuctionHouseError::ListingUnavailable.into());
+        }
+
+        // Step over the settlement accounts of the skipped listing.
+        let creators = metadata_data
+            .data
+            .creators
+            .map_or(0, |creators| creators.len());
+        let creator_accounts = if is_native { creators } else { creators * 2 };
+        let referrer_accounts = referral_basis_points.map_or(0, |_| 1);
+        for _ in 0..creator_accounts + referrer_accounts {
+            next_account_info(remaining_accounts)?;
+        }
+        let next = remaining_accounts.as_slice().first();
+        if matches!(next, Some(account) if is_collection_stats(account)) {
+            next_account_info(remaining_accounts)?;
+        }
+        let next = remaining_accounts.as_slice().first();
+        if matches!(next, Some(account) if is_fee_discount_schedule(account)) {
+            // The schedule, the holder token account and its metadata.
+            for _ in 0..3 {
+                next_account_info(remaining_accounts)?;
+            }
+        }
+
+        return Ok(false);
+    }
+
+    let auction_house_key = auction_house.key();
+    let seeds = [
+        PREFIX.as_bytes(),
+        auction_house_key.as_ref(),
+        FEE_PAYER.as_bytes(),
+        &[auction_house.fee_payer_bump],
+    ];
+
+    let (fee_payer, fee_payer_seeds) = get_fee_payer(
+        &accounts.authority,
+        auction_house,
+        accounts.buyer.to_account_info(),
+        accounts.auction_house_fee_account.to_account_info(),
+        &seeds,
+    )?;
+
+    let rent = accounts.rent.to_account_info();
+    let settlement = SaleSettlement {
+        auction_house,
+        auction_house_treasury: &accounts.auction_house_treasury,
+        escrow_payment_account: &accounts.escrow_payment_account,
+        treasury_mint: &accounts.treasury_mint,
+        buyer: &accounts.buyer,
+        seller,
+        seller_payment_receipt_account,
+        metadata,
+        token_account,
+        token_mint,
+        buyer_receipt_token_account,
+        fee_payer: &fee_payer,
+        program_as_signer: &accounts.program_as_signer,
+        token_program: &accounts.token_program,
+        system_program: &accounts.system_program,
+        ata_program: &accounts.ata_program,
+        rent: &rent,
+    };
+    settlement.pay(
+        remaining_accounts,
+        escrow_payment_bump,
+        fee_payer_seeds,
+        listing.price,
+        referral_basis_points,
+    )?;
+    // Programmable NFTs were rejected above, so the token moves with a plain SPL transfer.
+    settlement.deliver(
+        &mut [].iter(),
+        fee_payer_seeds,
+        program_as_signer_bump,
+        listing.token_size,
+    )?;
+
+    let token_account_data = SplAccount::unpack(&token_account.data.borrow())?;
+    if token_account_data.delegated_amount == 0 {
+        close_account(seller_trade_state, &fee_payer)?;
+    }
+
+    Ok(true)
+}
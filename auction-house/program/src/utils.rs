@@ -5,7 +5,7 @@ use crate::{
 use anchor_lang::{
     prelude::*,
     solana_program::{
-        program::invoke_signed,
+        program::{invoke, invoke_signed},
         program_memory::{sol_memcmp, sol_memset},
         program_option::COption,
         program_pack::{IsInitialized, Pack},
@@ -181,6 +181,7 @@ pub fn assert_keys_equal_err(key1: Pubkey, key2: Pubkey) -> Result<()> {
 #[derive(Debug, Clone)]
 pub enum BidType {
     PublicSale,
+    PartialPublicSale,
     PrivateSale,
     AuctioneerPublicSale,
     AuctioneerPrivateSale,
@@ -189,6 +190,7 @@ pub enum BidType {
 #[derive(Debug, Clone)]
 pub enum ListingType {
     Sell,
+    ScheduledSell,
     AuctioneerSell,
 }
 
@@ -207,6 +209,7 @@ pub enum CancelType {
 pub fn assert_program_bid_instruction(sighash: &[u8]) -> Result<BidType> {
     match sighash {
         [169, 84, 218, 35, 42, 206, 16, 171] => Ok(BidType::PublicSale),
+        [54, 36, 128, 214, 65, 161, 194, 72] => Ok(BidType::PartialPublicSale),
         [102, 6, 61, 18, 1, 218, 235, 234] => Ok(BidType::PrivateSale),
         [221, 239, 99, 240, 86, 46, 213, 126] => Ok(BidType::AuctioneerPublicSale),
         [17, 106, 133, 46, 229, 48, 45, 208] => Ok(BidType::AuctioneerPrivateSale),
@@ -217,6 +220,7 @@ pub fn assert_program_bid_instruction(sighash: &[u8]) -> Result<BidType> {
 pub fn assert_program_listing_instruction(sighash: &[u8]) -> Result<ListingType> {
     match sighash {
         [51, 230, 133, 164, 1, 127, 131, 173] => Ok(ListingType::Sell),
+        [181, 21, 189, 137, 0, 6, 237, 202] => Ok(ListingType::ScheduledSell),
         [251, 60, 142, 195, 121, 203, 26, 183] => Ok(ListingType::AuctioneerSell),
         _ => Err(AuctionHouseError::InstructionMismatch.into()),
     }
@@ -263,6 +267,8 @@ pub fn assert_owned_by(account: &AccountInfo, owner: &Pubkey) -> Result<()> {
     }
 }
 
+/// Pay the Auction House fee out of the escrow, sending `referral_basis_points` of it to the
+/// referrer when one is provided. Returns the total fee paid.
 #[allow(clippy::too_many_arguments)]
 pub fn pay_auction_house_fees<'a>(
     auction_house: &anchor_lang::prelude::Account<'a, AuctionHouse>,
@@ -273,26 +279,85 @@ pub fn pay_auction_house_fees<'a>(
     signer_seeds: &[&[u8]],
     size: u64,
     is_native: bool,
+    referral: Option<(&AccountInfo<'a>, u16)>,
+    fee_discount: Option<u16>,
 ) -> Result<u64> {
-    let fees = auction_house.seller_fee_basis_points;
+    // A holder discount can only lower the Auction House fee.
+    let fees = match fee_discount {
+        Some(discounted) => discounted.min(auction_house.seller_fee_basis_points),
+        None => auction_house.seller_fee_basis_points,
+    };
     let total_fee = (fees as u128)
         .checked_mul(size as u128)
         .ok_or(AuctionHouseError::NumericalOverflow)?
         .checked_div(10000)
         .ok_or(AuctionHouseError::NumericalOverflow)? as u64;
+
+    let referral_fee = match referral {
+        Some((referrer, referral_basis_points)) => {
+            let referral_fee = (referral_basis_points as u128)
+                .checked_mul(total_fee as u128)
+                .ok_or(AuctionHouseError::NumericalOverflow)?
+                .checked_div(10000)
+                .ok_or(AuctionHouseError::NumericalOverflow)? as u64;
+
+            if referral_fee > 0 {
+                transfer_from_escrow(
+                    auction_house,
+                    referrer,
+                    escrow_payment_account,
+                    token_program,
+                    system_program,
+                    signer_seeds,
+                    referral_fee,
+                    is_native,
+                )?;
+            }
+
+            referral_fee
+        }
+        None => 0,
+    };
+
+    transfer_from_escrow(
+        auction_house,
+        auction_house_treasury,
+        escrow_payment_account,
+        token_program,
+        system_program,
+        signer_seeds,
+        total_fee
+            .checked_sub(referral_fee)
+            .ok_or(AuctionHouseError::NumericalOverflow)?,
+        is_native,
+    )?;
+
+    Ok(total_fee)
+}
+
+fn transfer_from_escrow<'a>(
+    auction_house: &anchor_lang::prelude::Account<'a, AuctionHouse>,
+    destination: &AccountInfo<'a>,
+    escrow_payment_account: &AccountInfo<'a>,
+    token_program: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    signer_seeds: &[&[u8]],
+    amount: u64,
+    is_native: bool,
+) -> Result<()> {
     if !is_native {
         invoke_signed(
             &spl_token::instruction::transfer(
                 token_program.key,
                 escrow_payment_account.key,
-                auction_house_treasury.key,
+                destination.key,
                 &auction_house.key(),
                 &[],
-                total_fee,
+                amount,
             )?,
             &[
                 escrow_payment_account.clone(),
-                auction_house_treasury.clone(),
+                destination.clone(),
                 token_program.clone(),
                 auction_house.to_account_info(),
             ],
@@ -300,20 +365,150 @@ pub fn pay_auction_house_fees<'a>(
         )?;
     } else {
         invoke_signed(
-            &system_instruction::transfer(
-                escrow_payment_account.key,
-                auction_house_treasury.key,
-                total_fee,
-            ),
+            &system_instruction::transfer(escrow_payment_account.key, destination.key, amount),
             &[
                 escrow_payment_account.clone(),
-                auction_house_treasury.clone(),
+                destination.clone(),
                 system_program.clone(),
             ],
             &[signer_seeds],
         )?;
     }
-    Ok(total_fee)
+    Ok(())
+}
+
+/// Read the optional referrer from the remaining accounts, checking the referral basis points
+/// against the Auction House maximum and that SPL referrers hold the treasury mint.
+pub fn get_referral<'c, 'info>(
+    remaining_accounts: &mut Iter<'c, AccountInfo<'info>>,
+    auction_house: &anchor_lang::prelude::Account<'info, AuctionHouse>,
+    referral_basis_points: Option<u16>,
+    is_native: bool,
+) -> Result<Option<(&'c AccountInfo<'info>, u16)>> {
+    let referral_basis_points = match referral_basis_points {
+        Some(referral_basis_points) => referral_basis_points,
+        None => return Ok(None),
+    };
+
+    if referral_basis_points > auction_house.max_referral_basis_points {
+        return Err(AuctionHouseError::InvalidReferralBasisPoints.into());
+    }
+
+    let referrer = next_account_info(remaining_accounts)?;
+    if !is_native {
+        assert_owned_by(referrer, &spl_token::id())?;
+        let referrer_account: SplAccount = assert_initialized(referrer)?;
+        assert_keys_equal(referrer_account.mint, auction_house.treasury_mint)?;
+    }
+
+    Ok(Some((referrer, referral_basis_points)))
+}
+
+/// Move `amount` from a buyer payment account delegated to the program as signer by a delegated
+/// bid into the buyer escrow, so the sale then settles like any escrow backed bid.
+pub fn transfer_delegated_bid_funds<'a>(
+    buyer_payment_account: &AccountInfo<'a>,
+    escrow_payment_account: &AccountInfo<'a>,
+    buyer: &Pubkey,
+    treasury_mint: &Pubkey,
+    program_as_signer: &AccountInfo<'a>,
+    token_program: &AccountInfo<'a>,
+    program_as_signer_seeds: &[&[u8]],
+    amount: u64,
+) -> Result<()> {
+    if *treasury_mint == spl_token::native_mint::id() {
+        return Err(AuctionHouseError::DelegatedBidRequiresSplTreasuryMint.into());
+    }
+
+    assert_owned_by(buyer_payment_account, &spl_token::id())?;
+    let payment_account: SplAccount = assert_initialized(buyer_payment_account)?;
+    assert_keys_equal(payment_account.owner, *buyer)?;
+    assert_keys_equal(payment_account.mint, *treasury_mint)?;
+
+    if payment_account.delegate != COption::Some(*program_as_signer.key)
+        || payment_account.delegated_amount < amount
+    {
+        return Err(AuctionHouseError::InsufficientDelegatedFunds.into());
+    }
+
+    if payment_account.amount < amount {
+        return Err(AuctionHouseError::InsufficientFunds.into());
+    }
+
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            token_program.key,
+            buyer_payment_account.key,
+            escrow_payment_account.key,
+            program_as_signer.key,
+            &[],
+            amount,
+        )?,
+        &[
+            buyer_payment_account.clone(),
+            escrow_payment_account.clone(),
+            program_as_signer.clone(),
+            token_program.clone(),
+        ],
+        &[program_as_signer_seeds],
+    )?;
+
+    Ok(())
+}
+
+/// Lower the allowance of the program as signer on a buyer payment account by the price of a
+/// canceled delegated bid, revoking it once it no longer covers any bid. The allowance is left
+/// alone when the program as signer is no longer the delegate.
+pub fn release_delegated_bid_funds<'a>(
+    buyer_payment_account: &AccountInfo<'a>,
+    buyer: &AccountInfo<'a>,
+    treasury_mint: &Pubkey,
+    program_as_signer: &AccountInfo<'a>,
+    token_program: &AccountInfo<'a>,
+    amount: u64,
+) -> Result<()> {
+    assert_keys_equal(
+        *program_as_signer.key,
+        crate::pda::find_program_as_signer_address().0,
+    )?;
+    assert_owned_by(buyer_payment_account, &spl_token::id())?;
+    let payment_account: SplAccount = assert_initialized(buyer_payment_account)?;
+    assert_keys_equal(payment_account.owner, *buyer.key)?;
+    assert_keys_equal(payment_account.mint, *treasury_mint)?;
+
+    if payment_account.delegate != COption::Some(*program_as_signer.key) {
+        return Ok(());
+    }
+
+    let allowance = payment_account.delegated_amount.saturating_sub(amount);
+    let instruction = if allowance == 0 {
+        spl_token::instruction::revoke(
+            token_program.key,
+            buyer_payment_account.key,
+            buyer.key,
+            &[],
+        )?
+    } else {
+        spl_token::instruction::approve(
+            token_program.key,
+            buyer_payment_account.key,
+            program_as_signer.key,
+            buyer.key,
+            &[],
+            allowance,
+        )?
+    };
+    invoke(
+        &instruction,
+        &[
+            buyer_payment_account.clone(),
+            program_as_signer.clone(),
+            buyer.clone(),
+            token_program.clone(),
+        ],
+    )?;
+
+    Ok(())
 }
 
 pub fn create_program_token_account_if_not_present<'a>(
@@ -576,6 +771,51 @@ pub fn assert_derivation(program_id: &Pubkey, account: &AccountInfo, path: &[&[u
     Ok(bump)
 }
 
+/// Time a listing created by `scheduled_sell` becomes buyable, `None` for other listings.
+pub fn get_listing_start_time(seller_trade_state: &AccountInfo) -> Result<Option<i64>> {
+    if seller_trade_state.data_len() != SCHEDULED_TRADE_STATE_SIZE {
+        return Ok(None);
+    }
+
+    let data = seller_trade_state.try_borrow_data()?;
+    Ok(Some(i64::from_le_bytes(*array_ref![data, 1, 8])))
+}
+
+/// Tokens left to buy and escrow amount still reserved for them on a bid created by
+/// `partial_public_buy`, `None` for other bids.
+pub fn get_partial_bid(buyer_trade_state: &AccountInfo) -> Result<Option<(u64, u64)>> {
+    if buyer_trade_state.data_len() != PARTIAL_BID_TRADE_STATE_SIZE {
+        return Ok(None);
+    }
+
+    let data = buyer_trade_state.try_borrow_data()?;
+    Ok(Some((
+        u64::from_le_bytes(*array_ref![data, 1, 8]),
+        u64::from_le_bytes(*array_ref![data, 9, 8]),
+    )))
+}
+
+pub fn set_partial_bid(
+    buyer_trade_state: &AccountInfo,
+    remaining: u64,
+    reserved: u64,
+) -> Result<()> {
+    let mut data = buyer_trade_state.try_borrow_mut_data()?;
+    data[1..9].copy_from_slice(&remaining.to_le_bytes());
+    data[9..PARTIAL_BID_TRADE_STATE_SIZE].copy_from_slice(&reserved.to_le_bytes());
+
+    Ok(())
+}
+
+pub fn assert_listing_started(seller_trade_state: &AccountInfo) -> Result<()> {
+    match get_listing_start_time(seller_trade_state)? {
+        Some(start_time) if Clock::get()?.unix_timestamp < start_time => {
+            Err(AuctionHouseError::ListingNotStarted.into())
+        }
+        _ => Ok(()),
+    }
+}
+
 pub fn assert_valid_trade_state(
     wallet: &Pubkey,
     auction_house: &Account<AuctionHouse>,
@@ -663,13 +903,8 @@ pub fn assert_valid_auctioneer_and_scope(
     auctioneer_authority: &Pubkey,
     auctioneer_pda: &Account<Auctioneer>,
     scope: AuthorityScope,
+    price: Option<u64>,
 ) -> Result<()> {
-    // Assert the Auctioneer is tagged on the auction house
-    assert_keys_equal(
-        auction_house_instance.auctioneer_address,
-        auctioneer_pda.key(),
-    )
-    .map_err(|_e| AuctionHouseError::InvalidAuctioneer)?;
     // Assert the auctioneer_authority is tagged in the Auctioneer
     assert_keys_equal(
         auctioneer_pda.auctioneer_authority,
@@ -680,11 +915,11 @@ pub fn assert_valid_auctioneer_and_scope(
     assert_keys_equal(auctioneer_pda.auction_house, auction_house_instance.key())
         .map_err(|_e| AuctionHouseError::InvalidAuctioneer)?;
 
-    if !(auction_house_instance.scopes[scope as usize]) {
+    if !(auctioneer_pda.delegated_scopes(auction_house_instance)[scope.clone() as usize]) {
         return Err(AuctionHouseError::MissingAuctioneerScope.into());
     }
 
-    Ok(())
+    auctioneer_pda.assert_within_limits(scope, price, Clock::get()?.unix_timestamp)
 }
 
 pub fn assert_scopes_eq(
@@ -211,6 +211,7 @@ pub fn auctioneer_withdraw<'info>(
         &auctioneer_authority.key(),
         ah_auctioneer_pda,
         AuthorityScope::Withdraw,
+        None,
     )?;
 
     if escrow_payment_bump
@@ -0,0 +1,455 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use mpl_auction_house::pda::{
+    find_bid_receipt_address, find_listing_receipt_address, find_purchase_receipt_address,
+};
+use solana_program::{system_program, sysvar};
+use utils::{helpers::assert_error_ignoring_io_error_in_ci, setup_functions::*};
+
+const PRICE: u64 = 100_000_000;
+
+/// Cancel the listing and its receipt, returning the listing receipt address.
+async fn cancel_listing(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    test_metadata: &Metadata,
+    seller_trade_state: &Pubkey,
+) -> Pubkey {
+    let cancel_accounts = mpl_auction_house::accounts::Cancel {
+        auction_house: *ahkey,
+        wallet: test_metadata.token.pubkey(),
+        token_account: get_associated_token_address(
+            &test_metadata.token.pubkey(),
+            &test_metadata.mint.pubkey(),
+        ),
+        authority: ah.authority,
+        trade_state: *seller_trade_state,
+        token_program: spl_token::id(),
+        token_mint: test_metadata.mint.pubkey(),
+        auction_house_fee_account: ah.auction_house_fee_account,
+    }
+    .to_account_metas(None);
+
+    let (listing_receipt, _) = find_listing_receipt_address(seller_trade_state);
+    let cancel_receipt_accounts = mpl_auction_house::accounts::CancelListingReceipt {
+        receipt: listing_receipt,
+        system_program: system_program::id(),
+        instruction: sysvar::instructions::id(),
+    }
+    .to_account_metas(None);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            Instruction {
+                program_id: mpl_auction_house::id(),
+                data: mpl_auction_house::instruction::Cancel {
+                    buyer_price: PRICE,
+                    token_size: 1,
+                }
+                .data(),
+                accounts: cancel_accounts,
+            },
+            Instruction {
+                program_id: mpl_auction_house::id(),
+                data: mpl_auction_house::instruction::CancelListingReceipt {}.data(),
+                accounts: cancel_receipt_accounts,
+            },
+        ],
+        Some(&test_metadata.token.pubkey()),
+        &[&test_metadata.token],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    listing_receipt
+}
+
+fn close_listing_receipt_ix(receipt: &Pubkey, bookkeeper: &Pubkey, ahkey: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::CloseListingReceipt {}.data(),
+        accounts: mpl_auction_house::accounts::CloseListingReceipt {
+            receipt: *receipt,
+            bookkeeper: *bookkeeper,
+            auction_house: *ahkey,
+        }
+        .to_account_metas(None),
+    }
+}
+
+/// Buy the listing with a new buyer and execute the sale, returning the buyer and their trade state.
+async fn sell_to_new_buyer(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    authority: &Keypair,
+    test_metadata: &Metadata,
+    seller_trade_state: &Pubkey,
+) -> (Keypair, Pubkey) {
+    let buyer = Keypair::new();
+    airdrop(context, &buyer.pubkey(), TEN_SOL).await.unwrap();
+    let ((bid_acc, _), buy_tx) = buy(
+        context,
+        ahkey,
+        ah,
+        test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        PRICE,
+        1,
+    );
+    context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap();
+    airdrop(context, &ah.auction_house_fee_account, TEN_SOL)
+        .await
+        .unwrap();
+    let (_, sale_tx) = execute_sale(
+        context,
+        ahkey,
+        ah,
+        authority,
+        test_metadata,
+        &buyer.pubkey(),
+        &test_metadata.token.pubkey(),
+        &get_associated_token_address(&test_metadata.token.pubkey(), &test_metadata.mint.pubkey()),
+        seller_trade_state,
+        &bid_acc.buyer_trade_state,
+        1,
+        PRICE,
+    );
+    context
+        .banks_client
+        .process_transaction(sale_tx)
+        .await
+        .unwrap();
+
+    (buyer, bid_acc.buyer_trade_state)
+}
+
+#[tokio::test]
+async fn close_canceled_listing_receipt_success() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let (test_metadata, (sell_acc, _)) = listed_metadata(&mut context, &ahkey, &ah, PRICE, 1).await;
+    let seller_trade_state = sell_acc.seller_trade_state;
+    let listing_receipt = cancel_listing(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &seller_trade_state,
+    )
+    .await;
+
+    let seller = test_metadata.token.pubkey();
+    let receipt_lamports = context
+        .banks_client
+        .get_balance(listing_receipt)
+        .await
+        .unwrap();
+    let seller_before = context.banks_client.get_balance(seller).await.unwrap();
+
+    // The payer covers the fee so the refund to the seller can be checked exactly.
+    let tx = Transaction::new_signed_with_payer(
+        &[close_listing_receipt_ix(&listing_receipt, &seller, &ahkey)],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &test_metadata.token],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let seller_after = context.banks_client.get_balance(seller).await.unwrap();
+    assert_eq!(seller_before + receipt_lamports, seller_after);
+
+    let listing_receipt_account = context
+        .banks_client
+        .get_account(listing_receipt)
+        .await
+        .unwrap();
+    assert!(listing_receipt_account.is_none());
+}
+
+#[tokio::test]
+async fn close_active_listing_receipt_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let (test_metadata, (sell_acc, _)) = listed_metadata(&mut context, &ahkey, &ah, PRICE, 1).await;
+    let seller_trade_state = sell_acc.seller_trade_state;
+    let (listing_receipt, _) = find_listing_receipt_address(&seller_trade_state);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[close_listing_receipt_ix(
+            &listing_receipt,
+            &test_metadata.token.pubkey(),
+            &ahkey,
+        )],
+        Some(&test_metadata.token.pubkey()),
+        &[&test_metadata.token],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_error_ignoring_io_error_in_ci(&error, 6063);
+}
+
+#[tokio::test]
+async fn close_listing_receipt_within_retention_period_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (_, ahkey, authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let ah = set_receipt_retention_period(&mut context, &ahkey, &authority, 3600)
+        .await
+        .unwrap();
+    assert_eq!(ah.receipt_retention_period, 3600);
+
+    let (test_metadata, (sell_acc, _)) = listed_metadata(&mut context, &ahkey, &ah, PRICE, 1).await;
+    let seller_trade_state = sell_acc.seller_trade_state;
+    let listing_receipt = cancel_listing(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &seller_trade_state,
+    )
+    .await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[close_listing_receipt_ix(
+            &listing_receipt,
+            &test_metadata.token.pubkey(),
+            &ahkey,
+        )],
+        Some(&test_metadata.token.pubkey()),
+        &[&test_metadata.token],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+    assert_error_ignoring_io_error_in_ci(&error, 6064);
+
+    // Sending the same close again needs a new blockhash.
+    context.warp_to_slot(100).unwrap();
+    context.last_blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    warp_forward(&mut context, 3600).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[close_listing_receipt_ix(
+            &listing_receipt,
+            &test_metadata.token.pubkey(),
+            &ahkey,
+        )],
+        Some(&test_metadata.token.pubkey()),
+        &[&test_metadata.token],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+#[tokio::test]
+async fn close_receipts_after_sale_success() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let (test_metadata, (sell_acc, _)) = listed_metadata(&mut context, &ahkey, &ah, PRICE, 1).await;
+    let seller_trade_state = sell_acc.seller_trade_state;
+
+    let (buyer, buyer_trade_state) = sell_to_new_buyer(
+        &mut context,
+        &ahkey,
+        &ah,
+        &authority,
+        &test_metadata,
+        &seller_trade_state,
+    )
+    .await;
+
+    let (bid_receipt, _) = find_bid_receipt_address(&buyer_trade_state);
+    let (purchase_receipt, _) =
+        find_purchase_receipt_address(&seller_trade_state, &buyer_trade_state);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            Instruction {
+                program_id: mpl_auction_house::id(),
+                data: mpl_auction_house::instruction::CloseBidReceipt {}.data(),
+                accounts: [
+                    mpl_auction_house::accounts::CloseBidReceipt {
+                        receipt: bid_receipt,
+                        bookkeeper: buyer.pubkey(),
+                        auction_house: ahkey,
+                    }
+                    .to_account_metas(None),
+                    vec![AccountMeta::new_readonly(purchase_receipt, false)],
+                ]
+                .concat(),
+            },
+            Instruction {
+                program_id: mpl_auction_house::id(),
+                data: mpl_auction_house::instruction::ClosePurchaseReceipt {}.data(),
+                accounts: mpl_auction_house::accounts::ClosePurchaseReceipt {
+                    receipt: purchase_receipt,
+                    bookkeeper: authority.pubkey(),
+                    auction_house: ahkey,
+                }
+                .to_account_metas(None),
+            },
+        ],
+        Some(&buyer.pubkey()),
+        &[&buyer, &authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    for receipt in [bid_receipt, purchase_receipt] {
+        let account = context.banks_client.get_account(receipt).await.unwrap();
+        assert!(account.is_none());
+    }
+}
+
+#[tokio::test]
+async fn close_fulfilled_listing_receipt_within_retention_period_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (_, ahkey, authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let ah = set_receipt_retention_period(&mut context, &ahkey, &authority, 3600)
+        .await
+        .unwrap();
+
+    // The listing is older than the retention period, but the sale that fulfilled it is not.
+    let (test_metadata, (sell_acc, _)) = listed_metadata(&mut context, &ahkey, &ah, PRICE, 1).await;
+    let seller_trade_state = sell_acc.seller_trade_state;
+    warp_forward(&mut context, 3600).await;
+    let (_, buyer_trade_state) = sell_to_new_buyer(
+        &mut context,
+        &ahkey,
+        &ah,
+        &authority,
+        &test_metadata,
+        &seller_trade_state,
+    )
+    .await;
+
+    let (listing_receipt, _) = find_listing_receipt_address(&seller_trade_state);
+    let (purchase_receipt, _) =
+        find_purchase_receipt_address(&seller_trade_state, &buyer_trade_state);
+    let mut close_ix =
+        close_listing_receipt_ix(&listing_receipt, &test_metadata.token.pubkey(), &ahkey);
+    close_ix
+        .accounts
+        .push(AccountMeta::new_readonly(purchase_receipt, false));
+
+    let tx = Transaction::new_signed_with_payer(
+        &[close_ix],
+        Some(&test_metadata.token.pubkey()),
+        &[&test_metadata.token],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+    assert_error_ignoring_io_error_in_ci(&error, 6064);
+}
+
+#[tokio::test]
+async fn close_fulfilled_listing_receipt_without_purchase_receipt_keeps_retention() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let (test_metadata, (sell_acc, _)) = listed_metadata(&mut context, &ahkey, &ah, PRICE, 1).await;
+    let seller_trade_state = sell_acc.seller_trade_state;
+    let (_, buyer_trade_state) = sell_to_new_buyer(
+        &mut context,
+        &ahkey,
+        &ah,
+        &authority,
+        &test_metadata,
+        &seller_trade_state,
+    )
+    .await;
+
+    let (listing_receipt, _) = find_listing_receipt_address(&seller_trade_state);
+    let (purchase_receipt, _) =
+        find_purchase_receipt_address(&seller_trade_state, &buyer_trade_state);
+    let tx = Transaction::new_signed_with_payer(
+        &[Instruction {
+            program_id: mpl_auction_house::id(),
+            data: mpl_auction_house::instruction::ClosePurchaseReceipt {}.data(),
+            accounts: mpl_auction_house::accounts::ClosePurchaseReceipt {
+                receipt: purchase_receipt,
+                bookkeeper: authority.pubkey(),
+                auction_house: ahkey,
+            }
+            .to_account_metas(None),
+        }],
+        Some(&authority.pubkey()),
+        &[&authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // With the purchase receipt gone, the retention period runs from the listing receipt.
+    set_receipt_retention_period(&mut context, &ahkey, &authority, 3600)
+        .await
+        .unwrap();
+    let mut close_ix =
+        close_listing_receipt_ix(&listing_receipt, &test_metadata.token.pubkey(), &ahkey);
+    close_ix
+        .accounts
+        .push(AccountMeta::new_readonly(purchase_receipt, false));
+
+    let tx = Transaction::new_signed_with_payer(
+        &[close_ix.clone()],
+        Some(&test_metadata.token.pubkey()),
+        &[&test_metadata.token],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+    assert_error_ignoring_io_error_in_ci(&error, 6064);
+
+    context.warp_to_slot(100).unwrap();
+    context.last_blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    warp_forward(&mut context, 3600).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[close_ix],
+        Some(&test_metadata.token.pubkey()),
+        &[&test_metadata.token],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let account = context
+        .banks_client
+        .get_account(listing_receipt)
+        .await
+        .unwrap();
+    assert!(account.is_none());
+}
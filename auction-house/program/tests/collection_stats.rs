@@ -0,0 +1,240 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use mpl_auction_house::{
+    pda::{
+        find_collection_stats_address, find_escrow_payment_address, find_program_as_signer_address,
+        find_trade_state_address,
+    },
+    stats::CollectionStats,
+};
+use solana_program::{system_program, sysvar};
+use utils::{helpers::assert_error_ignoring_io_error_in_ci, setup_functions::*};
+
+const PRICE: u64 = 100_000_000;
+
+async fn init_collection_stats(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    collection_mint: &Pubkey,
+) -> Pubkey {
+    let (collection_stats, _) = find_collection_stats_address(ahkey, collection_mint);
+    let accounts = mpl_auction_house::accounts::InitCollectionStats {
+        payer: context.payer.pubkey(),
+        auction_house: *ahkey,
+        collection_mint: *collection_mint,
+        collection_stats,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[Instruction {
+            program_id: mpl_auction_house::id(),
+            data: mpl_auction_house::instruction::InitCollectionStats {}.data(),
+            accounts,
+        }],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    collection_stats
+}
+
+/// List `test_metadata`, bid on it and return the signed `execute_sale` transaction with the
+/// stats account as the only remaining account.
+async fn execute_sale_with_stats(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    authority: &Keypair,
+    test_metadata: &Metadata,
+    collection_stats: &Pubkey,
+) -> Transaction {
+    let ((sell_acc, _), sell_tx) = sell(context, ahkey, ah, test_metadata, PRICE, 1);
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    let buyer = Keypair::new();
+    airdrop(context, &buyer.pubkey(), TEN_SOL).await.unwrap();
+    let ((bid_acc, _), buy_tx) = buy(
+        context,
+        ahkey,
+        ah,
+        test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        PRICE,
+        1,
+    );
+    context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap();
+    airdrop(context, &ah.auction_house_fee_account, TEN_SOL)
+        .await
+        .unwrap();
+
+    let mut accounts = mpl_auction_house::accounts::ExecuteSale {
+        buyer: buyer.pubkey(),
+        seller: test_metadata.token.pubkey(),
+        token_account: sell_acc.token_account,
+        token_mint: test_metadata.mint.pubkey(),
+        metadata: test_metadata.pubkey,
+        treasury_mint: ah.treasury_mint,
+        escrow_payment_account: bid_acc.escrow_payment_account,
+        seller_payment_receipt_account: test_metadata.token.pubkey(),
+        buyer_receipt_token_account: get_associated_token_address(
+            &buyer.pubkey(),
+            &test_metadata.mint.pubkey(),
+        ),
+        authority: ah.authority,
+        auction_house: *ahkey,
+        auction_house_fee_account: ah.auction_house_fee_account,
+        auction_house_treasury: ah.auction_house_treasury,
+        buyer_trade_state: bid_acc.buyer_trade_state,
+        seller_trade_state: sell_acc.seller_trade_state,
+        free_trade_state: sell_acc.free_seller_trade_state,
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        ata_program: spl_associated_token_account::id(),
+        program_as_signer: sell_acc.program_as_signer,
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+    // The metadata has no creators, so the stats account is the only remaining account.
+    accounts.push(AccountMeta::new(*collection_stats, false));
+
+    let (_, free_trade_state_bump) = find_trade_state_address(
+        &test_metadata.token.pubkey(),
+        ahkey,
+        &sell_acc.token_account,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        0,
+        1,
+    );
+    let (_, escrow_payment_bump) = find_escrow_payment_address(ahkey, &buyer.pubkey());
+    let (_, program_as_signer_bump) = find_program_as_signer_address();
+
+    Transaction::new_signed_with_payer(
+        &[Instruction {
+            program_id: mpl_auction_house::id(),
+            data: mpl_auction_house::instruction::ExecuteSale {
+                escrow_payment_bump,
+                _free_trade_state_bump: free_trade_state_bump,
+                program_as_signer_bump,
+                buyer_price: PRICE,
+                token_size: 1,
+            }
+            .data(),
+            accounts,
+        }],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    )
+}
+
+async fn get_collection_stats(
+    context: &mut ProgramTestContext,
+    collection_stats: &Pubkey,
+) -> CollectionStats {
+    let account = context
+        .banks_client
+        .get_account(*collection_stats)
+        .await
+        .unwrap()
+        .expect("Collection stats empty");
+    CollectionStats::from_account_data(&account.data).unwrap()
+}
+
+#[tokio::test]
+async fn init_collection_stats_success() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (_, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let (collection, _) = create_collection(&mut context).await;
+
+    let collection_stats =
+        init_collection_stats(&mut context, &ahkey, &collection.mint.pubkey()).await;
+
+    let stats = get_collection_stats(&mut context, &collection_stats).await;
+    assert_eq!(stats.auction_house, ahkey);
+    assert_eq!(stats.collection_mint, collection.mint.pubkey());
+    assert_eq!(stats.volume, 0);
+    assert_eq!(stats.sale_count, 0);
+}
+
+#[tokio::test]
+async fn execute_sale_updates_collection_stats() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let (collection, master_edition) = create_collection(&mut context).await;
+    let collection_stats =
+        init_collection_stats(&mut context, &ahkey, &collection.mint.pubkey()).await;
+
+    for _ in 0..2 {
+        let test_metadata =
+            create_collection_item(&mut context, &collection, &master_edition, true).await;
+        let tx = execute_sale_with_stats(
+            &mut context,
+            &ahkey,
+            &ah,
+            &authority,
+            &test_metadata,
+            &collection_stats,
+        )
+        .await;
+        context.banks_client.process_transaction(tx).await.unwrap();
+    }
+
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    let stats = get_collection_stats(&mut context, &collection_stats).await;
+    assert_eq!(stats.volume, 2 * PRICE);
+    assert_eq!(stats.sale_count, 2);
+    assert_eq!(stats.last_sale_price, PRICE);
+    assert!(stats.last_sale_timestamp > 0);
+    assert!(stats.last_sale_timestamp <= clock.unix_timestamp);
+}
+
+#[tokio::test]
+async fn execute_sale_unverified_collection_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let (collection, master_edition) = create_collection(&mut context).await;
+    let collection_stats =
+        init_collection_stats(&mut context, &ahkey, &collection.mint.pubkey()).await;
+    let test_metadata =
+        create_collection_item(&mut context, &collection, &master_edition, false).await;
+
+    let tx = execute_sale_with_stats(
+        &mut context,
+        &ahkey,
+        &ah,
+        &authority,
+        &test_metadata,
+        &collection_stats,
+    )
+    .await;
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_error_ignoring_io_error_in_ci(&error, 6057);
+}
@@ -32,8 +32,16 @@ pub const BUYER_TRADE_STATE_NOT_VALID: u32 = 6037;
 pub const NOT_ENOUGH_TOKENS_AVAIL_FOR_PURCHASE: u32 = 6039;
 pub const PARTIAL_BUY_PRICE_MISMATCH: u32 = 6040;
 pub const MISSING_ELEMENTS_NEEDED_FOR_PARTIAL_BUY: u32 = 6038;
-pub const AUCTIONEER_ALREADY_DELEGATED: u32 = 6041;
 pub const INSUFFICIENT_FUNDS: u32 = 6043;
+pub const AUCTIONEER_DELEGATION_EXPIRED: u32 = 6066;
+pub const AUCTIONEER_PRICE_LIMIT_EXCEEDED: u32 = 6067;
+pub const LISTING_NOT_STARTED: u32 = 6069;
+pub const PARTIAL_BID_OVERFILLED: u32 = 6070;
+pub const GOVERNANCE_ENABLED: u32 = 6046;
+pub const GOVERNED_INSTRUCTION_CHANGE: u32 = 6072;
+pub const GOVERNED_CHANGE_MISMATCH: u32 = 6074;
+pub const PROGRAMMABLE_NFT_NOT_SUPPORTED: u32 = 6075;
+pub const PAYMENT_ACCOUNT_DELEGATED: u32 = 6076;
 
 pub const TEN_SOL: u64 = 10_000_000_000;
 pub const ONE_SOL: u64 = 1_000_000_000;
@@ -0,0 +1,419 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use mpl_auction_house::{
+    counter_offer::CounterOffer,
+    pda::{
+        find_bid_receipt_address, find_counter_offer_address, find_escrow_payment_address,
+        find_program_as_signer_address, find_purchase_receipt_address,
+    },
+    receipt::{BidReceipt, PurchaseReceipt},
+};
+use solana_program::{program_pack::Pack, system_program, sysvar};
+use utils::{helpers::assert_error_ignoring_io_error_in_ci, setup_functions::*};
+
+const BID_PRICE: u64 = 50_000_000;
+const COUNTER_PRICE: u64 = 80_000_000;
+
+struct CounterOfferSetup {
+    test_metadata: Metadata,
+    buyer: Keypair,
+    buyer_trade_state: Pubkey,
+    seller_trade_state: Pubkey,
+    counter_offer: Pubkey,
+}
+
+/// Bid on a fresh NFT, then list it at the counter price and counter the bid.
+async fn counter_offer_setup(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+) -> CounterOfferSetup {
+    let test_metadata = Metadata::new();
+    airdrop(context, &test_metadata.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let buyer = Keypair::new();
+    airdrop(context, &buyer.pubkey(), TEN_SOL).await.unwrap();
+    let ((bid_acc, _), buy_tx) = buy(
+        context,
+        ahkey,
+        ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        BID_PRICE,
+        1,
+    );
+    context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap();
+
+    let ((sell_acc, _), sell_tx) = sell(context, ahkey, ah, &test_metadata, COUNTER_PRICE, 1);
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    let (bid_receipt, _) = find_bid_receipt_address(&bid_acc.buyer_trade_state);
+    let (counter_offer, _) = find_counter_offer_address(&bid_receipt, &sell_acc.seller_trade_state);
+    let accounts = mpl_auction_house::accounts::MakeCounterOffer {
+        seller: test_metadata.token.pubkey(),
+        auction_house: *ahkey,
+        bid_receipt,
+        token_account: sell_acc.token_account,
+        seller_trade_state: sell_acc.seller_trade_state,
+        counter_offer,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[Instruction {
+            program_id: mpl_auction_house::id(),
+            data: mpl_auction_house::instruction::MakeCounterOffer {
+                price: COUNTER_PRICE,
+            }
+            .data(),
+            accounts,
+        }],
+        Some(&test_metadata.token.pubkey()),
+        &[&test_metadata.token],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    CounterOfferSetup {
+        buyer_trade_state: bid_acc.buyer_trade_state,
+        seller_trade_state: sell_acc.seller_trade_state,
+        test_metadata,
+        buyer,
+        counter_offer,
+    }
+}
+
+fn accept_counter_offer_ix(
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    setup: &CounterOfferSetup,
+) -> Instruction {
+    let buyer = setup.buyer.pubkey();
+    let seller = setup.test_metadata.token.pubkey();
+    let mint = setup.test_metadata.mint.pubkey();
+
+    let mut accounts = mpl_auction_house::accounts::AcceptCounterOffer {
+        sweep: mpl_auction_house::accounts::Sweep {
+            buyer,
+            treasury_mint: ah.treasury_mint,
+            escrow_payment_account: find_escrow_payment_address(ahkey, &buyer).0,
+            authority: ah.authority,
+            auction_house: *ahkey,
+            auction_house_fee_account: ah.auction_house_fee_account,
+            auction_house_treasury: ah.auction_house_treasury,
+            token_program: spl_token::id(),
+            system_program: system_program::id(),
+            ata_program: spl_associated_token_account::id(),
+            program_as_signer: find_program_as_signer_address().0,
+            rent: sysvar::rent::id(),
+        },
+        counter_offer: setup.counter_offer,
+        bid_receipt: find_bid_receipt_address(&setup.buyer_trade_state).0,
+        purchase_receipt: find_purchase_receipt_address(
+            &setup.seller_trade_state,
+            &setup.buyer_trade_state,
+        )
+        .0,
+        seller,
+        buyer_trade_state: setup.buyer_trade_state,
+        payment_account: buyer,
+    }
+    .to_account_metas(None);
+    // The metadata has no creators, so the listing group ends with the buyer receipt account.
+    accounts.extend([
+        AccountMeta::new(setup.seller_trade_state, false),
+        AccountMeta::new(seller, false),
+        AccountMeta::new(get_associated_token_address(&seller, &mint), false),
+        AccountMeta::new_readonly(mint, false),
+        AccountMeta::new_readonly(setup.test_metadata.pubkey, false),
+        AccountMeta::new(seller, false),
+        AccountMeta::new(get_associated_token_address(&buyer, &mint), false),
+    ]);
+
+    Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::AcceptCounterOffer {}.data(),
+        accounts,
+    }
+}
+
+fn withdraw_counter_offer_ix(setup: &CounterOfferSetup, wallet: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::WithdrawCounterOffer {}.data(),
+        accounts: mpl_auction_house::accounts::WithdrawCounterOffer {
+            wallet: *wallet,
+            counter_offer: setup.counter_offer,
+            seller: setup.test_metadata.token.pubkey(),
+            seller_trade_state: setup.seller_trade_state,
+        }
+        .to_account_metas(None),
+    }
+}
+
+async fn get_counter_offer(
+    context: &mut ProgramTestContext,
+    counter_offer: &Pubkey,
+) -> CounterOffer {
+    let account = context
+        .banks_client
+        .get_account(*counter_offer)
+        .await
+        .unwrap()
+        .expect("Counter offer empty");
+    CounterOffer::try_deserialize(&mut account.data.as_ref()).unwrap()
+}
+
+#[tokio::test]
+async fn accept_counter_offer_success() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let setup = counter_offer_setup(&mut context, &ahkey, &ah).await;
+
+    let counter_offer = get_counter_offer(&mut context, &setup.counter_offer).await;
+    assert_eq!(counter_offer.bid_price, BID_PRICE);
+    assert_eq!(counter_offer.price, COUNTER_PRICE);
+    assert_eq!(counter_offer.buyer, setup.buyer.pubkey());
+    assert_eq!(
+        counter_offer.bid_receipt,
+        find_bid_receipt_address(&setup.buyer_trade_state).0
+    );
+
+    let seller_before = context
+        .banks_client
+        .get_balance(setup.test_metadata.token.pubkey())
+        .await
+        .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[accept_counter_offer_ix(&ahkey, &ah, &setup)],
+        Some(&setup.buyer.pubkey()),
+        &[&setup.buyer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let seller_after = context
+        .banks_client
+        .get_balance(setup.test_metadata.token.pubkey())
+        .await
+        .unwrap();
+    let house_fee = ah.seller_fee_basis_points as u64 * COUNTER_PRICE / 10000;
+    assert_eq!(seller_before + COUNTER_PRICE - house_fee, seller_after);
+
+    let buyer_token_account = context
+        .banks_client
+        .get_account(get_associated_token_address(
+            &setup.buyer.pubkey(),
+            &setup.test_metadata.mint.pubkey(),
+        ))
+        .await
+        .unwrap()
+        .expect("Buyer token account empty");
+    let buyer_token_account = spl_token::state::Account::unpack(&buyer_token_account.data).unwrap();
+    assert_eq!(buyer_token_account.amount, 1);
+
+    let buyer_trade_state = context
+        .banks_client
+        .get_account(setup.buyer_trade_state)
+        .await
+        .unwrap();
+    assert!(buyer_trade_state.is_none());
+
+    let counter_offer = context
+        .banks_client
+        .get_account(setup.counter_offer)
+        .await
+        .unwrap();
+    assert!(counter_offer.is_none());
+
+    let bid_receipt = context
+        .banks_client
+        .get_account(find_bid_receipt_address(&setup.buyer_trade_state).0)
+        .await
+        .unwrap()
+        .expect("Bid receipt empty");
+    let bid_receipt = BidReceipt::try_deserialize(&mut bid_receipt.data.as_ref()).unwrap();
+    let (purchase_receipt, _) =
+        find_purchase_receipt_address(&setup.seller_trade_state, &setup.buyer_trade_state);
+    assert_eq!(bid_receipt.purchase_receipt, Some(purchase_receipt));
+    assert!(bid_receipt.canceled_at.is_none());
+
+    let purchase_receipt = context
+        .banks_client
+        .get_account(purchase_receipt)
+        .await
+        .unwrap()
+        .expect("Purchase receipt empty");
+    let purchase_receipt =
+        PurchaseReceipt::try_deserialize(&mut purchase_receipt.data.as_ref()).unwrap();
+    assert_eq!(purchase_receipt.buyer, setup.buyer.pubkey());
+    assert_eq!(purchase_receipt.price, COUNTER_PRICE);
+    assert_eq!(purchase_receipt.token_size, 1);
+}
+
+#[tokio::test]
+async fn accept_withdrawn_counter_offer_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let setup = counter_offer_setup(&mut context, &ahkey, &ah).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[withdraw_counter_offer_ix(
+            &setup,
+            &setup.test_metadata.token.pubkey(),
+        )],
+        Some(&setup.test_metadata.token.pubkey()),
+        &[&setup.test_metadata.token],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let counter_offer = context
+        .banks_client
+        .get_account(setup.counter_offer)
+        .await
+        .unwrap();
+    assert!(counter_offer.is_none());
+
+    let tx = Transaction::new_signed_with_payer(
+        &[accept_counter_offer_ix(&ahkey, &ah, &setup)],
+        Some(&setup.buyer.pubkey()),
+        &[&setup.buyer],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // AccountNotInitialized
+    assert_error_ignoring_io_error_in_ci(&error, 3012);
+}
+
+#[tokio::test]
+async fn withdraw_counter_offer_by_third_party_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let setup = counter_offer_setup(&mut context, &ahkey, &ah).await;
+
+    let outsider = Keypair::new();
+    airdrop(&mut context, &outsider.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[withdraw_counter_offer_ix(&setup, &outsider.pubkey())],
+        Some(&outsider.pubkey()),
+        &[&outsider],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_error_ignoring_io_error_in_ci(&error, 6022);
+}
+
+#[tokio::test]
+async fn close_expired_counter_offer_by_third_party_success() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let setup = counter_offer_setup(&mut context, &ahkey, &ah).await;
+    let seller = setup.test_metadata.token.pubkey();
+    let mint = setup.test_metadata.mint.pubkey();
+
+    // Cancel the counter-offer listing, which expires the counter-offer.
+    let tx = Transaction::new_signed_with_payer(
+        &[Instruction {
+            program_id: mpl_auction_house::id(),
+            data: mpl_auction_house::instruction::Cancel {
+                buyer_price: COUNTER_PRICE,
+                token_size: 1,
+            }
+            .data(),
+            accounts: mpl_auction_house::accounts::Cancel {
+                wallet: seller,
+                token_account: get_associated_token_address(&seller, &mint),
+                token_mint: mint,
+                authority: ah.authority,
+                auction_house: ahkey,
+                auction_house_fee_account: ah.auction_house_fee_account,
+                trade_state: setup.seller_trade_state,
+                token_program: spl_token::id(),
+            }
+            .to_account_metas(None),
+        }],
+        Some(&seller),
+        &[&setup.test_metadata.token],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let outsider = Keypair::new();
+    airdrop(&mut context, &outsider.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    let seller_before = context.banks_client.get_balance(seller).await.unwrap();
+    let counter_offer_rent = context
+        .banks_client
+        .get_balance(setup.counter_offer)
+        .await
+        .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[withdraw_counter_offer_ix(&setup, &outsider.pubkey())],
+        Some(&outsider.pubkey()),
+        &[&outsider],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let counter_offer = context
+        .banks_client
+        .get_account(setup.counter_offer)
+        .await
+        .unwrap();
+    assert!(counter_offer.is_none());
+
+    let seller_after = context.banks_client.get_balance(seller).await.unwrap();
+    assert_eq!(seller_before + counter_offer_rent, seller_after);
+}
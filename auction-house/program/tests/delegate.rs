@@ -3,6 +3,7 @@ pub mod common;
 pub mod utils;
 
 use common::*;
+use mpl_auction_house::AuctioneerLimits;
 use utils::{
     helpers::{assert_scopes_eq, default_scopes},
     setup_functions::*,
@@ -183,41 +184,419 @@ async fn incorrect_auctioneer_pda_fails() {
 }
 
 #[tokio::test]
-async fn delegate_already_init_fail() {
+async fn delegate_multiple_auctioneers_success() {
     let mut context = auction_house_program_test().start_with_context().await;
-    let (_ah, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+    let (ah, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
         .await
         .unwrap();
-    let auctioneer_authority = Keypair::new();
-    let auctioneer_authority_pubkey = auctioneer_authority.pubkey();
+    let test_metadata = Metadata::new();
 
-    let (auctioneer_pda, _) = find_auctioneer_pda(&ahkey, &auctioneer_authority_pubkey);
-    let scopes = default_scopes();
+    let english_authority = Keypair::new();
+    let (english_pda, _) = find_auctioneer_pda(&ahkey, &english_authority.pubkey());
     delegate_auctioneer(
         &mut context,
         ahkey,
         &ah_authority,
-        auctioneer_authority_pubkey,
+        english_authority.pubkey(),
+        english_pda,
+        default_scopes(),
+    )
+    .await
+    .unwrap();
+
+    let dutch_authority = Keypair::new();
+    let (dutch_pda, _) = find_auctioneer_pda(&ahkey, &dutch_authority.pubkey());
+    let dutch_scopes = vec![AuthorityScope::Buy, AuthorityScope::ExecuteSale];
+    delegate_auctioneer(
+        &mut context,
+        ahkey,
+        &ah_authority,
+        dutch_authority.pubkey(),
+        dutch_pda,
+        dutch_scopes.clone(),
+    )
+    .await
+    .unwrap();
+
+    let ah_account = context
+        .banks_client
+        .get_account(ahkey)
+        .await
+        .expect("Error getting auction house account")
+        .expect("Auction House empty");
+    let new_ah = AuctionHouse::deserialize(&mut ah_account.data[8..].as_ref())
+        .expect("Failed to deserialize Auction House data");
+    let dutch_account = context
+        .banks_client
+        .get_account(dutch_pda)
+        .await
+        .expect("Error getting auctioneer account")
+        .expect("Auctioneer empty");
+    let dutch = Auctioneer::deserialize(&mut dutch_account.data[8..].as_ref())
+        .expect("Failed to deserialize Auctioneer data");
+
+    assert!(new_ah.has_auctioneer);
+    assert_eq!(new_ah.auctioneer_count, 2);
+    assert_eq!(new_ah.scope_delegations, [1, 2, 1, 2, 1, 1, 1]);
+    assert_scopes_eq(default_scopes(), new_ah.scopes);
+    assert_scopes_eq(dutch_scopes, dutch.scopes);
+    assert!(!dutch.scopes[AuthorityScope::Deposit as usize]);
+
+    // Each auctioneer is checked against its own scopes.
+    let buyer = Keypair::new();
+    airdrop(&mut context, &buyer.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    let (_, deposit_tx) = auctioneer_deposit(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &buyer,
+        &dutch_authority,
+        ONE_SOL,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(deposit_tx)
+        .await
+        .unwrap_err();
+    assert_error!(err, MISSING_AUCTIONEER_SCOPE);
+
+    let (_, deposit_tx) = auctioneer_deposit(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &buyer,
+        &english_authority,
+        ONE_SOL,
+    );
+    context
+        .banks_client
+        .process_transaction(deposit_tx)
+        .await
+        .unwrap();
+
+    // Revoking one auctioneer leaves the other delegated.
+    revoke_auctioneer(
+        &mut context,
+        ahkey,
+        &ah_authority,
+        english_authority.pubkey(),
+        english_pda,
+    )
+    .await
+    .unwrap();
+
+    let ah_account = context
+        .banks_client
+        .get_account(ahkey)
+        .await
+        .expect("Error getting auction house account")
+        .expect("Auction House empty");
+    let new_ah = AuctionHouse::deserialize(&mut ah_account.data[8..].as_ref())
+        .expect("Failed to deserialize Auction House data");
+    assert!(new_ah.has_auctioneer);
+    assert_eq!(new_ah.auctioneer_count, 1);
+    assert_eq!(
+        new_ah.scopes,
+        [false, true, false, true, false, false, false]
+    );
+}
+
+#[tokio::test]
+async fn revoke_auctioneer_success() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (_, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let auctioneer_authority = Keypair::new();
+    let (auctioneer_pda, _) = find_auctioneer_pda(&ahkey, &auctioneer_authority.pubkey());
+    delegate_auctioneer(
+        &mut context,
+        ahkey,
+        &ah_authority,
+        auctioneer_authority.pubkey(),
         auctioneer_pda,
-        scopes.clone(),
+        default_scopes(),
     )
     .await
     .unwrap();
-    // Call `delegate_auctioneer` with the auction house authority and a new auctioneer program.
-    let auctioneer_authority2 = Keypair::new();
-    let auctioneer_authority_pubkey2 = auctioneer_authority2.pubkey();
-    let (new_auctioneer_pda, _) = find_auctioneer_pda(&ahkey, &auctioneer_authority_pubkey2);
-    let scopes = default_scopes();
-    let err = delegate_auctioneer(
+
+    revoke_auctioneer(
+        &mut context,
+        ahkey,
+        &ah_authority,
+        auctioneer_authority.pubkey(),
+        auctioneer_pda,
+    )
+    .await
+    .unwrap();
+
+    let ah_account = context
+        .banks_client
+        .get_account(ahkey)
+        .await
+        .expect("Error getting auction house account")
+        .expect("Auction House empty");
+    let ah = AuctionHouse::deserialize(&mut ah_account.data[8..].as_ref())
+        .expect("Failed to deserialize Auction House data");
+    assert!(!ah.has_auctioneer);
+    assert_eq!(ah.auctioneer_address, Pubkey::default());
+    assert!(context
+        .banks_client
+        .get_account(auctioneer_pda)
+        .await
+        .unwrap()
+        .is_none());
+
+    // A new auctioneer can be delegated once the previous one is revoked.
+    let new_auctioneer_authority = Keypair::new();
+    let (new_auctioneer_pda, _) = find_auctioneer_pda(&ahkey, &new_auctioneer_authority.pubkey());
+    delegate_auctioneer(
         &mut context,
         ahkey,
         &ah_authority,
-        auctioneer_authority_pubkey2,
+        new_auctioneer_authority.pubkey(),
         new_auctioneer_pda,
-        scopes.clone(),
+        default_scopes(),
     )
     .await
-    .unwrap_err();
-    // Assert account already created
-    assert_error!(err, AUCTIONEER_ALREADY_DELEGATED);
+    .unwrap();
+}
+
+#[tokio::test]
+async fn expired_delegation_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = Metadata::new();
+    let auctioneer_authority = Keypair::new();
+    let (auctioneer_pda, _) = find_auctioneer_pda(&ahkey, &auctioneer_authority.pubkey());
+    delegate_auctioneer(
+        &mut context,
+        ahkey,
+        &ah_authority,
+        auctioneer_authority.pubkey(),
+        auctioneer_pda,
+        default_scopes(),
+    )
+    .await
+    .unwrap();
+
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    set_auctioneer_limits(
+        &mut context,
+        ahkey,
+        &ah_authority,
+        auctioneer_authority.pubkey(),
+        auctioneer_pda,
+        vec![AuthorityScope::Deposit],
+        AuctioneerLimits {
+            expires_at: Some(clock.unix_timestamp),
+            ..AuctioneerLimits::default()
+        },
+    )
+    .await
+    .unwrap();
+
+    let buyer = Keypair::new();
+    airdrop(&mut context, &buyer.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    let (_, deposit_tx) = auctioneer_deposit(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &buyer,
+        &auctioneer_authority,
+        ONE_SOL,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(deposit_tx)
+        .await
+        .unwrap_err();
+
+    assert_error!(err, AUCTIONEER_DELEGATION_EXPIRED);
+}
+
+#[tokio::test]
+async fn bid_above_price_limit_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = Metadata::new();
+    airdrop(&mut context, &test_metadata.token.pubkey(), ONE_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let auctioneer_authority = Keypair::new();
+    let (auctioneer_pda, _) = find_auctioneer_pda(&ahkey, &auctioneer_authority.pubkey());
+    delegate_auctioneer(
+        &mut context,
+        ahkey,
+        &ah_authority,
+        auctioneer_authority.pubkey(),
+        auctioneer_pda,
+        default_scopes(),
+    )
+    .await
+    .unwrap();
+    set_auctioneer_limits(
+        &mut context,
+        ahkey,
+        &ah_authority,
+        auctioneer_authority.pubkey(),
+        auctioneer_pda,
+        vec![AuthorityScope::Buy],
+        AuctioneerLimits {
+            max_sale_price: Some(ONE_SOL / 2),
+            ..AuctioneerLimits::default()
+        },
+    )
+    .await
+    .unwrap();
+
+    let buyer = Keypair::new();
+    airdrop(&mut context, &buyer.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    let (_, deposit_tx) = auctioneer_deposit(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &buyer,
+        &auctioneer_authority,
+        ONE_SOL,
+    );
+    context
+        .banks_client
+        .process_transaction(deposit_tx)
+        .await
+        .unwrap();
+
+    let (_, buy_tx) = auctioneer_buy(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        &auctioneer_authority,
+        ONE_SOL,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap_err();
+
+    assert_error!(err, AUCTIONEER_PRICE_LIMIT_EXCEEDED);
+}
+
+#[tokio::test]
+async fn bid_above_sale_price_limit_success() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = Metadata::new();
+    airdrop(&mut context, &test_metadata.token.pubkey(), ONE_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let auctioneer_authority = Keypair::new();
+    let (auctioneer_pda, _) = find_auctioneer_pda(&ahkey, &auctioneer_authority.pubkey());
+    delegate_auctioneer(
+        &mut context,
+        ahkey,
+        &ah_authority,
+        auctioneer_authority.pubkey(),
+        auctioneer_pda,
+        default_scopes(),
+    )
+    .await
+    .unwrap();
+    set_auctioneer_limits(
+        &mut context,
+        ahkey,
+        &ah_authority,
+        auctioneer_authority.pubkey(),
+        auctioneer_pda,
+        // Only sales are limited, so bids above the limit are still allowed.
+        vec![AuthorityScope::ExecuteSale],
+        AuctioneerLimits {
+            max_sale_price: Some(ONE_SOL / 2),
+            ..AuctioneerLimits::default()
+        },
+    )
+    .await
+    .unwrap();
+
+    let buyer = Keypair::new();
+    airdrop(&mut context, &buyer.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    let (_, deposit_tx) = auctioneer_deposit(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &buyer,
+        &auctioneer_authority,
+        ONE_SOL,
+    );
+    context
+        .banks_client
+        .process_transaction(deposit_tx)
+        .await
+        .unwrap();
+
+    let (_, buy_tx) = auctioneer_buy(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        &auctioneer_authority,
+        ONE_SOL,
+    );
+    context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap();
 }
@@ -0,0 +1,513 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use mpl_auction_house::pda::{
+    find_auction_house_address, find_auction_house_fee_account_address,
+    find_auction_house_treasury_address, find_escrow_payment_address,
+    find_program_as_signer_address, find_trade_state_address,
+};
+use mpl_testing_utils::solana::{create_associated_token_account, create_mint, get_token_account};
+use solana_program::{system_program, sysvar};
+use utils::{helpers::assert_error_ignoring_io_error_in_ci, setup_functions::*};
+
+const PRICE: u64 = 100_000_000;
+
+async fn spl_auction_house_test_context(
+    context: &mut ProgramTestContext,
+) -> (AuctionHouse, Pubkey, Keypair) {
+    let treasury_mint = Keypair::new();
+    let payer = context.payer.pubkey();
+    create_mint(context, &treasury_mint, &payer, None)
+        .await
+        .unwrap();
+
+    let authority = Keypair::new();
+    airdrop(context, &authority.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    let treasury_withdrawal_destination =
+        create_associated_token_account(context, &authority, &treasury_mint.pubkey())
+            .await
+            .unwrap();
+
+    let (auction_house, bump) =
+        find_auction_house_address(&authority.pubkey(), &treasury_mint.pubkey());
+    let (fee_account, fee_payer_bump) = find_auction_house_fee_account_address(&auction_house);
+    let (treasury, treasury_bump) = find_auction_house_treasury_address(&auction_house);
+
+    create_auction_house(
+        context,
+        &authority,
+        &authority.pubkey(),
+        &authority.pubkey(),
+        &treasury_mint.pubkey(),
+        &treasury_withdrawal_destination,
+        &auction_house,
+        bump,
+        &fee_account,
+        fee_payer_bump,
+        &treasury,
+        treasury_bump,
+        100,
+        false,
+        false,
+    )
+    .await
+    .unwrap();
+
+    let account = context
+        .banks_client
+        .get_account(auction_house)
+        .await
+        .unwrap()
+        .expect("Auction House empty");
+    let ah = AuctionHouse::try_deserialize(&mut account.data.as_ref()).unwrap();
+
+    (ah, auction_house, authority)
+}
+
+fn buyer_trade_state(
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    test_metadata: &Metadata,
+    buyer: &Pubkey,
+) -> (Pubkey, u8) {
+    let token_account =
+        get_associated_token_address(&test_metadata.token.pubkey(), &test_metadata.mint.pubkey());
+    find_trade_state_address(
+        buyer,
+        ahkey,
+        &token_account,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        PRICE,
+        1,
+    )
+}
+
+fn delegated_buy_ix(
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    test_metadata: &Metadata,
+    buyer: &Pubkey,
+    payment_account: &Pubkey,
+) -> Instruction {
+    let (escrow_payment_account, escrow_payment_bump) = find_escrow_payment_address(ahkey, buyer);
+    let (buyer_trade_state, trade_state_bump) = buyer_trade_state(ahkey, ah, test_metadata, buyer);
+
+    Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::DelegatedBuy {
+            trade_state_bump,
+            escrow_payment_bump,
+            buyer_price: PRICE,
+            token_size: 1,
+        }
+        .data(),
+        accounts: mpl_auction_house::accounts::DelegatedBuy {
+            wallet: *buyer,
+            payment_account: *payment_account,
+            treasury_mint: ah.treasury_mint,
+            token_account: get_associated_token_address(
+                &test_metadata.token.pubkey(),
+                &test_metadata.mint.pubkey(),
+            ),
+            metadata: test_metadata.pubkey,
+            escrow_payment_account,
+            authority: ah.authority,
+            auction_house: *ahkey,
+            auction_house_fee_account: ah.auction_house_fee_account,
+            buyer_trade_state,
+            program_as_signer: find_program_as_signer_address().0,
+            token_program: spl_token::id(),
+            system_program: system_program::id(),
+            rent: sysvar::rent::id(),
+        }
+        .to_account_metas(None),
+    }
+}
+
+fn execute_delegated_sale_ix(
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    test_metadata: &Metadata,
+    buyer: &Pubkey,
+    payment_account: &Pubkey,
+) -> Instruction {
+    let seller = test_metadata.token.pubkey();
+    let mint = test_metadata.mint.pubkey();
+    let token_account = get_associated_token_address(&seller, &mint);
+    let (seller_trade_state, _) = find_trade_state_address(
+        &seller,
+        ahkey,
+        &token_account,
+        &ah.treasury_mint,
+        &mint,
+        PRICE,
+        1,
+    );
+    let (free_trade_state, free_trade_state_bump) = find_trade_state_address(
+        &seller,
+        ahkey,
+        &token_account,
+        &ah.treasury_mint,
+        &mint,
+        0,
+        1,
+    );
+    let (escrow_payment_account, escrow_payment_bump) = find_escrow_payment_address(ahkey, buyer);
+    let (program_as_signer, program_as_signer_bump) = find_program_as_signer_address();
+
+    let mut accounts = mpl_auction_house::accounts::ExecuteSale {
+        buyer: *buyer,
+        seller,
+        token_account,
+        token_mint: mint,
+        metadata: test_metadata.pubkey,
+        treasury_mint: ah.treasury_mint,
+        escrow_payment_account,
+        seller_payment_receipt_account: get_associated_token_address(&seller, &ah.treasury_mint),
+        buyer_receipt_token_account: get_associated_token_address(buyer, &mint),
+        authority: ah.authority,
+        auction_house: *ahkey,
+        auction_house_fee_account: ah.auction_house_fee_account,
+        auction_house_treasury: ah.auction_house_treasury,
+        buyer_trade_state: buyer_trade_state(ahkey, ah, test_metadata, buyer).0,
+        seller_trade_state,
+        free_trade_state,
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        ata_program: spl_associated_token_account::id(),
+        program_as_signer,
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+    // The metadata has no creators, so the payment account is the only remaining account.
+    accounts.push(AccountMeta::new(*payment_account, false));
+
+    Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::ExecuteSale {
+            escrow_payment_bump,
+            _free_trade_state_bump: free_trade_state_bump,
+            program_as_signer_bump,
+            buyer_price: PRICE,
+            token_size: 1,
+        }
+        .data(),
+        accounts,
+    }
+}
+
+fn cancel_delegated_bid_ix(
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    test_metadata: &Metadata,
+    buyer: &Pubkey,
+    payment_account: &Pubkey,
+) -> Instruction {
+    let mut accounts = mpl_auction_house::accounts::Cancel {
+        wallet: *buyer,
+        token_account: get_associated_token_address(
+            &test_metadata.token.pubkey(),
+            &test_metadata.mint.pubkey(),
+        ),
+        token_mint: test_metadata.mint.pubkey(),
+        authority: ah.authority,
+        auction_house: *ahkey,
+        auction_house_fee_account: ah.auction_house_fee_account,
+        trade_state: buyer_trade_state(ahkey, ah, test_metadata, buyer).0,
+        token_program: spl_token::id(),
+    }
+    .to_account_metas(None);
+    accounts.extend([
+        AccountMeta::new(*payment_account, false),
+        AccountMeta::new_readonly(find_program_as_signer_address().0, false),
+    ]);
+
+    Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::Cancel {
+            buyer_price: PRICE,
+            token_size: 1,
+        }
+        .data(),
+        accounts,
+    }
+}
+
+#[tokio::test]
+async fn delegated_buy_execute_sale_success() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, authority) = spl_auction_house_test_context(&mut context).await;
+    let test_metadata = listed_metadata(&mut context, &ahkey, &ah, PRICE, 1).await.0;
+    let (buyer, payment_account) = funded_spl_buyer(&mut context, &ah, 2 * PRICE).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[delegated_buy_ix(
+            &ahkey,
+            &ah,
+            &test_metadata,
+            &buyer.pubkey(),
+            &payment_account,
+        )],
+        Some(&buyer.pubkey()),
+        &[&buyer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // No funds are locked by the bid.
+    let payment = get_token_account(&mut context, &payment_account)
+        .await
+        .unwrap();
+    assert_eq!(payment.amount, 2 * PRICE);
+    assert_eq!(
+        payment.delegate,
+        Some(find_program_as_signer_address().0).into()
+    );
+    assert_eq!(payment.delegated_amount, PRICE);
+
+    airdrop(&mut context, &ah.auction_house_fee_account, TEN_SOL)
+        .await
+        .unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[execute_delegated_sale_ix(
+            &ahkey,
+            &ah,
+            &test_metadata,
+            &buyer.pubkey(),
+            &payment_account,
+        )],
+        Some(&authority.pubkey()),
+        &[&authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let payment = get_token_account(&mut context, &payment_account)
+        .await
+        .unwrap();
+    assert_eq!(payment.amount, PRICE);
+    assert_eq!(payment.delegated_amount, 0);
+
+    let house_fee = ah.seller_fee_basis_points as u64 * PRICE / 10000;
+    let seller_payment = get_token_account(
+        &mut context,
+        &get_associated_token_address(&test_metadata.token.pubkey(), &ah.treasury_mint),
+    )
+    .await
+    .unwrap();
+    assert_eq!(seller_payment.amount, PRICE - house_fee);
+
+    let buyer_token_account = get_token_account(
+        &mut context,
+        &get_associated_token_address(&buyer.pubkey(), &test_metadata.mint.pubkey()),
+    )
+    .await
+    .unwrap();
+    assert_eq!(buyer_token_account.amount, 1);
+
+    let (escrow_payment_account, _) = find_escrow_payment_address(&ahkey, &buyer.pubkey());
+    let escrow = get_token_account(&mut context, &escrow_payment_account)
+        .await
+        .unwrap();
+    assert_eq!(escrow.amount, 0);
+}
+
+#[tokio::test]
+async fn execute_sale_with_revoked_delegation_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, authority) = spl_auction_house_test_context(&mut context).await;
+    let test_metadata = listed_metadata(&mut context, &ahkey, &ah, PRICE, 1).await.0;
+    let (buyer, payment_account) = funded_spl_buyer(&mut context, &ah, 2 * PRICE).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            delegated_buy_ix(
+                &ahkey,
+                &ah,
+                &test_metadata,
+                &buyer.pubkey(),
+                &payment_account,
+            ),
+            spl_token::instruction::revoke(
+                &spl_token::id(),
+                &payment_account,
+                &buyer.pubkey(),
+                &[],
+            )
+            .unwrap(),
+        ],
+        Some(&buyer.pubkey()),
+        &[&buyer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    airdrop(&mut context, &ah.auction_house_fee_account, TEN_SOL)
+        .await
+        .unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[execute_delegated_sale_ix(
+            &ahkey,
+            &ah,
+            &test_metadata,
+            &buyer.pubkey(),
+            &payment_account,
+        )],
+        Some(&authority.pubkey()),
+        &[&authority],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_error_ignoring_io_error_in_ci(&error, 6056);
+}
+
+#[tokio::test]
+async fn delegated_buy_native_treasury_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = listed_metadata(&mut context, &ahkey, &ah, PRICE, 1).await.0;
+    let buyer = Keypair::new();
+    airdrop(&mut context, &buyer.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[delegated_buy_ix(
+            &ahkey,
+            &ah,
+            &test_metadata,
+            &buyer.pubkey(),
+            &buyer.pubkey(),
+        )],
+        Some(&buyer.pubkey()),
+        &[&buyer],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_error_ignoring_io_error_in_ci(&error, 6055);
+}
+
+#[tokio::test]
+async fn cancel_delegated_bid_releases_allowance() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, _) = spl_auction_house_test_context(&mut context).await;
+    let first = listed_metadata(&mut context, &ahkey, &ah, PRICE, 1).await.0;
+    let second = listed_metadata(&mut context, &ahkey, &ah, PRICE, 1).await.0;
+    let (buyer, payment_account) = funded_spl_buyer(&mut context, &ah, 2 * PRICE).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            delegated_buy_ix(&ahkey, &ah, &first, &buyer.pubkey(), &payment_account),
+            delegated_buy_ix(&ahkey, &ah, &second, &buyer.pubkey(), &payment_account),
+        ],
+        Some(&buyer.pubkey()),
+        &[&buyer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let payment = get_token_account(&mut context, &payment_account)
+        .await
+        .unwrap();
+    assert_eq!(payment.delegated_amount, 2 * PRICE);
+
+    // Canceling one bid keeps the allowance of the other one.
+    let tx = Transaction::new_signed_with_payer(
+        &[cancel_delegated_bid_ix(
+            &ahkey,
+            &ah,
+            &first,
+            &buyer.pubkey(),
+            &payment_account,
+        )],
+        Some(&buyer.pubkey()),
+        &[&buyer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let payment = get_token_account(&mut context, &payment_account)
+        .await
+        .unwrap();
+    assert_eq!(
+        payment.delegate,
+        Some(find_program_as_signer_address().0).into()
+    );
+    assert_eq!(payment.delegated_amount, PRICE);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[cancel_delegated_bid_ix(
+            &ahkey,
+            &ah,
+            &second,
+            &buyer.pubkey(),
+            &payment_account,
+        )],
+        Some(&buyer.pubkey()),
+        &[&buyer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let payment = get_token_account(&mut context, &payment_account)
+        .await
+        .unwrap();
+    assert!(payment.delegate.is_none());
+    assert_eq!(payment.delegated_amount, 0);
+}
+
+#[tokio::test]
+async fn delegated_buy_with_other_delegate_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, _) = spl_auction_house_test_context(&mut context).await;
+    let test_metadata = listed_metadata(&mut context, &ahkey, &ah, PRICE, 1).await.0;
+    let (buyer, payment_account) = funded_spl_buyer(&mut context, &ah, 2 * PRICE).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            spl_token::instruction::approve(
+                &spl_token::id(),
+                &payment_account,
+                &Keypair::new().pubkey(),
+                &buyer.pubkey(),
+                &[],
+                PRICE,
+            )
+            .unwrap(),
+            delegated_buy_ix(
+                &ahkey,
+                &ah,
+                &test_metadata,
+                &buyer.pubkey(),
+                &payment_account,
+            ),
+        ],
+        Some(&buyer.pubkey()),
+        &[&buyer],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_error_ignoring_io_error_in_ci(&error, PAYMENT_ACCOUNT_DELEGATED);
+}
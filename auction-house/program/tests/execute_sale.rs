@@ -4036,3 +4036,270 @@ async fn execute_sale_with_creators(metadata_creators: Vec<(Pubkey, u8, bool)>)
     assert!(seller_before.lamports < seller_after.lamports);
     assert_eq!(buyer_token_after.amount, 1);
 }
+
+fn execute_sale_with_referral_ix(
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    test_metadata: &Metadata,
+    sell_acc: &mpl_auction_house::accounts::Sell,
+    bid_acc: &mpl_auction_house::accounts::Buy,
+    buyer: &Pubkey,
+    referrer: &Pubkey,
+    referral_basis_points: u16,
+) -> Instruction {
+    let buyer_token_account = get_associated_token_address(buyer, &test_metadata.mint.pubkey());
+    let mut accounts = mpl_auction_house::accounts::ExecuteSale {
+        buyer: *buyer,
+        seller: test_metadata.token.pubkey(),
+        auction_house: *ahkey,
+        metadata: test_metadata.pubkey,
+        token_account: sell_acc.token_account,
+        authority: ah.authority,
+        seller_trade_state: sell_acc.seller_trade_state,
+        buyer_trade_state: bid_acc.buyer_trade_state,
+        token_program: spl_token::id(),
+        free_trade_state: sell_acc.free_seller_trade_state,
+        seller_payment_receipt_account: test_metadata.token.pubkey(),
+        buyer_receipt_token_account: buyer_token_account,
+        escrow_payment_account: bid_acc.escrow_payment_account,
+        token_mint: test_metadata.mint.pubkey(),
+        auction_house_fee_account: ah.auction_house_fee_account,
+        auction_house_treasury: ah.auction_house_treasury,
+        treasury_mint: ah.treasury_mint,
+        program_as_signer: sell_acc.program_as_signer,
+        system_program: system_program::id(),
+        ata_program: spl_associated_token_account::id(),
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+    // The metadata has no creators, so the referrer is the first remaining account.
+    accounts.push(AccountMeta::new(*referrer, false));
+
+    let (_, free_sts_bump) = find_trade_state_address(
+        &test_metadata.token.pubkey(),
+        ahkey,
+        &sell_acc.token_account,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        0,
+        1,
+    );
+    let (_, escrow_bump) = find_escrow_payment_address(ahkey, buyer);
+    let (_, pas_bump) = find_program_as_signer_address();
+
+    Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::ExecuteSaleWithReferral {
+            escrow_payment_bump: escrow_bump,
+            _free_trade_state_bump: free_sts_bump,
+            program_as_signer_bump: pas_bump,
+            token_size: 1,
+            buyer_price: 100_000_000,
+            referral_basis_points,
+        }
+        .data(),
+        accounts,
+    }
+}
+
+#[tokio::test]
+async fn execute_sale_with_referrer_splits_fee() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (_, ahkey, authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let ah = set_max_referral_basis_points(&mut context, &ahkey, &authority, 5_000)
+        .await
+        .unwrap();
+    assert_eq!(ah.max_referral_basis_points, 5_000);
+
+    let test_metadata = Metadata::new();
+    airdrop(&mut context, &test_metadata.token.pubkey(), 10_000_000_000)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+    let ((sell_acc, _), sell_tx) = sell(&mut context, &ahkey, &ah, &test_metadata, 100_000_000, 1);
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+    let buyer = Keypair::new();
+    airdrop(&mut context, &buyer.pubkey(), 10_000_000_000)
+        .await
+        .unwrap();
+    let ((bid_acc, _), buy_tx) = buy(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        100_000_000,
+        1,
+    );
+    context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap();
+    airdrop(&mut context, &ah.auction_house_fee_account, 10_000_000_000)
+        .await
+        .unwrap();
+    let referrer = Keypair::new();
+    airdrop(&mut context, &referrer.pubkey(), 10_000_000_000)
+        .await
+        .unwrap();
+
+    let instruction = execute_sale_with_referral_ix(
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &sell_acc,
+        &bid_acc,
+        &buyer.pubkey(),
+        &referrer.pubkey(),
+        2_500,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[&authority],
+        context.last_blockhash,
+    );
+
+    let seller_before = context
+        .banks_client
+        .get_balance(test_metadata.token.pubkey())
+        .await
+        .unwrap();
+    let treasury_before = context
+        .banks_client
+        .get_balance(ah.auction_house_treasury)
+        .await
+        .unwrap();
+    let referrer_before = context
+        .banks_client
+        .get_balance(referrer.pubkey())
+        .await
+        .unwrap();
+    context.banks_client.process_transaction(tx).await.unwrap();
+    let seller_after = context
+        .banks_client
+        .get_balance(test_metadata.token.pubkey())
+        .await
+        .unwrap();
+    let treasury_after = context
+        .banks_client
+        .get_balance(ah.auction_house_treasury)
+        .await
+        .unwrap();
+    let referrer_after = context
+        .banks_client
+        .get_balance(referrer.pubkey())
+        .await
+        .unwrap();
+
+    let house_fee: u64 = (ah.seller_fee_basis_points as u64 * 100_000_000) / 10000;
+    let referral_fee = house_fee * 2_500 / 10000;
+    // The seller gets the same amount as without a referrer.
+    assert_eq!(seller_before + 100_000_000 - house_fee, seller_after);
+    assert_eq!(referrer_before + referral_fee, referrer_after);
+    assert_eq!(treasury_before + house_fee - referral_fee, treasury_after);
+}
+
+#[tokio::test]
+async fn execute_sale_referral_above_max_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (_, ahkey, authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let ah = set_max_referral_basis_points(&mut context, &ahkey, &authority, 1_000)
+        .await
+        .unwrap();
+
+    let test_metadata = Metadata::new();
+    airdrop(&mut context, &test_metadata.token.pubkey(), 10_000_000_000)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+    let ((sell_acc, _), sell_tx) = sell(&mut context, &ahkey, &ah, &test_metadata, 100_000_000, 1);
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+    let buyer = Keypair::new();
+    airdrop(&mut context, &buyer.pubkey(), 10_000_000_000)
+        .await
+        .unwrap();
+    let ((bid_acc, _), buy_tx) = buy(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        100_000_000,
+        1,
+    );
+    context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap();
+    airdrop(&mut context, &ah.auction_house_fee_account, 10_000_000_000)
+        .await
+        .unwrap();
+    let referrer = Keypair::new();
+    airdrop(&mut context, &referrer.pubkey(), 10_000_000_000)
+        .await
+        .unwrap();
+
+    let instruction = execute_sale_with_referral_ix(
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &sell_acc,
+        &bid_acc,
+        &buyer.pubkey(),
+        &referrer.pubkey(),
+        1_001,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[&authority],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+    assert_error_ignoring_io_error_in_ci(&error, 6045);
+}
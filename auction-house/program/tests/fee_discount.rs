@@ -0,0 +1,415 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use mpl_auction_house::{
+    fee_discount::{FeeDiscount, FeeDiscountSchedule},
+    governance::AuctionHouseChange,
+    pda::{
+        find_escrow_payment_address, find_fee_discount_schedule_address,
+        find_program_as_signer_address, find_trade_state_address,
+    },
+};
+use solana_program::{system_program, sysvar};
+use std::result::Result as StdResult;
+use utils::{
+    helpers::{assert_error_ignoring_io_error_in_ci, DirtyClone},
+    setup_functions::*,
+};
+
+const PRICE: u64 = 100_000_000;
+const DISCOUNTED_BASIS_POINTS: u16 = 25;
+
+fn create_fee_discount_schedule_ix(
+    ahkey: &Pubkey,
+    authority: &Pubkey,
+    discounts: Vec<FeeDiscount>,
+) -> Instruction {
+    let (fee_discount_schedule, _) = find_fee_discount_schedule_address(ahkey);
+    let accounts = mpl_auction_house::accounts::CreateFeeDiscountSchedule {
+        auction_house: *ahkey,
+        authority: *authority,
+        fee_discount_schedule,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::CreateFeeDiscountSchedule { discounts }.data(),
+        accounts,
+    }
+}
+
+async fn create_fee_discount_schedule(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    authority: &Keypair,
+    discounts: Vec<FeeDiscount>,
+) -> StdResult<Pubkey, BanksClientError> {
+    let tx = Transaction::new_signed_with_payer(
+        &[create_fee_discount_schedule_ix(
+            ahkey,
+            &authority.pubkey(),
+            discounts,
+        )],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await?;
+
+    Ok(find_fee_discount_schedule_address(ahkey).0)
+}
+
+/// List a fresh NFT, bid on it from `buyer` and return the signed `execute_sale` transaction
+/// with `proof` (holder token account and metadata) after the fee discount schedule.
+async fn execute_sale_with_discount(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    authority: &Keypair,
+    buyer: &Keypair,
+    proof: (Pubkey, Pubkey),
+) -> Transaction {
+    let test_metadata = Metadata::new();
+    airdrop(context, &test_metadata.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let ((sell_acc, _), sell_tx) = sell(context, ahkey, ah, &test_metadata, PRICE, 1);
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+    let ((bid_acc, _), buy_tx) = buy(
+        context,
+        ahkey,
+        ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        buyer,
+        PRICE,
+        1,
+    );
+    context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap();
+    airdrop(context, &ah.auction_house_fee_account, TEN_SOL)
+        .await
+        .unwrap();
+
+    let mut accounts = mpl_auction_house::accounts::ExecuteSale {
+        buyer: buyer.pubkey(),
+        seller: test_metadata.token.pubkey(),
+        token_account: sell_acc.token_account,
+        token_mint: test_metadata.mint.pubkey(),
+        metadata: test_metadata.pubkey,
+        treasury_mint: ah.treasury_mint,
+        escrow_payment_account: bid_acc.escrow_payment_account,
+        seller_payment_receipt_account: test_metadata.token.pubkey(),
+        buyer_receipt_token_account: get_associated_token_address(
+            &buyer.pubkey(),
+            &test_metadata.mint.pubkey(),
+        ),
+        authority: ah.authority,
+        auction_house: *ahkey,
+        auction_house_fee_account: ah.auction_house_fee_account,
+        auction_house_treasury: ah.auction_house_treasury,
+        buyer_trade_state: bid_acc.buyer_trade_state,
+        seller_trade_state: sell_acc.seller_trade_state,
+        free_trade_state: sell_acc.free_seller_trade_state,
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        ata_program: spl_associated_token_account::id(),
+        program_as_signer: sell_acc.program_as_signer,
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+    // The metadata has no creators, so the fee discount group is the only remaining accounts.
+    accounts.extend([
+        AccountMeta::new_readonly(find_fee_discount_schedule_address(ahkey).0, false),
+        AccountMeta::new_readonly(proof.0, false),
+        AccountMeta::new_readonly(proof.1, false),
+    ]);
+
+    let (_, free_trade_state_bump) = find_trade_state_address(
+        &test_metadata.token.pubkey(),
+        ahkey,
+        &sell_acc.token_account,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        0,
+        1,
+    );
+    let (_, escrow_payment_bump) = find_escrow_payment_address(ahkey, &buyer.pubkey());
+    let (_, program_as_signer_bump) = find_program_as_signer_address();
+
+    Transaction::new_signed_with_payer(
+        &[Instruction {
+            program_id: mpl_auction_house::id(),
+            data: mpl_auction_house::instruction::ExecuteSale {
+                escrow_payment_bump,
+                _free_trade_state_bump: free_trade_state_bump,
+                program_as_signer_bump,
+                buyer_price: PRICE,
+                token_size: 1,
+            }
+            .data(),
+            accounts,
+        }],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn create_fee_discount_schedule_success() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (_, ahkey, authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let (collection, _) = create_collection(&mut context).await;
+
+    let discounts = vec![FeeDiscount {
+        collection_mint: collection.mint.pubkey(),
+        seller_fee_basis_points: DISCOUNTED_BASIS_POINTS,
+    }];
+    let fee_discount_schedule =
+        create_fee_discount_schedule(&mut context, &ahkey, &authority, discounts.clone())
+            .await
+            .unwrap();
+
+    let account = context
+        .banks_client
+        .get_account(fee_discount_schedule)
+        .await
+        .unwrap()
+        .expect("Fee discount schedule empty");
+    let schedule = FeeDiscountSchedule::try_deserialize(&mut account.data.as_ref()).unwrap();
+    assert_eq!(schedule.auction_house, ahkey);
+    assert_eq!(schedule.discounts, discounts);
+}
+
+#[tokio::test]
+async fn create_fee_discount_schedule_with_governance_requires_change() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (_, ahkey, authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    enable_governance(
+        &mut context,
+        ahkey,
+        &authority,
+        vec![authority.pubkey()],
+        1,
+        3600,
+    )
+    .await
+    .unwrap();
+    let (collection, _) = create_collection(&mut context).await;
+
+    let discounts = vec![FeeDiscount {
+        collection_mint: collection.mint.pubkey(),
+        seller_fee_basis_points: DISCOUNTED_BASIS_POINTS,
+    }];
+    let error = create_fee_discount_schedule(&mut context, &ahkey, &authority, discounts.clone())
+        .await
+        .unwrap_err();
+    assert_error_ignoring_io_error_in_ci(&error, GOVERNANCE_ENABLED);
+
+    let change = AuctionHouseChange {
+        fee_discounts: Some(discounts.clone()),
+        ..AuctionHouseChange::default()
+    };
+    let pending_change = propose_auction_house_change(&mut context, ahkey, &authority, change)
+        .await
+        .unwrap();
+    warp_forward(&mut context, 3600).await;
+
+    let create = |discounts: Vec<FeeDiscount>| {
+        let mut instruction =
+            create_fee_discount_schedule_ix(&ahkey, &authority.pubkey(), discounts);
+        instruction.accounts.extend(instruction_change_accounts(
+            &ahkey,
+            pending_change,
+            authority.pubkey(),
+        ));
+        instruction
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[create(vec![])],
+        Some(&authority.pubkey()),
+        &[&authority],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+    assert_error_ignoring_io_error_in_ci(&error, GOVERNED_CHANGE_MISMATCH);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[create(discounts.clone())],
+        Some(&authority.pubkey()),
+        &[&authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    assert!(context
+        .banks_client
+        .get_account(pending_change)
+        .await
+        .unwrap()
+        .is_none());
+    let account = context
+        .banks_client
+        .get_account(find_fee_discount_schedule_address(&ahkey).0)
+        .await
+        .unwrap()
+        .expect("Fee discount schedule empty");
+    let schedule = FeeDiscountSchedule::try_deserialize(&mut account.data.as_ref()).unwrap();
+    assert_eq!(schedule.discounts, discounts);
+}
+
+#[tokio::test]
+async fn execute_sale_with_holder_discount_success() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let (collection, master_edition) = create_collection(&mut context).await;
+    create_fee_discount_schedule(
+        &mut context,
+        &ahkey,
+        &authority,
+        vec![FeeDiscount {
+            collection_mint: collection.mint.pubkey(),
+            seller_fee_basis_points: DISCOUNTED_BASIS_POINTS,
+        }],
+    )
+    .await
+    .unwrap();
+
+    // The buyer holds a pass from the discounted collection.
+    let pass = create_collection_item(&mut context, &collection, &master_edition, true).await;
+    let buyer = pass.token.dirty_clone();
+    let proof = (
+        get_associated_token_address(&buyer.pubkey(), &pass.mint.pubkey()),
+        pass.pubkey,
+    );
+
+    let tx = execute_sale_with_discount(&mut context, &ahkey, &ah, &authority, &buyer, proof).await;
+    let treasury_before = context
+        .banks_client
+        .get_balance(ah.auction_house_treasury)
+        .await
+        .unwrap();
+    context.banks_client.process_transaction(tx).await.unwrap();
+    let treasury_after = context
+        .banks_client
+        .get_balance(ah.auction_house_treasury)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        treasury_before + DISCOUNTED_BASIS_POINTS as u64 * PRICE / 10000,
+        treasury_after
+    );
+}
+
+#[tokio::test]
+async fn execute_sale_with_unverified_pass_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let (collection, master_edition) = create_collection(&mut context).await;
+    create_fee_discount_schedule(
+        &mut context,
+        &ahkey,
+        &authority,
+        vec![FeeDiscount {
+            collection_mint: collection.mint.pubkey(),
+            seller_fee_basis_points: DISCOUNTED_BASIS_POINTS,
+        }],
+    )
+    .await
+    .unwrap();
+
+    let pass = create_collection_item(&mut context, &collection, &master_edition, false).await;
+    let buyer = pass.token.dirty_clone();
+    let proof = (
+        get_associated_token_address(&buyer.pubkey(), &pass.mint.pubkey()),
+        pass.pubkey,
+    );
+
+    let tx = execute_sale_with_discount(&mut context, &ahkey, &ah, &authority, &buyer, proof).await;
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_error_ignoring_io_error_in_ci(&error, 6059);
+}
+
+#[tokio::test]
+async fn execute_sale_with_pass_of_third_party_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let (collection, master_edition) = create_collection(&mut context).await;
+    create_fee_discount_schedule(
+        &mut context,
+        &ahkey,
+        &authority,
+        vec![FeeDiscount {
+            collection_mint: collection.mint.pubkey(),
+            seller_fee_basis_points: DISCOUNTED_BASIS_POINTS,
+        }],
+    )
+    .await
+    .unwrap();
+
+    let pass = create_collection_item(&mut context, &collection, &master_edition, true).await;
+    let buyer = Keypair::new();
+    airdrop(&mut context, &buyer.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    let proof = (
+        get_associated_token_address(&pass.token.pubkey(), &pass.mint.pubkey()),
+        pass.pubkey,
+    );
+
+    let tx = execute_sale_with_discount(&mut context, &ahkey, &ah, &authority, &buyer, proof).await;
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_error_ignoring_io_error_in_ci(&error, 6059);
+}
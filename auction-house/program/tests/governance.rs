@@ -0,0 +1,488 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use mpl_auction_house::governance::{
+    AuctionHouseChange, AuctionHouseGovernance, AuctioneerChange, PendingAuctionHouseChange,
+};
+use utils::{helpers::assert_error_ignoring_io_error_in_ci, setup_functions::*};
+
+const DELAY: i64 = 3600;
+
+async fn governed_auction_house(
+    context: &mut ProgramTestContext,
+) -> (AuctionHouse, Pubkey, Vec<Keypair>) {
+    let (ah, ahkey, ah_authority) = existing_auction_house_test_context(context).await.unwrap();
+
+    let signers: Vec<Keypair> = (0..3).map(|_| Keypair::new()).collect();
+    for signer in &signers {
+        airdrop(context, &signer.pubkey(), TEN_SOL).await.unwrap();
+    }
+
+    enable_governance(
+        context,
+        ahkey,
+        &ah_authority,
+        signers.iter().map(|s| s.pubkey()).collect(),
+        2,
+        DELAY,
+    )
+    .await
+    .unwrap();
+
+    (ah, ahkey, signers)
+}
+
+async fn get_auction_house(context: &mut ProgramTestContext, ahkey: Pubkey) -> AuctionHouse {
+    let account = context
+        .banks_client
+        .get_account(ahkey)
+        .await
+        .unwrap()
+        .expect("Auction House empty");
+    AuctionHouse::try_deserialize(&mut account.data.as_ref()).unwrap()
+}
+
+#[tokio::test]
+async fn execute_change_after_delay_success() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, signers) = governed_auction_house(&mut context).await;
+
+    let change = AuctionHouseChange {
+        seller_fee_basis_points: Some(250),
+        requires_sign_off: Some(true),
+        ..AuctionHouseChange::default()
+    };
+    let pending_change =
+        propose_auction_house_change(&mut context, ahkey, &signers[0], change.clone())
+            .await
+            .unwrap();
+    approve_auction_house_change(&mut context, ahkey, pending_change, &signers[1])
+        .await
+        .unwrap();
+
+    warp_forward(&mut context, DELAY).await;
+
+    execute_auction_house_change(
+        &mut context,
+        ahkey,
+        &ah,
+        pending_change,
+        signers[0].pubkey(),
+        &signers[2],
+    )
+    .await
+    .unwrap();
+
+    let new_ah = get_auction_house(&mut context, ahkey).await;
+    assert!(new_ah.has_governance);
+    assert_eq!(new_ah.seller_fee_basis_points, 250);
+    assert!(new_ah.requires_sign_off);
+    assert_eq!(new_ah.authority, ah.authority);
+
+    let pending_change_account = context
+        .banks_client
+        .get_account(pending_change)
+        .await
+        .unwrap();
+    assert!(pending_change_account.is_none());
+}
+
+#[tokio::test]
+async fn execute_change_updates_signers() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, signers) = governed_auction_house(&mut context).await;
+
+    let new_signer = Keypair::new();
+    let change = AuctionHouseChange {
+        signers: Some(vec![signers[0].pubkey(), new_signer.pubkey()]),
+        threshold: Some(1),
+        delay: Some(0),
+        ..AuctionHouseChange::default()
+    };
+    let pending_change = propose_auction_house_change(&mut context, ahkey, &signers[0], change)
+        .await
+        .unwrap();
+    approve_auction_house_change(&mut context, ahkey, pending_change, &signers[2])
+        .await
+        .unwrap();
+
+    warp_forward(&mut context, DELAY).await;
+
+    execute_auction_house_change(
+        &mut context,
+        ahkey,
+        &ah,
+        pending_change,
+        signers[0].pubkey(),
+        &signers[0],
+    )
+    .await
+    .unwrap();
+
+    let (governance, _) = mpl_auction_house::pda::find_governance_address(&ahkey);
+    let governance_account = context
+        .banks_client
+        .get_account(governance)
+        .await
+        .unwrap()
+        .expect("Governance empty");
+    let governance =
+        AuctionHouseGovernance::try_deserialize(&mut governance_account.data.as_ref()).unwrap();
+
+    assert_eq!(
+        governance.signers,
+        vec![signers[0].pubkey(), new_signer.pubkey()]
+    );
+    assert_eq!(governance.threshold, 1);
+    assert_eq!(governance.delay, 0);
+    assert_eq!(governance.proposal_count, 1);
+}
+
+#[tokio::test]
+async fn execute_change_before_delay_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, signers) = governed_auction_house(&mut context).await;
+
+    let change = AuctionHouseChange {
+        seller_fee_basis_points: Some(250),
+        ..AuctionHouseChange::default()
+    };
+    let pending_change = propose_auction_house_change(&mut context, ahkey, &signers[0], change)
+        .await
+        .unwrap();
+    approve_auction_house_change(&mut context, ahkey, pending_change, &signers[1])
+        .await
+        .unwrap();
+
+    let error = execute_auction_house_change(
+        &mut context,
+        ahkey,
+        &ah,
+        pending_change,
+        signers[0].pubkey(),
+        &signers[0],
+    )
+    .await
+    .unwrap_err();
+
+    assert_error_ignoring_io_error_in_ci(&error, 6053);
+}
+
+#[tokio::test]
+async fn execute_change_without_threshold_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, signers) = governed_auction_house(&mut context).await;
+
+    let change = AuctionHouseChange {
+        seller_fee_basis_points: Some(250),
+        ..AuctionHouseChange::default()
+    };
+    let pending_change = propose_auction_house_change(&mut context, ahkey, &signers[0], change)
+        .await
+        .unwrap();
+
+    warp_forward(&mut context, DELAY).await;
+
+    let error = execute_auction_house_change(
+        &mut context,
+        ahkey,
+        &ah,
+        pending_change,
+        signers[0].pubkey(),
+        &signers[0],
+    )
+    .await
+    .unwrap_err();
+
+    assert_error_ignoring_io_error_in_ci(&error, 6052);
+}
+
+#[tokio::test]
+async fn approve_by_non_signer_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (_, ahkey, signers) = governed_auction_house(&mut context).await;
+
+    let change = AuctionHouseChange {
+        seller_fee_basis_points: Some(250),
+        ..AuctionHouseChange::default()
+    };
+    let pending_change = propose_auction_house_change(&mut context, ahkey, &signers[0], change)
+        .await
+        .unwrap();
+
+    let outsider = Keypair::new();
+    airdrop(&mut context, &outsider.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+
+    let error = approve_auction_house_change(&mut context, ahkey, pending_change, &outsider)
+        .await
+        .unwrap_err();
+
+    assert_error_ignoring_io_error_in_ci(&error, 6050);
+}
+
+#[tokio::test]
+async fn cancel_change_success() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, signers) = governed_auction_house(&mut context).await;
+
+    let change = AuctionHouseChange {
+        seller_fee_basis_points: Some(250),
+        ..AuctionHouseChange::default()
+    };
+    let pending_change = propose_auction_house_change(&mut context, ahkey, &signers[0], change)
+        .await
+        .unwrap();
+
+    cancel_auction_house_change(
+        &mut context,
+        ahkey,
+        pending_change,
+        signers[0].pubkey(),
+        &signers[1],
+    )
+    .await
+    .unwrap();
+
+    let pending_change_account = context
+        .banks_client
+        .get_account(pending_change)
+        .await
+        .unwrap();
+    assert!(pending_change_account.is_none());
+
+    let new_ah = get_auction_house(&mut context, ahkey).await;
+    assert_eq!(new_ah.seller_fee_basis_points, ah.seller_fee_basis_points);
+}
+
+#[tokio::test]
+async fn update_auction_house_with_governance_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    enable_governance(
+        &mut context,
+        ahkey,
+        &ah_authority,
+        vec![ah_authority.pubkey()],
+        1,
+        DELAY,
+    )
+    .await
+    .unwrap();
+
+    let error = update_auction_house(
+        &mut context,
+        &ahkey,
+        &ah,
+        &ah_authority,
+        Some(250),
+        None,
+        None,
+    )
+    .await
+    .err()
+    .unwrap();
+
+    assert_error_ignoring_io_error_in_ci(&error, 6046);
+}
+
+#[tokio::test]
+async fn approve_drops_approvals_of_removed_signers() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, signers) = governed_auction_house(&mut context).await;
+
+    let stale_change = AuctionHouseChange {
+        seller_fee_basis_points: Some(250),
+        ..AuctionHouseChange::default()
+    };
+    let stale_pending_change =
+        propose_auction_house_change(&mut context, ahkey, &signers[0], stale_change)
+            .await
+            .unwrap();
+
+    let new_signer = Keypair::new();
+    airdrop(&mut context, &new_signer.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    let change = AuctionHouseChange {
+        signers: Some(vec![signers[1].pubkey(), new_signer.pubkey()]),
+        ..AuctionHouseChange::default()
+    };
+    let pending_change = propose_auction_house_change(&mut context, ahkey, &signers[1], change)
+        .await
+        .unwrap();
+    approve_auction_house_change(&mut context, ahkey, pending_change, &signers[2])
+        .await
+        .unwrap();
+    warp_forward(&mut context, DELAY).await;
+    execute_auction_house_change(
+        &mut context,
+        ahkey,
+        &ah,
+        pending_change,
+        signers[1].pubkey(),
+        &signers[1],
+    )
+    .await
+    .unwrap();
+
+    approve_auction_house_change(&mut context, ahkey, stale_pending_change, &new_signer)
+        .await
+        .unwrap();
+
+    let stale_pending_change_account = context
+        .banks_client
+        .get_account(stale_pending_change)
+        .await
+        .unwrap()
+        .expect("Pending change empty");
+    let stale_pending_change =
+        PendingAuctionHouseChange::try_deserialize(&mut stale_pending_change_account.data.as_ref())
+            .unwrap();
+    assert_eq!(stale_pending_change.approvals, vec![new_signer.pubkey()]);
+    assert_eq!(stale_pending_change.approved_at, None);
+}
+
+#[tokio::test]
+async fn delegate_auctioneer_with_governance_requires_change() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (_, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    enable_governance(
+        &mut context,
+        ahkey,
+        &ah_authority,
+        vec![ah_authority.pubkey()],
+        1,
+        DELAY,
+    )
+    .await
+    .unwrap();
+
+    let auctioneer_authority = Keypair::new();
+    let (ah_auctioneer_pda, _) = find_auctioneer_pda(&ahkey, &auctioneer_authority.pubkey());
+    let scopes = vec![AuthorityScope::Buy, AuthorityScope::Sell];
+
+    let error = delegate_auctioneer(
+        &mut context,
+        ahkey,
+        &ah_authority,
+        auctioneer_authority.pubkey(),
+        ah_auctioneer_pda,
+        scopes.clone(),
+    )
+    .await
+    .unwrap_err();
+    assert_error_ignoring_io_error_in_ci(&error, GOVERNANCE_ENABLED);
+
+    let change = AuctionHouseChange {
+        auctioneer: Some(AuctioneerChange {
+            auctioneer_authority: auctioneer_authority.pubkey(),
+            scopes: Some(scopes.clone()),
+            ..AuctioneerChange::default()
+        }),
+        ..AuctionHouseChange::default()
+    };
+    let pending_change = propose_auction_house_change(&mut context, ahkey, &ah_authority, change)
+        .await
+        .unwrap();
+    warp_forward(&mut context, DELAY).await;
+
+    let delegate = |scopes: Vec<AuthorityScope>| {
+        let mut accounts = mpl_auction_house::accounts::DelegateAuctioneer {
+            auction_house: ahkey,
+            authority: ah_authority.pubkey(),
+            auctioneer_authority: auctioneer_authority.pubkey(),
+            ah_auctioneer_pda,
+            system_program: solana_program::system_program::id(),
+        }
+        .to_account_metas(None);
+        accounts.extend(instruction_change_accounts(
+            &ahkey,
+            pending_change,
+            ah_authority.pubkey(),
+        ));
+
+        Instruction {
+            program_id: mpl_auction_house::id(),
+            data: mpl_auction_house::instruction::DelegateAuctioneer { scopes }.data(),
+            accounts,
+        }
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[delegate(vec![AuthorityScope::Buy])],
+        Some(&ah_authority.pubkey()),
+        &[&ah_authority],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+    assert_error_ignoring_io_error_in_ci(&error, GOVERNED_CHANGE_MISMATCH);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[delegate(scopes)],
+        Some(&ah_authority.pubkey()),
+        &[&ah_authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    assert!(context
+        .banks_client
+        .get_account(pending_change)
+        .await
+        .unwrap()
+        .is_none());
+    let auctioneer_account = context
+        .banks_client
+        .get_account(ah_auctioneer_pda)
+        .await
+        .unwrap()
+        .expect("Auctioneer empty");
+    let auctioneer = Auctioneer::try_deserialize(&mut auctioneer_account.data.as_ref()).unwrap();
+    assert_eq!(
+        auctioneer.auctioneer_authority,
+        auctioneer_authority.pubkey()
+    );
+}
+
+#[tokio::test]
+async fn execute_instruction_change_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, signers) = governed_auction_house(&mut context).await;
+
+    let change = AuctionHouseChange {
+        treasury_withdrawal: Some(ONE_SOL),
+        ..AuctionHouseChange::default()
+    };
+    let pending_change = propose_auction_house_change(&mut context, ahkey, &signers[0], change)
+        .await
+        .unwrap();
+    approve_auction_house_change(&mut context, ahkey, pending_change, &signers[1])
+        .await
+        .unwrap();
+    warp_forward(&mut context, DELAY).await;
+
+    let error = execute_auction_house_change(
+        &mut context,
+        ahkey,
+        &ah,
+        pending_change,
+        signers[0].pubkey(),
+        &signers[0],
+    )
+    .await
+    .unwrap_err();
+    assert_error_ignoring_io_error_in_ci(&error, GOVERNED_INSTRUCTION_CHANGE);
+}
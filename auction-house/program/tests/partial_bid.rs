@@ -0,0 +1,398 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use mpl_auction_house::pda::{
+    find_escrow_payment_address, find_program_as_signer_address, find_trade_state_address,
+};
+use mpl_testing_utils::solana::transfer;
+use solana_program::{system_program, sysvar};
+use utils::{helpers::DirtyClone, setup_functions::*};
+
+const UNIT_PRICE: u64 = 100_000_000;
+
+async fn create_sft(context: &mut ProgramTestContext, amount: u64) -> Metadata {
+    let test_metadata = Metadata::new();
+    airdrop(context, &test_metadata.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            amount,
+        )
+        .await
+        .unwrap();
+
+    test_metadata
+}
+
+async fn list(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    seller_metadata: &Metadata,
+    token_size: u64,
+) -> mpl_auction_house::accounts::Sell {
+    let ((sell_acc, _), sell_tx) = sell(
+        context,
+        ahkey,
+        ah,
+        seller_metadata,
+        UNIT_PRICE * token_size,
+        token_size,
+    );
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    sell_acc
+}
+
+async fn place_partial_bid(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    test_metadata: &Metadata,
+    buyer: &Keypair,
+    bid_size: u64,
+) -> Pubkey {
+    airdrop(context, &buyer.pubkey(), TEN_SOL).await.unwrap();
+    let ((bid_acc, _), buy_tx) = partial_public_buy(
+        context,
+        ahkey,
+        ah,
+        test_metadata,
+        &test_metadata.token.pubkey(),
+        buyer,
+        UNIT_PRICE * bid_size,
+        bid_size,
+    );
+    context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap();
+
+    bid_acc.buyer_trade_state
+}
+
+/// Fill the partial bid of `buyer` from the `token_size` tokens listed in `sell_acc`.
+#[allow(clippy::too_many_arguments)]
+fn fill_bid(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    authority: &Keypair,
+    token_mint: &Pubkey,
+    sell_acc: &mpl_auction_house::accounts::Sell,
+    buyer: &Pubkey,
+    buyer_trade_state: &Pubkey,
+    token_size: u64,
+    bid_size: u64,
+) -> Transaction {
+    let (free_trade_state, free_trade_state_bump) = find_trade_state_address(
+        &sell_acc.wallet,
+        ahkey,
+        &sell_acc.token_account,
+        &ah.treasury_mint,
+        token_mint,
+        0,
+        token_size,
+    );
+    let (escrow_payment_account, escrow_bump) = find_escrow_payment_address(ahkey, buyer);
+    let (program_as_signer, pas_bump) = find_program_as_signer_address();
+
+    let accounts = mpl_auction_house::accounts::ExecuteSale {
+        buyer: *buyer,
+        seller: sell_acc.wallet,
+        auction_house: *ahkey,
+        metadata: sell_acc.metadata,
+        token_account: sell_acc.token_account,
+        authority: ah.authority,
+        seller_trade_state: sell_acc.seller_trade_state,
+        buyer_trade_state: *buyer_trade_state,
+        token_program: spl_token::id(),
+        free_trade_state,
+        seller_payment_receipt_account: sell_acc.wallet,
+        buyer_receipt_token_account: get_associated_token_address(buyer, token_mint),
+        escrow_payment_account,
+        token_mint: *token_mint,
+        auction_house_fee_account: ah.auction_house_fee_account,
+        auction_house_treasury: ah.auction_house_treasury,
+        treasury_mint: ah.treasury_mint,
+        program_as_signer,
+        system_program: system_program::id(),
+        ata_program: spl_associated_token_account::id(),
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::ExecutePartialSale {
+            escrow_payment_bump: escrow_bump,
+            _free_trade_state_bump: free_trade_state_bump,
+            program_as_signer_bump: pas_bump,
+            token_size,
+            buyer_price: UNIT_PRICE * token_size,
+            partial_order_size: Some(bid_size),
+            partial_order_price: Some(UNIT_PRICE * bid_size),
+        }
+        .data(),
+        accounts,
+    };
+
+    Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    )
+}
+
+async fn partial_bid(context: &mut ProgramTestContext, buyer_trade_state: &Pubkey) -> (u64, u64) {
+    let data = context
+        .banks_client
+        .get_account(*buyer_trade_state)
+        .await
+        .unwrap()
+        .expect("Trade State Empty")
+        .data;
+    assert_eq!(data.len(), 17);
+
+    (
+        u64::from_le_bytes(data[1..9].try_into().unwrap()),
+        u64::from_le_bytes(data[9..17].try_into().unwrap()),
+    )
+}
+
+#[tokio::test]
+async fn partial_bid_filled_by_multiple_sellers_success() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    airdrop(&mut context, &ah.auction_house_fee_account, TEN_SOL)
+        .await
+        .unwrap();
+
+    let first_seller = create_sft(&mut context, 3).await;
+    let mint = first_seller.mint.pubkey();
+    let second_seller = Metadata {
+        mint: first_seller.mint.dirty_clone(),
+        token: Keypair::new(),
+        ata: first_seller.ata,
+        pubkey: first_seller.pubkey,
+        master_edition: first_seller.master_edition,
+        token_record: first_seller.token_record,
+    };
+    airdrop(&mut context, &second_seller.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    transfer(
+        &mut context,
+        &mint,
+        &first_seller.token,
+        &second_seller.token,
+    )
+    .await
+    .unwrap();
+
+    let buyer = Keypair::new();
+    let buyer_trade_state =
+        place_partial_bid(&mut context, &ahkey, &ah, &first_seller, &buyer, 3).await;
+    assert_eq!(
+        partial_bid(&mut context, &buyer_trade_state).await,
+        (3, UNIT_PRICE * 3)
+    );
+
+    let first_listing = list(&mut context, &ahkey, &ah, &first_seller, 2).await;
+    let tx = fill_bid(
+        &mut context,
+        &ahkey,
+        &ah,
+        &authority,
+        &mint,
+        &first_listing,
+        &buyer.pubkey(),
+        &buyer_trade_state,
+        2,
+        3,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    assert_eq!(
+        partial_bid(&mut context, &buyer_trade_state).await,
+        (1, UNIT_PRICE)
+    );
+    assert_eq!(token_balance(&mut context, &buyer.pubkey(), &mint).await, 2);
+    let first_seller_ts = context
+        .banks_client
+        .get_account(first_listing.seller_trade_state)
+        .await
+        .unwrap();
+    assert!(first_seller_ts.is_none());
+
+    let second_listing = list(&mut context, &ahkey, &ah, &second_seller, 1).await;
+    let tx = fill_bid(
+        &mut context,
+        &ahkey,
+        &ah,
+        &authority,
+        &mint,
+        &second_listing,
+        &buyer.pubkey(),
+        &buyer_trade_state,
+        1,
+        3,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    assert_eq!(token_balance(&mut context, &buyer.pubkey(), &mint).await, 3);
+    let buyer_ts = context
+        .banks_client
+        .get_account(buyer_trade_state)
+        .await
+        .unwrap();
+    assert!(buyer_ts.is_none());
+}
+
+#[tokio::test]
+async fn execute_sale_overfilling_partial_bid_failure() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    airdrop(&mut context, &ah.auction_house_fee_account, TEN_SOL)
+        .await
+        .unwrap();
+
+    let seller = create_sft(&mut context, 4).await;
+    let mint = seller.mint.pubkey();
+    let buyer = Keypair::new();
+    let buyer_trade_state = place_partial_bid(&mut context, &ahkey, &ah, &seller, &buyer, 3).await;
+
+    let listing = list(&mut context, &ahkey, &ah, &seller, 1).await;
+    let tx = fill_bid(
+        &mut context,
+        &ahkey,
+        &ah,
+        &authority,
+        &mint,
+        &listing,
+        &buyer.pubkey(),
+        &buyer_trade_state,
+        1,
+        3,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // A listing matching the original size of the bid can't take more than what is left of it.
+    let listing = list(&mut context, &ahkey, &ah, &seller, 3).await;
+    let (_, tx) = execute_sale(
+        &mut context,
+        &ahkey,
+        &ah,
+        &authority,
+        &seller,
+        &buyer.pubkey(),
+        &seller.token.pubkey(),
+        &listing.token_account,
+        &listing.seller_trade_state,
+        &buyer_trade_state,
+        3,
+        UNIT_PRICE * 3,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+    assert_error!(error, PARTIAL_BID_OVERFILLED);
+
+    assert_eq!(
+        partial_bid(&mut context, &buyer_trade_state).await,
+        (2, UNIT_PRICE * 2)
+    );
+}
+
+#[tokio::test]
+async fn partial_bid_sent_again_tops_up_reservation() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    airdrop(&mut context, &ah.auction_house_fee_account, TEN_SOL)
+        .await
+        .unwrap();
+
+    let seller = create_sft(&mut context, 3).await;
+    let mint = seller.mint.pubkey();
+    let buyer = Keypair::new();
+    let buyer_trade_state = place_partial_bid(&mut context, &ahkey, &ah, &seller, &buyer, 3).await;
+
+    let listing = list(&mut context, &ahkey, &ah, &seller, 2).await;
+    let tx = fill_bid(
+        &mut context,
+        &ahkey,
+        &ah,
+        &authority,
+        &mint,
+        &listing,
+        &buyer.pubkey(),
+        &buyer_trade_state,
+        2,
+        3,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let (escrow_payment_account, _) = find_escrow_payment_address(&ahkey, &buyer.pubkey());
+    let escrow_before = context
+        .banks_client
+        .get_balance(escrow_payment_account)
+        .await
+        .unwrap();
+
+    // The escrow still holds what is reserved for the last token, so sending the bid again
+    // neither takes more funds nor reopens the filled part of the bid.
+    context.warp_to_slot(100).unwrap();
+    context.last_blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let (_, buy_tx) = partial_public_buy(
+        &mut context,
+        &ahkey,
+        &ah,
+        &seller,
+        &seller.token.pubkey(),
+        &buyer,
+        UNIT_PRICE * 3,
+        3,
+    );
+    context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        context
+            .banks_client
+            .get_balance(escrow_payment_account)
+            .await
+            .unwrap(),
+        escrow_before
+    );
+    assert_eq!(
+        partial_bid(&mut context, &buyer_trade_state).await,
+        (1, UNIT_PRICE)
+    );
+}
@@ -0,0 +1,189 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use solana_program::program_pack::Pack;
+use utils::setup_functions::*;
+
+const PRICE: u64 = 100_000_000;
+const DELAY: i64 = 3_600;
+
+struct ScheduledListing {
+    test_metadata: Metadata,
+    sell_acc: mpl_auction_house::accounts::Sell,
+    buyer: Keypair,
+    buyer_trade_state: Pubkey,
+    start_time: i64,
+}
+
+async fn scheduled_listing_with_bid(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+) -> ScheduledListing {
+    let test_metadata = Metadata::new();
+    airdrop(context, &test_metadata.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let start_time = context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp
+        + DELAY;
+    let ((sell_acc, listing_receipt_acc), sell_tx) =
+        scheduled_sell(context, ahkey, ah, &test_metadata, PRICE, 1, start_time);
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    let sts = context
+        .banks_client
+        .get_account(sell_acc.seller_trade_state)
+        .await
+        .unwrap()
+        .expect("Trade State Empty");
+    assert_eq!(sts.data.len(), 9);
+    assert_eq!(
+        i64::from_le_bytes(sts.data[1..9].try_into().unwrap()),
+        start_time
+    );
+
+    let listing_receipt = context
+        .banks_client
+        .get_account(listing_receipt_acc.receipt)
+        .await
+        .unwrap()
+        .expect("Listing Receipt Empty");
+    let listing_receipt =
+        ListingReceipt::try_deserialize(&mut listing_receipt.data.as_ref()).unwrap();
+    assert_eq!(listing_receipt.trade_state, sell_acc.seller_trade_state);
+    assert_eq!(listing_receipt.price, PRICE);
+
+    let buyer = Keypair::new();
+    airdrop(context, &buyer.pubkey(), TEN_SOL).await.unwrap();
+    let ((bid_acc, _), buy_tx) = buy(
+        context,
+        ahkey,
+        ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        PRICE,
+        1,
+    );
+    context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap();
+
+    ScheduledListing {
+        test_metadata,
+        sell_acc,
+        buyer,
+        buyer_trade_state: bid_acc.buyer_trade_state,
+        start_time,
+    }
+}
+
+fn execute_scheduled_sale(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    authority: &Keypair,
+    listing: &ScheduledListing,
+) -> Transaction {
+    let (_, tx) = execute_sale(
+        context,
+        ahkey,
+        ah,
+        authority,
+        &listing.test_metadata,
+        &listing.buyer.pubkey(),
+        &listing.test_metadata.token.pubkey(),
+        &listing.sell_acc.token_account,
+        &listing.sell_acc.seller_trade_state,
+        &listing.buyer_trade_state,
+        1,
+        PRICE,
+    );
+    tx
+}
+
+#[tokio::test]
+async fn execute_scheduled_sale_before_start_time_failure() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let listing = scheduled_listing_with_bid(&mut context, &ahkey, &ah).await;
+    airdrop(&mut context, &ah.auction_house_fee_account, TEN_SOL)
+        .await
+        .unwrap();
+
+    let tx = execute_scheduled_sale(&mut context, &ahkey, &ah, &authority, &listing);
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+    assert_error!(error, LISTING_NOT_STARTED);
+
+    let sts = context
+        .banks_client
+        .get_account(listing.sell_acc.seller_trade_state)
+        .await
+        .unwrap();
+    assert!(sts.is_some());
+}
+
+#[tokio::test]
+async fn execute_scheduled_sale_after_start_time_success() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let listing = scheduled_listing_with_bid(&mut context, &ahkey, &ah).await;
+    airdrop(&mut context, &ah.auction_house_fee_account, TEN_SOL)
+        .await
+        .unwrap();
+
+    let mut clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    clock.unix_timestamp = listing.start_time;
+    context.set_sysvar(&clock);
+
+    let tx = execute_scheduled_sale(&mut context, &ahkey, &ah, &authority, &listing);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let buyer_token_account = get_associated_token_address(
+        &listing.buyer.pubkey(),
+        &listing.test_metadata.mint.pubkey(),
+    );
+    let buyer_token = context
+        .banks_client
+        .get_account(buyer_token_account)
+        .await
+        .unwrap()
+        .unwrap();
+    let buyer_token = spl_token::state::Account::unpack_from_slice(&buyer_token.data).unwrap();
+    assert_eq!(buyer_token.amount, 1);
+}
@@ -0,0 +1,402 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use mpl_auction_house::{
+    pda::{find_escrow_payment_address, find_program_as_signer_address, find_trade_state_address},
+    sweep::SweepListing,
+};
+use mpl_token_metadata::state::{PrintSupply, TokenStandard};
+use solana_program::{system_program, sysvar};
+use utils::{
+    helpers::{assert_error_ignoring_io_error_in_ci, DirtyClone},
+    setup_functions::*,
+};
+
+const PRICE: u64 = 100_000_000;
+
+async fn create_listing(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    list: bool,
+) -> Metadata {
+    let test_metadata = Metadata::new();
+    airdrop(context, &test_metadata.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    if list {
+        let (_, sell_tx) = sell(context, ahkey, ah, &test_metadata, PRICE, 1);
+        context
+            .banks_client
+            .process_transaction(sell_tx)
+            .await
+            .unwrap();
+    }
+
+    test_metadata
+}
+
+fn sweep_ix(
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    buyer: &Pubkey,
+    listings: &[&Metadata],
+    skip_unavailable: bool,
+    referral: Option<(&Pubkey, u16)>,
+) -> Instruction {
+    let mut accounts = mpl_auction_house::accounts::Sweep {
+        buyer: *buyer,
+        treasury_mint: ah.treasury_mint,
+        escrow_payment_account: find_escrow_payment_address(ahkey, buyer).0,
+        authority: ah.authority,
+        auction_house: *ahkey,
+        auction_house_fee_account: ah.auction_house_fee_account,
+        auction_house_treasury: ah.auction_house_treasury,
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        ata_program: spl_associated_token_account::id(),
+        program_as_signer: find_program_as_signer_address().0,
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+
+    // The metadata have no creators, so each group ends with the buyer receipt token account,
+    // followed by the referrer if there is one.
+    for test_metadata in listings {
+        let seller = test_metadata.token.pubkey();
+        let mint = test_metadata.mint.pubkey();
+        let token_account = get_associated_token_address(&seller, &mint);
+        let (seller_trade_state, _) = find_trade_state_address(
+            &seller,
+            ahkey,
+            &token_account,
+            &ah.treasury_mint,
+            &mint,
+            PRICE,
+            1,
+        );
+
+        accounts.extend([
+            AccountMeta::new(seller_trade_state, false),
+            AccountMeta::new(seller, false),
+            AccountMeta::new(token_account, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new_readonly(test_metadata.pubkey, false),
+            AccountMeta::new(seller, false),
+            AccountMeta::new(get_associated_token_address(buyer, &mint), false),
+        ]);
+        if let Some((referrer, _)) = referral {
+            accounts.push(AccountMeta::new(*referrer, false));
+        }
+    }
+
+    Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::Sweep {
+            listings: vec![
+                SweepListing {
+                    price: PRICE,
+                    token_size: 1,
+                };
+                listings.len()
+            ],
+            skip_unavailable,
+            referral_basis_points: referral.map(|(_, basis_points)| basis_points),
+        }
+        .data(),
+        accounts,
+    }
+}
+
+#[tokio::test]
+async fn sweep_success() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    let first = create_listing(&mut context, &ahkey, &ah, true).await;
+    let second = create_listing(&mut context, &ahkey, &ah, true).await;
+    let buyer = funded_buyer(&mut context, &ahkey, &ah, &first, 2 * PRICE).await;
+
+    let seller_before = context
+        .banks_client
+        .get_balance(first.token.pubkey())
+        .await
+        .unwrap();
+    let treasury_before = context
+        .banks_client
+        .get_balance(ah.auction_house_treasury)
+        .await
+        .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[sweep_ix(
+            &ahkey,
+            &ah,
+            &buyer.pubkey(),
+            &[&first, &second],
+            false,
+            None,
+        )],
+        Some(&buyer.pubkey()),
+        &[&buyer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let seller_after = context
+        .banks_client
+        .get_balance(first.token.pubkey())
+        .await
+        .unwrap();
+    let treasury_after = context
+        .banks_client
+        .get_balance(ah.auction_house_treasury)
+        .await
+        .unwrap();
+
+    let house_fee = ah.seller_fee_basis_points as u64 * PRICE / 10000;
+    // Without creators the royalties stay with the seller.
+    assert_eq!(seller_before + PRICE - house_fee, seller_after);
+    assert_eq!(treasury_before + 2 * house_fee, treasury_after);
+
+    for test_metadata in [&first, &second] {
+        let mint = test_metadata.mint.pubkey();
+        assert_eq!(token_balance(&mut context, &buyer.pubkey(), &mint).await, 1);
+        assert_eq!(
+            token_balance(&mut context, &test_metadata.token.pubkey(), &mint).await,
+            0
+        );
+    }
+}
+
+#[tokio::test]
+async fn sweep_skips_unavailable_listings() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    let listed = create_listing(&mut context, &ahkey, &ah, true).await;
+    let unlisted = create_listing(&mut context, &ahkey, &ah, false).await;
+    let buyer = funded_buyer(&mut context, &ahkey, &ah, &listed, PRICE).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[sweep_ix(
+            &ahkey,
+            &ah,
+            &buyer.pubkey(),
+            &[&unlisted, &listed],
+            true,
+            None,
+        )],
+        Some(&buyer.pubkey()),
+        &[&buyer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    assert_eq!(
+        token_balance(&mut context, &buyer.pubkey(), &listed.mint.pubkey()).await,
+        1
+    );
+    assert_eq!(
+        token_balance(&mut context, &buyer.pubkey(), &unlisted.mint.pubkey()).await,
+        0
+    );
+    assert_eq!(
+        token_balance(
+            &mut context,
+            &unlisted.token.pubkey(),
+            &unlisted.mint.pubkey()
+        )
+        .await,
+        1
+    );
+}
+
+#[tokio::test]
+async fn sweep_unavailable_listing_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    let listed = create_listing(&mut context, &ahkey, &ah, true).await;
+    let unlisted = create_listing(&mut context, &ahkey, &ah, false).await;
+    let buyer = funded_buyer(&mut context, &ahkey, &ah, &listed, PRICE).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[sweep_ix(
+            &ahkey,
+            &ah,
+            &buyer.pubkey(),
+            &[&listed, &unlisted],
+            false,
+            None,
+        )],
+        Some(&buyer.pubkey()),
+        &[&buyer],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_error_ignoring_io_error_in_ci(&error, 6054);
+}
+
+#[tokio::test]
+async fn sweep_with_referrer_splits_fee() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (_, ahkey, authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let ah = set_max_referral_basis_points(&mut context, &ahkey, &authority, 5_000)
+        .await
+        .unwrap();
+
+    let first = create_listing(&mut context, &ahkey, &ah, true).await;
+    let second = create_listing(&mut context, &ahkey, &ah, true).await;
+    let buyer = funded_buyer(&mut context, &ahkey, &ah, &first, 2 * PRICE).await;
+    let referrer = Keypair::new();
+    airdrop(&mut context, &referrer.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+
+    let treasury_before = context
+        .banks_client
+        .get_balance(ah.auction_house_treasury)
+        .await
+        .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[sweep_ix(
+            &ahkey,
+            &ah,
+            &buyer.pubkey(),
+            &[&first, &second],
+            false,
+            Some((&referrer.pubkey(), 2_500)),
+        )],
+        Some(&buyer.pubkey()),
+        &[&buyer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let treasury_after = context
+        .banks_client
+        .get_balance(ah.auction_house_treasury)
+        .await
+        .unwrap();
+    let referrer_after = context
+        .banks_client
+        .get_balance(referrer.pubkey())
+        .await
+        .unwrap();
+
+    let house_fee = ah.seller_fee_basis_points as u64 * PRICE / 10000;
+    let referral_fee = house_fee * 2_500 / 10000;
+    assert_eq!(TEN_SOL + 2 * referral_fee, referrer_after);
+    assert_eq!(
+        treasury_before + 2 * (house_fee - referral_fee),
+        treasury_after
+    );
+}
+
+#[tokio::test]
+async fn sweep_pnft_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    let payer = context.payer.dirty_clone();
+    let (rule_set, auth_data) = create_sale_delegate_rule_set(&mut context, payer).await;
+
+    let test_metadata = Metadata::new();
+    airdrop(&mut context, &test_metadata.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create_via_builder(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            None,
+            None,
+            true,
+            TokenStandard::ProgrammableNonFungible,
+            None,
+            Some(rule_set),
+            Some(0),
+            Some(PrintSupply::Zero),
+        )
+        .await
+        .unwrap();
+    test_metadata
+        .mint_via_builder(&mut context, 1, Some(auth_data))
+        .await
+        .unwrap();
+
+    let (_, sell_tx) = sell_pnft(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &rule_set,
+        PRICE,
+        1,
+    );
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+    let buyer = funded_buyer(&mut context, &ahkey, &ah, &test_metadata, PRICE).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[sweep_ix(
+            &ahkey,
+            &ah,
+            &buyer.pubkey(),
+            &[&test_metadata],
+            true,
+            None,
+        )],
+        Some(&buyer.pubkey()),
+        &[&buyer],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_error_ignoring_io_error_in_ci(&error, PROGRAMMABLE_NFT_NOT_SUPPORTED);
+}
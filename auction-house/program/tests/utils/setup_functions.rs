@@ -7,26 +7,32 @@ use anchor_client::solana_sdk::{
 };
 use anchor_lang::*;
 use mpl_auction_house::{
+    governance::{AuctionHouseChange, AuctionHouseGovernance},
     pda::{
         find_auction_house_address, find_auction_house_fee_account_address,
         find_auction_house_treasury_address, find_auctioneer_pda,
         find_auctioneer_trade_state_address, find_bid_receipt_address, find_escrow_payment_address,
-        find_listing_receipt_address, find_program_as_signer_address,
-        find_public_bid_trade_state_address, find_purchase_receipt_address,
-        find_trade_state_address,
+        find_governance_address, find_listing_receipt_address, find_pending_change_address,
+        find_program_as_signer_address, find_public_bid_trade_state_address,
+        find_purchase_receipt_address, find_trade_state_address,
     },
-    AuctionHouse, AuthorityScope,
+    AuctionHouse, AuctioneerLimits, AuthorityScope,
 };
 
-use mpl_testing_utils::{solana::airdrop, utils::Metadata};
+use mpl_testing_utils::{
+    solana::{airdrop, create_associated_token_account, mint_tokens},
+    utils::{MasterEditionV2, Metadata},
+};
 use std::result::Result as StdResult;
 
 use mpl_token_metadata::{
     pda::{find_metadata_account, find_token_record_account},
     processor::{AuthorizationData, DelegateScenario, TransferScenario},
-    state::{Operation, TokenDelegateRole},
+    state::{Collection, Operation, TokenDelegateRole},
 };
 
+use super::helpers::DirtyClone;
+
 use mpl_token_auth_rules::{
     instruction::{builders::CreateOrUpdateBuilder, CreateOrUpdateArgs, InstructionBuilder},
     payload::Payload,
@@ -38,7 +44,11 @@ use rmp_serde::Serializer;
 use serde::Serialize;
 use solana_program_test::*;
 use solana_sdk::{
-    compute_budget::ComputeBudgetInstruction, instruction::Instruction, transaction::Transaction,
+    clock::Clock,
+    compute_budget::ComputeBudgetInstruction,
+    instruction::{AccountMeta, Instruction},
+    program_pack::Pack,
+    transaction::Transaction,
 };
 use spl_associated_token_account::get_associated_token_address;
 
@@ -114,6 +124,147 @@ pub async fn create_auction_house(
         .map(|_| *auction_house_key)
 }
 
+pub async fn update_auction_house(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    authority: &Keypair,
+    seller_fee_basis_points: Option<u16>,
+    requires_sign_off: Option<bool>,
+    can_change_sale_price: Option<bool>,
+) -> StdResult<AuctionHouse, BanksClientError> {
+    let accounts = mpl_auction_house::accounts::UpdateAuctionHouse {
+        treasury_mint: ah.treasury_mint,
+        payer: authority.pubkey(),
+        authority: authority.pubkey(),
+        new_authority: authority.pubkey(),
+        fee_withdrawal_destination: ah.fee_withdrawal_destination,
+        treasury_withdrawal_destination: ah.treasury_withdrawal_destination,
+        treasury_withdrawal_destination_owner: ah.treasury_withdrawal_destination,
+        auction_house: *ahkey,
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        ata_program: spl_associated_token_account::id(),
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::UpdateAuctionHouse {
+        seller_fee_basis_points,
+        requires_sign_off,
+        can_change_sale_price,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await?;
+
+    let auction_house_acc = context
+        .banks_client
+        .get_account(*ahkey)
+        .await?
+        .expect("account empty");
+
+    AuctionHouse::try_deserialize(&mut auction_house_acc.data.as_ref())
+        .map_err(|e| BanksClientError::Io(io::Error::new(io::ErrorKind::InvalidData, e)))
+}
+
+pub async fn set_max_referral_basis_points(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    authority: &Keypair,
+    max_referral_basis_points: u16,
+) -> StdResult<AuctionHouse, BanksClientError> {
+    let accounts = mpl_auction_house::accounts::UpdateAuctionHouseSetting {
+        authority: authority.pubkey(),
+        auction_house: *ahkey,
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::SetMaxReferralBasisPoints {
+        max_referral_basis_points,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await?;
+
+    let auction_house_acc = context
+        .banks_client
+        .get_account(*ahkey)
+        .await?
+        .expect("account empty");
+
+    AuctionHouse::try_deserialize(&mut auction_house_acc.data.as_ref())
+        .map_err(|e| BanksClientError::Io(io::Error::new(io::ErrorKind::InvalidData, e)))
+}
+
+pub async fn set_receipt_retention_period(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    authority: &Keypair,
+    receipt_retention_period: i64,
+) -> StdResult<AuctionHouse, BanksClientError> {
+    let accounts = mpl_auction_house::accounts::UpdateAuctionHouseSetting {
+        authority: authority.pubkey(),
+        auction_house: *ahkey,
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::SetReceiptRetentionPeriod {
+        receipt_retention_period,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await?;
+
+    let auction_house_acc = context
+        .banks_client
+        .get_account(*ahkey)
+        .await?
+        .expect("account empty");
+
+    AuctionHouse::try_deserialize(&mut auction_house_acc.data.as_ref())
+        .map_err(|e| BanksClientError::Io(io::Error::new(io::ErrorKind::InvalidData, e)))
+}
+
 pub fn deposit(
     context: &mut ProgramTestContext,
     ahkey: &Pubkey,
@@ -492,6 +643,99 @@ pub fn public_buy(
     )
 }
 
+pub fn partial_public_buy(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    test_metadata: &Metadata,
+    owner: &Pubkey,
+    buyer: &Keypair,
+    sale_price: u64,
+    token_size: u64,
+) -> (
+    (
+        mpl_auction_house::accounts::PublicBuy,
+        mpl_auction_house::accounts::PrintBidReceipt,
+    ),
+    Transaction,
+) {
+    let seller_token_account = get_associated_token_address(owner, &test_metadata.mint.pubkey());
+    let trade_state = find_public_bid_trade_state_address(
+        &buyer.pubkey(),
+        ahkey,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        sale_price,
+        token_size,
+    );
+    let (escrow, escrow_bump) = find_escrow_payment_address(ahkey, &buyer.pubkey());
+    let (bts, bts_bump) = trade_state;
+
+    let accounts = mpl_auction_house::accounts::PublicBuy {
+        wallet: buyer.pubkey(),
+        token_account: seller_token_account,
+        metadata: test_metadata.pubkey,
+        authority: ah.authority,
+        auction_house: *ahkey,
+        auction_house_fee_account: ah.auction_house_fee_account,
+        buyer_trade_state: bts,
+        token_program: spl_token::id(),
+        treasury_mint: ah.treasury_mint,
+        payment_account: buyer.pubkey(),
+        transfer_authority: buyer.pubkey(),
+        system_program: solana_program::system_program::id(),
+        rent: sysvar::rent::id(),
+        escrow_payment_account: escrow,
+    };
+    let account_metas = accounts.to_account_metas(None);
+
+    let buy_ix = mpl_auction_house::instruction::PartialPublicBuy {
+        trade_state_bump: bts_bump,
+        escrow_payment_bump: escrow_bump,
+        token_size,
+        buyer_price: sale_price,
+    };
+    let data = buy_ix.data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts: account_metas,
+    };
+
+    let (bid_receipt, bid_receipt_bump) = find_bid_receipt_address(&bts);
+    let print_receipt_accounts = mpl_auction_house::accounts::PrintBidReceipt {
+        receipt: bid_receipt,
+        bookkeeper: buyer.pubkey(),
+        system_program: solana_program::system_program::id(),
+        rent: sysvar::rent::id(),
+        instruction: sysvar::instructions::id(),
+    };
+
+    let account_metas = print_receipt_accounts.to_account_metas(None);
+
+    let print_bid_receipt_ix = mpl_auction_house::instruction::PrintBidReceipt {
+        receipt_bump: bid_receipt_bump,
+    };
+    let data = print_bid_receipt_ix.data();
+
+    let print_bid_receipt_instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts: account_metas,
+    };
+
+    (
+        (accounts, print_receipt_accounts),
+        Transaction::new_signed_with_payer(
+            &[instruction, print_bid_receipt_instruction],
+            Some(&buyer.pubkey()),
+            &[buyer],
+            context.last_blockhash,
+        ),
+    )
+}
+
 pub fn auctioneer_public_buy(
     context: &mut ProgramTestContext,
     ahkey: &Pubkey,
@@ -1020,6 +1264,103 @@ pub fn sell(
     )
 }
 
+pub fn scheduled_sell(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    test_metadata: &Metadata,
+    sale_price: u64,
+    token_size: u64,
+    start_time: i64,
+) -> (
+    (
+        mpl_auction_house::accounts::Sell,
+        mpl_auction_house::accounts::PrintListingReceipt,
+    ),
+    Transaction,
+) {
+    let program_id = mpl_auction_house::id();
+    let token =
+        get_associated_token_address(&test_metadata.token.pubkey(), &test_metadata.mint.pubkey());
+    let (seller_trade_state, sts_bump) = find_trade_state_address(
+        &test_metadata.token.pubkey(),
+        ahkey,
+        &token,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        sale_price,
+        token_size,
+    );
+    let (listing_receipt, receipt_bump) = find_listing_receipt_address(&seller_trade_state);
+
+    let (free_seller_trade_state, free_sts_bump) = find_trade_state_address(
+        &test_metadata.token.pubkey(),
+        ahkey,
+        &token,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        0,
+        token_size,
+    );
+    let (pas, pas_bump) = find_program_as_signer_address();
+
+    let accounts = mpl_auction_house::accounts::Sell {
+        wallet: test_metadata.token.pubkey(),
+        token_account: token,
+        metadata: test_metadata.pubkey,
+        authority: ah.authority,
+        auction_house: *ahkey,
+        auction_house_fee_account: ah.auction_house_fee_account,
+        seller_trade_state,
+        free_seller_trade_state,
+        token_program: spl_token::id(),
+        system_program: solana_program::system_program::id(),
+        program_as_signer: pas,
+        rent: sysvar::rent::id(),
+    };
+    let account_metas = accounts.to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::ScheduledSell {
+        trade_state_bump: sts_bump,
+        free_trade_state_bump: free_sts_bump,
+        program_as_signer_bump: pas_bump,
+        token_size,
+        buyer_price: sale_price,
+        start_time,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id,
+        data,
+        accounts: account_metas,
+    };
+
+    let listing_receipt_accounts = mpl_auction_house::accounts::PrintListingReceipt {
+        receipt: listing_receipt,
+        bookkeeper: test_metadata.token.pubkey(),
+        system_program: system_program::id(),
+        rent: sysvar::rent::id(),
+        instruction: sysvar::instructions::id(),
+    };
+
+    let print_receipt_instruction = Instruction {
+        program_id,
+        data: mpl_auction_house::instruction::PrintListingReceipt { receipt_bump }.data(),
+        accounts: listing_receipt_accounts.to_account_metas(None),
+    };
+
+    (
+        (accounts, listing_receipt_accounts),
+        Transaction::new_signed_with_payer(
+            &[instruction, print_receipt_instruction],
+            Some(&test_metadata.token.pubkey()),
+            &[&test_metadata.token],
+            context.last_blockhash,
+        ),
+    )
+}
+
 pub fn sell_multiple_signers(
     context: &mut ProgramTestContext,
     ahkey: &Pubkey,
@@ -1453,6 +1794,231 @@ pub async fn delegate_auctioneer(
     context.banks_client.process_transaction(tx).await
 }
 
+pub async fn set_auctioneer_limits(
+    context: &mut ProgramTestContext,
+    auction_house: Pubkey,
+    authority: &Keypair,
+    auctioneer_authority: Pubkey,
+    ah_auctioneer_pda: Pubkey,
+    scopes: Vec<AuthorityScope>,
+    limits: AuctioneerLimits,
+) -> StdResult<(), BanksClientError> {
+    let accounts = mpl_auction_house::accounts::UpdateAuctioneer {
+        auction_house,
+        authority: authority.pubkey(),
+        auctioneer_authority,
+        ah_auctioneer_pda,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::SetAuctioneerLimits { scopes, limits }.data(),
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn revoke_auctioneer(
+    context: &mut ProgramTestContext,
+    auction_house: Pubkey,
+    authority: &Keypair,
+    auctioneer_authority: Pubkey,
+    ah_auctioneer_pda: Pubkey,
+) -> StdResult<(), BanksClientError> {
+    let accounts = mpl_auction_house::accounts::RevokeAuctioneer {
+        auction_house,
+        authority: authority.pubkey(),
+        auctioneer_authority,
+        ah_auctioneer_pda,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::RevokeAuctioneer {}.data(),
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+async fn process_governance_instruction(
+    context: &mut ProgramTestContext,
+    accounts: Vec<AccountMeta>,
+    data: Vec<u8>,
+    signer: &Keypair,
+) -> StdResult<(), BanksClientError> {
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&signer.pubkey()),
+        &[signer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn enable_governance(
+    context: &mut ProgramTestContext,
+    auction_house: Pubkey,
+    authority: &Keypair,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+    delay: i64,
+) -> StdResult<Pubkey, BanksClientError> {
+    let (governance, _) = find_governance_address(&auction_house);
+    let accounts = mpl_auction_house::accounts::EnableGovernance {
+        auction_house,
+        authority: authority.pubkey(),
+        governance,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::EnableGovernance {
+        signers,
+        threshold,
+        delay,
+    }
+    .data();
+
+    process_governance_instruction(context, accounts, data, authority).await?;
+
+    Ok(governance)
+}
+
+/// Propose `change` as the next proposal of the governance, returning the pending change address.
+pub async fn propose_auction_house_change(
+    context: &mut ProgramTestContext,
+    auction_house: Pubkey,
+    proposer: &Keypair,
+    change: AuctionHouseChange,
+) -> StdResult<Pubkey, BanksClientError> {
+    let (governance, _) = find_governance_address(&auction_house);
+    let governance_account = context
+        .banks_client
+        .get_account(governance)
+        .await?
+        .expect("account empty");
+    let proposal_count =
+        AuctionHouseGovernance::try_deserialize(&mut governance_account.data.as_ref())
+            .map_err(|e| BanksClientError::Io(io::Error::new(io::ErrorKind::InvalidData, e)))?
+            .proposal_count;
+    let (pending_change, _) = find_pending_change_address(&auction_house, proposal_count);
+
+    let accounts = mpl_auction_house::accounts::ProposeAuctionHouseChange {
+        auction_house,
+        governance,
+        pending_change,
+        proposer: proposer.pubkey(),
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::ProposeAuctionHouseChange { change }.data();
+
+    process_governance_instruction(context, accounts, data, proposer).await?;
+
+    Ok(pending_change)
+}
+
+pub async fn approve_auction_house_change(
+    context: &mut ProgramTestContext,
+    auction_house: Pubkey,
+    pending_change: Pubkey,
+    signer: &Keypair,
+) -> StdResult<(), BanksClientError> {
+    let accounts = mpl_auction_house::accounts::ApproveAuctionHouseChange {
+        governance: find_governance_address(&auction_house).0,
+        pending_change,
+        signer: signer.pubkey(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::ApproveAuctionHouseChange {}.data();
+
+    process_governance_instruction(context, accounts, data, signer).await
+}
+
+pub async fn execute_auction_house_change(
+    context: &mut ProgramTestContext,
+    auction_house: Pubkey,
+    ah: &AuctionHouse,
+    pending_change: Pubkey,
+    proposer: Pubkey,
+    payer: &Keypair,
+) -> StdResult<(), BanksClientError> {
+    let accounts = mpl_auction_house::accounts::ExecuteAuctionHouseChange {
+        treasury_mint: ah.treasury_mint,
+        auction_house,
+        governance: find_governance_address(&auction_house).0,
+        pending_change,
+        proposer,
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::ExecuteAuctionHouseChange {}.data();
+
+    process_governance_instruction(context, accounts, data, payer).await
+}
+
+pub async fn cancel_auction_house_change(
+    context: &mut ProgramTestContext,
+    auction_house: Pubkey,
+    pending_change: Pubkey,
+    proposer: Pubkey,
+    signer: &Keypair,
+) -> StdResult<(), BanksClientError> {
+    let accounts = mpl_auction_house::accounts::CancelAuctionHouseChange {
+        governance: find_governance_address(&auction_house).0,
+        pending_change,
+        proposer,
+        signer: signer.pubkey(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::CancelAuctionHouseChange {}.data();
+
+    process_governance_instruction(context, accounts, data, signer).await
+}
+
+/// Remaining accounts consuming `pending_change` in an instruction of a governed Auction House.
+pub fn instruction_change_accounts(
+    auction_house: &Pubkey,
+    pending_change: Pubkey,
+    proposer: Pubkey,
+) -> Vec<AccountMeta> {
+    vec![
+        AccountMeta::new_readonly(find_governance_address(auction_house).0, false),
+        AccountMeta::new(pending_change, false),
+        AccountMeta::new(proposer, false),
+    ]
+}
+
 pub fn withdraw(
     context: &mut ProgramTestContext,
     buyer: &Keypair,
@@ -1686,3 +2252,200 @@ pub async fn create_sale_delegate_rule_set(
 
     (ruleset_addr, auth_data)
 }
+
+pub async fn create_collection(context: &mut ProgramTestContext) -> (Metadata, MasterEditionV2) {
+    let collection = Metadata::new();
+    collection
+        .create(
+            context,
+            "Collection".to_string(),
+            "COL".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+    let master_edition = MasterEditionV2::new(&collection);
+    master_edition.create(context, Some(0)).await.unwrap();
+
+    (collection, master_edition)
+}
+
+/// Create an NFT in `collection`, verifying the membership when `verified` is set.
+pub async fn create_collection_item(
+    context: &mut ProgramTestContext,
+    collection: &Metadata,
+    master_edition: &MasterEditionV2,
+    verified: bool,
+) -> Metadata {
+    let test_metadata = Metadata::new();
+    airdrop(context, &test_metadata.token.pubkey(), 10_000_000_000)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            true,
+            1,
+        )
+        .await
+        .unwrap();
+    test_metadata
+        .update_v2(
+            context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            true,
+            Some(Collection {
+                verified: false,
+                key: collection.mint.pubkey(),
+            }),
+            None,
+        )
+        .await
+        .unwrap();
+
+    if verified {
+        let collection_authority = context.payer.dirty_clone();
+        test_metadata
+            .verify_collection(
+                context,
+                collection.pubkey,
+                collection_authority,
+                collection.mint.pubkey(),
+                master_edition.pubkey,
+                None,
+            )
+            .await
+            .unwrap();
+    }
+
+    test_metadata
+}
+
+/// Create an NFT of `amount` tokens owned by its own funded wallet and list it at `price`.
+pub async fn listed_metadata(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    price: u64,
+    amount: u64,
+) -> (
+    Metadata,
+    (
+        mpl_auction_house::accounts::Sell,
+        mpl_auction_house::accounts::PrintListingReceipt,
+    ),
+) {
+    let test_metadata = Metadata::new();
+    airdrop(context, &test_metadata.token.pubkey(), 10_000_000_000)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            amount,
+        )
+        .await
+        .unwrap();
+
+    let (sell_accounts, sell_tx) = sell(context, ahkey, ah, &test_metadata, price, amount);
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    (test_metadata, sell_accounts)
+}
+
+/// Create a funded buyer with `amount` deposited in its escrow.
+pub async fn funded_buyer(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    test_metadata: &Metadata,
+    amount: u64,
+) -> Keypair {
+    let buyer = Keypair::new();
+    airdrop(context, &buyer.pubkey(), 10_000_000_000)
+        .await
+        .unwrap();
+    let (_, deposit_tx) = deposit(context, ahkey, ah, test_metadata, &buyer, amount);
+    context
+        .banks_client
+        .process_transaction(deposit_tx)
+        .await
+        .unwrap();
+    buyer
+}
+
+/// Create a funded buyer holding `amount` of the SPL treasury mint in its associated token
+/// account, returning the buyer and that payment account.
+pub async fn funded_spl_buyer(
+    context: &mut ProgramTestContext,
+    ah: &AuctionHouse,
+    amount: u64,
+) -> (Keypair, Pubkey) {
+    let buyer = Keypair::new();
+    airdrop(context, &buyer.pubkey(), 10_000_000_000)
+        .await
+        .unwrap();
+    let payment_account = create_associated_token_account(context, &buyer, &ah.treasury_mint)
+        .await
+        .unwrap();
+    let payer = context.payer.pubkey();
+    mint_tokens(
+        context,
+        &ah.treasury_mint,
+        &payment_account,
+        amount,
+        &payer,
+        None,
+    )
+    .await
+    .unwrap();
+
+    (buyer, payment_account)
+}
+
+/// Balance of the `mint` associated token account of `owner`, zero if it doesn't exist.
+pub async fn token_balance(context: &mut ProgramTestContext, owner: &Pubkey, mint: &Pubkey) -> u64 {
+    let account = context
+        .banks_client
+        .get_account(get_associated_token_address(owner, mint))
+        .await
+        .unwrap();
+    match account {
+        Some(account) => {
+            spl_token::state::Account::unpack(&account.data)
+                .unwrap()
+                .amount
+        }
+        None => 0,
+    }
+}
+
+/// Move the cluster clock `seconds` forward.
+pub async fn warp_forward(context: &mut ProgramTestContext, seconds: i64) {
+    let mut clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    clock.unix_timestamp += seconds;
+    context.set_sysvar(&clock);
+}
@@ -9,6 +9,9 @@ import * as beet from '@metaplex-foundation/beet';
 import * as web3 from '@solana/web3.js';
 import { ListingConfigVersion, listingConfigVersionBeet } from '../types/ListingConfigVersion';
 import { Bid, bidBeet } from '../types/Bid';
+import { BidGate, bidGateBeet } from '../types/BidGate';
+import { SoftClose, softCloseBeet } from '../types/SoftClose';
+import { CancelPolicy, cancelPolicyBeet } from '../types/CancelPolicy';
 
 /**
  * Arguments used to create {@link ListingConfig}
@@ -26,6 +29,11 @@ export type ListingConfigArgs = {
   timeExtPeriod: number;
   timeExtDelta: number;
   allowHighBidCancel: boolean;
+  bidGate: beet.COption<BidGate>;
+  softClose: beet.COption<SoftClose>;
+  extensions: number;
+  cancelPolicy: beet.COption<CancelPolicy>;
+  hasBidHistory: boolean;
 };
 
 const listingConfigDiscriminator = [183, 196, 26, 41, 131, 46, 184, 115];
@@ -48,6 +56,11 @@ export class ListingConfig implements ListingConfigArgs {
     readonly timeExtPeriod: number,
     readonly timeExtDelta: number,
     readonly allowHighBidCancel: boolean,
+    readonly bidGate: beet.COption<BidGate>,
+    readonly softClose: beet.COption<SoftClose>,
+    readonly extensions: number,
+    readonly cancelPolicy: beet.COption<CancelPolicy>,
+    readonly hasBidHistory: boolean,
   ) {}
 
   /**
@@ -65,6 +78,11 @@ export class ListingConfig implements ListingConfigArgs {
       args.timeExtPeriod,
       args.timeExtDelta,
       args.allowHighBidCancel,
+      args.bidGate,
+      args.softClose,
+      args.extensions,
+      args.cancelPolicy,
+      args.hasBidHistory,
     );
   }
 
@@ -117,31 +135,33 @@ export class ListingConfig implements ListingConfigArgs {
 
   /**
    * Returns the byteSize of a {@link Buffer} holding the serialized data of
-   * {@link ListingConfig}
+   * {@link ListingConfig} for the provided args.
+   *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
    */
-  static get byteSize() {
-    return listingConfigBeet.byteSize;
+  static byteSize(args: ListingConfigArgs) {
+    const instance = ListingConfig.fromArgs(args);
+    return listingConfigBeet.toFixedFromValue({
+      accountDiscriminator: listingConfigDiscriminator,
+      ...instance,
+    }).byteSize;
   }
 
   /**
    * Fetches the minimum balance needed to exempt an account holding
    * {@link ListingConfig} data from rent
    *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
    * @param connection used to retrieve the rent exemption information
    */
   static async getMinimumBalanceForRentExemption(
+    args: ListingConfigArgs,
     connection: web3.Connection,
     commitment?: web3.Commitment,
   ): Promise<number> {
-    return connection.getMinimumBalanceForRentExemption(ListingConfig.byteSize, commitment);
-  }
-
-  /**
-   * Determines if the provided {@link Buffer} has the correct byte size to
-   * hold {@link ListingConfig} data.
-   */
-  static hasCorrectByteSize(buf: Buffer, offset = 0) {
-    return buf.byteLength - offset === ListingConfig.byteSize;
+    return connection.getMinimumBalanceForRentExemption(ListingConfig.byteSize(args), commitment);
   }
 
   /**
@@ -180,6 +200,11 @@ export class ListingConfig implements ListingConfigArgs {
       timeExtPeriod: this.timeExtPeriod,
       timeExtDelta: this.timeExtDelta,
       allowHighBidCancel: this.allowHighBidCancel,
+      bidGate: this.bidGate,
+      softClose: this.softClose,
+      extensions: this.extensions,
+      cancelPolicy: this.cancelPolicy,
+      hasBidHistory: this.hasBidHistory,
     };
   }
 }
@@ -188,7 +213,7 @@ export class ListingConfig implements ListingConfigArgs {
  * @category Accounts
  * @category generated
  */
-export const listingConfigBeet = new beet.BeetStruct<
+export const listingConfigBeet = new beet.FixableBeetStruct<
   ListingConfig,
   ListingConfigArgs & {
     accountDiscriminator: number[] /* size: 8 */;
@@ -206,6 +231,11 @@ export const listingConfigBeet = new beet.BeetStruct<
     ['timeExtPeriod', beet.u32],
     ['timeExtDelta', beet.u32],
     ['allowHighBidCancel', beet.bool],
+    ['bidGate', beet.coption(bidGateBeet)],
+    ['softClose', beet.coption(softCloseBeet)],
+    ['extensions', beet.u16],
+    ['cancelPolicy', beet.coption(cancelPolicyBeet)],
+    ['hasBidHistory', beet.bool],
   ],
   ListingConfig.fromArgs,
   'ListingConfig',
@@ -1,2 +1,6 @@
 export * from './AuctioneerAuthority';
+export * from './BidHistory';
+export * from './ForfeitDeposit';
 export * from './ListingConfig';
+export * from './MultiUnitListing';
+export * from './Raffle';
@@ -211,6 +211,832 @@ export class CannotCancelHighestBidError extends Error {
 createErrorFromCodeLookup.set(0x1779, () => new CannotCancelHighestBidError());
 createErrorFromNameLookup.set('CannotCancelHighestBid', () => new CannotCancelHighestBidError());
 
+/**
+ * InvalidMaxWinners: 'A multi-unit auction needs between one and the maximum number of bids as winners'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidMaxWinnersError extends Error {
+  readonly code: number = 0x177a;
+  readonly name: string = 'InvalidMaxWinners';
+  constructor() {
+    super('A multi-unit auction needs between one and the maximum number of bids as winners');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidMaxWinnersError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x177a, () => new InvalidMaxWinnersError());
+createErrorFromNameLookup.set('InvalidMaxWinners', () => new InvalidMaxWinnersError());
+
+/**
+ * InvalidBidQuantity: 'The bid quantity must fit in the listing and divide the bid price'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidBidQuantityError extends Error {
+  readonly code: number = 0x177b;
+  readonly name: string = 'InvalidBidQuantity';
+  constructor() {
+    super('The bid quantity must fit in the listing and divide the bid price');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidBidQuantityError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x177b, () => new InvalidBidQuantityError());
+createErrorFromNameLookup.set('InvalidBidQuantity', () => new InvalidBidQuantityError());
+
+/**
+ * BidAlreadyPlaced: 'The bid has already been placed'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class BidAlreadyPlacedError extends Error {
+  readonly code: number = 0x177c;
+  readonly name: string = 'BidAlreadyPlaced';
+  constructor() {
+    super('The bid has already been placed');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, BidAlreadyPlacedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x177c, () => new BidAlreadyPlacedError());
+createErrorFromNameLookup.set('BidAlreadyPlaced', () => new BidAlreadyPlacedError());
+
+/**
+ * NotWinningBid: 'The bid is not one of the winning bids'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class NotWinningBidError extends Error {
+  readonly code: number = 0x177d;
+  readonly name: string = 'NotWinningBid';
+  constructor() {
+    super('The bid is not one of the winning bids');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, NotWinningBidError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x177d, () => new NotWinningBidError());
+createErrorFromNameLookup.set('NotWinningBid', () => new NotWinningBidError());
+
+/**
+ * BidAlreadySettled: 'The winning bid has already been settled'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class BidAlreadySettledError extends Error {
+  readonly code: number = 0x177e;
+  readonly name: string = 'BidAlreadySettled';
+  constructor() {
+    super('The winning bid has already been settled');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, BidAlreadySettledError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x177e, () => new BidAlreadySettledError());
+createErrorFromNameLookup.set('BidAlreadySettled', () => new BidAlreadySettledError());
+
+/**
+ * ListingHasBids: 'The listing has bids that have not been settled'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ListingHasBidsError extends Error {
+  readonly code: number = 0x177f;
+  readonly name: string = 'ListingHasBids';
+  constructor() {
+    super('The listing has bids that have not been settled');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ListingHasBidsError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x177f, () => new ListingHasBidsError());
+createErrorFromNameLookup.set('ListingHasBids', () => new ListingHasBidsError());
+
+/**
+ * BidHistoryMismatch: 'The bid history belongs to another listing'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class BidHistoryMismatchError extends Error {
+  readonly code: number = 0x1780;
+  readonly name: string = 'BidHistoryMismatch';
+  constructor() {
+    super('The bid history belongs to another listing');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, BidHistoryMismatchError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1780, () => new BidHistoryMismatchError());
+createErrorFromNameLookup.set('BidHistoryMismatch', () => new BidHistoryMismatchError());
+
+/**
+ * ListingStillOpen: 'The listing has not been sold or canceled yet'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ListingStillOpenError extends Error {
+  readonly code: number = 0x1781;
+  readonly name: string = 'ListingStillOpen';
+  constructor() {
+    super('The listing has not been sold or canceled yet');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ListingStillOpenError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1781, () => new ListingStillOpenError());
+createErrorFromNameLookup.set('ListingStillOpen', () => new ListingStillOpenError());
+
+/**
+ * MissingForfeitDeposit: 'The bidder has no forfeit deposit for this listing'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MissingForfeitDepositError extends Error {
+  readonly code: number = 0x1782;
+  readonly name: string = 'MissingForfeitDeposit';
+  constructor() {
+    super('The bidder has no forfeit deposit for this listing');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MissingForfeitDepositError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1782, () => new MissingForfeitDepositError());
+createErrorFromNameLookup.set('MissingForfeitDeposit', () => new MissingForfeitDepositError());
+
+/**
+ * ForfeitDepositTooLow: 'The forfeit deposit is lower than the bid history requires'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ForfeitDepositTooLowError extends Error {
+  readonly code: number = 0x1783;
+  readonly name: string = 'ForfeitDepositTooLow';
+  constructor() {
+    super('The forfeit deposit is lower than the bid history requires');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ForfeitDepositTooLowError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1783, () => new ForfeitDepositTooLowError());
+createErrorFromNameLookup.set('ForfeitDepositTooLow', () => new ForfeitDepositTooLowError());
+
+/**
+ * SecondChanceNotOpen: 'The grace period after the end of the auction has not passed yet'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class SecondChanceNotOpenError extends Error {
+  readonly code: number = 0x1784;
+  readonly name: string = 'SecondChanceNotOpen';
+  constructor() {
+    super('The grace period after the end of the auction has not passed yet');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, SecondChanceNotOpenError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1784, () => new SecondChanceNotOpenError());
+createErrorFromNameLookup.set('SecondChanceNotOpen', () => new SecondChanceNotOpenError());
+
+/**
+ * BidNotInHistory: 'The bid is not recorded in the bid history'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class BidNotInHistoryError extends Error {
+  readonly code: number = 0x1785;
+  readonly name: string = 'BidNotInHistory';
+  constructor() {
+    super('The bid is not recorded in the bid history');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, BidNotInHistoryError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1785, () => new BidNotInHistoryError());
+createErrorFromNameLookup.set('BidNotInHistory', () => new BidNotInHistoryError());
+
+/**
+ * IncompleteBidHistory: 'The bid history does not record the highest bid'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class IncompleteBidHistoryError extends Error {
+  readonly code: number = 0x1786;
+  readonly name: string = 'IncompleteBidHistory';
+  constructor() {
+    super('The bid history does not record the highest bid');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, IncompleteBidHistoryError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1786, () => new IncompleteBidHistoryError());
+createErrorFromNameLookup.set('IncompleteBidHistory', () => new IncompleteBidHistoryError());
+
+/**
+ * HigherBidStillFunded: 'A higher bid is still funded'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class HigherBidStillFundedError extends Error {
+  readonly code: number = 0x1787;
+  readonly name: string = 'HigherBidStillFunded';
+  constructor() {
+    super('A higher bid is still funded');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, HigherBidStillFundedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1787, () => new HigherBidStillFundedError());
+createErrorFromNameLookup.set('HigherBidStillFunded', () => new HigherBidStillFundedError());
+
+/**
+ * InvalidEscrowAccount: 'The account is not the escrow of the higher bidder'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidEscrowAccountError extends Error {
+  readonly code: number = 0x1788;
+  readonly name: string = 'InvalidEscrowAccount';
+  constructor() {
+    super('The account is not the escrow of the higher bidder');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidEscrowAccountError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1788, () => new InvalidEscrowAccountError());
+createErrorFromNameLookup.set('InvalidEscrowAccount', () => new InvalidEscrowAccountError());
+
+/**
+ * BidGateNotMet: 'The bidder does not hold the tokens the listing requires'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class BidGateNotMetError extends Error {
+  readonly code: number = 0x1789;
+  readonly name: string = 'BidGateNotMet';
+  constructor() {
+    super('The bidder does not hold the tokens the listing requires');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, BidGateNotMetError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1789, () => new BidGateNotMetError());
+createErrorFromNameLookup.set('BidGateNotMet', () => new BidGateNotMetError());
+
+/**
+ * InvalidSoftClose: 'The hard end time of the soft close must not be before the end time'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidSoftCloseError extends Error {
+  readonly code: number = 0x178a;
+  readonly name: string = 'InvalidSoftClose';
+  constructor() {
+    super('The hard end time of the soft close must not be before the end time');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidSoftCloseError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178a, () => new InvalidSoftCloseError());
+createErrorFromNameLookup.set('InvalidSoftClose', () => new InvalidSoftCloseError());
+
+/**
+ * InvalidTicketQuantity: 'Ticket quantity must be positive and within the tickets left'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidTicketQuantityError extends Error {
+  readonly code: number = 0x178b;
+  readonly name: string = 'InvalidTicketQuantity';
+  constructor() {
+    super('Ticket quantity must be positive and within the tickets left');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidTicketQuantityError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178b, () => new InvalidTicketQuantityError());
+createErrorFromNameLookup.set('InvalidTicketQuantity', () => new InvalidTicketQuantityError());
+
+/**
+ * RaffleFull: 'The raffle has no room for another buyer'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RaffleFullError extends Error {
+  readonly code: number = 0x178c;
+  readonly name: string = 'RaffleFull';
+  constructor() {
+    super('The raffle has no room for another buyer');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RaffleFullError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178c, () => new RaffleFullError());
+createErrorFromNameLookup.set('RaffleFull', () => new RaffleFullError());
+
+/**
+ * InvalidRaffleSeed: 'The seed does not match the raffle commitment'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidRaffleSeedError extends Error {
+  readonly code: number = 0x178d;
+  readonly name: string = 'InvalidRaffleSeed';
+  constructor() {
+    super('The seed does not match the raffle commitment');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidRaffleSeedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178d, () => new InvalidRaffleSeedError());
+createErrorFromNameLookup.set('InvalidRaffleSeed', () => new InvalidRaffleSeedError());
+
+/**
+ * NoRaffleTickets: 'No raffle tickets'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class NoRaffleTicketsError extends Error {
+  readonly code: number = 0x178e;
+  readonly name: string = 'NoRaffleTickets';
+  constructor() {
+    super('No raffle tickets');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, NoRaffleTicketsError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178e, () => new NoRaffleTicketsError());
+createErrorFromNameLookup.set('NoRaffleTickets', () => new NoRaffleTicketsError());
+
+/**
+ * InvalidSlotHashes: 'The account is not the SlotHashes sysvar'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidSlotHashesError extends Error {
+  readonly code: number = 0x178f;
+  readonly name: string = 'InvalidSlotHashes';
+  constructor() {
+    super('The account is not the SlotHashes sysvar');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidSlotHashesError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178f, () => new InvalidSlotHashesError());
+createErrorFromNameLookup.set('InvalidSlotHashes', () => new InvalidSlotHashesError());
+
+/**
+ * NumericalOverflow: 'Numerical overflow'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class NumericalOverflowError extends Error {
+  readonly code: number = 0x1790;
+  readonly name: string = 'NumericalOverflow';
+  constructor() {
+    super('Numerical overflow');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, NumericalOverflowError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1790, () => new NumericalOverflowError());
+createErrorFromNameLookup.set('NumericalOverflow', () => new NumericalOverflowError());
+
+/**
+ * RaffleRequiresNativeMint: 'Raffles can only be listed on native SOL Auction Houses'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RaffleRequiresNativeMintError extends Error {
+  readonly code: number = 0x1791;
+  readonly name: string = 'RaffleRequiresNativeMint';
+  constructor() {
+    super('Raffles can only be listed on native SOL Auction Houses');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RaffleRequiresNativeMintError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1791, () => new RaffleRequiresNativeMintError());
+createErrorFromNameLookup.set(
+  'RaffleRequiresNativeMint',
+  () => new RaffleRequiresNativeMintError(),
+);
+
+/**
+ * RaffleAlreadyDrawn: 'The raffle has already been drawn'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RaffleAlreadyDrawnError extends Error {
+  readonly code: number = 0x1792;
+  readonly name: string = 'RaffleAlreadyDrawn';
+  constructor() {
+    super('The raffle has already been drawn');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RaffleAlreadyDrawnError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1792, () => new RaffleAlreadyDrawnError());
+createErrorFromNameLookup.set('RaffleAlreadyDrawn', () => new RaffleAlreadyDrawnError());
+
+/**
+ * RevealPeriodOver: 'The raffle can no longer be drawn'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RevealPeriodOverError extends Error {
+  readonly code: number = 0x1793;
+  readonly name: string = 'RevealPeriodOver';
+  constructor() {
+    super('The raffle can no longer be drawn');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RevealPeriodOverError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1793, () => new RevealPeriodOverError());
+createErrorFromNameLookup.set('RevealPeriodOver', () => new RevealPeriodOverError());
+
+/**
+ * NotRaffleWinner: 'The wallet is not the raffle winner'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class NotRaffleWinnerError extends Error {
+  readonly code: number = 0x1794;
+  readonly name: string = 'NotRaffleWinner';
+  constructor() {
+    super('The wallet is not the raffle winner');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, NotRaffleWinnerError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1794, () => new NotRaffleWinnerError());
+createErrorFromNameLookup.set('NotRaffleWinner', () => new NotRaffleWinnerError());
+
+/**
+ * RaffleNotRefundable: 'The raffle tickets cannot be refunded yet'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RaffleNotRefundableError extends Error {
+  readonly code: number = 0x1795;
+  readonly name: string = 'RaffleNotRefundable';
+  constructor() {
+    super('The raffle tickets cannot be refunded yet');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RaffleNotRefundableError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1795, () => new RaffleNotRefundableError());
+createErrorFromNameLookup.set('RaffleNotRefundable', () => new RaffleNotRefundableError());
+
+/**
+ * RaffleAlreadyClaimed: 'The raffle prize has already been claimed'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RaffleAlreadyClaimedError extends Error {
+  readonly code: number = 0x1796;
+  readonly name: string = 'RaffleAlreadyClaimed';
+  constructor() {
+    super('The raffle prize has already been claimed');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RaffleAlreadyClaimedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1796, () => new RaffleAlreadyClaimedError());
+createErrorFromNameLookup.set('RaffleAlreadyClaimed', () => new RaffleAlreadyClaimedError());
+
+/**
+ * RaffleNotClaimed: 'The raffle prize has not been claimed'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RaffleNotClaimedError extends Error {
+  readonly code: number = 0x1797;
+  readonly name: string = 'RaffleNotClaimed';
+  constructor() {
+    super('The raffle prize has not been claimed');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RaffleNotClaimedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1797, () => new RaffleNotClaimedError());
+createErrorFromNameLookup.set('RaffleNotClaimed', () => new RaffleNotClaimedError());
+
+/**
+ * RaffleHasTickets: 'The raffle still holds ticket funds'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RaffleHasTicketsError extends Error {
+  readonly code: number = 0x1798;
+  readonly name: string = 'RaffleHasTickets';
+  constructor() {
+    super('The raffle still holds ticket funds');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RaffleHasTicketsError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1798, () => new RaffleHasTicketsError());
+createErrorFromNameLookup.set('RaffleHasTickets', () => new RaffleHasTicketsError());
+
+/**
+ * CancelAfterFirstBid: 'The listing cannot be canceled once it has bids'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class CancelAfterFirstBidError extends Error {
+  readonly code: number = 0x1799;
+  readonly name: string = 'CancelAfterFirstBid';
+  constructor() {
+    super('The listing cannot be canceled once it has bids');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, CancelAfterFirstBidError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1799, () => new CancelAfterFirstBidError());
+createErrorFromNameLookup.set('CancelAfterFirstBid', () => new CancelAfterFirstBidError());
+
+/**
+ * CancelAfterReserveMet: 'The listing cannot be canceled once the reserve price is met'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class CancelAfterReserveMetError extends Error {
+  readonly code: number = 0x179a;
+  readonly name: string = 'CancelAfterReserveMet';
+  constructor() {
+    super('The listing cannot be canceled once the reserve price is met');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, CancelAfterReserveMetError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179a, () => new CancelAfterReserveMetError());
+createErrorFromNameLookup.set('CancelAfterReserveMet', () => new CancelAfterReserveMetError());
+
+/**
+ * InvalidHighestBidder: 'The account is not the wallet of the highest bidder'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidHighestBidderError extends Error {
+  readonly code: number = 0x179b;
+  readonly name: string = 'InvalidHighestBidder';
+  constructor() {
+    super('The account is not the wallet of the highest bidder');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidHighestBidderError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179b, () => new InvalidHighestBidderError());
+createErrorFromNameLookup.set('InvalidHighestBidder', () => new InvalidHighestBidderError());
+
+/**
+ * MultiUnitBookFull: 'The multi-unit bid book is full and the bid is not above its lowest losing bid'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MultiUnitBookFullError extends Error {
+  readonly code: number = 0x179c;
+  readonly name: string = 'MultiUnitBookFull';
+  constructor() {
+    super('The multi-unit bid book is full and the bid is not above its lowest losing bid');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MultiUnitBookFullError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179c, () => new MultiUnitBookFullError());
+createErrorFromNameLookup.set('MultiUnitBookFull', () => new MultiUnitBookFullError());
+
+/**
+ * WinningBidStillFunded: 'The winning bid is still funded'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class WinningBidStillFundedError extends Error {
+  readonly code: number = 0x179d;
+  readonly name: string = 'WinningBidStillFunded';
+  constructor() {
+    super('The winning bid is still funded');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, WinningBidStillFundedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179d, () => new WinningBidStillFundedError());
+createErrorFromNameLookup.set('WinningBidStillFunded', () => new WinningBidStillFundedError());
+
+/**
+ * MissingBidHistory: 'The bid history of the listing is missing'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MissingBidHistoryError extends Error {
+  readonly code: number = 0x179e;
+  readonly name: string = 'MissingBidHistory';
+  constructor() {
+    super('The bid history of the listing is missing');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MissingBidHistoryError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179e, () => new MissingBidHistoryError());
+createErrorFromNameLookup.set('MissingBidHistory', () => new MissingBidHistoryError());
+
+/**
+ * BidHistoryAfterFirstBid: 'The bid history must be created before the first bid'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class BidHistoryAfterFirstBidError extends Error {
+  readonly code: number = 0x179f;
+  readonly name: string = 'BidHistoryAfterFirstBid';
+  constructor() {
+    super('The bid history must be created before the first bid');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, BidHistoryAfterFirstBidError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179f, () => new BidHistoryAfterFirstBidError());
+createErrorFromNameLookup.set('BidHistoryAfterFirstBid', () => new BidHistoryAfterFirstBidError());
+
+/**
+ * RaffleDrawNotCommitted: 'The raffle draw slot has not been committed'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RaffleDrawNotCommittedError extends Error {
+  readonly code: number = 0x17a0;
+  readonly name: string = 'RaffleDrawNotCommitted';
+  constructor() {
+    super('The raffle draw slot has not been committed');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RaffleDrawNotCommittedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a0, () => new RaffleDrawNotCommittedError());
+createErrorFromNameLookup.set('RaffleDrawNotCommitted', () => new RaffleDrawNotCommittedError());
+
+/**
+ * RaffleDrawAlreadyCommitted: 'The raffle draw slot has already been committed'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RaffleDrawAlreadyCommittedError extends Error {
+  readonly code: number = 0x17a1;
+  readonly name: string = 'RaffleDrawAlreadyCommitted';
+  constructor() {
+    super('The raffle draw slot has already been committed');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RaffleDrawAlreadyCommittedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a1, () => new RaffleDrawAlreadyCommittedError());
+createErrorFromNameLookup.set(
+  'RaffleDrawAlreadyCommitted',
+  () => new RaffleDrawAlreadyCommittedError(),
+);
+
+/**
+ * RaffleDrawSlotPending: 'The hash of the raffle draw slot is not available yet'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RaffleDrawSlotPendingError extends Error {
+  readonly code: number = 0x17a2;
+  readonly name: string = 'RaffleDrawSlotPending';
+  constructor() {
+    super('The hash of the raffle draw slot is not available yet');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RaffleDrawSlotPendingError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a2, () => new RaffleDrawSlotPendingError());
+createErrorFromNameLookup.set('RaffleDrawSlotPending', () => new RaffleDrawSlotPendingError());
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
@@ -65,7 +65,7 @@ const executeSaleStruct = new beet.BeetArgsStruct<
  * @property [_writable_] sellerTradeState
  * @property [_writable_] freeTradeState
  * @property [] auctioneerAuthority
- * @property [] ahAuctioneerPda
+ * @property [_writable_] ahAuctioneerPda
  * @property [] programAsSigner
  * @category Instructions
  * @category ExecuteSale
@@ -237,7 +237,7 @@ export function createExecuteSaleInstruction(
     },
     {
       pubkey: ahAuctioneerPda,
-      isWritable: false,
+      isWritable: true,
       isSigner: false,
     },
     {
@@ -1,7 +1,27 @@
 export * from './authorize';
 export * from './buy';
+export * from './buyMultiUnit';
+export * from './buyRaffleTickets';
 export * from './cancel';
+export * from './cancelMultiUnit';
+export * from './cancelRaffle';
+export * from './claimRafflePrize';
+export * from './closeBidHistory';
+export * from './commitRaffleDraw';
+export * from './createBidHistory';
 export * from './deposit';
+export * from './depositForfeit';
+export * from './drawRaffle';
+export * from './executeMultiUnitSale';
+export * from './executeRaffleSale';
 export * from './executeSale';
+export * from './executeSecondChanceSale';
+export * from './migrateListingConfig';
+export * from './reclaimForfeit';
+export * from './refundRaffleTickets';
+export * from './removeDefaultedMultiUnitBid';
 export * from './sell';
+export * from './sellMultiUnit';
+export * from './sellRaffle';
+export * from './sellWithOptions';
 export * from './withdraw';
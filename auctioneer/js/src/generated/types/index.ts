@@ -1,2 +1,9 @@
 export * from './Bid';
+export * from './BidGate';
+export * from './BidRecord';
+export * from './CancelPolicy';
 export * from './ListingConfigVersion';
+export * from './MultiUnitBid';
+export * from './RaffleEntry';
+export * from './SettlementMode';
+export * from './SoftClose';
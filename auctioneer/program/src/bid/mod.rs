@@ -11,7 +11,7 @@ use mpl_auction_house::{
     AuctionHouse,
 };
 
-use crate::{constants::*, sell::config::*, utils::*};
+use crate::{bid_history::record_bid, constants::*, sell::config::*, utils::*};
 
 /// Accounts for the [`private_bid_with_auctioneer` handler](fn.private_bid_with_auctioneer.html).
 #[derive(Accounts)]
@@ -126,13 +126,27 @@ pub fn auctioneer_buy<'info>(
     token_size: u64,
 ) -> Result<()> {
     assert_auction_active(&ctx.accounts.listing_config)?;
+    let mut remaining_accounts = ctx.remaining_accounts.iter();
+    assert_bid_gate(
+        &ctx.accounts.listing_config,
+        &mut remaining_accounts,
+        &ctx.accounts.wallet.key(),
+    )?;
     assert_higher_bid(&ctx.accounts.listing_config, buyer_price)?;
     assert_exceeds_reserve_price(&ctx.accounts.listing_config, buyer_price)?;
-    process_time_extension(&mut ctx.accounts.listing_config)?;
+    process_time_extension(&mut ctx.accounts.listing_config, buyer_price)?;
     ctx.accounts.listing_config.highest_bid.amount = buyer_price;
     ctx.accounts.listing_config.highest_bid.buyer_trade_state =
         ctx.accounts.buyer_trade_state.key();
 
+    record_bid(
+        remaining_accounts.as_slice(),
+        &ctx.accounts.listing_config.key(),
+        ctx.accounts.listing_config.has_bid_history,
+        &ctx.accounts.wallet.key(),
+        buyer_price,
+    )?;
+
     let cpi_program = ctx.accounts.auction_house_program.to_account_info();
     let cpi_accounts = AHBuy {
         wallet: ctx.accounts.wallet.to_account_info(),
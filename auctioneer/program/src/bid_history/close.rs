@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::{bid_history::*, constants::*, errors::*};
+
+/// Accounts for the [`close_bid_history` handler](auctioneer/fn.close_bid_history.html).
+#[derive(Accounts)]
+pub struct CloseBidHistory<'info> {
+    /// CHECK: Checked against the payer recorded in the bid history.
+    /// Wallet refunded for the history.
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+
+    /// CHECK: Checked against the listing recorded in the bid history.
+    /// The Listing Config whose bids were recorded.
+    pub listing_config: UncheckedAccount<'info>,
+
+    /// The bid history PDA of the listing.
+    #[account(
+        mut,
+        close=payer,
+        has_one=payer,
+        has_one=listing_config,
+        seeds=[BID_HISTORY.as_bytes(), listing_config.key().as_ref()],
+        bump=bid_history.bump,
+    )]
+    pub bid_history: Box<Account<'info, BidHistory>>,
+}
+
+/// Close the bid history once its listing has been sold or canceled.
+pub fn auctioneer_close_bid_history(ctx: Context<CloseBidHistory>) -> Result<()> {
+    let listing_config = &ctx.accounts.listing_config;
+    if listing_config.lamports() > 0 && !listing_config.data_is_empty() {
+        return err!(AuctioneerError::ListingStillOpen);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use mpl_auction_house::AuctionHouse;
+
+use crate::{bid_history::*, constants::*, errors::*, sell::config::*};
+
+/// Accounts for the [`create_bid_history` handler](auctioneer/fn.create_bid_history.html).
+#[derive(Accounts)]
+#[instruction(token_size: u64)]
+pub struct CreateBidHistory<'info> {
+    /// Seller of the listing, paying for the history and refunded when it is closed.
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    /// Auction House instance the listing was made on.
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// SPL token account of the listing.
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// The Listing Config whose bids are recorded.
+    #[account(
+        mut,
+        seeds=[
+            LISTING_CONFIG.as_bytes(),
+            seller.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_account.mint.as_ref(),
+            &token_size.to_le_bytes()
+        ],
+        bump=listing_config.bump,
+    )]
+    pub listing_config: Box<Account<'info, ListingConfig>>,
+
+    /// The bid history PDA of the listing.
+    #[account(
+        init,
+        payer=seller,
+        space=BID_HISTORY_SIZE,
+        seeds=[BID_HISTORY.as_bytes(), listing_config.key().as_ref()],
+        bump,
+    )]
+    pub bid_history: Box<Account<'info, BidHistory>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the bid history of a listing before its first bid, only recording bidders holding a forfeit deposit of at
+/// least `forfeit_deposit` lamports when it is not zero.
+pub fn auctioneer_create_bid_history(
+    ctx: Context<CreateBidHistory>,
+    _token_size: u64,
+    forfeit_deposit: u64,
+) -> Result<()> {
+    let listing_config = &mut ctx.accounts.listing_config;
+    if listing_config.highest_bid.amount > 0 {
+        return err!(AuctioneerError::BidHistoryAfterFirstBid);
+    }
+    listing_config.has_bid_history = true;
+
+    let bid_history = &mut ctx.accounts.bid_history;
+    bid_history.listing_config = ctx.accounts.listing_config.key();
+    bid_history.payer = ctx.accounts.seller.key();
+    bid_history.forfeit_deposit = forfeit_deposit;
+    bid_history.bump = *ctx
+        .bumps
+        .get("bid_history")
+        .ok_or(AuctioneerError::BumpSeedNotInHashMap)?;
+
+    Ok(())
+}
@@ -0,0 +1,118 @@
+use anchor_lang::{prelude::*, system_program};
+
+use crate::{constants::*, errors::*, sell::config::*};
+
+pub const FORFEIT_DEPOSIT_SIZE: usize = 8 + 32 + 32 + 8 + 1;
+
+/// Lamports a bidder puts at stake on a listing, handed to the seller if the bidder wins and
+/// defaults.
+#[account]
+pub struct ForfeitDeposit {
+    pub listing_config: Pubkey,
+    pub bidder: Pubkey,
+    pub amount: u64,
+    pub bump: u8,
+}
+
+/// Accounts for the [`deposit_forfeit` handler](auctioneer/fn.deposit_forfeit.html).
+#[derive(Accounts)]
+pub struct DepositForfeit<'info> {
+    /// Bidder putting the deposit at stake.
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+
+    /// The Listing Config the deposit is held for.
+    pub listing_config: Box<Account<'info, ListingConfig>>,
+
+    /// The forfeit deposit PDA of the bidder on the listing.
+    #[account(
+        init,
+        payer=bidder,
+        space=FORFEIT_DEPOSIT_SIZE,
+        seeds=[FORFEIT_DEPOSIT.as_bytes(), listing_config.key().as_ref(), bidder.key().as_ref()],
+        bump,
+    )]
+    pub forfeit_deposit: Box<Account<'info, ForfeitDeposit>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn auctioneer_deposit_forfeit(ctx: Context<DepositForfeit>, amount: u64) -> Result<()> {
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.bidder.to_account_info(),
+                to: ctx.accounts.forfeit_deposit.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let forfeit_deposit = &mut ctx.accounts.forfeit_deposit;
+    forfeit_deposit.listing_config = ctx.accounts.listing_config.key();
+    forfeit_deposit.bidder = ctx.accounts.bidder.key();
+    forfeit_deposit.amount = amount;
+    forfeit_deposit.bump = *ctx
+        .bumps
+        .get("forfeit_deposit")
+        .ok_or(AuctioneerError::BumpSeedNotInHashMap)?;
+
+    Ok(())
+}
+
+/// Accounts for the [`reclaim_forfeit` handler](auctioneer/fn.reclaim_forfeit.html).
+#[derive(Accounts)]
+pub struct ReclaimForfeit<'info> {
+    /// Bidder getting the deposit back.
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+
+    /// CHECK: Checked against the listing recorded in the forfeit deposit.
+    /// The Listing Config the deposit was held for.
+    pub listing_config: UncheckedAccount<'info>,
+
+    /// The forfeit deposit PDA of the bidder on the listing.
+    #[account(
+        mut,
+        close=bidder,
+        has_one=bidder,
+        has_one=listing_config,
+        seeds=[FORFEIT_DEPOSIT.as_bytes(), listing_config.key().as_ref(), bidder.key().as_ref()],
+        bump=forfeit_deposit.bump,
+    )]
+    pub forfeit_deposit: Box<Account<'info, ForfeitDeposit>>,
+}
+
+/// Return a forfeit deposit that was not forfeited once its listing has been sold or canceled.
+pub fn auctioneer_reclaim_forfeit(ctx: Context<ReclaimForfeit>) -> Result<()> {
+    let listing_config = &ctx.accounts.listing_config;
+    if listing_config.lamports() > 0 && !listing_config.data_is_empty() {
+        return err!(AuctioneerError::ListingStillOpen);
+    }
+
+    Ok(())
+}
+
+/// Move the lamports of the `forfeit_deposit` account of `bidder` to `seller` and clear it.
+pub fn forfeit<'info>(
+    forfeit_deposit_info: &AccountInfo<'info>,
+    seller: &AccountInfo<'info>,
+    listing_config: &Pubkey,
+    bidder: &Pubkey,
+) -> Result<()> {
+    let forfeit_deposit: Account<ForfeitDeposit> = Account::try_from(forfeit_deposit_info)
+        .map_err(|_| AuctioneerError::MissingForfeitDeposit)?;
+    if forfeit_deposit.listing_config != *listing_config || forfeit_deposit.bidder != *bidder {
+        return err!(AuctioneerError::MissingForfeitDeposit);
+    }
+
+    **seller.lamports.borrow_mut() = seller
+        .lamports()
+        .checked_add(forfeit_deposit_info.lamports())
+        .unwrap();
+    **forfeit_deposit_info.lamports.borrow_mut() = 0;
+    forfeit_deposit_info.data.borrow_mut().fill(0);
+
+    Ok(())
+}
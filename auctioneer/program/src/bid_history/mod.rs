@@ -0,0 +1,111 @@
+//! Optional bounded history of the bids placed on a listing, kept so UIs can render the bidding
+//! ladder and the runner-up bids remain known after the auction ends.
+
+pub mod close;
+pub mod create;
+pub mod forfeit;
+
+pub use close::*;
+pub use create::*;
+pub use forfeit::*;
+
+use anchor_lang::prelude::*;
+use solana_program::clock::UnixTimestamp;
+
+use crate::{errors::*, pda::find_bid_history_address};
+
+pub const MAX_BID_HISTORY_LEN: usize = 32;
+pub const BID_RECORD_SIZE: usize = 32 + 8 + 8;
+pub const BID_HISTORY_SIZE: usize =
+    8 + 32 + 32 + 8 + 1 + 1 + 1 + MAX_BID_HISTORY_LEN * BID_RECORD_SIZE;
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct BidRecord {
+    pub bidder: Pubkey,
+    pub amount: u64,
+    pub timestamp: UnixTimestamp,
+}
+
+#[account]
+pub struct BidHistory {
+    pub listing_config: Pubkey,
+    /// Wallet refunded when the history is closed.
+    pub payer: Pubkey,
+    /// Lamports a bidder must hold in a forfeit deposit for their bids to be recorded.
+    pub forfeit_deposit: u64,
+    pub bump: u8,
+    /// Index the next bid is written at.
+    pub head: u8,
+    pub len: u8,
+    pub records: [BidRecord; MAX_BID_HISTORY_LEN],
+}
+
+impl BidHistory {
+    /// Append a bid, overwriting the oldest one once the buffer is full.
+    pub fn push(&mut self, bidder: Pubkey, amount: u64, timestamp: UnixTimestamp) {
+        self.records[self.head as usize] = BidRecord {
+            bidder,
+            amount,
+            timestamp,
+        };
+        self.head = ((self.head as usize + 1) % MAX_BID_HISTORY_LEN) as u8;
+        if (self.len as usize) < MAX_BID_HISTORY_LEN {
+            self.len += 1;
+        }
+    }
+
+    /// The most recent bid, if any.
+    pub fn newest(&self) -> Option<&BidRecord> {
+        self.newest_first().next()
+    }
+
+    /// The retained bids, newest first.
+    pub fn newest_first(&self) -> impl Iterator<Item = &BidRecord> + '_ {
+        (1..=self.len as usize).map(move |offset| {
+            &self.records[(self.head as usize + MAX_BID_HISTORY_LEN - offset) % MAX_BID_HISTORY_LEN]
+        })
+    }
+}
+
+/// Append a bid to the `bid_history` account of a listing with a history, which must be the first
+/// remaining account, doing nothing when the listing has no history. A history requiring a
+/// forfeit deposit expects the bidder's deposit as the second remaining account.
+pub fn record_bid<'info>(
+    remaining_accounts: &[AccountInfo<'info>],
+    listing_config: &Pubkey,
+    has_bid_history: bool,
+    bidder: &Pubkey,
+    amount: u64,
+) -> Result<()> {
+    if !has_bid_history {
+        return Ok(());
+    }
+    let bid_history_info = remaining_accounts
+        .first()
+        .filter(|account| account.key() == find_bid_history_address(listing_config).0)
+        .ok_or(AuctioneerError::MissingBidHistory)?;
+
+    let mut bid_history: Account<BidHistory> = Account::try_from(bid_history_info)?;
+    if bid_history.listing_config != *listing_config {
+        return err!(AuctioneerError::BidHistoryMismatch);
+    }
+    if !bid_history_info.is_writable {
+        return Err(ErrorCode::AccountNotMutable.into());
+    }
+    if bid_history.forfeit_deposit > 0 {
+        let forfeit_deposit_info = remaining_accounts
+            .get(1)
+            .ok_or(AuctioneerError::MissingForfeitDeposit)?;
+        let forfeit_deposit: Account<ForfeitDeposit> = Account::try_from(forfeit_deposit_info)
+            .map_err(|_| AuctioneerError::MissingForfeitDeposit)?;
+        if forfeit_deposit.listing_config != *listing_config || forfeit_deposit.bidder != *bidder {
+            return err!(AuctioneerError::MissingForfeitDeposit);
+        }
+        if forfeit_deposit.amount < bid_history.forfeit_deposit {
+            return err!(AuctioneerError::ForfeitDepositTooLow);
+        }
+    }
+
+    bid_history.push(*bidder, amount, Clock::get()?.unix_timestamp);
+    bid_history.exit(&crate::id())
+}
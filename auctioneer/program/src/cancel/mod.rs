@@ -1,10 +1,11 @@
-use anchor_lang::{prelude::*, AnchorDeserialize, InstructionData};
+use anchor_lang::{prelude::*, system_program, AnchorDeserialize, InstructionData};
 use anchor_spl::token::{Mint, Token, TokenAccount};
 
 use mpl_auction_house::{
     self,
     constants::{AUCTIONEER, FEE_PAYER, PREFIX},
     cpi::accounts::AuctioneerCancel as AHCancel,
+    pda::find_trade_state_address,
     program::AuctionHouse as AuctionHouseProgram,
     AuctionHouse,
 };
@@ -91,6 +92,7 @@ pub struct AuctioneerCancel<'info> {
 }
 
 // Cancel a bid or ask by revoking the token delegate, transferring all lamports from the trade state account to the fee payer, and setting the trade state account data to zero so it can be garbage collected.
+// When the seller cancels a listing with a `CancelPolicy::Penalty` that has bids, the remaining accounts are the wallet of the highest bidder and the system program.
 
 pub fn auctioneer_cancel<'info>(
     ctx: Context<'_, '_, '_, 'info, AuctioneerCancel<'info>>,
@@ -98,11 +100,20 @@ pub fn auctioneer_cancel<'info>(
     buyer_price: u64,
     token_size: u64,
 ) -> Result<()> {
-    if !ctx.accounts.listing_config.allow_high_bid_cancel
-        && (ctx.accounts.trade_state.key()
-            == ctx.accounts.listing_config.highest_bid.buyer_trade_state)
-    {
-        return err!(AuctioneerError::CannotCancelHighestBid);
+    if ctx.accounts.trade_state.key() == ctx.accounts.listing_config.highest_bid.buyer_trade_state {
+        if !ctx.accounts.listing_config.allow_high_bid_cancel {
+            return err!(AuctioneerError::CannotCancelHighestBid);
+        }
+
+        // The canceled bid no longer counts as the highest bid of the listing.
+        ctx.accounts.listing_config.highest_bid.amount = 0;
+        ctx.accounts.listing_config.highest_bid.buyer_trade_state = Pubkey::default();
+    }
+
+    let is_seller = ctx.accounts.token_account.owner == ctx.accounts.wallet.key()
+        && ctx.accounts.wallet.is_signer;
+    if is_seller {
+        enforce_cancel_policy(ctx.accounts, ctx.remaining_accounts, token_size)?;
     }
 
     let cpi_program = ctx.accounts.auction_house_program.to_account_info();
@@ -155,9 +166,7 @@ pub fn auctioneer_cancel<'info>(
     invoke_signed(&ix, &cpi_accounts.to_account_infos(), &[&auctioneer_seeds])?;
 
     // Close the Listing Config account if the seller is canceling their listing.
-    if ctx.accounts.token_account.owner == ctx.accounts.wallet.key()
-        && ctx.accounts.wallet.is_signer
-    {
+    if is_seller {
         let listing_config = &ctx.accounts.listing_config.to_account_info();
         let seller = &ctx.accounts.seller.to_account_info();
 
@@ -174,3 +183,61 @@ pub fn auctioneer_cancel<'info>(
 
     Ok(())
 }
+
+/// Apply the cancel policy of a listing with bids to its seller, paying the penalty to the highest
+/// bidder when there is one.
+fn enforce_cancel_policy<'info>(
+    accounts: &AuctioneerCancel<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    token_size: u64,
+) -> Result<()> {
+    let listing_config = &accounts.listing_config;
+    let highest_bid = listing_config.highest_bid.amount;
+    if highest_bid == 0 {
+        return Ok(());
+    }
+
+    match listing_config.cancel_policy {
+        None => Ok(()),
+        Some(CancelPolicy::BeforeFirstBid) => err!(AuctioneerError::CancelAfterFirstBid),
+        Some(CancelPolicy::UntilReserveMet) => {
+            if highest_bid >= listing_config.reserve_price {
+                return err!(AuctioneerError::CancelAfterReserveMet);
+            }
+            Ok(())
+        }
+        Some(CancelPolicy::Penalty(penalty)) => {
+            let highest_bidder = remaining_accounts
+                .first()
+                .ok_or(AuctioneerError::InvalidHighestBidder)?;
+            let (buyer_trade_state, _) = find_trade_state_address(
+                &highest_bidder.key(),
+                &accounts.auction_house.key(),
+                &accounts.token_account.key(),
+                &accounts.auction_house.treasury_mint,
+                &accounts.token_account.mint,
+                highest_bid,
+                token_size,
+            );
+            if buyer_trade_state != listing_config.highest_bid.buyer_trade_state {
+                return err!(AuctioneerError::InvalidHighestBidder);
+            }
+
+            let system_program: Program<System> = Program::try_from(
+                remaining_accounts
+                    .get(1)
+                    .ok_or(anchor_lang::error::ErrorCode::AccountNotEnoughKeys)?,
+            )?;
+            system_program::transfer(
+                CpiContext::new(
+                    system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: accounts.wallet.to_account_info(),
+                        to: highest_bidder.clone(),
+                    },
+                ),
+                penalty,
+            )
+        }
+    }
+}
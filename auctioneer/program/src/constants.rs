@@ -1,2 +1,10 @@
 pub const LISTING_CONFIG: &str = "listing_config";
+pub const MULTI_UNIT_LISTING: &str = "multi_unit_listing";
+pub const BID_HISTORY: &str = "bid_history";
+pub const FORFEIT_DEPOSIT: &str = "forfeit_deposit";
 pub const AUCTIONEER_BUYER_PRICE: u64 = u64::MAX;
+/// Seconds after the end of an auction before the seller may settle with a lower bid.
+pub const SECOND_CHANCE_GRACE_PERIOD: i64 = 60 * 60 * 24;
+pub const RAFFLE: &str = "raffle";
+/// Seconds after the end of a raffle the seller has to draw it before buyers may take refunds.
+pub const RAFFLE_REVEAL_PERIOD: i64 = 60 * 60 * 24;
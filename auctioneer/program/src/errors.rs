@@ -41,4 +41,168 @@ pub enum AuctioneerError {
     // 6009
     #[msg("The highest bidder is not allowed to cancel")]
     CannotCancelHighestBid,
+
+    // 6010
+    #[msg("A multi-unit auction needs between one and the maximum number of bids as winners")]
+    InvalidMaxWinners,
+
+    // 6011
+    #[msg("The bid quantity must fit in the listing and divide the bid price")]
+    InvalidBidQuantity,
+
+    // 6012
+    #[msg("The bid has already been placed")]
+    BidAlreadyPlaced,
+
+    // 6013
+    #[msg("The bid is not one of the winning bids")]
+    NotWinningBid,
+
+    // 6014
+    #[msg("The winning bid has already been settled")]
+    BidAlreadySettled,
+
+    // 6015
+    #[msg("The listing has bids that have not been settled")]
+    ListingHasBids,
+
+    // 6016
+    #[msg("The bid history belongs to another listing")]
+    BidHistoryMismatch,
+
+    // 6017
+    #[msg("The listing has not been sold or canceled yet")]
+    ListingStillOpen,
+
+    // 6018
+    #[msg("The bidder has no forfeit deposit for this listing")]
+    MissingForfeitDeposit,
+
+    // 6019
+    #[msg("The forfeit deposit is lower than the bid history requires")]
+    ForfeitDepositTooLow,
+
+    // 6020
+    #[msg("The grace period after the end of the auction has not passed yet")]
+    SecondChanceNotOpen,
+
+    // 6021
+    #[msg("The bid is not recorded in the bid history")]
+    BidNotInHistory,
+
+    // 6022
+    #[msg("The bid history does not record the highest bid")]
+    IncompleteBidHistory,
+
+    // 6023
+    #[msg("A higher bid is still funded")]
+    HigherBidStillFunded,
+
+    // 6024
+    #[msg("The account is not the escrow of the higher bidder")]
+    InvalidEscrowAccount,
+
+    // 6025
+    #[msg("The bidder does not hold the tokens the listing requires")]
+    BidGateNotMet,
+
+    // 6026
+    #[msg("The hard end time of the soft close must not be before the end time")]
+    InvalidSoftClose,
+
+    // 6027
+    #[msg("Ticket quantity must be positive and within the tickets left")]
+    InvalidTicketQuantity,
+
+    // 6028
+    #[msg("The raffle has no room for another buyer")]
+    RaffleFull,
+
+    // 6029
+    #[msg("The seed does not match the raffle commitment")]
+    InvalidRaffleSeed,
+
+    // 6030
+    #[msg("No raffle tickets")]
+    NoRaffleTickets,
+
+    // 6031
+    #[msg("The account is not the SlotHashes sysvar")]
+    InvalidSlotHashes,
+
+    // 6032
+    #[msg("Numerical overflow")]
+    NumericalOverflow,
+
+    // 6033
+    #[msg("Raffles can only be listed on native SOL Auction Houses")]
+    RaffleRequiresNativeMint,
+
+    // 6034
+    #[msg("The raffle has already been drawn")]
+    RaffleAlreadyDrawn,
+
+    // 6035
+    #[msg("The raffle can no longer be drawn")]
+    RevealPeriodOver,
+
+    // 6036
+    #[msg("The wallet is not the raffle winner")]
+    NotRaffleWinner,
+
+    // 6037
+    #[msg("The raffle tickets cannot be refunded yet")]
+    RaffleNotRefundable,
+
+    // 6038
+    #[msg("The raffle prize has already been claimed")]
+    RaffleAlreadyClaimed,
+
+    // 6039
+    #[msg("The raffle prize has not been claimed")]
+    RaffleNotClaimed,
+
+    // 6040
+    #[msg("The raffle still holds ticket funds")]
+    RaffleHasTickets,
+
+    // 6041
+    #[msg("The listing cannot be canceled once it has bids")]
+    CancelAfterFirstBid,
+
+    // 6042
+    #[msg("The listing cannot be canceled once the reserve price is met")]
+    CancelAfterReserveMet,
+
+    // 6043
+    #[msg("The account is not the wallet of the highest bidder")]
+    InvalidHighestBidder,
+
+    // 6044
+    #[msg("The multi-unit bid book is full and the bid is not above its lowest losing bid")]
+    MultiUnitBookFull,
+
+    // 6045
+    #[msg("The winning bid is still funded")]
+    WinningBidStillFunded,
+
+    // 6046
+    #[msg("The bid history of the listing is missing")]
+    MissingBidHistory,
+
+    // 6047
+    #[msg("The bid history must be created before the first bid")]
+    BidHistoryAfterFirstBid,
+
+    // 6048
+    #[msg("The raffle draw slot has not been committed")]
+    RaffleDrawNotCommitted,
+
+    // 6049
+    #[msg("The raffle draw slot has already been committed")]
+    RaffleDrawAlreadyCommitted,
+
+    // 6050
+    #[msg("The hash of the raffle draw slot is not available yet")]
+    RaffleDrawSlotPending,
 }
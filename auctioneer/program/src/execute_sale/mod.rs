@@ -1,3 +1,7 @@
+pub mod second_chance;
+
+pub use second_chance::*;
+
 use anchor_lang::{prelude::*, AnchorDeserialize, InstructionData};
 use anchor_spl::{associated_token::AssociatedToken, token::Token};
 
@@ -120,8 +124,9 @@ pub struct AuctioneerExecuteSale<'info> {
     pub auctioneer_authority: UncheckedAccount<'info>,
 
     /// CHECK: Not dangerous. Account seeds checked in constraint.
-    /// The auctioneer PDA owned by Auction House storing scopes.
+    /// The auctioneer PDA owned by Auction House storing scopes and counting sales.
     #[account(
+        mut,
         seeds = [
             AUCTIONEER.as_bytes(),
             auction_house.key().as_ref(),
@@ -158,34 +163,56 @@ pub fn auctioneer_execute_sale<'info>(
         ctx.accounts.buyer_trade_state.key(),
     )?;
 
-    let cpi_program = ctx.accounts.auction_house_program.to_account_info();
+    settle(
+        ctx.accounts,
+        ctx.remaining_accounts,
+        escrow_payment_bump,
+        free_trade_state_bump,
+        program_as_signer_bump,
+        auctioneer_authority_bump,
+        buyer_price,
+        token_size,
+    )
+}
+
+/// Execute the sale through Auction House and close the Listing Config, passing
+/// `remaining_accounts` on to Auction House.
+#[allow(clippy::too_many_arguments)]
+fn settle<'info>(
+    accounts: &AuctioneerExecuteSale<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    escrow_payment_bump: u8,
+    free_trade_state_bump: u8,
+    program_as_signer_bump: u8,
+    auctioneer_authority_bump: u8,
+    buyer_price: u64,
+    token_size: u64,
+) -> Result<()> {
+    let cpi_program = accounts.auction_house_program.to_account_info();
     let cpi_accounts = AHExecuteSale {
-        buyer: ctx.accounts.buyer.to_account_info(),
-        seller: ctx.accounts.seller.to_account_info(),
-        token_account: ctx.accounts.token_account.to_account_info(),
-        token_mint: ctx.accounts.token_mint.to_account_info(),
-        metadata: ctx.accounts.metadata.to_account_info(),
-        treasury_mint: ctx.accounts.treasury_mint.to_account_info(),
-        escrow_payment_account: ctx.accounts.escrow_payment_account.to_account_info(),
-        seller_payment_receipt_account: ctx
-            .accounts
-            .seller_payment_receipt_account
-            .to_account_info(),
-        buyer_receipt_token_account: ctx.accounts.buyer_receipt_token_account.to_account_info(),
-        auction_house: ctx.accounts.auction_house.to_account_info(),
-        auction_house_fee_account: ctx.accounts.auction_house_fee_account.to_account_info(),
-        auction_house_treasury: ctx.accounts.auction_house_treasury.to_account_info(),
-        buyer_trade_state: ctx.accounts.buyer_trade_state.to_account_info(),
-        seller_trade_state: ctx.accounts.seller_trade_state.to_account_info(),
-        free_trade_state: ctx.accounts.free_trade_state.to_account_info(),
-        authority: ctx.accounts.authority.to_account_info(),
-        auctioneer_authority: ctx.accounts.auctioneer_authority.to_account_info(),
-        ah_auctioneer_pda: ctx.accounts.ah_auctioneer_pda.to_account_info(),
-        token_program: ctx.accounts.token_program.to_account_info(),
-        system_program: ctx.accounts.system_program.to_account_info(),
-        ata_program: ctx.accounts.ata_program.to_account_info(),
-        program_as_signer: ctx.accounts.program_as_signer.to_account_info(),
-        rent: ctx.accounts.rent.to_account_info(),
+        buyer: accounts.buyer.to_account_info(),
+        seller: accounts.seller.to_account_info(),
+        token_account: accounts.token_account.to_account_info(),
+        token_mint: accounts.token_mint.to_account_info(),
+        metadata: accounts.metadata.to_account_info(),
+        treasury_mint: accounts.treasury_mint.to_account_info(),
+        escrow_payment_account: accounts.escrow_payment_account.to_account_info(),
+        seller_payment_receipt_account: accounts.seller_payment_receipt_account.to_account_info(),
+        buyer_receipt_token_account: accounts.buyer_receipt_token_account.to_account_info(),
+        auction_house: accounts.auction_house.to_account_info(),
+        auction_house_fee_account: accounts.auction_house_fee_account.to_account_info(),
+        auction_house_treasury: accounts.auction_house_treasury.to_account_info(),
+        buyer_trade_state: accounts.buyer_trade_state.to_account_info(),
+        seller_trade_state: accounts.seller_trade_state.to_account_info(),
+        free_trade_state: accounts.free_trade_state.to_account_info(),
+        authority: accounts.authority.to_account_info(),
+        auctioneer_authority: accounts.auctioneer_authority.to_account_info(),
+        ah_auctioneer_pda: accounts.ah_auctioneer_pda.to_account_info(),
+        token_program: accounts.token_program.to_account_info(),
+        system_program: accounts.system_program.to_account_info(),
+        ata_program: accounts.ata_program.to_account_info(),
+        program_as_signer: accounts.program_as_signer.to_account_info(),
+        rent: accounts.rent.to_account_info(),
     };
 
     let execute_sale_data = mpl_auction_house::instruction::AuctioneerExecuteSale {
@@ -202,17 +229,17 @@ pub fn auctioneer_execute_sale<'info>(
         .zip(cpi_accounts.to_account_infos())
         .map(|mut pair| {
             pair.0.is_signer = pair.1.is_signer;
-            if pair.0.pubkey == ctx.accounts.auctioneer_authority.key() {
+            if pair.0.pubkey == accounts.auctioneer_authority.key() {
                 pair.0.is_signer = true;
             }
             pair.0
         })
         .collect();
 
-    cpi_account_metas.append(&mut ctx.remaining_accounts.to_vec().to_account_metas(None));
+    cpi_account_metas.append(&mut remaining_accounts.to_vec().to_account_metas(None));
 
     let mut cpi_account_infos: Vec<AccountInfo> = cpi_accounts.to_account_infos();
-    cpi_account_infos.append(&mut ctx.remaining_accounts.to_vec());
+    cpi_account_infos.append(&mut remaining_accounts.to_vec());
 
     let ix = solana_program::instruction::Instruction {
         program_id: cpi_program.key(),
@@ -220,9 +247,9 @@ pub fn auctioneer_execute_sale<'info>(
         data: execute_sale_data.data(),
     };
 
-    let auction_house = &ctx.accounts.auction_house;
+    let auction_house = &accounts.auction_house;
     let ah_key = auction_house.key();
-    let auctioneer_authority = &ctx.accounts.auctioneer_authority;
+    let auctioneer_authority = &accounts.auctioneer_authority;
     let _aa_key = auctioneer_authority.key();
 
     let auctioneer_seeds = [
@@ -234,8 +261,8 @@ pub fn auctioneer_execute_sale<'info>(
     invoke_signed(&ix, &cpi_account_infos, &[&auctioneer_seeds])?;
 
     // Close the Listing Config account.
-    let listing_config = &ctx.accounts.listing_config.to_account_info();
-    let seller = &ctx.accounts.seller.to_account_info();
+    let listing_config = &accounts.listing_config.to_account_info();
+    let seller = &accounts.seller.to_account_info();
 
     let listing_config_lamports = listing_config.lamports();
     **seller.lamports.borrow_mut() = seller
@@ -0,0 +1,116 @@
+//! Settle a finished auction against a lower bid when every higher bid has defaulted.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{spl_token, TokenAccount};
+
+use mpl_auction_house::pda::find_escrow_payment_address;
+
+use crate::{
+    bid_history::*,
+    constants::*,
+    errors::*,
+    execute_sale::{settle, AuctioneerExecuteSale},
+};
+
+/// Execute the sale against the bid of `buyer_price` once `SECOND_CHANCE_GRACE_PERIOD` has passed
+/// since the end of the auction.
+///
+/// The remaining accounts start with the bid history of the listing, followed for every higher
+/// bid in the history, newest first, by the escrow payment account of its bidder. Each of these
+/// escrows must hold less than the bid it backed. When the history requires forfeit deposits, the
+/// escrow of the winning bid is followed by the forfeit deposit of the winner, which goes to the
+/// seller; lower bidders were outbid rather than defaulting and keep theirs. Any further accounts
+/// are passed on to Auction House.
+pub fn auctioneer_execute_second_chance_sale<'info>(
+    ctx: Context<'_, '_, '_, 'info, AuctioneerExecuteSale<'info>>,
+    escrow_payment_bump: u8,
+    free_trade_state_bump: u8,
+    program_as_signer_bump: u8,
+    auctioneer_authority_bump: u8,
+    buyer_price: u64,
+    token_size: u64,
+) -> Result<()> {
+    let listing_config = &ctx.accounts.listing_config;
+    if Clock::get()?.unix_timestamp < listing_config.end_time + SECOND_CHANCE_GRACE_PERIOD {
+        return err!(AuctioneerError::SecondChanceNotOpen);
+    }
+
+    let remaining_accounts = ctx.remaining_accounts;
+    let bid_history_info = remaining_accounts
+        .first()
+        .ok_or(AuctioneerError::BidNotInHistory)?;
+    let bid_history: Account<BidHistory> = Account::try_from(bid_history_info)?;
+    if bid_history.listing_config != listing_config.key() {
+        return err!(AuctioneerError::BidHistoryMismatch);
+    }
+    // The highest bid is cleared when it is canceled, leaving its record as the newest one.
+    let highest_bid = listing_config.highest_bid.amount;
+    if highest_bid > 0 && bid_history.newest().map(|record| record.amount) != Some(highest_bid) {
+        return err!(AuctioneerError::IncompleteBidHistory);
+    }
+
+    let buyer = ctx.accounts.buyer.key();
+    if !bid_history
+        .newest_first()
+        .any(|record| record.bidder == buyer && record.amount == buyer_price)
+    {
+        return err!(AuctioneerError::BidNotInHistory);
+    }
+
+    let auction_house = ctx.accounts.auction_house.key();
+    let is_native = ctx.accounts.auction_house.treasury_mint == spl_token::native_mint::id();
+    let seller = ctx.accounts.seller.to_account_info();
+    let mut accounts = remaining_accounts[1..].iter();
+
+    for (index, record) in bid_history
+        .newest_first()
+        .filter(|record| record.amount > buyer_price)
+        .enumerate()
+    {
+        let escrow = accounts
+            .next()
+            .ok_or(AuctioneerError::InvalidEscrowAccount)?;
+        if escrow.key() != find_escrow_payment_address(&auction_house, &record.bidder).0 {
+            return err!(AuctioneerError::InvalidEscrowAccount);
+        }
+        if escrow_balance(escrow, is_native)? >= record.amount {
+            return err!(AuctioneerError::HigherBidStillFunded);
+        }
+
+        // Only the winner, the newest record while the highest bid stands, defaulted on the sale.
+        let is_winner = index == 0 && highest_bid > 0;
+        if is_winner && bid_history.forfeit_deposit > 0 {
+            let forfeit_deposit = accounts
+                .next()
+                .ok_or(AuctioneerError::MissingForfeitDeposit)?;
+            forfeit(
+                forfeit_deposit,
+                &seller,
+                &listing_config.key(),
+                &record.bidder,
+            )?;
+        }
+    }
+
+    settle(
+        ctx.accounts,
+        accounts.as_slice(),
+        escrow_payment_bump,
+        free_trade_state_bump,
+        program_as_signer_bump,
+        auctioneer_authority_bump,
+        buyer_price,
+        token_size,
+    )
+}
+
+/// Funds held by an escrow payment account of the Auction House treasury mint.
+pub(crate) fn escrow_balance(escrow: &AccountInfo, is_native: bool) -> Result<u64> {
+    if is_native {
+        Ok(escrow.lamports())
+    } else if escrow.data_is_empty() {
+        Ok(0)
+    } else {
+        Ok(TokenAccount::try_deserialize(&mut escrow.data.borrow().as_ref())?.amount)
+    }
+}
@@ -1,17 +1,34 @@
 #![allow(clippy::result_large_err)]
 pub mod authorize;
 pub mod bid;
+pub mod bid_history;
 pub mod cancel;
 pub mod constants;
 pub mod deposit;
 pub mod errors;
 pub mod execute_sale;
+pub mod multi_unit;
 pub mod pda;
+pub mod raffle;
 pub mod sell;
 pub mod utils;
 pub mod withdraw;
 
-use crate::{authorize::*, bid::*, cancel::*, deposit::*, execute_sale::*, sell::*, withdraw::*};
+use crate::{
+    authorize::*,
+    bid::*,
+    bid_history::*,
+    cancel::*,
+    deposit::*,
+    execute_sale::*,
+    multi_unit::*,
+    raffle::*,
+    sell::{
+        config::{BidGate, CancelPolicy, SoftClose},
+        *,
+    },
+    withdraw::*,
+};
 
 use anchor_lang::prelude::*;
 
@@ -112,9 +129,61 @@ pub mod auctioneer {
             time_ext_period,
             time_ext_delta,
             allow_high_bid_cancel,
+            None,
+            None,
+            None,
         )
     }
 
+    /// Same as `sell`, additionally restricting bids to holders of the tokens described by
+    /// `bid_gate` when it is set. `soft_close` limits and scales the time extensions of late bids,
+    /// and `cancel_policy` limits when the seller may cancel once there are bids.
+    pub fn sell_with_options<'info>(
+        ctx: Context<'_, '_, '_, 'info, AuctioneerSell<'info>>,
+        trade_state_bump: u8,
+        free_trade_state_bump: u8,
+        program_as_signer_bump: u8,
+        auctioneer_authority_bump: u8,
+        token_size: u64,
+        start_time: UnixTimestamp,
+        end_time: UnixTimestamp,
+        reserve_price: Option<u64>,
+        min_bid_increment: Option<u64>,
+        time_ext_period: Option<u32>,
+        time_ext_delta: Option<u32>,
+        allow_high_bid_cancel: Option<bool>,
+        bid_gate: Option<BidGate>,
+        soft_close: Option<SoftClose>,
+        cancel_policy: Option<CancelPolicy>,
+    ) -> Result<()> {
+        auctioneer_sell(
+            ctx,
+            trade_state_bump,
+            free_trade_state_bump,
+            program_as_signer_bump,
+            auctioneer_authority_bump,
+            token_size,
+            start_time,
+            end_time,
+            reserve_price,
+            min_bid_increment,
+            time_ext_period,
+            time_ext_delta,
+            allow_high_bid_cancel,
+            bid_gate,
+            soft_close,
+            cancel_policy,
+        )
+    }
+
+    /// Rewrite a Listing Config created before `ListingConfigVersion::V1` with the current
+    /// layout, so it can be bid on and settled again.
+    pub fn migrate_listing_config<'info>(
+        ctx: Context<'_, '_, '_, 'info, MigrateListingConfig<'info>>,
+    ) -> Result<()> {
+        auctioneer_migrate_listing_config(ctx)
+    }
+
     /// Create a private buy bid by creating a `buyer_trade_state` account and an `escrow_payment` account and funding the escrow with the necessary SOL or SPL token amount.
     pub fn buy<'info>(
         ctx: Context<'_, '_, '_, 'info, AuctioneerBuy<'info>>,
@@ -133,4 +202,277 @@ pub mod auctioneer {
             token_size,
         )
     }
+
+    /// Create the bid history of a listing before its first bid. Every later `buy` must pass the
+    /// history as its first remaining account after any bid gate accounts. Bids are only
+    /// accepted for bidders holding a forfeit deposit of at least `forfeit_deposit` lamports when
+    /// it is not zero.
+    pub fn create_bid_history<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateBidHistory<'info>>,
+        token_size: u64,
+        forfeit_deposit: u64,
+    ) -> Result<()> {
+        auctioneer_create_bid_history(ctx, token_size, forfeit_deposit)
+    }
+
+    /// Close the bid history of a listing that has been sold or canceled, refunding its payer.
+    pub fn close_bid_history<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseBidHistory<'info>>,
+    ) -> Result<()> {
+        auctioneer_close_bid_history(ctx)
+    }
+
+    /// Put `amount` lamports at stake on a listing, forfeited to the seller if the bidder wins and
+    /// defaults.
+    pub fn deposit_forfeit<'info>(
+        ctx: Context<'_, '_, '_, 'info, DepositForfeit<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        auctioneer_deposit_forfeit(ctx, amount)
+    }
+
+    /// Return a forfeit deposit once its listing has been sold or canceled.
+    pub fn reclaim_forfeit<'info>(
+        ctx: Context<'_, '_, '_, 'info, ReclaimForfeit<'info>>,
+    ) -> Result<()> {
+        auctioneer_reclaim_forfeit(ctx)
+    }
+
+    /// Execute sale against a lower bid recorded in the bid history once the grace period after
+    /// the auction has passed and every higher bid has defaulted.
+    #[inline(never)]
+    pub fn execute_second_chance_sale<'info>(
+        ctx: Context<'_, '_, '_, 'info, AuctioneerExecuteSale<'info>>,
+        escrow_payment_bump: u8,
+        free_trade_state_bump: u8,
+        program_as_signer_bump: u8,
+        auctioneer_authority_bump: u8,
+        buyer_price: u64,
+        token_size: u64,
+    ) -> Result<()> {
+        auctioneer_execute_second_chance_sale(
+            ctx,
+            escrow_payment_bump,
+            free_trade_state_bump,
+            program_as_signer_bump,
+            auctioneer_authority_bump,
+            buyer_price,
+            token_size,
+        )
+    }
+
+    /// List `token_size` units of a semi-fungible token in an auction won by the top
+    /// `max_winners` bids, settled at a uniform price or at each winning bid. Time extensions
+    /// follow `soft_close` as they do for single-unit listings.
+    pub fn sell_multi_unit<'info>(
+        ctx: Context<'_, '_, '_, 'info, AuctioneerSellMultiUnit<'info>>,
+        trade_state_bump: u8,
+        free_trade_state_bump: u8,
+        program_as_signer_bump: u8,
+        auctioneer_authority_bump: u8,
+        token_size: u64,
+        start_time: UnixTimestamp,
+        end_time: UnixTimestamp,
+        reserve_price: Option<u64>,
+        time_ext_period: Option<u32>,
+        time_ext_delta: Option<u32>,
+        max_winners: u8,
+        settlement: SettlementMode,
+        soft_close: Option<SoftClose>,
+    ) -> Result<()> {
+        auctioneer_sell_multi_unit(
+            ctx,
+            trade_state_bump,
+            free_trade_state_bump,
+            program_as_signer_bump,
+            auctioneer_authority_bump,
+            token_size,
+            start_time,
+            end_time,
+            reserve_price,
+            time_ext_period,
+            time_ext_delta,
+            max_winners,
+            settlement,
+            soft_close,
+        )
+    }
+
+    /// Bid `buyer_price` in total for `token_size` units of a multi-unit listing of
+    /// `listing_token_size` units. `reserve_price` applies to the price per unit.
+    pub fn buy_multi_unit<'info>(
+        ctx: Context<'_, '_, '_, 'info, AuctioneerBuyMultiUnit<'info>>,
+        trade_state_bump: u8,
+        escrow_payment_bump: u8,
+        auctioneer_authority_bump: u8,
+        buyer_price: u64,
+        token_size: u64,
+        _listing_token_size: u64,
+    ) -> Result<()> {
+        auctioneer_buy_multi_unit(
+            ctx,
+            trade_state_bump,
+            escrow_payment_bump,
+            auctioneer_authority_bump,
+            buyer_price,
+            token_size,
+        )
+    }
+
+    /// Cancel a losing bid of a multi-unit auction, or the listing once its winners are settled or
+    /// removed as defaulted.
+    pub fn cancel_multi_unit<'info>(
+        ctx: Context<'_, '_, '_, 'info, AuctioneerCancelMultiUnit<'info>>,
+        auctioneer_authority_bump: u8,
+        buyer_price: u64,
+        token_size: u64,
+        _listing_token_size: u64,
+    ) -> Result<()> {
+        auctioneer_cancel_multi_unit(ctx, auctioneer_authority_bump, buyer_price, token_size)
+    }
+
+    /// Remove a winning bid whose escrow no longer covers it from a finished multi-unit auction,
+    /// letting the next bid win in its place.
+    pub fn remove_defaulted_multi_unit_bid<'info>(
+        ctx: Context<'_, '_, '_, 'info, AuctioneerRemoveDefaultedMultiUnitBid<'info>>,
+        buyer_trade_state: Pubkey,
+        _listing_token_size: u64,
+    ) -> Result<()> {
+        auctioneer_remove_defaulted_multi_unit_bid(ctx, buyer_trade_state)
+    }
+
+    /// Settle one winning bid of a finished multi-unit auction as a partial sale of the listing.
+    #[inline(never)]
+    pub fn execute_multi_unit_sale<'info>(
+        ctx: Context<'_, '_, '_, 'info, AuctioneerExecuteMultiUnitSale<'info>>,
+        escrow_payment_bump: u8,
+        free_trade_state_bump: u8,
+        program_as_signer_bump: u8,
+        auctioneer_authority_bump: u8,
+        token_size: u64,
+    ) -> Result<()> {
+        auctioneer_execute_multi_unit_sale(
+            ctx,
+            escrow_payment_bump,
+            free_trade_state_bump,
+            program_as_signer_bump,
+            auctioneer_authority_bump,
+            token_size,
+        )
+    }
+
+    /// List `token_size` units of a token in a raffle selling up to `max_tickets` tickets at
+    /// `ticket_price` lamports, drawn with the seed whose hash is `commitment`. `reveal_bond`
+    /// lamports are held from the seller until the draw and shared by the ticket buyers if the
+    /// seed is not revealed in time.
+    pub fn sell_raffle<'info>(
+        ctx: Context<'_, '_, '_, 'info, AuctioneerSellRaffle<'info>>,
+        trade_state_bump: u8,
+        free_trade_state_bump: u8,
+        program_as_signer_bump: u8,
+        auctioneer_authority_bump: u8,
+        token_size: u64,
+        start_time: UnixTimestamp,
+        end_time: UnixTimestamp,
+        ticket_price: u64,
+        max_tickets: u32,
+        commitment: [u8; 32],
+        reveal_bond: u64,
+    ) -> Result<()> {
+        auctioneer_sell_raffle(
+            ctx,
+            trade_state_bump,
+            free_trade_state_bump,
+            program_as_signer_bump,
+            auctioneer_authority_bump,
+            token_size,
+            start_time,
+            end_time,
+            ticket_price,
+            max_tickets,
+            commitment,
+            reveal_bond,
+        )
+    }
+
+    /// Buy `tickets` tickets of a raffle of `token_size` units.
+    pub fn buy_raffle_tickets<'info>(
+        ctx: Context<'_, '_, '_, 'info, BuyRaffleTickets<'info>>,
+        token_size: u64,
+        tickets: u32,
+    ) -> Result<()> {
+        auctioneer_buy_raffle_tickets(ctx, token_size, tickets)
+    }
+
+    /// Take back the price of the tickets of a raffle that was not drawn in time, along with a
+    /// share of the reveal bond.
+    pub fn refund_raffle_tickets<'info>(
+        ctx: Context<'_, '_, '_, 'info, RefundRaffleTickets<'info>>,
+        token_size: u64,
+    ) -> Result<()> {
+        auctioneer_refund_raffle_tickets(ctx, token_size)
+    }
+
+    /// Commit a finished raffle to the hash of the current slot, to be mixed into the draw.
+    pub fn commit_raffle_draw<'info>(
+        ctx: Context<'_, '_, '_, 'info, CommitRaffleDraw<'info>>,
+        token_size: u64,
+    ) -> Result<()> {
+        auctioneer_commit_raffle_draw(ctx, token_size)
+    }
+
+    /// Reveal the committed `seed` to draw the winner of a finished raffle committed to a slot.
+    pub fn draw_raffle<'info>(
+        ctx: Context<'_, '_, '_, 'info, DrawRaffle<'info>>,
+        token_size: u64,
+        seed: [u8; 32],
+    ) -> Result<()> {
+        auctioneer_draw_raffle(ctx, token_size, seed)
+    }
+
+    /// Bid for the prize of a drawn raffle with the price of the winning ticket.
+    pub fn claim_raffle_prize<'info>(
+        ctx: Context<'_, '_, '_, 'info, AuctioneerClaimRafflePrize<'info>>,
+        trade_state_bump: u8,
+        escrow_payment_bump: u8,
+        auctioneer_authority_bump: u8,
+        token_size: u64,
+    ) -> Result<()> {
+        auctioneer_claim_raffle_prize(
+            ctx,
+            trade_state_bump,
+            escrow_payment_bump,
+            auctioneer_authority_bump,
+            token_size,
+        )
+    }
+
+    /// Transfer the prize of a claimed raffle to its winner.
+    #[inline(never)]
+    pub fn execute_raffle_sale<'info>(
+        ctx: Context<'_, '_, '_, 'info, AuctioneerExecuteRaffleSale<'info>>,
+        escrow_payment_bump: u8,
+        free_trade_state_bump: u8,
+        program_as_signer_bump: u8,
+        auctioneer_authority_bump: u8,
+        token_size: u64,
+    ) -> Result<()> {
+        auctioneer_execute_raffle_sale(
+            ctx,
+            escrow_payment_bump,
+            free_trade_state_bump,
+            program_as_signer_bump,
+            auctioneer_authority_bump,
+            token_size,
+        )
+    }
+
+    /// Cancel a raffle that holds no ticket funds.
+    pub fn cancel_raffle<'info>(
+        ctx: Context<'_, '_, '_, 'info, AuctioneerCancelRaffle<'info>>,
+        auctioneer_authority_bump: u8,
+        token_size: u64,
+    ) -> Result<()> {
+        auctioneer_cancel_raffle(ctx, auctioneer_authority_bump, token_size)
+    }
 }
@@ -0,0 +1,183 @@
+use anchor_lang::{prelude::*, AnchorDeserialize};
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use mpl_auction_house::{
+    self,
+    constants::{AUCTIONEER, FEE_PAYER, PREFIX},
+    cpi::accounts::AuctioneerBuy as AHBuy,
+    program::AuctionHouse as AuctionHouseProgram,
+    AuctionHouse,
+};
+
+use crate::{constants::*, multi_unit::*, utils::extend_end_time};
+
+/// Accounts for the [`buy_multi_unit` handler](auctioneer/fn.buy_multi_unit.html).
+#[derive(Accounts)]
+#[instruction(trade_state_bump: u8, escrow_payment_bump: u8, auctioneer_authority_bump: u8, buyer_price: u64, token_size: u64, listing_token_size: u64)]
+pub struct AuctioneerBuyMultiUnit<'info> {
+    /// Auction House Program
+    pub auction_house_program: Program<'info, AuctionHouseProgram>,
+
+    // Accounts used for Auctioneer
+    /// The multi-unit listing holding the settings and bids of the auction.
+    #[account(
+        mut,
+        seeds=[
+            MULTI_UNIT_LISTING.as_bytes(),
+            seller.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_account.mint.as_ref(),
+            &listing_token_size.to_le_bytes()
+        ],
+        bump=multi_unit_listing.bump,
+    )]
+    pub multi_unit_listing: Box<Account<'info, MultiUnitListing>>,
+
+    /// The seller of the tokens
+    /// CHECK: Checked via multi-unit listing seeds
+    pub seller: UncheckedAccount<'info>,
+
+    // Accounts passed into Auction House CPI call
+    /// User wallet account.
+    wallet: Signer<'info>,
+
+    /// CHECK: Verified through CPI
+    /// User SOL or SPL account to transfer funds from.
+    #[account(mut)]
+    payment_account: UncheckedAccount<'info>,
+
+    /// CHECK:
+    /// SPL token account transfer authority.
+    transfer_authority: UncheckedAccount<'info>,
+
+    /// Auction House instance treasury mint account.
+    treasury_mint: Box<Account<'info, Mint>>,
+
+    /// SPL token account.
+    token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Verified through CPI
+    /// SPL token account metadata.
+    metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Buyer escrow payment account PDA.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            wallet.key().as_ref()
+        ], seeds::program=auction_house_program,
+        bump = escrow_payment_bump
+    )]
+    escrow_payment_account: UncheckedAccount<'info>,
+
+    /// CHECK: Verified with has_one constraint on auction house account.
+    /// Auction House instance authority account.
+    authority: UncheckedAccount<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(seeds = [PREFIX.as_bytes(), auction_house.creator.as_ref(), auction_house.treasury_mint.as_ref()], seeds::program=auction_house_program, bump = auction_house.bump, has_one = authority, has_one = treasury_mint, has_one = auction_house_fee_account)]
+    auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Auction House instance fee account.
+    #[account(mut, seeds = [PREFIX.as_bytes(), auction_house.key().as_ref(), FEE_PAYER.as_bytes()], seeds::program=auction_house_program, bump = auction_house.fee_payer_bump)]
+    auction_house_fee_account: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Buyer trade state PDA for `token_size` units at `buyer_price`.
+    #[account(mut, seeds = [PREFIX.as_bytes(), wallet.key().as_ref(), auction_house.key().as_ref(), token_account.key().as_ref(), treasury_mint.key().as_ref(), token_account.mint.as_ref(), buyer_price.to_le_bytes().as_ref(), token_size.to_le_bytes().as_ref()], seeds::program=auction_house_program, bump = trade_state_bump)]
+    buyer_trade_state: UncheckedAccount<'info>,
+
+    /// CHECK: Is used as a seed for ah_auctioneer_pda.
+    /// The auctioneer program PDA running this auction.
+    pub auctioneer_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// The auctioneer PDA owned by Auction House storing scopes.
+    #[account(
+        seeds = [
+            AUCTIONEER.as_bytes(),
+            auction_house.key().as_ref(),
+            auctioneer_authority.key().as_ref()
+        ], seeds::program=auction_house_program,
+        bump = ah_auctioneer_pda.bump,
+    )]
+    pub ah_auctioneer_pda: Account<'info, mpl_auction_house::Auctioneer>,
+
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+    rent: Sysvar<'info, Rent>,
+}
+
+/// Bid `buyer_price` in total for `token_size` units of a multi-unit listing.
+pub fn auctioneer_buy_multi_unit<'info>(
+    ctx: Context<'_, '_, '_, 'info, AuctioneerBuyMultiUnit<'info>>,
+    trade_state_bump: u8,
+    escrow_payment_bump: u8,
+    auctioneer_authority_bump: u8,
+    buyer_price: u64,
+    token_size: u64,
+) -> Result<()> {
+    let multi_unit_listing = &mut ctx.accounts.multi_unit_listing;
+    multi_unit_listing.assert_active()?;
+    let previous_unit_price = multi_unit_listing.highest_unit_price();
+    multi_unit_listing.place_bid(
+        ctx.accounts.wallet.key(),
+        ctx.accounts.buyer_trade_state.key(),
+        buyer_price,
+        token_size,
+    )?;
+
+    let multi_unit_listing: &mut MultiUnitListing = multi_unit_listing;
+    extend_end_time(
+        &mut multi_unit_listing.end_time,
+        &mut multi_unit_listing.extensions,
+        multi_unit_listing.time_ext_period,
+        multi_unit_listing.time_ext_delta,
+        multi_unit_listing.soft_close.as_ref(),
+        previous_unit_price,
+        // The quantity divides the price once the bid is placed.
+        buyer_price / token_size,
+    )?;
+
+    let cpi_program = ctx.accounts.auction_house_program.to_account_info();
+    let cpi_accounts = AHBuy {
+        wallet: ctx.accounts.wallet.to_account_info(),
+        payment_account: ctx.accounts.payment_account.to_account_info(),
+        transfer_authority: ctx.accounts.transfer_authority.to_account_info(),
+        treasury_mint: ctx.accounts.treasury_mint.to_account_info(),
+        token_account: ctx.accounts.token_account.to_account_info(),
+        metadata: ctx.accounts.metadata.to_account_info(),
+        escrow_payment_account: ctx.accounts.escrow_payment_account.to_account_info(),
+        auction_house: ctx.accounts.auction_house.to_account_info(),
+        auction_house_fee_account: ctx.accounts.auction_house_fee_account.to_account_info(),
+        buyer_trade_state: ctx.accounts.buyer_trade_state.to_account_info(),
+        authority: ctx.accounts.authority.to_account_info(),
+        auctioneer_authority: ctx.accounts.auctioneer_authority.to_account_info(),
+        ah_auctioneer_pda: ctx.accounts.ah_auctioneer_pda.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        rent: ctx.accounts.rent.to_account_info(),
+    };
+
+    let ah_key = ctx.accounts.auction_house.key();
+    let auctioneer_seeds = [
+        AUCTIONEER.as_bytes(),
+        ah_key.as_ref(),
+        &[auctioneer_authority_bump],
+    ];
+
+    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+    mpl_auction_house::cpi::auctioneer_buy(
+        cpi_ctx.with_signer(&[&auctioneer_seeds]),
+        trade_state_bump,
+        escrow_payment_bump,
+        buyer_price,
+        token_size,
+    )
+}
@@ -0,0 +1,79 @@
+use anchor_lang::{prelude::*, AnchorDeserialize};
+use anchor_spl::token::{spl_token, TokenAccount};
+
+use mpl_auction_house::{
+    constants::PREFIX, pda::find_escrow_payment_address,
+    program::AuctionHouse as AuctionHouseProgram, AuctionHouse,
+};
+
+use crate::{constants::*, errors::*, execute_sale::second_chance::escrow_balance, multi_unit::*};
+
+/// Accounts for the [`remove_defaulted_multi_unit_bid` handler](auctioneer/fn.remove_defaulted_multi_unit_bid.html).
+#[derive(Accounts)]
+#[instruction(buyer_trade_state: Pubkey, listing_token_size: u64)]
+pub struct AuctioneerRemoveDefaultedMultiUnitBid<'info> {
+    /// Auction House Program
+    pub auction_house_program: Program<'info, AuctionHouseProgram>,
+
+    /// The multi-unit listing holding the settings and bids of the auction.
+    #[account(
+        mut,
+        seeds=[
+            MULTI_UNIT_LISTING.as_bytes(),
+            seller.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_account.mint.as_ref(),
+            &listing_token_size.to_le_bytes()
+        ],
+        bump=multi_unit_listing.bump,
+    )]
+    pub multi_unit_listing: Box<Account<'info, MultiUnitListing>>,
+
+    /// The seller of the tokens.
+    pub seller: Signer<'info>,
+
+    /// SPL token account containing the tokens of the listing.
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Auction House instance PDA account.
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref(), auction_house.treasury_mint.as_ref()], seeds::program=auction_house_program, bump=auction_house.bump)]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// CHECK: Validated as the escrow of the winning bidder in the handler.
+    /// Escrow payment account of the winning bidder.
+    pub escrow_payment_account: UncheckedAccount<'info>,
+}
+
+/// Remove the winning bid with `buyer_trade_state` from a finished multi-unit auction once
+/// `SECOND_CHANCE_GRACE_PERIOD` has passed and the escrow of its bidder no longer covers it, so
+/// the next bid in the book wins in its place. The buyer trade state is left for its bidder to
+/// cancel.
+pub fn auctioneer_remove_defaulted_multi_unit_bid<'info>(
+    ctx: Context<'_, '_, '_, 'info, AuctioneerRemoveDefaultedMultiUnitBid<'info>>,
+    buyer_trade_state: Pubkey,
+) -> Result<()> {
+    let multi_unit_listing = &mut ctx.accounts.multi_unit_listing;
+    if Clock::get()?.unix_timestamp < multi_unit_listing.end_time + SECOND_CHANCE_GRACE_PERIOD {
+        return err!(AuctioneerError::SecondChanceNotOpen);
+    }
+
+    let index = multi_unit_listing.find_winner(&buyer_trade_state)?;
+    let bid = &multi_unit_listing.bids[index];
+
+    let auction_house = &ctx.accounts.auction_house;
+    let escrow = &ctx.accounts.escrow_payment_account;
+    if escrow.key() != find_escrow_payment_address(&auction_house.key(), &bid.buyer).0 {
+        return err!(AuctioneerError::InvalidEscrowAccount);
+    }
+
+    let is_native = auction_house.treasury_mint == spl_token::native_mint::id();
+    if escrow_balance(escrow, is_native)? >= multi_unit_listing.settlement_price(index) {
+        return err!(AuctioneerError::WinningBidStillFunded);
+    }
+
+    multi_unit_listing.bids.remove(index);
+
+    Ok(())
+}
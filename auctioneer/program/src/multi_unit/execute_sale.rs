@@ -0,0 +1,271 @@
+use anchor_lang::{prelude::*, AnchorDeserialize, InstructionData};
+use anchor_spl::{associated_token::AssociatedToken, token::Token};
+
+use mpl_auction_house::{
+    self,
+    constants::{AUCTIONEER, FEE_PAYER, PREFIX, SIGNER, TREASURY},
+    cpi::accounts::AuctioneerExecutePartialSale as AHExecutePartialSale,
+    program::AuctionHouse as AuctionHouseProgram,
+    AuctionHouse,
+};
+
+use solana_program::program::invoke_signed;
+
+use crate::{constants::*, errors::*, multi_unit::*};
+
+/// Accounts for the [`execute_multi_unit_sale` handler](auctioneer/fn.execute_multi_unit_sale.html).
+#[derive(Accounts)]
+#[instruction(escrow_payment_bump: u8, free_trade_state_bump: u8, program_as_signer_bump: u8, auctioneer_authority_bump: u8, token_size: u64)]
+pub struct AuctioneerExecuteMultiUnitSale<'info> {
+    /// Auction House Program
+    pub auction_house_program: Program<'info, AuctionHouseProgram>,
+
+    // Accounts used for Auctioneer
+    /// The multi-unit listing holding the settings and bids of the auction.
+    #[account(
+        mut,
+        seeds=[
+            MULTI_UNIT_LISTING.as_bytes(),
+            seller.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_mint.key().as_ref(),
+            &token_size.to_le_bytes()
+        ],
+        bump=multi_unit_listing.bump,
+    )]
+    pub multi_unit_listing: Box<Account<'info, MultiUnitListing>>,
+
+    // Accounts passed into Auction House CPI call
+    /// CHECK: Verified through CPI
+    /// Buyer user wallet account.
+    #[account(mut)]
+    pub buyer: UncheckedAccount<'info>,
+
+    /// CHECK: Verified through CPI
+    /// Seller user wallet account.
+    #[account(mut)]
+    pub seller: UncheckedAccount<'info>,
+
+    /// CHECK: Verified through CPI
+    // cannot mark these as real Accounts or else we blow stack size limit
+    ///Token account where the SPL token is stored.
+    #[account(mut)]
+    pub token_account: UncheckedAccount<'info>,
+
+    /// CHECK: Verified through CPI
+    /// Token mint account for the SPL token.
+    pub token_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Verified through CPI
+    /// Metaplex metadata account decorating SPL mint account.
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Verified through CPI
+    // cannot mark these as real Accounts or else we blow stack size limit
+    /// Auction House treasury mint account.
+    pub treasury_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Buyer escrow payment account.
+    #[account(mut, seeds=[PREFIX.as_bytes(), auction_house.key().as_ref(), buyer.key().as_ref()], seeds::program=auction_house_program, bump=escrow_payment_bump)]
+    pub escrow_payment_account: UncheckedAccount<'info>,
+
+    /// CHECK: Verified through CPI
+    /// Seller SOL or SPL account to receive payment at.
+    #[account(mut)]
+    pub seller_payment_receipt_account: UncheckedAccount<'info>,
+
+    /// CHECK: Verified through CPI
+    /// Buyer SPL token account to receive purchased item at.
+    #[account(mut)]
+    pub buyer_receipt_token_account: UncheckedAccount<'info>,
+
+    /// CHECK: Verified through CPI
+    /// Auction House instance authority.
+    pub authority: UncheckedAccount<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref(), auction_house.treasury_mint.as_ref()], seeds::program=auction_house_program, bump=auction_house.bump, has_one=treasury_mint, has_one=auction_house_treasury, has_one=auction_house_fee_account)]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Auction House instance fee account.
+    #[account(mut, seeds=[PREFIX.as_bytes(), auction_house.key().as_ref(), FEE_PAYER.as_bytes()], seeds::program=auction_house_program, bump=auction_house.fee_payer_bump)]
+    pub auction_house_fee_account: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Auction House instance treasury account.
+    #[account(mut, seeds=[PREFIX.as_bytes(), auction_house.key().as_ref(), TREASURY.as_bytes()], seeds::program=auction_house_program, bump=auction_house.treasury_bump)]
+    pub auction_house_treasury: UncheckedAccount<'info>,
+
+    /// CHECK: Verified through CPI
+    /// Buyer trade state PDA account encoding the winning bid.
+    #[account(mut)]
+    pub buyer_trade_state: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Seller trade state PDA account encoding the sell order.
+    #[account(mut, seeds=[PREFIX.as_bytes(), seller.key().as_ref(), auction_house.key().as_ref(), token_account.key().as_ref(), auction_house.treasury_mint.as_ref(), token_mint.key().as_ref(), &u64::MAX.to_le_bytes(), &token_size.to_le_bytes()], seeds::program=auction_house_program, bump=seller_trade_state.to_account_info().data.borrow()[0])]
+    pub seller_trade_state: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Free seller trade state PDA account encoding a free sell order.
+    #[account(mut, seeds=[PREFIX.as_bytes(), seller.key().as_ref(), auction_house.key().as_ref(), token_account.key().as_ref(), auction_house.treasury_mint.as_ref(), token_mint.key().as_ref(), &0u64.to_le_bytes(), &token_size.to_le_bytes()], seeds::program=auction_house_program, bump=free_trade_state_bump)]
+    pub free_trade_state: UncheckedAccount<'info>,
+
+    /// CHECK: Verified through CPI
+    /// The auctioneer program PDA running this auction.
+    #[account(seeds = [AUCTIONEER.as_bytes(), auction_house.key().as_ref()], bump=auctioneer_authority_bump)]
+    pub auctioneer_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// The auctioneer PDA owned by Auction House storing scopes and counting sales.
+    #[account(
+        mut,
+        seeds = [
+            AUCTIONEER.as_bytes(),
+            auction_house.key().as_ref(),
+            auctioneer_authority.key().as_ref()
+            ],
+        seeds::program=auction_house_program,
+        bump = ah_auctioneer_pda.bump,
+    )]
+    pub ah_auctioneer_pda: Account<'info, mpl_auction_house::Auctioneer>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub ata_program: Program<'info, AssociatedToken>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    #[account(seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()], seeds::program=auction_house_program, bump=program_as_signer_bump)]
+    pub program_as_signer: UncheckedAccount<'info>,
+
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Settle one winning bid of a multi-unit auction through a partial sale of the listing.
+pub fn auctioneer_execute_multi_unit_sale<'info>(
+    ctx: Context<'_, '_, '_, 'info, AuctioneerExecuteMultiUnitSale<'info>>,
+    escrow_payment_bump: u8,
+    free_trade_state_bump: u8,
+    program_as_signer_bump: u8,
+    auctioneer_authority_bump: u8,
+    token_size: u64,
+) -> Result<()> {
+    let multi_unit_listing = &mut ctx.accounts.multi_unit_listing;
+    multi_unit_listing.assert_over()?;
+
+    let index = multi_unit_listing.find_winner(&ctx.accounts.buyer_trade_state.key())?;
+    if multi_unit_listing.bids[index].buyer != ctx.accounts.buyer.key() {
+        return err!(AuctioneerError::NotWinningBid);
+    }
+
+    let bid_size = multi_unit_listing.bids[index].quantity;
+    let bid_price = multi_unit_listing.bids[index].unit_price * bid_size;
+    let settlement_price = multi_unit_listing.settlement_price(index);
+    multi_unit_listing.bids[index].settled = true;
+
+    let cpi_program = ctx.accounts.auction_house_program.to_account_info();
+    let cpi_accounts = AHExecutePartialSale {
+        buyer: ctx.accounts.buyer.to_account_info(),
+        seller: ctx.accounts.seller.to_account_info(),
+        token_account: ctx.accounts.token_account.to_account_info(),
+        token_mint: ctx.accounts.token_mint.to_account_info(),
+        metadata: ctx.accounts.metadata.to_account_info(),
+        treasury_mint: ctx.accounts.treasury_mint.to_account_info(),
+        escrow_payment_account: ctx.accounts.escrow_payment_account.to_account_info(),
+        seller_payment_receipt_account: ctx
+            .accounts
+            .seller_payment_receipt_account
+            .to_account_info(),
+        buyer_receipt_token_account: ctx.accounts.buyer_receipt_token_account.to_account_info(),
+        auction_house: ctx.accounts.auction_house.to_account_info(),
+        auction_house_fee_account: ctx.accounts.auction_house_fee_account.to_account_info(),
+        auction_house_treasury: ctx.accounts.auction_house_treasury.to_account_info(),
+        buyer_trade_state: ctx.accounts.buyer_trade_state.to_account_info(),
+        seller_trade_state: ctx.accounts.seller_trade_state.to_account_info(),
+        free_trade_state: ctx.accounts.free_trade_state.to_account_info(),
+        authority: ctx.accounts.authority.to_account_info(),
+        auctioneer_authority: ctx.accounts.auctioneer_authority.to_account_info(),
+        ah_auctioneer_pda: ctx.accounts.ah_auctioneer_pda.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        ata_program: ctx.accounts.ata_program.to_account_info(),
+        program_as_signer: ctx.accounts.program_as_signer.to_account_info(),
+        rent: ctx.accounts.rent.to_account_info(),
+    };
+
+    // Pay-as-bid winners pay their own bid, uniform price winners get the difference between
+    // their bid and the clearing price left in their escrow.
+    let execute_sale_data = match multi_unit_listing.settlement {
+        SettlementMode::PayAsBid => mpl_auction_house::instruction::AuctioneerExecutePartialSale {
+            escrow_payment_bump,
+            _free_trade_state_bump: free_trade_state_bump,
+            program_as_signer_bump,
+            buyer_price: AUCTIONEER_BUYER_PRICE,
+            token_size,
+            partial_order_size: Some(bid_size),
+            partial_order_price: Some(bid_price),
+        }
+        .data(),
+        SettlementMode::UniformPrice => {
+            mpl_auction_house::instruction::AuctioneerExecutePartialSaleAtPrice {
+                escrow_payment_bump,
+                _free_trade_state_bump: free_trade_state_bump,
+                program_as_signer_bump,
+                buyer_price: AUCTIONEER_BUYER_PRICE,
+                token_size,
+                partial_order_size: bid_size,
+                partial_order_price: bid_price,
+                settlement_price,
+            }
+            .data()
+        }
+    };
+
+    let mut cpi_account_metas: Vec<AccountMeta> = cpi_accounts
+        .to_account_metas(None)
+        .into_iter()
+        .zip(cpi_accounts.to_account_infos())
+        .map(|mut pair| {
+            pair.0.is_signer = pair.1.is_signer;
+            if pair.0.pubkey == ctx.accounts.auctioneer_authority.key() {
+                pair.0.is_signer = true;
+            }
+            pair.0
+        })
+        .collect();
+
+    cpi_account_metas.append(&mut ctx.remaining_accounts.to_vec().to_account_metas(None));
+
+    let mut cpi_account_infos: Vec<AccountInfo> = cpi_accounts.to_account_infos();
+    cpi_account_infos.append(&mut ctx.remaining_accounts.to_vec());
+
+    let ix = solana_program::instruction::Instruction {
+        program_id: cpi_program.key(),
+        accounts: cpi_account_metas,
+        data: execute_sale_data,
+    };
+
+    let ah_key = ctx.accounts.auction_house.key();
+    let auctioneer_seeds = [
+        AUCTIONEER.as_bytes(),
+        ah_key.as_ref(),
+        &[auctioneer_authority_bump],
+    ];
+
+    invoke_signed(&ix, &cpi_account_infos, &[&auctioneer_seeds])?;
+
+    // Close the multi-unit listing once every unit is sold, otherwise the seller closes it when
+    // canceling the rest of the listing.
+    if ctx.accounts.multi_unit_listing.is_sold_out() {
+        close_multi_unit_listing(
+            &ctx.accounts.multi_unit_listing.to_account_info(),
+            &ctx.accounts.seller.to_account_info(),
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,245 @@
+//! Multi-unit auctions of semi-fungible tokens where the top bids win, each for its own quantity.
+
+pub mod bid;
+pub mod cancel;
+pub mod defaulted;
+pub mod execute_sale;
+pub mod sell;
+
+pub use bid::*;
+pub use cancel::*;
+pub use defaulted::*;
+pub use execute_sale::*;
+pub use sell::*;
+
+use anchor_lang::prelude::*;
+use solana_program::clock::UnixTimestamp;
+
+use crate::{
+    errors::*,
+    sell::config::{ListingConfigVersion, SoftClose, SOFT_CLOSE_SIZE},
+};
+
+pub const MAX_MULTI_UNIT_BIDS: usize = 16;
+pub const MULTI_UNIT_BID_SIZE: usize = 32 + 32 + 8 + 8 + 1;
+pub const MULTI_UNIT_LISTING_SIZE: usize = 8
+    + 1
+    + 8
+    + 8
+    + 8
+    + 1
+    + 1
+    + 8
+    + 4
+    + 4
+    + SOFT_CLOSE_SIZE
+    + 2
+    + 1
+    + 4
+    + MAX_MULTI_UNIT_BIDS * MULTI_UNIT_BID_SIZE;
+
+/// How the winners of a multi-unit auction pay for their units.
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SettlementMode {
+    /// Every winner pays the lowest winning unit price.
+    UniformPrice,
+    /// Every winner pays their own bid.
+    PayAsBid,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, PartialEq, Eq, Debug)]
+pub struct MultiUnitBid {
+    pub buyer: Pubkey,
+    pub buyer_trade_state: Pubkey,
+    pub unit_price: u64,
+    pub quantity: u64,
+    pub settled: bool,
+}
+
+#[account]
+pub struct MultiUnitListing {
+    pub version: ListingConfigVersion,
+    pub start_time: UnixTimestamp,
+    pub end_time: UnixTimestamp,
+    pub token_size: u64,
+    pub max_winners: u8,
+    pub settlement: SettlementMode,
+    pub reserve_price: u64,
+    pub time_ext_period: u32,
+    pub time_ext_delta: u32,
+    pub soft_close: Option<SoftClose>,
+    /// Number of times `end_time` has been extended.
+    pub extensions: u16,
+    pub bump: u8,
+    /// Bids ordered from the highest unit price, earlier bids first on ties.
+    pub bids: Vec<MultiUnitBid>,
+}
+
+impl MultiUnitListing {
+    pub fn assert_active(&self) -> Result<()> {
+        let current_timestamp = Clock::get()?.unix_timestamp;
+
+        if current_timestamp < self.start_time {
+            return err!(AuctioneerError::AuctionNotStarted);
+        } else if current_timestamp > self.end_time {
+            return err!(AuctioneerError::AuctionEnded);
+        }
+
+        Ok(())
+    }
+
+    pub fn assert_over(&self) -> Result<()> {
+        if Clock::get()?.unix_timestamp < self.end_time {
+            return err!(AuctioneerError::AuctionActive);
+        }
+
+        Ok(())
+    }
+
+    /// Highest unit price in the book, 0 without bids.
+    pub fn highest_unit_price(&self) -> u64 {
+        self.bids.first().map_or(0, |bid| bid.unit_price)
+    }
+
+    /// Add a bid for `quantity` units at a total of `price` to the book. Once the book is full, the
+    /// bid replaces the lowest bid that doesn't win if it offers a higher unit price, and is
+    /// rejected otherwise. The trade state of a replaced bid is still canceled through
+    /// `cancel_multi_unit`.
+    pub fn place_bid(
+        &mut self,
+        buyer: Pubkey,
+        buyer_trade_state: Pubkey,
+        price: u64,
+        quantity: u64,
+    ) -> Result<()> {
+        if quantity == 0 || quantity > self.token_size || price.checked_rem(quantity) != Some(0) {
+            return err!(AuctioneerError::InvalidBidQuantity);
+        }
+
+        let unit_price = price / quantity;
+        if unit_price < self.reserve_price {
+            return err!(AuctioneerError::BelowReservePrice);
+        }
+
+        if self
+            .bids
+            .iter()
+            .any(|bid| bid.buyer_trade_state == buyer_trade_state)
+        {
+            return err!(AuctioneerError::BidAlreadyPlaced);
+        }
+
+        if self.bids.len() >= MAX_MULTI_UNIT_BIDS {
+            let winners = self.winners();
+            let lowest_loser = (0..self.bids.len())
+                .rev()
+                .find(|index| !winners.contains(index))
+                .filter(|index| self.bids[*index].unit_price < unit_price)
+                .ok_or(AuctioneerError::MultiUnitBookFull)?;
+            self.bids.remove(lowest_loser);
+        }
+
+        let position = self
+            .bids
+            .iter()
+            .position(|bid| bid.unit_price < unit_price)
+            .unwrap_or(self.bids.len());
+
+        self.bids.insert(
+            position,
+            MultiUnitBid {
+                buyer,
+                buyer_trade_state,
+                unit_price,
+                quantity,
+                settled: false,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Indexes of the winning bids: the highest bids that fit in the units left, up to
+    /// `max_winners` of them.
+    pub fn winners(&self) -> Vec<usize> {
+        let mut remaining = self.token_size;
+        let mut winners = Vec::new();
+
+        for (index, bid) in self.bids.iter().enumerate() {
+            if winners.len() == usize::from(self.max_winners) || remaining == 0 {
+                break;
+            }
+
+            if bid.quantity <= remaining {
+                remaining -= bid.quantity;
+                winners.push(index);
+            }
+        }
+
+        winners
+    }
+
+    /// Index of the unsettled winning bid with `buyer_trade_state`.
+    pub fn find_winner(&self, buyer_trade_state: &Pubkey) -> Result<usize> {
+        let index = self
+            .winners()
+            .into_iter()
+            .find(|index| self.bids[*index].buyer_trade_state == *buyer_trade_state)
+            .ok_or(AuctioneerError::NotWinningBid)?;
+
+        if self.bids[index].settled {
+            return err!(AuctioneerError::BidAlreadySettled);
+        }
+
+        Ok(index)
+    }
+
+    /// Total price the winning bid at `index` pays for its units.
+    pub fn settlement_price(&self, index: usize) -> u64 {
+        let bid = &self.bids[index];
+        let unit_price = match self.settlement {
+            SettlementMode::PayAsBid => bid.unit_price,
+            SettlementMode::UniformPrice => self
+                .winners()
+                .into_iter()
+                .map(|winner| self.bids[winner].unit_price)
+                .min()
+                .unwrap_or(bid.unit_price),
+        };
+
+        // Never above the bid itself, which fits in a u64.
+        unit_price * bid.quantity
+    }
+
+    pub fn all_winners_settled(&self) -> bool {
+        self.winners()
+            .into_iter()
+            .all(|winner| self.bids[winner].settled)
+    }
+
+    pub fn is_sold_out(&self) -> bool {
+        let sold: u64 = self
+            .bids
+            .iter()
+            .filter(|bid| bid.settled)
+            .map(|bid| bid.quantity)
+            .sum();
+
+        sold == self.token_size
+    }
+}
+
+/// Send the lamports of a closed multi-unit listing to the seller.
+pub fn close_multi_unit_listing(listing: &AccountInfo, seller: &AccountInfo) -> Result<()> {
+    let listing_lamports = listing.lamports();
+    **seller.lamports.borrow_mut() = seller
+        .lamports()
+        .checked_add(listing_lamports)
+        .ok_or(AuctioneerError::NumericalOverflow)?;
+    **listing.lamports.borrow_mut() = 0;
+
+    let mut source_data = listing.data.borrow_mut();
+    source_data.fill(0);
+
+    Ok(())
+}
@@ -25,6 +25,65 @@ pub fn find_listing_config_address(
     )
 }
 
+pub fn find_multi_unit_listing_address(
+    wallet: &Pubkey,
+    auction_house: &Pubkey,
+    token_account: &Pubkey,
+    treasury_mint: &Pubkey,
+    token_mint: &Pubkey,
+    token_size: u64,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            MULTI_UNIT_LISTING.as_bytes(),
+            wallet.as_ref(),
+            auction_house.as_ref(),
+            token_account.as_ref(),
+            treasury_mint.as_ref(),
+            token_mint.as_ref(),
+            &token_size.to_le_bytes(),
+        ],
+        &id(),
+    )
+}
+
+pub fn find_raffle_address(
+    wallet: &Pubkey,
+    auction_house: &Pubkey,
+    token_account: &Pubkey,
+    treasury_mint: &Pubkey,
+    token_mint: &Pubkey,
+    token_size: u64,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            RAFFLE.as_bytes(),
+            wallet.as_ref(),
+            auction_house.as_ref(),
+            token_account.as_ref(),
+            treasury_mint.as_ref(),
+            token_mint.as_ref(),
+            &token_size.to_le_bytes(),
+        ],
+        &id(),
+    )
+}
+
+pub fn find_bid_history_address(listing_config: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[BID_HISTORY.as_bytes(), listing_config.as_ref()], &id())
+}
+
+pub fn find_forfeit_deposit_address(listing_config: &Pubkey, bidder: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            FORFEIT_DEPOSIT.as_bytes(),
+            listing_config.as_ref(),
+            bidder.as_ref(),
+        ],
+        &id(),
+    )
+}
+
 pub fn find_auctioneer_authority_seeds(auction_house: &Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[AUCTIONEER.as_bytes(), auction_house.as_ref()], &id())
 }
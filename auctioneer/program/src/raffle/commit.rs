@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use mpl_auction_house::AuctionHouse;
+
+use crate::{constants::*, errors::*, raffle::*};
+
+/// Accounts for the [`commit_raffle_draw` handler](auctioneer/fn.commit_raffle_draw.html).
+#[derive(Accounts)]
+#[instruction(token_size: u64)]
+pub struct CommitRaffleDraw<'info> {
+    /// The seller of the raffle.
+    pub seller: Signer<'info>,
+
+    /// Auction House instance the raffle is listed on.
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// SPL token account of the raffled tokens.
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// The raffle holding the settings, tickets and ticket funds of the listing.
+    #[account(
+        mut,
+        seeds=[
+            RAFFLE.as_bytes(),
+            seller.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_account.mint.as_ref(),
+            &token_size.to_le_bytes()
+        ],
+        bump=raffle.bump,
+    )]
+    pub raffle: Box<Account<'info, Raffle>>,
+}
+
+/// Commit a finished raffle to the hash of the current slot, which the draw mixes with the seed.
+/// The seed must then be revealed while the hash is kept in the `SlotHashes` sysvar, otherwise
+/// the ticket buyers get their funds back along with the reveal bond.
+pub fn auctioneer_commit_raffle_draw(
+    ctx: Context<CommitRaffleDraw>,
+    _token_size: u64,
+) -> Result<()> {
+    let raffle = &mut ctx.accounts.raffle;
+    raffle.assert_over()?;
+    if raffle.winner.is_some() {
+        return err!(AuctioneerError::RaffleAlreadyDrawn);
+    }
+    if raffle.draw_slot.is_some() {
+        return err!(AuctioneerError::RaffleDrawAlreadyCommitted);
+    }
+    if raffle.is_refundable()? {
+        return err!(AuctioneerError::RevealPeriodOver);
+    }
+
+    raffle.draw_slot = Some(Clock::get()?.slot);
+
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use mpl_auction_house::AuctionHouse;
+
+use crate::{constants::*, errors::*, raffle::*};
+
+/// Accounts for the [`draw_raffle` handler](auctioneer/fn.draw_raffle.html).
+#[derive(Accounts)]
+#[instruction(token_size: u64)]
+pub struct DrawRaffle<'info> {
+    /// The seller revealing the seed, receiving the funds of the non-winning tickets.
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    /// Auction House instance the raffle is listed on.
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// SPL token account of the raffled tokens.
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// The raffle holding the settings, tickets and ticket funds of the listing.
+    #[account(
+        mut,
+        seeds=[
+            RAFFLE.as_bytes(),
+            seller.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_account.mint.as_ref(),
+            &token_size.to_le_bytes()
+        ],
+        bump=raffle.bump,
+    )]
+    pub raffle: Box<Account<'info, Raffle>>,
+
+    /// CHECK: Checked against the sysvar id when reading the hash of the draw slot.
+    /// The `SlotHashes` sysvar holding the hash of the draw slot.
+    pub slot_hashes: UncheckedAccount<'info>,
+}
+
+/// Draw the winner of a finished raffle by revealing the seed committed to when listing, mixed
+/// with the hash of the slot committed to by `commit_raffle_draw`, and pay the funds of every
+/// non-winning ticket and the reveal bond to the seller. The price of the winning ticket stays in
+/// the raffle to back the bid of the winner.
+pub fn auctioneer_draw_raffle(
+    ctx: Context<DrawRaffle>,
+    _token_size: u64,
+    seed: [u8; 32],
+) -> Result<()> {
+    let raffle = &mut ctx.accounts.raffle;
+    raffle.assert_over()?;
+    if raffle.winner.is_some() {
+        return err!(AuctioneerError::RaffleAlreadyDrawn);
+    }
+    if raffle.is_refundable()? {
+        return err!(AuctioneerError::RevealPeriodOver);
+    }
+
+    let draw_slot = raffle
+        .draw_slot
+        .ok_or(AuctioneerError::RaffleDrawNotCommitted)?;
+    let draw_slot_hash = slot_hash(&ctx.accounts.slot_hashes, draw_slot)?
+        .ok_or(AuctioneerError::RaffleDrawSlotPending)?;
+    let winner = raffle.draw(&seed, &draw_slot_hash, &raffle.key())?;
+    raffle.winner = Some(winner);
+
+    let amount = raffle
+        .ticket_price
+        .checked_mul(u64::from(raffle.tickets_sold - 1))
+        .and_then(|amount| amount.checked_add(raffle.reveal_bond))
+        .ok_or(AuctioneerError::NumericalOverflow)?;
+
+    pay_from_raffle(
+        &ctx.accounts.raffle.to_account_info(),
+        &ctx.accounts.seller.to_account_info(),
+        amount,
+    )
+}
@@ -0,0 +1,223 @@
+//! Raffle listings where buyers purchase tickets at a fixed price and the seller draws the winner
+//! from a committed seed mixed with the hash of a slot committed to after the raffle ends.
+
+pub mod cancel;
+pub mod claim;
+pub mod commit;
+pub mod draw;
+pub mod execute_sale;
+pub mod sell;
+pub mod ticket;
+
+pub use cancel::*;
+pub use claim::*;
+pub use commit::*;
+pub use draw::*;
+pub use execute_sale::*;
+pub use sell::*;
+pub use ticket::*;
+
+use anchor_lang::prelude::*;
+use solana_program::{clock::UnixTimestamp, hash::hashv, slot_hashes, sysvar};
+
+use crate::{constants::*, errors::*, sell::config::ListingConfigVersion};
+
+pub const MAX_RAFFLE_ENTRIES: usize = 64;
+pub const RAFFLE_ENTRY_SIZE: usize = 32 + 4 + 1;
+pub const RAFFLE_SIZE: usize = 8
+    + 1
+    + 8
+    + 8
+    + 8
+    + 8
+    + 4
+    + 4
+    + 8
+    + 32
+    + 1
+    + 8
+    + 1
+    + 32
+    + 1
+    + 1
+    + 4
+    + MAX_RAFFLE_ENTRIES * RAFFLE_ENTRY_SIZE;
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, PartialEq, Eq, Debug)]
+pub struct RaffleEntry {
+    pub buyer: Pubkey,
+    pub tickets: u32,
+    pub refunded: bool,
+}
+
+#[account]
+pub struct Raffle {
+    pub version: ListingConfigVersion,
+    pub start_time: UnixTimestamp,
+    pub end_time: UnixTimestamp,
+    pub token_size: u64,
+    pub ticket_price: u64,
+    pub max_tickets: u32,
+    pub tickets_sold: u32,
+    /// Lamports held from the seller until the draw, shared by the ticket buyers when the seller
+    /// fails to reveal the seed in time.
+    pub reveal_bond: u64,
+    /// Hash of the seed the seller reveals to draw the winner.
+    pub commitment: [u8; 32],
+    /// Slot whose hash is mixed into the draw, committed to after the raffle ends.
+    pub draw_slot: Option<u64>,
+    pub winner: Option<Pubkey>,
+    /// Whether the winner has bid for the prize through Auction House.
+    pub claimed: bool,
+    pub bump: u8,
+    pub entries: Vec<RaffleEntry>,
+}
+
+impl Raffle {
+    pub fn assert_active(&self) -> Result<()> {
+        let current_timestamp = Clock::get()?.unix_timestamp;
+
+        if current_timestamp < self.start_time {
+            return err!(AuctioneerError::AuctionNotStarted);
+        } else if current_timestamp > self.end_time {
+            return err!(AuctioneerError::AuctionEnded);
+        }
+
+        Ok(())
+    }
+
+    pub fn assert_over(&self) -> Result<()> {
+        if Clock::get()?.unix_timestamp < self.end_time {
+            return err!(AuctioneerError::AuctionActive);
+        }
+
+        Ok(())
+    }
+
+    /// Whether ticket holders may take their funds back because the seller did not draw in time,
+    /// either within `RAFFLE_REVEAL_PERIOD` or while the hash of the draw slot was still kept in
+    /// the `SlotHashes` sysvar.
+    pub fn is_refundable(&self) -> Result<bool> {
+        if self.winner.is_some() {
+            return Ok(false);
+        }
+
+        let clock = Clock::get()?;
+        let slot_expired = self
+            .draw_slot
+            .map(|slot| clock.slot > slot.saturating_add(slot_hashes::MAX_ENTRIES as u64))
+            .unwrap_or(false);
+
+        Ok(slot_expired || clock.unix_timestamp >= self.end_time + RAFFLE_REVEAL_PERIOD)
+    }
+
+    /// Share of the reveal bond owed to the holder of `tickets` tickets of an undrawn raffle.
+    pub fn reveal_bond_share(&self, tickets: u32) -> Result<u64> {
+        let share = u128::from(self.reveal_bond)
+            .checked_mul(u128::from(tickets))
+            .and_then(|amount| amount.checked_div(u128::from(self.tickets_sold)))
+            .ok_or(AuctioneerError::NumericalOverflow)?;
+
+        u64::try_from(share).map_err(|_| AuctioneerError::NumericalOverflow.into())
+    }
+
+    /// Add `tickets` tickets bought by `buyer`.
+    pub fn add_tickets(&mut self, buyer: Pubkey, tickets: u32) -> Result<()> {
+        let tickets_sold = self
+            .tickets_sold
+            .checked_add(tickets)
+            .filter(|sold| tickets > 0 && *sold <= self.max_tickets)
+            .ok_or(AuctioneerError::InvalidTicketQuantity)?;
+
+        match self.entries.iter_mut().find(|entry| entry.buyer == buyer) {
+            Some(entry) => entry.tickets += tickets,
+            None => {
+                if self.entries.len() == MAX_RAFFLE_ENTRIES {
+                    return err!(AuctioneerError::RaffleFull);
+                }
+                self.entries.push(RaffleEntry {
+                    buyer,
+                    tickets,
+                    refunded: false,
+                });
+            }
+        }
+        self.tickets_sold = tickets_sold;
+
+        Ok(())
+    }
+
+    /// Owner of the winning ticket for `seed` mixed with `draw_slot_hash`.
+    pub fn draw(&self, seed: &[u8; 32], draw_slot_hash: &[u8], raffle: &Pubkey) -> Result<Pubkey> {
+        if hashv(&[&seed[..]]).to_bytes() != self.commitment {
+            return err!(AuctioneerError::InvalidRaffleSeed);
+        }
+        if self.tickets_sold == 0 {
+            return err!(AuctioneerError::NoRaffleTickets);
+        }
+
+        let randomness = hashv(&[&seed[..], draw_slot_hash, raffle.as_ref()]).to_bytes();
+        let mut ticket =
+            u64::from_le_bytes(randomness[..8].try_into().unwrap()) % u64::from(self.tickets_sold);
+
+        for entry in &self.entries {
+            if ticket < u64::from(entry.tickets) {
+                return Ok(entry.buyer);
+            }
+            ticket -= u64::from(entry.tickets);
+        }
+
+        err!(AuctioneerError::NoRaffleTickets)
+    }
+
+    /// Whether no ticket funds are left in the raffle.
+    pub fn all_refunded(&self) -> bool {
+        self.entries.iter().all(|entry| entry.refunded)
+    }
+}
+
+/// Hash of `slot` from the `SlotHashes` sysvar, if it is still kept there.
+pub fn slot_hash(slot_hashes: &AccountInfo, slot: u64) -> Result<Option<[u8; 32]>> {
+    if slot_hashes.key() != sysvar::slot_hashes::id() {
+        return err!(AuctioneerError::InvalidSlotHashes);
+    }
+
+    // A u64 entry count followed by (slot, hash) entries, most recent first.
+    let data = slot_hashes.try_borrow_data()?;
+    let len = data
+        .get(..8)
+        .map(|len| u64::from_le_bytes(len.try_into().unwrap()) as usize)
+        .ok_or(AuctioneerError::InvalidSlotHashes)?;
+    let entries = data
+        .get(8..8 + len * 40)
+        .ok_or(AuctioneerError::InvalidSlotHashes)?;
+
+    Ok(entries
+        .chunks_exact(40)
+        .find(|entry| u64::from_le_bytes(entry[..8].try_into().unwrap()) == slot)
+        .map(|entry| entry[8..].try_into().unwrap()))
+}
+
+/// Move `amount` lamports out of the program owned raffle account.
+pub fn pay_from_raffle(raffle: &AccountInfo, recipient: &AccountInfo, amount: u64) -> Result<()> {
+    **raffle.lamports.borrow_mut() = raffle
+        .lamports()
+        .checked_sub(amount)
+        .ok_or(AuctioneerError::NumericalOverflow)?;
+    **recipient.lamports.borrow_mut() = recipient
+        .lamports()
+        .checked_add(amount)
+        .ok_or(AuctioneerError::NumericalOverflow)?;
+
+    Ok(())
+}
+
+/// Send the lamports of a closed raffle to the seller.
+pub fn close_raffle(raffle: &AccountInfo, seller: &AccountInfo) -> Result<()> {
+    pay_from_raffle(raffle, seller, raffle.lamports())?;
+
+    let mut source_data = raffle.data.borrow_mut();
+    source_data.fill(0);
+
+    Ok(())
+}
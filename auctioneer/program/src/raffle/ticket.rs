@@ -0,0 +1,137 @@
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::token::TokenAccount;
+
+use mpl_auction_house::AuctionHouse;
+
+use crate::{constants::*, errors::*, raffle::*};
+
+/// Accounts for the [`buy_raffle_tickets` handler](auctioneer/fn.buy_raffle_tickets.html).
+#[derive(Accounts)]
+#[instruction(token_size: u64)]
+pub struct BuyRaffleTickets<'info> {
+    /// Wallet buying the tickets.
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// The seller of the tokens
+    /// CHECK: Checked via raffle seeds
+    pub seller: UncheckedAccount<'info>,
+
+    /// Auction House instance the raffle is listed on.
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// SPL token account of the raffled tokens.
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// The raffle holding the settings, tickets and ticket funds of the listing.
+    #[account(
+        mut,
+        seeds=[
+            RAFFLE.as_bytes(),
+            seller.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_account.mint.as_ref(),
+            &token_size.to_le_bytes()
+        ],
+        bump=raffle.bump,
+    )]
+    pub raffle: Box<Account<'info, Raffle>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Buy `tickets` tickets of a raffle, holding their price in the raffle until it is drawn.
+pub fn auctioneer_buy_raffle_tickets(
+    ctx: Context<BuyRaffleTickets>,
+    _token_size: u64,
+    tickets: u32,
+) -> Result<()> {
+    let raffle = &mut ctx.accounts.raffle;
+    raffle.assert_active()?;
+    raffle.add_tickets(ctx.accounts.wallet.key(), tickets)?;
+
+    let amount = raffle
+        .ticket_price
+        .checked_mul(u64::from(tickets))
+        .ok_or(AuctioneerError::NumericalOverflow)?;
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.wallet.to_account_info(),
+                to: ctx.accounts.raffle.to_account_info(),
+            },
+        ),
+        amount,
+    )
+}
+
+/// Accounts for the [`refund_raffle_tickets` handler](auctioneer/fn.refund_raffle_tickets.html).
+#[derive(Accounts)]
+#[instruction(token_size: u64)]
+pub struct RefundRaffleTickets<'info> {
+    /// Wallet that bought the tickets.
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// The seller of the tokens
+    /// CHECK: Checked via raffle seeds
+    pub seller: UncheckedAccount<'info>,
+
+    /// Auction House instance the raffle is listed on.
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// SPL token account of the raffled tokens.
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// The raffle holding the settings, tickets and ticket funds of the listing.
+    #[account(
+        mut,
+        seeds=[
+            RAFFLE.as_bytes(),
+            seller.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_account.mint.as_ref(),
+            &token_size.to_le_bytes()
+        ],
+        bump=raffle.bump,
+    )]
+    pub raffle: Box<Account<'info, Raffle>>,
+}
+
+/// Return the price of a buyer's tickets along with their share of the reveal bond when the
+/// seller did not draw the raffle in time.
+pub fn auctioneer_refund_raffle_tickets(
+    ctx: Context<RefundRaffleTickets>,
+    _token_size: u64,
+) -> Result<()> {
+    let raffle = &mut ctx.accounts.raffle;
+    if !raffle.is_refundable()? {
+        return err!(AuctioneerError::RaffleNotRefundable);
+    }
+
+    let entry = raffle
+        .entries
+        .iter_mut()
+        .find(|entry| entry.buyer == ctx.accounts.wallet.key() && !entry.refunded)
+        .ok_or(AuctioneerError::NoRaffleTickets)?;
+    entry.refunded = true;
+    let tickets = entry.tickets;
+    let reveal_bond_share = raffle.reveal_bond_share(tickets)?;
+    let amount = raffle
+        .ticket_price
+        .checked_mul(u64::from(tickets))
+        .and_then(|amount| amount.checked_add(reveal_bond_share))
+        .ok_or(AuctioneerError::NumericalOverflow)?;
+
+    pay_from_raffle(
+        &ctx.accounts.raffle.to_account_info(),
+        &ctx.accounts.wallet.to_account_info(),
+        amount,
+    )
+}
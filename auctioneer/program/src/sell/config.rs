@@ -1,12 +1,34 @@
-use anchor_lang::prelude::*;
-use solana_program::clock::UnixTimestamp;
+use anchor_lang::{prelude::*, Discriminator};
+use mpl_utils::VersionedAccount;
+use solana_program::{clock::UnixTimestamp, program_error::ProgramError};
 
 pub const BID_SIZE: usize = 8 + 1 + 32;
-pub const LISTING_CONFIG_SIZE: usize = 8 + 1 + 8 + 8 + BID_SIZE + 1 + 8 + 8 + 4 + 4 + 1;
+pub const BID_GATE_SIZE: usize = 1 + 1 + 32 + 8;
+pub const SOFT_CLOSE_SIZE: usize = 1 + 1 + 8 + 1 + 2 + 4;
+pub const CANCEL_POLICY_SIZE: usize = 1 + 1 + 8;
+pub const LISTING_CONFIG_V0_SIZE: usize = 8 + 1 + 8 + 8 + BID_SIZE + 1 + 8 + 8 + 4 + 4 + 1;
+pub const LISTING_CONFIG_SIZE: usize = 8
+    + 1
+    + 8
+    + 8
+    + BID_SIZE
+    + 1
+    + 8
+    + 8
+    + 4
+    + 4
+    + 1
+    + BID_GATE_SIZE
+    + SOFT_CLOSE_SIZE
+    + 2
+    + CANCEL_POLICY_SIZE
+    + 1;
 
 #[derive(AnchorDeserialize, AnchorSerialize, Clone)]
 pub enum ListingConfigVersion {
     V0,
+    /// Adds `bid_gate`, `soft_close`, `extensions`, `cancel_policy` and `has_bid_history`.
+    V1,
 }
 
 #[derive(AnchorDeserialize, AnchorSerialize, Clone)]
@@ -16,6 +38,38 @@ pub struct Bid {
     pub buyer_trade_state: Pubkey,
 }
 
+/// Holdings a wallet must prove to bid on a listing.
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, PartialEq, Eq, Debug)]
+pub enum BidGate {
+    /// An NFT from the verified collection with this mint.
+    Collection(Pubkey),
+    /// At least `amount` tokens of `mint`.
+    Token { mint: Pubkey, amount: u64 },
+}
+
+/// Limits and scaling applied to the `time_ext_delta` extensions of a listing.
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, PartialEq, Eq, Debug)]
+pub struct SoftClose {
+    /// Time extensions never push `end_time` past.
+    pub hard_end_time: Option<UnixTimestamp>,
+    /// Number of times `end_time` may be extended.
+    pub max_extensions: Option<u16>,
+    /// Seconds added to `time_ext_delta` for every full percent a bid raises the highest bid.
+    pub delta_per_percent: u32,
+}
+
+/// When the seller may cancel a listing that has received bids. Listings without bids can always
+/// be canceled.
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, PartialEq, Eq, Debug)]
+pub enum CancelPolicy {
+    /// The seller may not cancel once there is a bid.
+    BeforeFirstBid,
+    /// The seller pays this many lamports to the highest bidder to cancel once there is a bid.
+    Penalty(u64),
+    /// The seller may cancel until the highest bid meets the reserve price.
+    UntilReserveMet,
+}
+
 #[account]
 pub struct ListingConfig {
     pub version: ListingConfigVersion,
@@ -28,4 +82,71 @@ pub struct ListingConfig {
     pub time_ext_period: u32,
     pub time_ext_delta: u32,
     pub allow_high_bid_cancel: bool,
+    pub bid_gate: Option<BidGate>,
+    pub soft_close: Option<SoftClose>,
+    /// Number of times `end_time` has been extended.
+    pub extensions: u16,
+    pub cancel_policy: Option<CancelPolicy>,
+    /// Whether bids must be recorded in the bid history of the listing.
+    pub has_bid_history: bool,
+}
+
+/// The `ListingConfig` fields written by `ListingConfigVersion::V0`.
+#[derive(AnchorDeserialize)]
+struct ListingConfigV0 {
+    start_time: UnixTimestamp,
+    end_time: UnixTimestamp,
+    highest_bid: Bid,
+    bump: u8,
+    reserve_price: u64,
+    min_bid_increment: u64,
+    time_ext_period: u32,
+    time_ext_delta: u32,
+    allow_high_bid_cancel: bool,
+}
+
+impl VersionedAccount for ListingConfig {
+    const LATEST_VERSION: u8 = ListingConfigVersion::V1 as u8;
+
+    fn detect_version(data: &[u8]) -> std::result::Result<u8, ProgramError> {
+        if !data.starts_with(&ListingConfig::discriminator()) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        data.get(8).copied().ok_or(ProgramError::InvalidAccountData)
+    }
+
+    fn migrate(version: u8, data: &[u8]) -> std::result::Result<Self, ProgramError> {
+        if version != ListingConfigVersion::V0 as u8 {
+            return ListingConfig::try_deserialize(&mut &data[..])
+                .map_err(|_| ProgramError::InvalidAccountData);
+        }
+
+        let v0 = ListingConfigV0::deserialize(&mut &data[9..])?;
+        Ok(ListingConfig {
+            version: ListingConfigVersion::V1,
+            start_time: v0.start_time,
+            end_time: v0.end_time,
+            highest_bid: v0.highest_bid,
+            bump: v0.bump,
+            reserve_price: v0.reserve_price,
+            min_bid_increment: v0.min_bid_increment,
+            time_ext_period: v0.time_ext_period,
+            time_ext_delta: v0.time_ext_delta,
+            allow_high_bid_cancel: v0.allow_high_bid_cancel,
+            bid_gate: None,
+            soft_close: None,
+            extensions: 0,
+            cancel_policy: None,
+            has_bid_history: false,
+        })
+    }
+
+    fn space(&self) -> usize {
+        LISTING_CONFIG_SIZE
+    }
+
+    fn write(&self, mut data: &mut [u8]) -> std::result::Result<(), ProgramError> {
+        self.try_serialize(&mut data)
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
 }
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use mpl_utils::migrate_account;
+
+use crate::sell::config::*;
+
+/// Accounts for the [`migrate_listing_config` handler](auctioneer/fn.migrate_listing_config.html).
+#[derive(Accounts)]
+pub struct MigrateListingConfig<'info> {
+    /// Wallet paying for the additional rent of the migrated account.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Checked to be a Listing Config owned by this program while migrating.
+    /// The Listing Config to migrate.
+    #[account(mut)]
+    pub listing_config: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Rewrite a Listing Config created with an older `ListingConfigVersion` with the latest layout,
+/// growing the account as needed. Listing Configs already on the latest version are left as is.
+pub fn auctioneer_migrate_listing_config(ctx: Context<MigrateListingConfig>) -> Result<()> {
+    migrate_account::<ListingConfig>(
+        &crate::id(),
+        &ctx.accounts.listing_config.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    Ok(())
+}
@@ -1,6 +1,9 @@
 pub mod config;
+pub mod migrate;
 
-use crate::{constants::*, errors::*, sell::config::*};
+pub use migrate::*;
+
+use crate::{constants::*, errors::*, sell::config::*, utils::*};
 
 use anchor_lang::{prelude::*, AnchorDeserialize, InstructionData};
 use anchor_spl::token::{Token, TokenAccount};
@@ -119,8 +122,13 @@ pub fn auctioneer_sell<'info>(
     time_ext_period: Option<u32>,
     time_ext_delta: Option<u32>,
     allow_high_bid_cancel: Option<bool>,
+    bid_gate: Option<BidGate>,
+    soft_close: Option<SoftClose>,
+    cancel_policy: Option<CancelPolicy>,
 ) -> Result<()> {
-    ctx.accounts.listing_config.version = ListingConfigVersion::V0;
+    assert_valid_soft_close(soft_close.as_ref(), end_time)?;
+
+    ctx.accounts.listing_config.version = ListingConfigVersion::V1;
     ctx.accounts.listing_config.highest_bid.version = ListingConfigVersion::V0;
     ctx.accounts.listing_config.start_time = start_time;
     ctx.accounts.listing_config.end_time = end_time;
@@ -129,6 +137,11 @@ pub fn auctioneer_sell<'info>(
     ctx.accounts.listing_config.time_ext_period = time_ext_period.unwrap_or(0);
     ctx.accounts.listing_config.time_ext_delta = time_ext_delta.unwrap_or(0);
     ctx.accounts.listing_config.allow_high_bid_cancel = allow_high_bid_cancel.unwrap_or(false);
+    ctx.accounts.listing_config.bid_gate = bid_gate;
+    ctx.accounts.listing_config.soft_close = soft_close;
+    ctx.accounts.listing_config.extensions = 0;
+    ctx.accounts.listing_config.cancel_policy = cancel_policy;
+    ctx.accounts.listing_config.has_bid_history = false;
     ctx.accounts.listing_config.bump = *ctx
         .bumps
         .get("listing_config")
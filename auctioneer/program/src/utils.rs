@@ -1,4 +1,9 @@
+use std::slice::Iter;
+
 use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use mpl_token_metadata::state::{Metadata, TokenMetadataAccount};
+use solana_program::clock::UnixTimestamp;
 
 use crate::{errors::*, sell::config::*};
 
@@ -63,12 +68,134 @@ pub fn assert_highest_bidder(
     Ok(())
 }
 
-pub fn process_time_extension(listing_config: &mut Account<ListingConfig>) -> Result<()> {
+/// Extend `end_time` by `time_ext_delta` when a bid of `new_bid_price` lands within
+/// `time_ext_period` of it, scaled and limited by the listing's soft close schedule.
+pub fn process_time_extension(
+    listing_config: &mut Account<ListingConfig>,
+    new_bid_price: u64,
+) -> Result<()> {
+    let previous_bid = listing_config.highest_bid.amount;
+    let listing_config: &mut ListingConfig = listing_config;
+
+    extend_end_time(
+        &mut listing_config.end_time,
+        &mut listing_config.extensions,
+        listing_config.time_ext_period,
+        listing_config.time_ext_delta,
+        listing_config.soft_close.as_ref(),
+        previous_bid,
+        new_bid_price,
+    )
+}
+
+/// Extend `end_time` by `time_ext_delta` when a bid raising `previous_bid` to `new_bid` lands
+/// within `time_ext_period` of it, scaled and limited by `soft_close`. Shared by every auction
+/// type with time extensions.
+pub fn extend_end_time(
+    end_time: &mut UnixTimestamp,
+    extensions: &mut u16,
+    time_ext_period: u32,
+    time_ext_delta: u32,
+    soft_close: Option<&SoftClose>,
+    previous_bid: u64,
+    new_bid: u64,
+) -> Result<()> {
     let clock = Clock::get()?;
     let current_timestamp = clock.unix_timestamp;
 
-    if current_timestamp >= (listing_config.end_time - i64::from(listing_config.time_ext_period)) {
-        listing_config.end_time += i64::from(listing_config.time_ext_delta);
+    if current_timestamp < (*end_time - i64::from(time_ext_period)) {
+        return Ok(());
+    }
+
+    let mut delta = i64::from(time_ext_delta);
+    let mut extended_end_time = *end_time;
+    if let Some(soft_close) = soft_close {
+        if let Some(max_extensions) = soft_close.max_extensions {
+            if *extensions >= max_extensions {
+                return Ok(());
+            }
+        }
+
+        if previous_bid > 0 {
+            let raise_percent =
+                u128::from(new_bid.saturating_sub(previous_bid)) * 100 / u128::from(previous_bid);
+            let scaled_delta = raise_percent * u128::from(soft_close.delta_per_percent);
+            delta = delta.saturating_add(i64::try_from(scaled_delta).unwrap_or(i64::MAX));
+        }
+
+        extended_end_time = extended_end_time.saturating_add(delta);
+        if let Some(hard_end_time) = soft_close.hard_end_time {
+            extended_end_time = extended_end_time.min(hard_end_time);
+        }
+    } else {
+        extended_end_time += delta;
+    }
+
+    if extended_end_time > *end_time {
+        *end_time = extended_end_time;
+        *extensions = extensions.saturating_add(1);
+    }
+
+    Ok(())
+}
+
+/// Reject a soft close schedule whose hard end time comes before the end of the auction.
+pub fn assert_valid_soft_close(
+    soft_close: Option<&SoftClose>,
+    end_time: UnixTimestamp,
+) -> Result<()> {
+    if let Some(hard_end_time) = soft_close.and_then(|soft_close| soft_close.hard_end_time) {
+        if hard_end_time < end_time {
+            return err!(AuctioneerError::InvalidSoftClose);
+        }
+    }
+
+    Ok(())
+}
+
+/// Check the bidder holds what the listing's bid gate requires, reading a token account of the
+/// bidder and, for a collection gate, that token's metadata from the remaining accounts. The
+/// iterator is left untouched when the listing has no gate.
+pub fn assert_bid_gate(
+    listing_config: &Account<ListingConfig>,
+    remaining_accounts: &mut Iter<AccountInfo>,
+    bidder: &Pubkey,
+) -> Result<()> {
+    let bid_gate = match &listing_config.bid_gate {
+        Some(bid_gate) => bid_gate,
+        None => return Ok(()),
+    };
+
+    let token_account_info = remaining_accounts
+        .next()
+        .ok_or(AuctioneerError::BidGateNotMet)?;
+    let token_account: Account<TokenAccount> = Account::try_from(token_account_info)?;
+    if token_account.owner != *bidder {
+        return err!(AuctioneerError::BidGateNotMet);
+    }
+
+    match bid_gate {
+        BidGate::Collection(collection_mint) => {
+            let metadata_info = remaining_accounts
+                .next()
+                .ok_or(AuctioneerError::BidGateNotMet)?;
+            if *metadata_info.owner != mpl_token_metadata::id() {
+                return err!(AuctioneerError::BidGateNotMet);
+            }
+            let metadata = Metadata::from_account_info(metadata_info)?;
+            if metadata.mint != token_account.mint || token_account.amount == 0 {
+                return err!(AuctioneerError::BidGateNotMet);
+            }
+            match metadata.collection {
+                Some(collection) if collection.verified && collection.key == *collection_mint => {}
+                _ => return err!(AuctioneerError::BidGateNotMet),
+            }
+        }
+        BidGate::Token { mint, amount } => {
+            if token_account.mint != *mint || token_account.amount < *amount {
+                return err!(AuctioneerError::BidGateNotMet);
+            }
+        }
     }
 
     Ok(())
@@ -0,0 +1,511 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+
+use mpl_auction_house::pda::{
+    find_escrow_payment_address, find_program_as_signer_address, find_trade_state_address,
+};
+use mpl_auctioneer::{
+    bid_history::BidHistory,
+    pda::{find_auctioneer_authority_seeds, find_bid_history_address},
+};
+use solana_program::{instruction::AccountMeta, program_pack::Pack, system_program};
+use solana_sdk::{compute_budget::ComputeBudgetInstruction, pubkey::Pubkey};
+use spl_token::state::Account;
+use std::time::SystemTime;
+use utils::setup_functions::*;
+
+fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs() as i64
+}
+
+async fn create_bid_history(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    test_metadata: &Metadata,
+    listing_config: &Pubkey,
+    forfeit_deposit: u64,
+) -> Pubkey {
+    let (bid_history, _) = find_bid_history_address(listing_config);
+    let accounts = mpl_auctioneer::accounts::CreateBidHistory {
+        seller: test_metadata.token.pubkey(),
+        auction_house: *ahkey,
+        token_account: get_associated_token_address(
+            &test_metadata.token.pubkey(),
+            &test_metadata.mint.pubkey(),
+        ),
+        listing_config: *listing_config,
+        bid_history,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+    let data = mpl_auctioneer::instruction::CreateBidHistory {
+        token_size: 1,
+        forfeit_deposit,
+    }
+    .data();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[Instruction {
+            program_id: mpl_auctioneer::id(),
+            data,
+            accounts,
+        }],
+        Some(&test_metadata.token.pubkey()),
+        &[&test_metadata.token],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    bid_history
+}
+
+/// Deposit and bid `price` with the bid history passed as the first remaining account.
+async fn buy_with_history(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    test_metadata: &Metadata,
+    listing_config: &Pubkey,
+    bid_history: &Pubkey,
+    price: u64,
+) -> Keypair {
+    let buyer = Keypair::new();
+    airdrop(context, &buyer.pubkey(), TEN_SOL).await.unwrap();
+    let (_, deposit_tx) = deposit(context, ahkey, ah, test_metadata, &buyer, price);
+    context
+        .banks_client
+        .process_transaction(deposit_tx)
+        .await
+        .unwrap();
+
+    let (accounts, _) = buy(
+        context,
+        ahkey,
+        ah,
+        test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        &test_metadata.token.pubkey(),
+        listing_config,
+        price,
+    );
+    let (_, trade_state_bump) = find_trade_state_address(
+        &buyer.pubkey(),
+        ahkey,
+        &accounts.token_account,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        price,
+        1,
+    );
+    let (_, escrow_payment_bump) = find_escrow_payment_address(ahkey, &buyer.pubkey());
+    let (_, auctioneer_authority_bump) = find_auctioneer_authority_seeds(ahkey);
+
+    let mut account_metas = accounts.to_account_metas(None);
+    account_metas.push(AccountMeta::new(*bid_history, false));
+    let data = mpl_auctioneer::instruction::Buy {
+        trade_state_bump,
+        escrow_payment_bump,
+        auctioneer_authority_bump,
+        buyer_price: price,
+        token_size: 1,
+    }
+    .data();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[Instruction {
+            program_id: mpl_auctioneer::id(),
+            data,
+            accounts: account_metas,
+        }],
+        Some(&buyer.pubkey()),
+        &[&buyer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    buyer
+}
+
+async fn list_nft(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+) -> (Metadata, mpl_auctioneer::accounts::AuctioneerSell, Pubkey) {
+    let test_metadata = Metadata::new();
+    airdrop(context, &test_metadata.token.pubkey(), ONE_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let ((sell_acc, listing_config), sell_tx) = sell(
+        context,
+        ahkey,
+        ah,
+        &test_metadata,
+        now() - 60,
+        now() + 60,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    (test_metadata, sell_acc, listing_config)
+}
+
+/// Settle with `buyer` at `buyer_price`, passing the bid history followed by the escrows of
+/// `defaulted`.
+#[allow(clippy::too_many_arguments)]
+fn execute_second_chance_sale(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    authority: &Keypair,
+    test_metadata: &Metadata,
+    sell_acc: &mpl_auctioneer::accounts::AuctioneerSell,
+    listing_config: &Pubkey,
+    bid_history: &Pubkey,
+    defaulted: &[Pubkey],
+    buyer: &Pubkey,
+    buyer_price: u64,
+) -> Instruction {
+    let (buyer_trade_state, _) = find_trade_state_address(
+        buyer,
+        ahkey,
+        &sell_acc.token_account,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        buyer_price,
+        1,
+    );
+    let (accounts, _) = execute_sale(
+        context,
+        listing_config,
+        ahkey,
+        ah,
+        authority,
+        test_metadata,
+        buyer,
+        &test_metadata.token.pubkey(),
+        &sell_acc.token_account,
+        &sell_acc.seller_trade_state,
+        &buyer_trade_state,
+        1,
+        buyer_price,
+    );
+    let (_, free_trade_state_bump) = find_trade_state_address(
+        &test_metadata.token.pubkey(),
+        ahkey,
+        &sell_acc.token_account,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        0,
+        1,
+    );
+    let (_, escrow_payment_bump) = find_escrow_payment_address(ahkey, buyer);
+    let (_, program_as_signer_bump) = find_program_as_signer_address();
+    let (_, auctioneer_authority_bump) = find_auctioneer_authority_seeds(ahkey);
+
+    let mut account_metas = accounts.to_account_metas(None);
+    account_metas.push(AccountMeta::new_readonly(*bid_history, false));
+    account_metas.extend(defaulted.iter().map(|bidder| {
+        AccountMeta::new_readonly(find_escrow_payment_address(ahkey, bidder).0, false)
+    }));
+    let data = mpl_auctioneer::instruction::ExecuteSecondChanceSale {
+        escrow_payment_bump,
+        free_trade_state_bump,
+        program_as_signer_bump,
+        auctioneer_authority_bump,
+        buyer_price,
+        token_size: 1,
+    }
+    .data();
+
+    Instruction {
+        program_id: mpl_auctioneer::id(),
+        data,
+        accounts: account_metas,
+    }
+}
+
+#[tokio::test]
+async fn bid_history_records_bids() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let (test_metadata, _, listing_config) = list_nft(&mut context, &ahkey, &ah).await;
+    let bid_history =
+        create_bid_history(&mut context, &ahkey, &test_metadata, &listing_config, 0).await;
+
+    let first = buy_with_history(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &listing_config,
+        &bid_history,
+        ONE_SOL,
+    )
+    .await;
+    let second = buy_with_history(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &listing_config,
+        &bid_history,
+        2 * ONE_SOL,
+    )
+    .await;
+
+    let bid_history_account = context
+        .banks_client
+        .get_account(bid_history)
+        .await
+        .expect("Error getting bid history")
+        .expect("Bid history empty");
+    let bid_history = BidHistory::try_deserialize(&mut bid_history_account.data.as_ref()).unwrap();
+
+    assert_eq!(bid_history.listing_config, listing_config);
+    assert_eq!(bid_history.len, 2);
+    let bids: Vec<_> = bid_history
+        .newest_first()
+        .map(|record| (record.bidder, record.amount))
+        .collect();
+    assert_eq!(
+        bids,
+        vec![(second.pubkey(), 2 * ONE_SOL), (first.pubkey(), ONE_SOL)]
+    );
+}
+
+#[tokio::test]
+async fn execute_second_chance_sale_success() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let (test_metadata, sell_acc, listing_config) = list_nft(&mut context, &ahkey, &ah).await;
+    let bid_history =
+        create_bid_history(&mut context, &ahkey, &test_metadata, &listing_config, 0).await;
+
+    let first = buy_with_history(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &listing_config,
+        &bid_history,
+        ONE_SOL,
+    )
+    .await;
+    let second = buy_with_history(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &listing_config,
+        &bid_history,
+        2 * ONE_SOL,
+    )
+    .await;
+
+    // The winner empties their escrow before settlement.
+    let (_, withdraw_tx) = withdraw(
+        &mut context,
+        &second,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        2 * ONE_SOL,
+        2 * ONE_SOL,
+    );
+    context
+        .banks_client
+        .process_transaction(withdraw_tx)
+        .await
+        .unwrap();
+
+    context.warp_to_slot(300_000).unwrap();
+    airdrop(&mut context, &ah.auction_house_fee_account, TEN_SOL)
+        .await
+        .unwrap();
+
+    let ix = execute_second_chance_sale(
+        &mut context,
+        &ahkey,
+        &ah,
+        &authority,
+        &test_metadata,
+        &sell_acc,
+        &listing_config,
+        &bid_history,
+        &[second.pubkey()],
+        &first.pubkey(),
+        ONE_SOL,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            ComputeBudgetInstruction::set_compute_unit_limit(400_000),
+            ix,
+        ],
+        Some(&authority.pubkey()),
+        &[&authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let buyer_token_account = context
+        .banks_client
+        .get_account(get_associated_token_address(
+            &first.pubkey(),
+            &test_metadata.mint.pubkey(),
+        ))
+        .await
+        .unwrap()
+        .unwrap();
+    let buyer_token_account = Account::unpack_from_slice(&buyer_token_account.data).unwrap();
+    assert_eq!(buyer_token_account.amount, 1);
+}
+
+#[tokio::test]
+async fn execute_second_chance_sale_funded_winner_fails() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let (test_metadata, sell_acc, listing_config) = list_nft(&mut context, &ahkey, &ah).await;
+    let bid_history =
+        create_bid_history(&mut context, &ahkey, &test_metadata, &listing_config, 0).await;
+
+    let first = buy_with_history(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &listing_config,
+        &bid_history,
+        ONE_SOL,
+    )
+    .await;
+    let second = buy_with_history(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &listing_config,
+        &bid_history,
+        2 * ONE_SOL,
+    )
+    .await;
+
+    context.warp_to_slot(300_000).unwrap();
+
+    let ix = execute_second_chance_sale(
+        &mut context,
+        &ahkey,
+        &ah,
+        &authority,
+        &test_metadata,
+        &sell_acc,
+        &listing_config,
+        &bid_history,
+        &[second.pubkey()],
+        &first.pubkey(),
+        ONE_SOL,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&authority.pubkey()),
+        &[&authority],
+        context.last_blockhash,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+    assert_error!(err, HIGHER_BID_STILL_FUNDED);
+}
+
+#[tokio::test]
+async fn buy_without_bid_history_fails() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let (test_metadata, _, listing_config) = list_nft(&mut context, &ahkey, &ah).await;
+    let bid_history =
+        create_bid_history(&mut context, &ahkey, &test_metadata, &listing_config, 0).await;
+
+    buy_with_history(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &listing_config,
+        &bid_history,
+        ONE_SOL,
+    )
+    .await;
+
+    let winner = Keypair::new();
+    airdrop(&mut context, &winner.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    let (_, deposit_tx) = deposit(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &winner,
+        2 * ONE_SOL,
+    );
+    context
+        .banks_client
+        .process_transaction(deposit_tx)
+        .await
+        .unwrap();
+    let (_, buy_tx) = buy(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        &winner,
+        &test_metadata.token.pubkey(),
+        &listing_config,
+        2 * ONE_SOL,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap_err();
+    assert_error!(err, MISSING_BID_HISTORY);
+}
@@ -3,7 +3,13 @@ pub mod common;
 pub mod utils;
 
 use common::*;
-use mpl_auctioneer::sell::config::ListingConfig;
+use mpl_auction_house::pda::{find_escrow_payment_address, find_trade_state_address};
+use mpl_auctioneer::{
+    pda::find_auctioneer_authority_seeds,
+    sell::config::{BidGate, ListingConfig, SoftClose},
+};
+use solana_program::instruction::AccountMeta;
+use solana_sdk::pubkey::Pubkey;
 use std::{assert_eq, time::SystemTime};
 use utils::setup_functions::*;
 
@@ -838,3 +844,495 @@ async fn multiple_bids_time_ext_success() {
     let config2 = ListingConfig::try_deserialize(&mut listing2.as_ref()).unwrap();
     assert_eq!(config2.end_time, end_time_t0 + 120);
 }
+
+/// Deposit `price` for a new bidder and bid it.
+async fn place_bid(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    test_metadata: &Metadata,
+    listing_config: &Pubkey,
+    price: u64,
+) {
+    let buyer = Keypair::new();
+    airdrop(context, &buyer.pubkey(), price + ONE_SOL)
+        .await
+        .unwrap();
+    let (_, deposit_tx) = deposit(context, ahkey, ah, test_metadata, &buyer, price);
+    context
+        .banks_client
+        .process_transaction(deposit_tx)
+        .await
+        .unwrap();
+
+    let (_, buy_tx) = buy(
+        context,
+        ahkey,
+        ah,
+        test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        &test_metadata.token.pubkey(),
+        listing_config,
+        price,
+    );
+    context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap();
+}
+
+async fn get_listing_config(
+    context: &mut ProgramTestContext,
+    listing_config: &Pubkey,
+) -> ListingConfig {
+    let listing = context
+        .banks_client
+        .get_account(*listing_config)
+        .await
+        .unwrap()
+        .unwrap()
+        .data;
+    ListingConfig::try_deserialize(&mut listing.as_ref()).unwrap()
+}
+
+/// List a new NFT with a 60 second extension period and delta, returning its end time.
+async fn list_with_soft_close(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    test_metadata: &Metadata,
+    soft_close: impl FnOnce(i64) -> SoftClose,
+) -> (Pubkey, i64) {
+    airdrop(context, &test_metadata.token.pubkey(), ONE_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs() as i64;
+    let end_time = now + 60;
+    let ((_, listing_config_address), sell_tx) = sell_with_options(
+        context,
+        ahkey,
+        ah,
+        test_metadata,
+        now - 60,
+        end_time,
+        None,
+        None,
+        Some(60),
+        Some(60),
+        None,
+        None,
+        Some(soft_close(end_time)),
+        None,
+    );
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    (listing_config_address, end_time)
+}
+
+#[tokio::test]
+async fn soft_close_scaled_extension_capped_by_hard_end() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = Metadata::new();
+    let (listing_config, end_time) =
+        list_with_soft_close(&mut context, &ahkey, &ah, &test_metadata, |end_time| {
+            SoftClose {
+                hard_end_time: Some(end_time + 300),
+                max_extensions: None,
+                delta_per_percent: 1,
+            }
+        })
+        .await;
+
+    context.warp_to_slot(400).unwrap();
+
+    // The opening bid has no previous bid to scale against.
+    place_bid(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &listing_config,
+        ONE_SOL,
+    )
+    .await;
+    let config = get_listing_config(&mut context, &listing_config).await;
+    assert_eq!(config.end_time, end_time + 60);
+    assert_eq!(config.extensions, 1);
+
+    context.warp_to_slot(121 * 400).unwrap();
+
+    // Raising the bid by 900% adds 900 seconds, capped by the hard end time.
+    place_bid(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &listing_config,
+        10 * ONE_SOL,
+    )
+    .await;
+    let config = get_listing_config(&mut context, &listing_config).await;
+    assert_eq!(config.end_time, end_time + 300);
+    assert_eq!(config.extensions, 2);
+}
+
+#[tokio::test]
+async fn soft_close_stops_after_max_extensions() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = Metadata::new();
+    let (listing_config, end_time) =
+        list_with_soft_close(&mut context, &ahkey, &ah, &test_metadata, |_| SoftClose {
+            hard_end_time: None,
+            max_extensions: Some(1),
+            delta_per_percent: 0,
+        })
+        .await;
+
+    context.warp_to_slot(400).unwrap();
+
+    place_bid(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &listing_config,
+        ONE_SOL,
+    )
+    .await;
+    context.warp_to_slot(121 * 400).unwrap();
+    place_bid(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &listing_config,
+        2 * ONE_SOL,
+    )
+    .await;
+
+    let config = get_listing_config(&mut context, &listing_config).await;
+    assert_eq!(config.end_time, end_time + 60);
+    assert_eq!(config.extensions, 1);
+}
+
+#[tokio::test]
+async fn sell_hard_end_before_end_time_fails() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = Metadata::new();
+    airdrop(&mut context, &test_metadata.token.pubkey(), ONE_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs() as i64;
+    let (_, sell_tx) = sell_with_options(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        now - 60,
+        now + 60,
+        None,
+        None,
+        Some(60),
+        Some(60),
+        None,
+        None,
+        Some(SoftClose {
+            hard_end_time: Some(now),
+            max_extensions: None,
+            delta_per_percent: 0,
+        }),
+        None,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap_err();
+    assert_error!(err, INVALID_SOFT_CLOSE);
+}
+
+/// Bid `price` with `remaining_accounts` appended to the `buy` accounts.
+#[allow(clippy::too_many_arguments)]
+fn buy_with_remaining_accounts(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    test_metadata: &Metadata,
+    buyer: &Keypair,
+    listing_config: &Pubkey,
+    price: u64,
+    remaining_accounts: Vec<AccountMeta>,
+) -> Transaction {
+    let (accounts, _) = buy(
+        context,
+        ahkey,
+        ah,
+        test_metadata,
+        &test_metadata.token.pubkey(),
+        buyer,
+        &test_metadata.token.pubkey(),
+        listing_config,
+        price,
+    );
+    let (_, trade_state_bump) = find_trade_state_address(
+        &buyer.pubkey(),
+        ahkey,
+        &accounts.token_account,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        price,
+        1,
+    );
+    let (_, escrow_payment_bump) = find_escrow_payment_address(ahkey, &buyer.pubkey());
+    let (_, auctioneer_authority_bump) = find_auctioneer_authority_seeds(ahkey);
+
+    let mut account_metas = accounts.to_account_metas(None);
+    account_metas.extend(remaining_accounts);
+    let data = mpl_auctioneer::instruction::Buy {
+        trade_state_bump,
+        escrow_payment_bump,
+        auctioneer_authority_bump,
+        buyer_price: price,
+        token_size: 1,
+    }
+    .data();
+
+    Transaction::new_signed_with_payer(
+        &[Instruction {
+            program_id: mpl_auctioneer::id(),
+            data,
+            accounts: account_metas,
+        }],
+        Some(&buyer.pubkey()),
+        &[buyer],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn buy_token_gated_success() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = Metadata::new();
+    airdrop(&mut context, &test_metadata.token.pubkey(), ONE_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    // The bidder holds an NFT of the gate mint.
+    let gate_metadata = Metadata::new();
+    airdrop(&mut context, &gate_metadata.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    gate_metadata
+        .create(
+            &mut context,
+            "Gate".to_string(),
+            "GTE".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs() as i64;
+    let ((_, listing_config_address), sell_tx) = sell_with_options(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        now - 60,
+        now + 60,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(BidGate::Token {
+            mint: gate_metadata.mint.pubkey(),
+            amount: 1,
+        }),
+        None,
+        None,
+    );
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    let buyer = &gate_metadata.token;
+    let (_, deposit_tx) = deposit(&mut context, &ahkey, &ah, &test_metadata, buyer, ONE_SOL);
+    context
+        .banks_client
+        .process_transaction(deposit_tx)
+        .await
+        .unwrap();
+
+    let buy_tx = buy_with_remaining_accounts(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        buyer,
+        &listing_config_address,
+        ONE_SOL,
+        vec![AccountMeta::new_readonly(
+            get_associated_token_address(&buyer.pubkey(), &gate_metadata.mint.pubkey()),
+            false,
+        )],
+    );
+    context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn buy_collection_gated_non_holder_fails() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = Metadata::new();
+    airdrop(&mut context, &test_metadata.token.pubkey(), ONE_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs() as i64;
+    let ((_, listing_config_address), sell_tx) = sell_with_options(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        now - 60,
+        now + 60,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(BidGate::Collection(Keypair::new().pubkey())),
+        None,
+        None,
+    );
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    let buyer = Keypair::new();
+    airdrop(&mut context, &buyer.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    let (_, deposit_tx) = deposit(&mut context, &ahkey, &ah, &test_metadata, &buyer, ONE_SOL);
+    context
+        .banks_client
+        .process_transaction(deposit_tx)
+        .await
+        .unwrap();
+
+    let buy_tx = buy_with_remaining_accounts(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &buyer,
+        &listing_config_address,
+        ONE_SOL,
+        vec![],
+    );
+    let err = context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap_err();
+    assert_error!(err, BID_GATE_NOT_MET);
+}
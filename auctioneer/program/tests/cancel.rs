@@ -3,11 +3,144 @@ pub mod common;
 pub mod utils;
 
 use common::*;
-use mpl_auctioneer::pda::*;
+use mpl_auction_house::pda::find_trade_state_address;
+use mpl_auctioneer::{
+    pda::*,
+    sell::config::{CancelPolicy, ListingConfig},
+};
+use solana_program::{instruction::AccountMeta, system_program};
 use solana_sdk::signature::Keypair;
 use std::time::SystemTime;
 use utils::setup_functions::*;
 
+fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs() as i64
+}
+
+/// List with `cancel_policy` and place a bid of `price`, returning the listing accounts and the
+/// bidder.
+async fn list_with_bid(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    test_metadata: &Metadata,
+    allow_high_bid_cancel: Option<bool>,
+    cancel_policy: CancelPolicy,
+    price: u64,
+) -> ((mpl_auctioneer::accounts::AuctioneerSell, Pubkey), Keypair) {
+    airdrop(context, &test_metadata.token.pubkey(), 1000000000)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            context,
+            "Tests".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let ((sell_acc, listing_config_address), sell_tx) = sell_with_options(
+        context,
+        ahkey,
+        ah,
+        test_metadata,
+        now() - 60,
+        now() + 60,
+        None,
+        None,
+        None,
+        None,
+        allow_high_bid_cancel,
+        None,
+        None,
+        Some(cancel_policy),
+    );
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    context.warp_to_slot(100).unwrap();
+    let buyer = Keypair::new();
+    airdrop(context, &buyer.pubkey(), 2000000000).await.unwrap();
+    let (_, buy_tx) = buy(
+        context,
+        ahkey,
+        ah,
+        test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        &sell_acc.wallet,
+        &listing_config_address,
+        price,
+    );
+    context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap();
+
+    ((sell_acc, listing_config_address), buyer)
+}
+
+fn seller_cancel(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    test_metadata: &Metadata,
+    sell_acc: &mpl_auctioneer::accounts::AuctioneerSell,
+    listing_config_address: &Pubkey,
+    remaining_accounts: Vec<AccountMeta>,
+) -> Transaction {
+    let (auctioneer_authority, aa_bump) = find_auctioneer_authority_seeds(ahkey);
+    let (auctioneer_pda, _) = find_auctioneer_pda(ahkey, &auctioneer_authority);
+    let mut accounts = mpl_auctioneer::accounts::AuctioneerCancel {
+        auction_house_program: mpl_auction_house::id(),
+        listing_config: *listing_config_address,
+        seller: sell_acc.wallet,
+        auction_house: *ahkey,
+        wallet: sell_acc.wallet,
+        token_account: sell_acc.token_account,
+        authority: ah.authority,
+        trade_state: sell_acc.seller_trade_state,
+        token_program: spl_token::id(),
+        token_mint: test_metadata.mint.pubkey(),
+        auction_house_fee_account: ah.auction_house_fee_account,
+        auctioneer_authority,
+        ah_auctioneer_pda: auctioneer_pda,
+    }
+    .to_account_metas(None);
+    accounts.extend(remaining_accounts);
+
+    let instruction = Instruction {
+        program_id: mpl_auctioneer::id(),
+        data: mpl_auctioneer::instruction::Cancel {
+            auctioneer_authority_bump: aa_bump,
+            buyer_price: u64::MAX,
+            token_size: 1,
+        }
+        .data(),
+        accounts,
+    };
+
+    Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&test_metadata.token.pubkey()),
+        &[&test_metadata.token],
+        context.last_blockhash,
+    )
+}
+
 #[tokio::test]
 async fn cancel_listing() {
     let mut context = auctioneer_program_test().start_with_context().await;
@@ -467,3 +600,207 @@ async fn cancel_highest_bid() {
     );
     context.banks_client.process_transaction(tx2).await.unwrap();
 }
+
+#[tokio::test]
+async fn cancel_listing_after_first_bid_fails() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = Metadata::new();
+    let ((sell_acc, listing_config_address), _) = list_with_bid(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        None,
+        CancelPolicy::BeforeFirstBid,
+        1000000000,
+    )
+    .await;
+
+    let tx = seller_cancel(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &sell_acc,
+        &listing_config_address,
+        vec![],
+    );
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+    assert_error!(err, CANCEL_AFTER_FIRST_BID);
+}
+
+#[tokio::test]
+async fn cancel_listing_with_penalty_pays_highest_bidder() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = Metadata::new();
+    let penalty = 100000000;
+    let ((sell_acc, listing_config_address), buyer) = list_with_bid(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        None,
+        CancelPolicy::Penalty(penalty),
+        1000000000,
+    )
+    .await;
+
+    let listing_config_account = context
+        .banks_client
+        .get_account(listing_config_address)
+        .await
+        .unwrap()
+        .unwrap();
+    let listing_config =
+        ListingConfig::try_deserialize(&mut listing_config_account.data.as_ref()).unwrap();
+    assert_eq!(
+        listing_config.cancel_policy,
+        Some(CancelPolicy::Penalty(penalty))
+    );
+
+    let buyer_before = context
+        .banks_client
+        .get_account(buyer.pubkey())
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    let tx = seller_cancel(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &sell_acc,
+        &listing_config_address,
+        vec![
+            AccountMeta::new(buyer.pubkey(), false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let buyer_after = context
+        .banks_client
+        .get_account(buyer.pubkey())
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(buyer_after - buyer_before, penalty);
+
+    let listing_config_closed = context
+        .banks_client
+        .get_account(listing_config_address)
+        .await
+        .unwrap();
+    assert!(listing_config_closed.is_none());
+}
+
+#[tokio::test]
+async fn cancel_listing_after_highest_bid_canceled() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = Metadata::new();
+    let price = 1000000000;
+    let ((sell_acc, listing_config_address), buyer) = list_with_bid(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        Some(true),
+        CancelPolicy::Penalty(100000000),
+        price,
+    )
+    .await;
+
+    let (buyer_trade_state, _) = find_trade_state_address(
+        &buyer.pubkey(),
+        &ahkey,
+        &sell_acc.token_account,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        price,
+        1,
+    );
+    let (auctioneer_authority, aa_bump) = find_auctioneer_authority_seeds(&ahkey);
+    let (auctioneer_pda, _) = find_auctioneer_pda(&ahkey, &auctioneer_authority);
+    let accounts = mpl_auctioneer::accounts::AuctioneerCancel {
+        auction_house_program: mpl_auction_house::id(),
+        listing_config: listing_config_address,
+        seller: sell_acc.wallet,
+        auction_house: ahkey,
+        wallet: buyer.pubkey(),
+        token_account: sell_acc.token_account,
+        authority: ah.authority,
+        trade_state: buyer_trade_state,
+        token_program: spl_token::id(),
+        token_mint: test_metadata.mint.pubkey(),
+        auction_house_fee_account: ah.auction_house_fee_account,
+        auctioneer_authority,
+        ah_auctioneer_pda: auctioneer_pda,
+    }
+    .to_account_metas(None);
+    let instruction = Instruction {
+        program_id: mpl_auctioneer::id(),
+        data: mpl_auctioneer::instruction::Cancel {
+            auctioneer_authority_bump: aa_bump,
+            buyer_price: price,
+            token_size: 1,
+        }
+        .data(),
+        accounts,
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&buyer.pubkey()),
+        &[&buyer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let listing_config_account = context
+        .banks_client
+        .get_account(listing_config_address)
+        .await
+        .unwrap()
+        .unwrap();
+    let listing_config =
+        ListingConfig::try_deserialize(&mut listing_config_account.data.as_ref()).unwrap();
+    assert_eq!(listing_config.highest_bid.amount, 0);
+    assert_eq!(
+        listing_config.highest_bid.buyer_trade_state,
+        Pubkey::default()
+    );
+
+    // With no bid left the seller cancels without paying the penalty.
+    let tx = seller_cancel(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &sell_acc,
+        &listing_config_address,
+        vec![],
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let listing_config_closed = context
+        .banks_client
+        .get_account(listing_config_address)
+        .await
+        .unwrap();
+    assert!(listing_config_closed.is_none());
+}
@@ -38,3 +38,17 @@ pub const NOT_HIGH_BIDDER: u32 = 6006;
 pub const BELOW_RESERVE_PRICE: u32 = 6007;
 pub const BELOW_BID_INCREMENT: u32 = 6008;
 pub const CANNOT_CANCEL_HIGHEST_BID: u32 = 6009;
+pub const INVALID_BID_QUANTITY: u32 = 6011;
+pub const NOT_WINNING_BID: u32 = 6013;
+pub const HIGHER_BID_STILL_FUNDED: u32 = 6023;
+pub const BID_GATE_NOT_MET: u32 = 6025;
+pub const INVALID_SOFT_CLOSE: u32 = 6026;
+pub const INVALID_TICKET_QUANTITY: u32 = 6027;
+pub const INVALID_RAFFLE_SEED: u32 = 6029;
+pub const RAFFLE_REQUIRES_NATIVE_MINT: u32 = 6033;
+pub const REVEAL_PERIOD_OVER: u32 = 6035;
+pub const CANCEL_AFTER_FIRST_BID: u32 = 6041;
+pub const MULTI_UNIT_BOOK_FULL: u32 = 6044;
+pub const WINNING_BID_STILL_FUNDED: u32 = 6045;
+pub const MISSING_BID_HISTORY: u32 = 6046;
+pub const RAFFLE_DRAW_NOT_COMMITTED: u32 = 6048;
@@ -0,0 +1,705 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+
+use anchor_lang::{InstructionData, ToAccountMetas};
+use mpl_testing_utils::{solana::airdrop, utils::Metadata};
+use solana_sdk::{compute_budget::ComputeBudgetInstruction, signer::Signer};
+
+use std::{assert_eq, result::Result as StdResult, time::SystemTime};
+
+use solana_program::{instruction::Instruction, system_program, sysvar};
+
+use solana_program::program_pack::Pack;
+
+use mpl_auction_house::pda::{
+    find_auctioneer_pda, find_auctioneer_trade_state_address, find_escrow_payment_address,
+    find_program_as_signer_address, find_trade_state_address,
+};
+use mpl_auctioneer::{
+    multi_unit::{MultiUnitListing, SettlementMode, MAX_MULTI_UNIT_BIDS},
+    pda::{find_auctioneer_authority_seeds, find_multi_unit_listing_address},
+};
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, transaction::Transaction};
+use spl_associated_token_account::get_associated_token_address;
+use spl_token::state::Account;
+use utils::setup_functions::*;
+
+const UNITS: u64 = 3;
+
+fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs() as i64
+}
+
+async fn sell_multi_unit(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    test_metadata: &Metadata,
+    max_winners: u8,
+    settlement: SettlementMode,
+) -> Pubkey {
+    let token =
+        get_associated_token_address(&test_metadata.token.pubkey(), &test_metadata.mint.pubkey());
+    let (seller_trade_state, sts_bump) = find_auctioneer_trade_state_address(
+        &test_metadata.token.pubkey(),
+        ahkey,
+        &token,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        UNITS,
+    );
+    let (free_seller_trade_state, free_sts_bump) = find_trade_state_address(
+        &test_metadata.token.pubkey(),
+        ahkey,
+        &token,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        0,
+        UNITS,
+    );
+    let (multi_unit_listing, _) = find_multi_unit_listing_address(
+        &test_metadata.token.pubkey(),
+        ahkey,
+        &token,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        UNITS,
+    );
+    let (program_as_signer, pas_bump) = find_program_as_signer_address();
+    let (auctioneer_authority, aa_bump) = find_auctioneer_authority_seeds(ahkey);
+    let (auctioneer_pda, _) = find_auctioneer_pda(ahkey, &auctioneer_authority);
+
+    let accounts = mpl_auctioneer::accounts::AuctioneerSellMultiUnit {
+        auction_house_program: mpl_auction_house::id(),
+        multi_unit_listing,
+        wallet: test_metadata.token.pubkey(),
+        token_account: token,
+        metadata: test_metadata.pubkey,
+        authority: ah.authority,
+        auction_house: *ahkey,
+        auction_house_fee_account: ah.auction_house_fee_account,
+        seller_trade_state,
+        free_seller_trade_state,
+        auctioneer_authority,
+        ah_auctioneer_pda: auctioneer_pda,
+        program_as_signer,
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auctioneer::instruction::SellMultiUnit {
+        trade_state_bump: sts_bump,
+        free_trade_state_bump: free_sts_bump,
+        program_as_signer_bump: pas_bump,
+        auctioneer_authority_bump: aa_bump,
+        token_size: UNITS,
+        start_time: now() - 60,
+        end_time: now() + 60,
+        reserve_price: None,
+        time_ext_period: None,
+        time_ext_delta: None,
+        max_winners,
+        settlement,
+        soft_close: None,
+    }
+    .data();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[Instruction {
+            program_id: mpl_auctioneer::id(),
+            data,
+            accounts,
+        }],
+        Some(&test_metadata.token.pubkey()),
+        &[&test_metadata.token],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    multi_unit_listing
+}
+
+/// Bid `price` in total for `quantity` units, returning the buyer trade state.
+async fn buy_multi_unit(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    test_metadata: &Metadata,
+    multi_unit_listing: &Pubkey,
+    buyer: &Keypair,
+    price: u64,
+    quantity: u64,
+) -> StdResult<Pubkey, BanksClientError> {
+    let token =
+        get_associated_token_address(&test_metadata.token.pubkey(), &test_metadata.mint.pubkey());
+    let (buyer_trade_state, bts_bump) = find_trade_state_address(
+        &buyer.pubkey(),
+        ahkey,
+        &token,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        price,
+        quantity,
+    );
+    let (escrow_payment_account, escrow_bump) = find_escrow_payment_address(ahkey, &buyer.pubkey());
+    let (auctioneer_authority, aa_bump) = find_auctioneer_authority_seeds(ahkey);
+    let (auctioneer_pda, _) = find_auctioneer_pda(ahkey, &auctioneer_authority);
+
+    let accounts = mpl_auctioneer::accounts::AuctioneerBuyMultiUnit {
+        auction_house_program: mpl_auction_house::id(),
+        multi_unit_listing: *multi_unit_listing,
+        seller: test_metadata.token.pubkey(),
+        wallet: buyer.pubkey(),
+        payment_account: buyer.pubkey(),
+        transfer_authority: buyer.pubkey(),
+        treasury_mint: ah.treasury_mint,
+        token_account: token,
+        metadata: test_metadata.pubkey,
+        escrow_payment_account,
+        authority: ah.authority,
+        auction_house: *ahkey,
+        auction_house_fee_account: ah.auction_house_fee_account,
+        buyer_trade_state,
+        auctioneer_authority,
+        ah_auctioneer_pda: auctioneer_pda,
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auctioneer::instruction::BuyMultiUnit {
+        trade_state_bump: bts_bump,
+        escrow_payment_bump: escrow_bump,
+        auctioneer_authority_bump: aa_bump,
+        buyer_price: price,
+        token_size: quantity,
+        _listing_token_size: UNITS,
+    }
+    .data();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[Instruction {
+            program_id: mpl_auctioneer::id(),
+            data,
+            accounts,
+        }],
+        Some(&buyer.pubkey()),
+        &[buyer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await?;
+
+    Ok(buyer_trade_state)
+}
+
+fn execute_multi_unit_sale(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    authority: &Keypair,
+    test_metadata: &Metadata,
+    multi_unit_listing: &Pubkey,
+    buyer: &Pubkey,
+    buyer_trade_state: &Pubkey,
+) -> Transaction {
+    let token =
+        get_associated_token_address(&test_metadata.token.pubkey(), &test_metadata.mint.pubkey());
+    let (seller_trade_state, _) = find_auctioneer_trade_state_address(
+        &test_metadata.token.pubkey(),
+        ahkey,
+        &token,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        UNITS,
+    );
+    let (free_trade_state, free_sts_bump) = find_trade_state_address(
+        &test_metadata.token.pubkey(),
+        ahkey,
+        &token,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        0,
+        UNITS,
+    );
+    let (escrow_payment_account, escrow_bump) = find_escrow_payment_address(ahkey, buyer);
+    let (program_as_signer, pas_bump) = find_program_as_signer_address();
+    let (auctioneer_authority, aa_bump) = find_auctioneer_authority_seeds(ahkey);
+    let (auctioneer_pda, _) = find_auctioneer_pda(ahkey, &auctioneer_authority);
+
+    let accounts = mpl_auctioneer::accounts::AuctioneerExecuteMultiUnitSale {
+        auction_house_program: mpl_auction_house::id(),
+        multi_unit_listing: *multi_unit_listing,
+        buyer: *buyer,
+        seller: test_metadata.token.pubkey(),
+        token_account: token,
+        token_mint: test_metadata.mint.pubkey(),
+        metadata: test_metadata.pubkey,
+        treasury_mint: ah.treasury_mint,
+        escrow_payment_account,
+        seller_payment_receipt_account: test_metadata.token.pubkey(),
+        buyer_receipt_token_account: get_associated_token_address(
+            buyer,
+            &test_metadata.mint.pubkey(),
+        ),
+        authority: ah.authority,
+        auction_house: *ahkey,
+        auction_house_fee_account: ah.auction_house_fee_account,
+        auction_house_treasury: ah.auction_house_treasury,
+        buyer_trade_state: *buyer_trade_state,
+        seller_trade_state,
+        free_trade_state,
+        auctioneer_authority,
+        ah_auctioneer_pda: auctioneer_pda,
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        ata_program: spl_associated_token_account::id(),
+        program_as_signer,
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auctioneer::instruction::ExecuteMultiUnitSale {
+        escrow_payment_bump: escrow_bump,
+        free_trade_state_bump: free_sts_bump,
+        program_as_signer_bump: pas_bump,
+        auctioneer_authority_bump: aa_bump,
+        token_size: UNITS,
+    }
+    .data();
+
+    Transaction::new_signed_with_payer(
+        &[
+            ComputeBudgetInstruction::set_compute_unit_limit(350_000),
+            Instruction {
+                program_id: mpl_auctioneer::id(),
+                data,
+                accounts,
+            },
+        ],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    )
+}
+
+fn remove_defaulted_bid(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    test_metadata: &Metadata,
+    multi_unit_listing: &Pubkey,
+    buyer: &Pubkey,
+    buyer_trade_state: &Pubkey,
+) -> Transaction {
+    let accounts = mpl_auctioneer::accounts::AuctioneerRemoveDefaultedMultiUnitBid {
+        auction_house_program: mpl_auction_house::id(),
+        multi_unit_listing: *multi_unit_listing,
+        seller: test_metadata.token.pubkey(),
+        token_account: get_associated_token_address(
+            &test_metadata.token.pubkey(),
+            &test_metadata.mint.pubkey(),
+        ),
+        auction_house: *ahkey,
+        escrow_payment_account: find_escrow_payment_address(ahkey, buyer).0,
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auctioneer::instruction::RemoveDefaultedMultiUnitBid {
+        buyer_trade_state: *buyer_trade_state,
+        _listing_token_size: UNITS,
+    }
+    .data();
+
+    Transaction::new_signed_with_payer(
+        &[Instruction {
+            program_id: mpl_auctioneer::id(),
+            data,
+            accounts,
+        }],
+        Some(&test_metadata.token.pubkey()),
+        &[&test_metadata.token],
+        context.last_blockhash,
+    )
+}
+
+async fn create_sft(context: &mut ProgramTestContext) -> Metadata {
+    let test_metadata = Metadata::new();
+    airdrop(context, &test_metadata.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            UNITS,
+        )
+        .await
+        .unwrap();
+
+    test_metadata
+}
+
+async fn funded_buyer(context: &mut ProgramTestContext) -> Keypair {
+    let buyer = Keypair::new();
+    airdrop(context, &buyer.pubkey(), TEN_SOL).await.unwrap();
+    buyer
+}
+
+#[tokio::test]
+async fn execute_multi_unit_sale_uniform_price_success() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = create_sft(&mut context).await;
+    let multi_unit_listing = sell_multi_unit(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        2,
+        SettlementMode::UniformPrice,
+    )
+    .await;
+
+    // Two units at 3 SOL and one unit at 2 SOL win, the one unit at 1 SOL loses.
+    let first = funded_buyer(&mut context).await;
+    let first_trade_state = buy_multi_unit(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &multi_unit_listing,
+        &first,
+        6 * ONE_SOL,
+        2,
+    )
+    .await
+    .unwrap();
+    let second = funded_buyer(&mut context).await;
+    buy_multi_unit(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &multi_unit_listing,
+        &second,
+        2 * ONE_SOL,
+        1,
+    )
+    .await
+    .unwrap();
+    let third = funded_buyer(&mut context).await;
+    let third_trade_state = buy_multi_unit(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &multi_unit_listing,
+        &third,
+        ONE_SOL,
+        1,
+    )
+    .await
+    .unwrap();
+
+    let listing_account = context
+        .banks_client
+        .get_account(multi_unit_listing)
+        .await
+        .unwrap()
+        .unwrap();
+    let listing = MultiUnitListing::try_deserialize(&mut listing_account.data.as_ref()).unwrap();
+    assert_eq!(listing.bids.len(), 3);
+    assert_eq!(listing.winners(), vec![0, 1]);
+    assert_eq!(listing.settlement_price(0), 4 * ONE_SOL);
+
+    context.warp_to_slot(120 * 400).unwrap();
+    airdrop(&mut context, &ah.auction_house_fee_account, TEN_SOL)
+        .await
+        .unwrap();
+
+    let (escrow, _) = find_escrow_payment_address(&ahkey, &first.pubkey());
+    let escrow_before = context
+        .banks_client
+        .get_account(escrow)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    let tx = execute_multi_unit_sale(
+        &mut context,
+        &ahkey,
+        &ah,
+        &authority,
+        &test_metadata,
+        &multi_unit_listing,
+        &first.pubkey(),
+        &first_trade_state,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let escrow_after = context
+        .banks_client
+        .get_account(escrow)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    let buyer_token = Account::unpack_from_slice(
+        context
+            .banks_client
+            .get_account(get_associated_token_address(
+                &first.pubkey(),
+                &test_metadata.mint.pubkey(),
+            ))
+            .await
+            .unwrap()
+            .unwrap()
+            .data
+            .as_slice(),
+    )
+    .unwrap();
+    assert_eq!(buyer_token.amount, 2);
+    // The first winner pays the clearing price of 2 SOL per unit and keeps the rest in escrow.
+    assert_eq!(escrow_before - escrow_after, 4 * ONE_SOL);
+
+    let tx = execute_multi_unit_sale(
+        &mut context,
+        &ahkey,
+        &ah,
+        &authority,
+        &test_metadata,
+        &multi_unit_listing,
+        &third.pubkey(),
+        &third_trade_state,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+    assert_error!(err, NOT_WINNING_BID);
+}
+
+#[tokio::test]
+async fn buy_multi_unit_uneven_price_fails() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = create_sft(&mut context).await;
+    let multi_unit_listing = sell_multi_unit(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        2,
+        SettlementMode::PayAsBid,
+    )
+    .await;
+
+    let buyer = funded_buyer(&mut context).await;
+    let err = buy_multi_unit(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &multi_unit_listing,
+        &buyer,
+        ONE_SOL + 1,
+        2,
+    )
+    .await
+    .unwrap_err();
+    assert_error!(err, INVALID_BID_QUANTITY);
+}
+
+#[tokio::test]
+async fn remove_defaulted_multi_unit_bid_success() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = create_sft(&mut context).await;
+    let multi_unit_listing = sell_multi_unit(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        1,
+        SettlementMode::PayAsBid,
+    )
+    .await;
+
+    let winner = funded_buyer(&mut context).await;
+    let winner_trade_state = buy_multi_unit(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &multi_unit_listing,
+        &winner,
+        2 * ONE_SOL,
+        1,
+    )
+    .await
+    .unwrap();
+    let runner_up = funded_buyer(&mut context).await;
+    let runner_up_trade_state = buy_multi_unit(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &multi_unit_listing,
+        &runner_up,
+        ONE_SOL,
+        1,
+    )
+    .await
+    .unwrap();
+
+    // Past the end of the auction and the grace period.
+    context.warp_to_slot(300_000).unwrap();
+
+    let tx = remove_defaulted_bid(
+        &mut context,
+        &ahkey,
+        &test_metadata,
+        &multi_unit_listing,
+        &winner.pubkey(),
+        &winner_trade_state,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+    assert_error!(err, WINNING_BID_STILL_FUNDED);
+
+    let (_, withdraw_tx) = withdraw(
+        &mut context,
+        &winner,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        2 * ONE_SOL,
+        ONE_SOL,
+    );
+    context
+        .banks_client
+        .process_transaction(withdraw_tx)
+        .await
+        .unwrap();
+
+    let tx = remove_defaulted_bid(
+        &mut context,
+        &ahkey,
+        &test_metadata,
+        &multi_unit_listing,
+        &winner.pubkey(),
+        &winner_trade_state,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let listing_account = context
+        .banks_client
+        .get_account(multi_unit_listing)
+        .await
+        .unwrap()
+        .unwrap();
+    let listing = MultiUnitListing::try_deserialize(&mut listing_account.data.as_ref()).unwrap();
+    assert_eq!(listing.bids.len(), 1);
+    assert_eq!(listing.bids[0].buyer_trade_state, runner_up_trade_state);
+    assert_eq!(listing.winners(), vec![0]);
+}
+
+#[tokio::test]
+async fn buy_multi_unit_full_book_replaces_lowest_losing_bid() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = create_sft(&mut context).await;
+    let multi_unit_listing = sell_multi_unit(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        1,
+        SettlementMode::PayAsBid,
+    )
+    .await;
+
+    // A single wallet fills the book with bids just above each other.
+    let spammer = funded_buyer(&mut context).await;
+    for i in 0..MAX_MULTI_UNIT_BIDS as u64 {
+        buy_multi_unit(
+            &mut context,
+            &ahkey,
+            &ah,
+            &test_metadata,
+            &multi_unit_listing,
+            &spammer,
+            ONE_SOL / 10 + i,
+            1,
+        )
+        .await
+        .unwrap();
+    }
+
+    let buyer = funded_buyer(&mut context).await;
+    let err = buy_multi_unit(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &multi_unit_listing,
+        &buyer,
+        ONE_SOL / 10,
+        1,
+    )
+    .await
+    .unwrap_err();
+    assert_error!(err, MULTI_UNIT_BOOK_FULL);
+
+    let buyer_trade_state = buy_multi_unit(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &multi_unit_listing,
+        &buyer,
+        ONE_SOL,
+        1,
+    )
+    .await
+    .unwrap();
+
+    let listing_account = context
+        .banks_client
+        .get_account(multi_unit_listing)
+        .await
+        .unwrap()
+        .unwrap();
+    let listing = MultiUnitListing::try_deserialize(&mut listing_account.data.as_ref()).unwrap();
+    assert_eq!(listing.bids.len(), MAX_MULTI_UNIT_BIDS);
+    assert_eq!(listing.bids[0].buyer_trade_state, buyer_trade_state);
+    assert_eq!(listing.winners(), vec![0]);
+    // The lowest losing bid made room for the new one.
+    assert_eq!(listing.bids.last().unwrap().unit_price, ONE_SOL / 10 + 1);
+}
@@ -0,0 +1,756 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+
+use anchor_lang::{InstructionData, ToAccountMetas};
+use mpl_testing_utils::{
+    solana::{airdrop, create_associated_token_account, create_mint},
+    utils::Metadata,
+};
+use solana_sdk::{compute_budget::ComputeBudgetInstruction, signer::Signer};
+
+use std::{assert_eq, result::Result as StdResult, time::SystemTime};
+
+use solana_program::{hash::hashv, instruction::Instruction, system_program, sysvar};
+
+use solana_program::program_pack::Pack;
+
+use mpl_auction_house::pda::{
+    find_auction_house_address, find_auction_house_fee_account_address,
+    find_auction_house_treasury_address, find_auctioneer_pda, find_auctioneer_trade_state_address,
+    find_escrow_payment_address, find_program_as_signer_address, find_trade_state_address,
+};
+use mpl_auctioneer::{
+    pda::{find_auctioneer_authority_seeds, find_raffle_address},
+    raffle::Raffle,
+};
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, transaction::Transaction};
+use spl_associated_token_account::get_associated_token_address;
+use spl_token::state::Account;
+use utils::setup_functions::*;
+
+const SEED: [u8; 32] = [7; 32];
+const TICKET_PRICE: u64 = ONE_SOL;
+const REVEAL_BOND: u64 = ONE_SOL / 10;
+
+fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs() as i64
+}
+
+fn sell_raffle_tx(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    test_metadata: &Metadata,
+    max_tickets: u32,
+) -> (Pubkey, Transaction) {
+    let token =
+        get_associated_token_address(&test_metadata.token.pubkey(), &test_metadata.mint.pubkey());
+    let (seller_trade_state, sts_bump) = find_auctioneer_trade_state_address(
+        &test_metadata.token.pubkey(),
+        ahkey,
+        &token,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        1,
+    );
+    let (free_seller_trade_state, free_sts_bump) = find_trade_state_address(
+        &test_metadata.token.pubkey(),
+        ahkey,
+        &token,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        0,
+        1,
+    );
+    let (raffle, _) = find_raffle_address(
+        &test_metadata.token.pubkey(),
+        ahkey,
+        &token,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        1,
+    );
+    let (program_as_signer, pas_bump) = find_program_as_signer_address();
+    let (auctioneer_authority, aa_bump) = find_auctioneer_authority_seeds(ahkey);
+    let (auctioneer_pda, _) = find_auctioneer_pda(ahkey, &auctioneer_authority);
+
+    let accounts = mpl_auctioneer::accounts::AuctioneerSellRaffle {
+        auction_house_program: mpl_auction_house::id(),
+        raffle,
+        wallet: test_metadata.token.pubkey(),
+        token_account: token,
+        metadata: test_metadata.pubkey,
+        authority: ah.authority,
+        auction_house: *ahkey,
+        auction_house_fee_account: ah.auction_house_fee_account,
+        seller_trade_state,
+        free_seller_trade_state,
+        auctioneer_authority,
+        ah_auctioneer_pda: auctioneer_pda,
+        program_as_signer,
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auctioneer::instruction::SellRaffle {
+        trade_state_bump: sts_bump,
+        free_trade_state_bump: free_sts_bump,
+        program_as_signer_bump: pas_bump,
+        auctioneer_authority_bump: aa_bump,
+        token_size: 1,
+        start_time: now() - 60,
+        end_time: now() + 60,
+        ticket_price: TICKET_PRICE,
+        max_tickets,
+        commitment: hashv(&[&SEED[..]]).to_bytes(),
+        reveal_bond: REVEAL_BOND,
+    }
+    .data();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[Instruction {
+            program_id: mpl_auctioneer::id(),
+            data,
+            accounts,
+        }],
+        Some(&test_metadata.token.pubkey()),
+        &[&test_metadata.token],
+        context.last_blockhash,
+    );
+
+    (raffle, tx)
+}
+
+async fn sell_raffle(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    test_metadata: &Metadata,
+    max_tickets: u32,
+) -> Pubkey {
+    let (raffle, tx) = sell_raffle_tx(context, ahkey, ah, test_metadata, max_tickets);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    raffle
+}
+
+async fn buy_raffle_tickets(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    test_metadata: &Metadata,
+    raffle: &Pubkey,
+    buyer: &Keypair,
+    tickets: u32,
+) -> StdResult<(), BanksClientError> {
+    let accounts = mpl_auctioneer::accounts::BuyRaffleTickets {
+        wallet: buyer.pubkey(),
+        seller: test_metadata.token.pubkey(),
+        auction_house: *ahkey,
+        token_account: get_associated_token_address(
+            &test_metadata.token.pubkey(),
+            &test_metadata.mint.pubkey(),
+        ),
+        raffle: *raffle,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auctioneer::instruction::BuyRaffleTickets {
+        token_size: 1,
+        tickets,
+    }
+    .data();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[Instruction {
+            program_id: mpl_auctioneer::id(),
+            data,
+            accounts,
+        }],
+        Some(&buyer.pubkey()),
+        &[buyer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+async fn refund_raffle_tickets(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    test_metadata: &Metadata,
+    raffle: &Pubkey,
+    buyer: &Keypair,
+) -> StdResult<(), BanksClientError> {
+    let accounts = mpl_auctioneer::accounts::RefundRaffleTickets {
+        wallet: buyer.pubkey(),
+        seller: test_metadata.token.pubkey(),
+        auction_house: *ahkey,
+        token_account: get_associated_token_address(
+            &test_metadata.token.pubkey(),
+            &test_metadata.mint.pubkey(),
+        ),
+        raffle: *raffle,
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auctioneer::instruction::RefundRaffleTickets { token_size: 1 }.data();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[Instruction {
+            program_id: mpl_auctioneer::id(),
+            data,
+            accounts,
+        }],
+        Some(&buyer.pubkey()),
+        &[buyer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+/// Commit the raffle to the current slot and move to the next one, whose `SlotHashes` holds the
+/// hash of the committed slot. Returns the committed slot.
+async fn commit_raffle_draw(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    test_metadata: &Metadata,
+    raffle: &Pubkey,
+) -> u64 {
+    let accounts = mpl_auctioneer::accounts::CommitRaffleDraw {
+        seller: test_metadata.token.pubkey(),
+        auction_house: *ahkey,
+        token_account: get_associated_token_address(
+            &test_metadata.token.pubkey(),
+            &test_metadata.mint.pubkey(),
+        ),
+        raffle: *raffle,
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auctioneer::instruction::CommitRaffleDraw { token_size: 1 }.data();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[Instruction {
+            program_id: mpl_auctioneer::id(),
+            data,
+            accounts,
+        }],
+        Some(&test_metadata.token.pubkey()),
+        &[&test_metadata.token],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let draw_slot = get_raffle(context, raffle)
+        .await
+        .draw_slot
+        .expect("Draw slot not committed");
+    context.warp_to_slot(draw_slot + 1).unwrap();
+
+    draw_slot
+}
+
+async fn draw_raffle(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    test_metadata: &Metadata,
+    raffle: &Pubkey,
+    seed: [u8; 32],
+) -> StdResult<(), BanksClientError> {
+    let accounts = mpl_auctioneer::accounts::DrawRaffle {
+        seller: test_metadata.token.pubkey(),
+        auction_house: *ahkey,
+        token_account: get_associated_token_address(
+            &test_metadata.token.pubkey(),
+            &test_metadata.mint.pubkey(),
+        ),
+        raffle: *raffle,
+        slot_hashes: sysvar::slot_hashes::id(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auctioneer::instruction::DrawRaffle {
+        token_size: 1,
+        seed,
+    }
+    .data();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[Instruction {
+            program_id: mpl_auctioneer::id(),
+            data,
+            accounts,
+        }],
+        Some(&test_metadata.token.pubkey()),
+        &[&test_metadata.token],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+async fn claim_raffle_prize(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    test_metadata: &Metadata,
+    raffle: &Pubkey,
+    winner: &Keypair,
+) -> Pubkey {
+    let token =
+        get_associated_token_address(&test_metadata.token.pubkey(), &test_metadata.mint.pubkey());
+    let (buyer_trade_state, bts_bump) = find_trade_state_address(
+        &winner.pubkey(),
+        ahkey,
+        &token,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        TICKET_PRICE,
+        1,
+    );
+    let (escrow_payment_account, escrow_bump) =
+        find_escrow_payment_address(ahkey, &winner.pubkey());
+    let (auctioneer_authority, aa_bump) = find_auctioneer_authority_seeds(ahkey);
+    let (auctioneer_pda, _) = find_auctioneer_pda(ahkey, &auctioneer_authority);
+
+    let accounts = mpl_auctioneer::accounts::AuctioneerClaimRafflePrize {
+        auction_house_program: mpl_auction_house::id(),
+        raffle: *raffle,
+        seller: test_metadata.token.pubkey(),
+        wallet: winner.pubkey(),
+        payment_account: winner.pubkey(),
+        transfer_authority: winner.pubkey(),
+        treasury_mint: ah.treasury_mint,
+        token_account: token,
+        metadata: test_metadata.pubkey,
+        escrow_payment_account,
+        authority: ah.authority,
+        auction_house: *ahkey,
+        auction_house_fee_account: ah.auction_house_fee_account,
+        buyer_trade_state,
+        auctioneer_authority,
+        ah_auctioneer_pda: auctioneer_pda,
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auctioneer::instruction::ClaimRafflePrize {
+        trade_state_bump: bts_bump,
+        escrow_payment_bump: escrow_bump,
+        auctioneer_authority_bump: aa_bump,
+        token_size: 1,
+    }
+    .data();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[Instruction {
+            program_id: mpl_auctioneer::id(),
+            data,
+            accounts,
+        }],
+        Some(&winner.pubkey()),
+        &[winner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    buyer_trade_state
+}
+
+fn execute_raffle_sale(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    authority: &Keypair,
+    test_metadata: &Metadata,
+    raffle: &Pubkey,
+    winner: &Pubkey,
+    buyer_trade_state: &Pubkey,
+) -> Transaction {
+    let token =
+        get_associated_token_address(&test_metadata.token.pubkey(), &test_metadata.mint.pubkey());
+    let (seller_trade_state, _) = find_auctioneer_trade_state_address(
+        &test_metadata.token.pubkey(),
+        ahkey,
+        &token,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        1,
+    );
+    let (free_trade_state, free_sts_bump) = find_trade_state_address(
+        &test_metadata.token.pubkey(),
+        ahkey,
+        &token,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        0,
+        1,
+    );
+    let (escrow_payment_account, escrow_bump) = find_escrow_payment_address(ahkey, winner);
+    let (program_as_signer, pas_bump) = find_program_as_signer_address();
+    let (auctioneer_authority, aa_bump) = find_auctioneer_authority_seeds(ahkey);
+    let (auctioneer_pda, _) = find_auctioneer_pda(ahkey, &auctioneer_authority);
+
+    let accounts = mpl_auctioneer::accounts::AuctioneerExecuteRaffleSale {
+        auction_house_program: mpl_auction_house::id(),
+        raffle: *raffle,
+        buyer: *winner,
+        seller: test_metadata.token.pubkey(),
+        token_account: token,
+        token_mint: test_metadata.mint.pubkey(),
+        metadata: test_metadata.pubkey,
+        treasury_mint: ah.treasury_mint,
+        escrow_payment_account,
+        seller_payment_receipt_account: test_metadata.token.pubkey(),
+        buyer_receipt_token_account: get_associated_token_address(
+            winner,
+            &test_metadata.mint.pubkey(),
+        ),
+        authority: ah.authority,
+        auction_house: *ahkey,
+        auction_house_fee_account: ah.auction_house_fee_account,
+        auction_house_treasury: ah.auction_house_treasury,
+        buyer_trade_state: *buyer_trade_state,
+        seller_trade_state,
+        free_trade_state,
+        auctioneer_authority,
+        ah_auctioneer_pda: auctioneer_pda,
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        ata_program: spl_associated_token_account::id(),
+        program_as_signer,
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auctioneer::instruction::ExecuteRaffleSale {
+        escrow_payment_bump: escrow_bump,
+        free_trade_state_bump: free_sts_bump,
+        program_as_signer_bump: pas_bump,
+        auctioneer_authority_bump: aa_bump,
+        token_size: 1,
+    }
+    .data();
+
+    Transaction::new_signed_with_payer(
+        &[
+            ComputeBudgetInstruction::set_compute_unit_limit(350_000),
+            Instruction {
+                program_id: mpl_auctioneer::id(),
+                data,
+                accounts,
+            },
+        ],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    )
+}
+
+async fn create_nft(context: &mut ProgramTestContext) -> Metadata {
+    let test_metadata = Metadata::new();
+    airdrop(context, &test_metadata.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    test_metadata
+}
+
+async fn funded_buyer(context: &mut ProgramTestContext) -> Keypair {
+    let buyer = Keypair::new();
+    airdrop(context, &buyer.pubkey(), TEN_SOL).await.unwrap();
+    buyer
+}
+
+async fn get_raffle(context: &mut ProgramTestContext, raffle: &Pubkey) -> Raffle {
+    let raffle_account = context
+        .banks_client
+        .get_account(*raffle)
+        .await
+        .unwrap()
+        .unwrap();
+    Raffle::try_deserialize(&mut raffle_account.data.as_ref()).unwrap()
+}
+
+#[tokio::test]
+async fn raffle_draw_and_execute_sale_success() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = create_nft(&mut context).await;
+    let raffle = sell_raffle(&mut context, &ahkey, &ah, &test_metadata, 10).await;
+
+    let first = funded_buyer(&mut context).await;
+    buy_raffle_tickets(&mut context, &ahkey, &test_metadata, &raffle, &first, 2)
+        .await
+        .unwrap();
+    let second = funded_buyer(&mut context).await;
+    buy_raffle_tickets(&mut context, &ahkey, &test_metadata, &raffle, &second, 1)
+        .await
+        .unwrap();
+
+    let raffle_state = get_raffle(&mut context, &raffle).await;
+    assert_eq!(raffle_state.tickets_sold, 3);
+    assert_eq!(raffle_state.entries.len(), 2);
+
+    context.warp_to_slot(120 * 400).unwrap();
+    airdrop(&mut context, &ah.auction_house_fee_account, TEN_SOL)
+        .await
+        .unwrap();
+
+    commit_raffle_draw(&mut context, &ahkey, &test_metadata, &raffle).await;
+
+    let seller_before = context
+        .banks_client
+        .get_account(test_metadata.token.pubkey())
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    draw_raffle(&mut context, &ahkey, &test_metadata, &raffle, SEED)
+        .await
+        .unwrap();
+    let seller_after = context
+        .banks_client
+        .get_account(test_metadata.token.pubkey())
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    // The seller gets the funds of the two non-winning tickets and the reveal bond, less the
+    // transaction fee.
+    assert!(seller_after + ONE_SOL / 1000 > seller_before + 2 * TICKET_PRICE + REVEAL_BOND);
+
+    let winner = match get_raffle(&mut context, &raffle).await.winner {
+        Some(winner) if winner == first.pubkey() => first,
+        Some(winner) if winner == second.pubkey() => second,
+        _ => panic!("the raffle winner is not a ticket buyer"),
+    };
+
+    let buyer_trade_state =
+        claim_raffle_prize(&mut context, &ahkey, &ah, &test_metadata, &raffle, &winner).await;
+    let tx = execute_raffle_sale(
+        &mut context,
+        &ahkey,
+        &ah,
+        &authority,
+        &test_metadata,
+        &raffle,
+        &winner.pubkey(),
+        &buyer_trade_state,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let winner_token = Account::unpack_from_slice(
+        context
+            .banks_client
+            .get_account(get_associated_token_address(
+                &winner.pubkey(),
+                &test_metadata.mint.pubkey(),
+            ))
+            .await
+            .unwrap()
+            .unwrap()
+            .data
+            .as_slice(),
+    )
+    .unwrap();
+    assert_eq!(winner_token.amount, 1);
+    assert!(context
+        .banks_client
+        .get_account(raffle)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn draw_raffle_wrong_seed_fails() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = create_nft(&mut context).await;
+    let raffle = sell_raffle(&mut context, &ahkey, &ah, &test_metadata, 10).await;
+
+    let buyer = funded_buyer(&mut context).await;
+    buy_raffle_tickets(&mut context, &ahkey, &test_metadata, &raffle, &buyer, 1)
+        .await
+        .unwrap();
+
+    context.warp_to_slot(120 * 400).unwrap();
+    commit_raffle_draw(&mut context, &ahkey, &test_metadata, &raffle).await;
+
+    let err = draw_raffle(&mut context, &ahkey, &test_metadata, &raffle, [8; 32])
+        .await
+        .unwrap_err();
+    assert_error!(err, INVALID_RAFFLE_SEED);
+}
+
+#[tokio::test]
+async fn buy_raffle_tickets_over_max_fails() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = create_nft(&mut context).await;
+    let raffle = sell_raffle(&mut context, &ahkey, &ah, &test_metadata, 2).await;
+
+    let buyer = funded_buyer(&mut context).await;
+    let err = buy_raffle_tickets(&mut context, &ahkey, &test_metadata, &raffle, &buyer, 3)
+        .await
+        .unwrap_err();
+    assert_error!(err, INVALID_TICKET_QUANTITY);
+}
+
+#[tokio::test]
+async fn draw_raffle_without_commit_fails() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = create_nft(&mut context).await;
+    let raffle = sell_raffle(&mut context, &ahkey, &ah, &test_metadata, 10).await;
+
+    let buyer = funded_buyer(&mut context).await;
+    buy_raffle_tickets(&mut context, &ahkey, &test_metadata, &raffle, &buyer, 1)
+        .await
+        .unwrap();
+
+    context.warp_to_slot(120 * 400).unwrap();
+
+    let err = draw_raffle(&mut context, &ahkey, &test_metadata, &raffle, SEED)
+        .await
+        .unwrap_err();
+    assert_error!(err, RAFFLE_DRAW_NOT_COMMITTED);
+}
+
+#[tokio::test]
+async fn missed_raffle_reveal_refunds_reveal_bond() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = create_nft(&mut context).await;
+    let raffle = sell_raffle(&mut context, &ahkey, &ah, &test_metadata, 10).await;
+
+    let first = funded_buyer(&mut context).await;
+    buy_raffle_tickets(&mut context, &ahkey, &test_metadata, &raffle, &first, 3)
+        .await
+        .unwrap();
+    let second = funded_buyer(&mut context).await;
+    buy_raffle_tickets(&mut context, &ahkey, &test_metadata, &raffle, &second, 1)
+        .await
+        .unwrap();
+
+    context.warp_to_slot(120 * 400).unwrap();
+    let draw_slot = commit_raffle_draw(&mut context, &ahkey, &test_metadata, &raffle).await;
+
+    // The hash of the draw slot is no longer kept in `SlotHashes`.
+    context.warp_to_slot(draw_slot + 600).unwrap();
+    let err = draw_raffle(&mut context, &ahkey, &test_metadata, &raffle, SEED)
+        .await
+        .unwrap_err();
+    assert_error!(err, REVEAL_PERIOD_OVER);
+
+    let first_before = context
+        .banks_client
+        .get_account(first.pubkey())
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    refund_raffle_tickets(&mut context, &ahkey, &test_metadata, &raffle, &first)
+        .await
+        .unwrap();
+    let first_after = context
+        .banks_client
+        .get_account(first.pubkey())
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    // Three of the four tickets get three quarters of the reveal bond, less the transaction fee.
+    assert!(first_after + ONE_SOL / 1000 > first_before + 3 * TICKET_PRICE + REVEAL_BOND * 3 / 4);
+    assert!(first_after < first_before + 3 * TICKET_PRICE + REVEAL_BOND * 3 / 4);
+}
+
+#[tokio::test]
+async fn sell_raffle_spl_treasury_fails() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let treasury_mint = Keypair::new();
+    let payer = context.payer.pubkey();
+    create_mint(&mut context, &treasury_mint, &payer, None)
+        .await
+        .unwrap();
+
+    let authority = Keypair::new();
+    airdrop(&mut context, &authority.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    let treasury_withdrawal_destination =
+        create_associated_token_account(&mut context, &authority, &treasury_mint.pubkey())
+            .await
+            .unwrap();
+    let (ahkey, bump) = find_auction_house_address(&authority.pubkey(), &treasury_mint.pubkey());
+    let (fee_account, fee_payer_bump) = find_auction_house_fee_account_address(&ahkey);
+    let (treasury, treasury_bump) = find_auction_house_treasury_address(&ahkey);
+    create_auction_house(
+        &mut context,
+        &authority,
+        &authority.pubkey(),
+        &authority.pubkey(),
+        &treasury_mint.pubkey(),
+        &treasury_withdrawal_destination,
+        &ahkey,
+        bump,
+        &fee_account,
+        fee_payer_bump,
+        &treasury,
+        treasury_bump,
+        100,
+        false,
+        false,
+    )
+    .await
+    .unwrap();
+    let ah_account = context
+        .banks_client
+        .get_account(ahkey)
+        .await
+        .unwrap()
+        .unwrap();
+    let ah = AuctionHouse::try_deserialize(&mut ah_account.data.as_ref()).unwrap();
+
+    let test_metadata = create_nft(&mut context).await;
+    let (_, tx) = sell_raffle_tx(&mut context, &ahkey, &ah, &test_metadata, 10);
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+    assert_error!(err, RAFFLE_REQUIRES_NATIVE_MINT);
+}
@@ -5,8 +5,12 @@ pub mod utils;
 use common::*;
 use utils::setup_functions::*;
 
+use mpl_auctioneer::sell::config::{
+    ListingConfig, ListingConfigVersion, LISTING_CONFIG_SIZE, LISTING_CONFIG_V0_SIZE,
+};
 use mpl_testing_utils::{solana::airdrop, utils::Metadata};
-use solana_sdk::signer::Signer;
+use solana_program::system_program;
+use solana_sdk::{account::AccountSharedData, signer::Signer};
 use std::{assert_eq, time::SystemTime};
 
 #[tokio::test]
@@ -66,3 +70,103 @@ async fn sell_success() {
         .expect("Trade State Empty");
     assert_eq!(sts.data.len(), 1);
 }
+
+#[tokio::test]
+async fn migrate_listing_config_success() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = Metadata::new();
+    let owner_pubkey = &test_metadata.token.pubkey();
+    airdrop(&mut context, owner_pubkey, TEN_SOL).await.unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+    let end_time = (SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs()
+        + 60) as i64;
+    let ((_, listing_config), sell_tx) = sell(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        end_time - 120,
+        end_time,
+        Some(ONE_SOL),
+        None,
+        None,
+        None,
+        None,
+    );
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    // Rewrite the listing with the V0 layout, which is a prefix of the V1 one.
+    let listing = context
+        .banks_client
+        .get_account(listing_config)
+        .await
+        .expect("Error Getting Listing Config")
+        .expect("Listing Config Empty");
+    let mut v0_data = listing.data[..LISTING_CONFIG_V0_SIZE].to_vec();
+    v0_data[8] = ListingConfigVersion::V0 as u8;
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let mut v0_listing = AccountSharedData::new(
+        rent.minimum_balance(LISTING_CONFIG_V0_SIZE),
+        LISTING_CONFIG_V0_SIZE,
+        &mpl_auctioneer::id(),
+    );
+    v0_listing.set_data(v0_data);
+    context.set_account(&listing_config, &v0_listing);
+
+    let accounts = mpl_auctioneer::accounts::MigrateListingConfig {
+        payer: *owner_pubkey,
+        listing_config,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+    let data = mpl_auctioneer::instruction::MigrateListingConfig {}.data();
+    let tx = Transaction::new_signed_with_payer(
+        &[Instruction {
+            program_id: mpl_auctioneer::id(),
+            data,
+            accounts,
+        }],
+        Some(owner_pubkey),
+        &[&test_metadata.token],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let listing = context
+        .banks_client
+        .get_account(listing_config)
+        .await
+        .expect("Error Getting Listing Config")
+        .expect("Listing Config Empty");
+    assert_eq!(listing.data.len(), LISTING_CONFIG_SIZE);
+    assert_eq!(listing.lamports, rent.minimum_balance(LISTING_CONFIG_SIZE));
+    let config = ListingConfig::try_deserialize(&mut listing.data.as_ref()).unwrap();
+    assert!(matches!(config.version, ListingConfigVersion::V1));
+    assert_eq!(config.end_time, end_time);
+    assert_eq!(config.reserve_price, ONE_SOL);
+    assert_eq!(config.bid_gate, None);
+    assert_eq!(config.extensions, 0);
+    assert!(!config.has_bid_history);
+}
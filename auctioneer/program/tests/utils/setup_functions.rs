@@ -15,7 +15,10 @@ use mpl_auction_house::{
     },
     AuctionHouse,
 };
-use mpl_auctioneer::pda::*;
+use mpl_auctioneer::{
+    pda::*,
+    sell::config::{BidGate, CancelPolicy, SoftClose},
+};
 use mpl_testing_utils::{solana::airdrop, utils::Metadata};
 use std::result::Result as StdResult;
 
@@ -481,6 +484,43 @@ pub fn sell(
 ) -> (
     (mpl_auctioneer::accounts::AuctioneerSell, Pubkey),
     Transaction,
+) {
+    sell_with_options(
+        context,
+        ahkey,
+        ah,
+        test_metadata,
+        start_time,
+        end_time,
+        reserve_price,
+        min_bid_increment,
+        time_ext_period,
+        time_ext_delta,
+        allow_high_bid_cancel,
+        None,
+        None,
+        None,
+    )
+}
+
+pub fn sell_with_options(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    test_metadata: &Metadata,
+    start_time: UnixTimestamp,
+    end_time: UnixTimestamp,
+    reserve_price: Option<u64>,
+    min_bid_increment: Option<u64>,
+    time_ext_period: Option<u32>,
+    time_ext_delta: Option<u32>,
+    allow_high_bid_cancel: Option<bool>,
+    bid_gate: Option<BidGate>,
+    soft_close: Option<SoftClose>,
+    cancel_policy: Option<CancelPolicy>,
+) -> (
+    (mpl_auctioneer::accounts::AuctioneerSell, Pubkey),
+    Transaction,
 ) {
     let token =
         get_associated_token_address(&test_metadata.token.pubkey(), &test_metadata.mint.pubkey());
@@ -536,21 +576,42 @@ pub fn sell(
     };
     let account_metas = accounts.to_account_metas(None);
 
-    let data = mpl_auctioneer::instruction::Sell {
-        trade_state_bump: sts_bump,
-        free_trade_state_bump: free_sts_bump,
-        program_as_signer_bump: pas_bump,
-        auctioneer_authority_bump: aa_bump,
-        token_size: 1,
-        start_time,
-        end_time,
-        reserve_price,
-        min_bid_increment,
-        time_ext_period,
-        time_ext_delta,
-        allow_high_bid_cancel,
-    }
-    .data();
+    let data = if bid_gate.is_none() && soft_close.is_none() && cancel_policy.is_none() {
+        mpl_auctioneer::instruction::Sell {
+            trade_state_bump: sts_bump,
+            free_trade_state_bump: free_sts_bump,
+            program_as_signer_bump: pas_bump,
+            auctioneer_authority_bump: aa_bump,
+            token_size: 1,
+            start_time,
+            end_time,
+            reserve_price,
+            min_bid_increment,
+            time_ext_period,
+            time_ext_delta,
+            allow_high_bid_cancel,
+        }
+        .data()
+    } else {
+        mpl_auctioneer::instruction::SellWithOptions {
+            trade_state_bump: sts_bump,
+            free_trade_state_bump: free_sts_bump,
+            program_as_signer_bump: pas_bump,
+            auctioneer_authority_bump: aa_bump,
+            token_size: 1,
+            start_time,
+            end_time,
+            reserve_price,
+            min_bid_increment,
+            time_ext_period,
+            time_ext_delta,
+            allow_high_bid_cancel,
+            bid_gate,
+            soft_close,
+            cancel_policy,
+        }
+        .data()
+    };
 
     let instruction = Instruction {
         program_id: mpl_auctioneer::id(),
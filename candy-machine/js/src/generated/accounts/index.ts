@@ -1,9 +1,19 @@
 export * from './CandyMachine';
 export * from './CollectionPDA';
+export * from './EndSettingsPDA';
 export * from './FreezePDA';
+export * from './MintReceipt';
 
 import { CandyMachine } from './CandyMachine';
 import { CollectionPDA } from './CollectionPDA';
 import { FreezePDA } from './FreezePDA';
+import { EndSettingsPDA } from './EndSettingsPDA';
+import { MintReceipt } from './MintReceipt';
 
-export const accountProviders = { CandyMachine, CollectionPDA, FreezePDA };
+export const accountProviders = {
+  CandyMachine,
+  CollectionPDA,
+  FreezePDA,
+  EndSettingsPDA,
+  MintReceipt,
+};
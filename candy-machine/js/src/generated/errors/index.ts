@@ -1132,6 +1132,178 @@ createErrorFromNameLookup.set(
   () => new CannotSwitchFromHiddenSettingsError(),
 );
 
+/**
+ * EndDateReached: 'Candy machine end date has been reached!'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class EndDateReachedError extends Error {
+  readonly code: number = 0x17a4;
+  readonly name: string = 'EndDateReached';
+  constructor() {
+    super('Candy machine end date has been reached!');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, EndDateReachedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a4, () => new EndDateReachedError());
+createErrorFromNameLookup.set('EndDateReached', () => new EndDateReachedError());
+
+/**
+ * EndAmountReached: 'Candy machine end amount has been reached!'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class EndAmountReachedError extends Error {
+  readonly code: number = 0x17a5;
+  readonly name: string = 'EndAmountReached';
+  constructor() {
+    super('Candy machine end amount has been reached!');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, EndAmountReachedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a5, () => new EndAmountReachedError());
+createErrorFromNameLookup.set('EndAmountReached', () => new EndAmountReachedError());
+
+/**
+ * PresaleAmountReached: 'Candy machine presale amount has been reached!'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PresaleAmountReachedError extends Error {
+  readonly code: number = 0x17a6;
+  readonly name: string = 'PresaleAmountReached';
+  constructor() {
+    super('Candy machine presale amount has been reached!');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PresaleAmountReachedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a6, () => new PresaleAmountReachedError());
+createErrorFromNameLookup.set('PresaleAmountReached', () => new PresaleAmountReachedError());
+
+/**
+ * TooManyEndSettings: 'Can only provide up to MAX_END_SETTINGS end settings.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TooManyEndSettingsError extends Error {
+  readonly code: number = 0x17a7;
+  readonly name: string = 'TooManyEndSettings';
+  constructor() {
+    super('Can only provide up to MAX_END_SETTINGS end settings.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TooManyEndSettingsError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a7, () => new TooManyEndSettingsError());
+createErrorFromNameLookup.set('TooManyEndSettings', () => new TooManyEndSettingsError());
+
+/**
+ * MismatchedMintReceiptPDA: 'Mint receipt PDA address is invalid'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MismatchedMintReceiptPDAError extends Error {
+  readonly code: number = 0x17a8;
+  readonly name: string = 'MismatchedMintReceiptPDA';
+  constructor() {
+    super('Mint receipt PDA address is invalid');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MismatchedMintReceiptPDAError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a8, () => new MismatchedMintReceiptPDAError());
+createErrorFromNameLookup.set(
+  'MismatchedMintReceiptPDA',
+  () => new MismatchedMintReceiptPDAError(),
+);
+
+/**
+ * InvalidMintReceiptCloser: 'Only the candy machine authority or the payer can close a mint receipt'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidMintReceiptCloserError extends Error {
+  readonly code: number = 0x17a9;
+  readonly name: string = 'InvalidMintReceiptCloser';
+  constructor() {
+    super('Only the candy machine authority or the payer can close a mint receipt');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidMintReceiptCloserError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a9, () => new InvalidMintReceiptCloserError());
+createErrorFromNameLookup.set(
+  'InvalidMintReceiptCloser',
+  () => new InvalidMintReceiptCloserError(),
+);
+
+/**
+ * MismatchedEndSettingsPDA: 'End settings PDA address is invalid'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MismatchedEndSettingsPDAError extends Error {
+  readonly code: number = 0x17aa;
+  readonly name: string = 'MismatchedEndSettingsPDA';
+  constructor() {
+    super('End settings PDA address is invalid');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MismatchedEndSettingsPDAError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17aa, () => new MismatchedEndSettingsPDAError());
+createErrorFromNameLookup.set(
+  'MismatchedEndSettingsPDA',
+  () => new MismatchedEndSettingsPDAError(),
+);
+
+/**
+ * NoWithdrawWithEndSettings: 'Can't withdraw Candy Machine while end settings PDA is set. Remove end settings first.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class NoWithdrawWithEndSettingsError extends Error {
+  readonly code: number = 0x17ab;
+  readonly name: string = 'NoWithdrawWithEndSettings';
+  constructor() {
+    super("Can't withdraw Candy Machine while end settings PDA is set. Remove end settings first.");
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, NoWithdrawWithEndSettingsError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ab, () => new NoWithdrawWithEndSettingsError());
+createErrorFromNameLookup.set(
+  'NoWithdrawWithEndSettings',
+  () => new NoWithdrawWithEndSettingsError(),
+);
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
@@ -1,11 +1,16 @@
 export * from './addConfigLines';
+export * from './closeMintReceipt';
 export * from './initializeCandyMachine';
 export * from './mintNft';
 export * from './removeCollection';
+export * from './removeEndSettings';
 export * from './removeFreeze';
+export * from './removeMintReceipts';
 export * from './setCollection';
 export * from './setCollectionDuringMint';
+export * from './setEndSettings';
 export * from './setFreeze';
+export * from './setMintReceipts';
 export * from './thawNft';
 export * from './unlockFunds';
 export * from './updateAuthority';
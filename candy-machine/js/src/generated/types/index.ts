@@ -5,5 +5,6 @@ export * from './EndSettingType';
 export * from './EndSettings';
 export * from './GatekeeperConfig';
 export * from './HiddenSettings';
+export * from './MintPhase';
 export * from './WhitelistMintMode';
 export * from './WhitelistMintSettings';
@@ -14,9 +14,14 @@ pub const MAX_FREEZE_TIME: i64 = 60 * 60 * 24 * 31; // 1 month
 pub const COLLECTIONS_FEATURE_INDEX: usize = 0;
 pub const FREEZE_FEATURE_INDEX: usize = 1;
 pub const FREEZE_LOCK_FEATURE_INDEX: usize = 2;
+pub const MINT_RECEIPT_FEATURE_INDEX: usize = 3;
+pub const END_SETTINGS_FEATURE_INDEX: usize = 4;
 
 pub const COLLECTION_PDA_SIZE: usize = 8 + 32 + 32;
 
+pub const MAX_END_SETTINGS: usize = 4;
+pub const END_SETTINGS_SIZE: usize = 1 + 8; // type + number
+
 pub const CONFIG_LINE_SIZE: usize = 4 + MAX_NAME_LENGTH + 4 + MAX_URI_LENGTH;
 
 pub const BLOCK_HASHES: Pubkey =
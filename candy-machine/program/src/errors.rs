@@ -110,4 +110,22 @@ pub enum CandyError {
     SizedCollectionMetadataMustBeMutable,
     #[msg("Cannot remove Hidden Settings.")]
     CannotSwitchFromHiddenSettings,
+    #[msg("Candy machine end date has been reached!")]
+    EndDateReached,
+    #[msg("Candy machine end amount has been reached!")]
+    EndAmountReached,
+    #[msg("Candy machine presale amount has been reached!")]
+    PresaleAmountReached,
+    #[msg("Can only provide up to MAX_END_SETTINGS end settings.")]
+    TooManyEndSettings,
+    #[msg("Mint receipt PDA address is invalid")]
+    MismatchedMintReceiptPDA,
+    #[msg("Only the candy machine authority or the payer can close a mint receipt")]
+    InvalidMintReceiptCloser,
+    #[msg("End settings PDA address is invalid")]
+    MismatchedEndSettingsPDA,
+    #[msg(
+        "Can't withdraw Candy Machine while end settings PDA is set. Remove end settings first."
+    )]
+    NoWithdrawWithEndSettings,
 }
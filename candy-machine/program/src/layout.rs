@@ -0,0 +1,150 @@
+use anchor_lang::{error::ErrorCode, prelude::*};
+use arrayref::array_ref;
+use mpl_token_metadata::state::{MAX_NAME_LENGTH, MAX_URI_LENGTH};
+
+use crate::{
+    constants::{CONFIG_ARRAY_START, CONFIG_LINE_SIZE},
+    CandyError, CandyMachine, ConfigLine,
+};
+
+// Everything past CONFIG_ARRAY_START is laid out as:
+//
+// u32 number of loaded config lines
+// items_available * CONFIG_LINE_SIZE config lines
+// u32 length of the loaded bitmask
+// ceil(items_available / 8) bytes marking which config lines were loaded
+// u32 length of the minted bitmask
+// ceil(items_available / 8) bytes marking which config lines were minted
+
+/// Offset of the config line at `index`.
+pub fn config_line_offset(index: usize) -> usize {
+    CONFIG_ARRAY_START + 4 + index * CONFIG_LINE_SIZE
+}
+
+/// Offset of the bitmask marking which config lines have been loaded.
+pub fn loaded_bitmask_offset(items_available: usize) -> usize {
+    config_line_offset(items_available) + 4
+}
+
+/// Offset of the bitmask marking which config lines have been minted.
+pub fn minted_bitmask_offset(items_available: usize) -> usize {
+    loaded_bitmask_offset(items_available) + items_available / 8 + 4
+}
+
+/// Byte offset inside a bitmask and the bit used for `index`.
+pub fn bitmask_position(index: usize) -> (usize, u8) {
+    (index / 8, 1 << (7 - index % 8))
+}
+
+/// Reads a zero padded config line as written by `add_config_lines`.
+pub fn read_config_line(data: &[u8]) -> Result<ConfigLine> {
+    let name = &data[4..4 + MAX_NAME_LENGTH];
+    let uri = &data[8 + MAX_NAME_LENGTH..8 + MAX_NAME_LENGTH + MAX_URI_LENGTH];
+
+    Ok(ConfigLine {
+        name: read_padded_string(name)?,
+        uri: read_padded_string(uri)?,
+    })
+}
+
+fn read_padded_string(data: &[u8]) -> Result<String> {
+    let len = data.iter().position(|b| *b == 0).unwrap_or(data.len());
+    String::from_utf8(data[..len].to_vec()).map_err(|_| error!(CandyError::InvalidString))
+}
+
+/// Full candy machine account, including the data stored past the
+/// Anchor-serialized [`CandyMachine`].
+#[derive(Debug)]
+pub struct CandyMachineAccountData {
+    pub candy_machine: CandyMachine,
+    /// Number of loaded config lines as tracked by the program.
+    pub loaded_count: u32,
+    /// Config lines by index, empty where nothing has been loaded yet.
+    pub config_lines: Vec<ConfigLine>,
+    /// Whether the config line at each index has been loaded.
+    pub loaded: Vec<bool>,
+    /// Whether the config line at each index has been minted.
+    pub minted: Vec<bool>,
+}
+
+impl CandyMachineAccountData {
+    /// Decodes the raw data of a candy machine account. Candy machines using
+    /// hidden settings have no config lines, so only the struct is returned.
+    pub fn from_account_data(data: &[u8]) -> Result<Self> {
+        let candy_machine = CandyMachine::try_deserialize(&mut &data[..])?;
+
+        if candy_machine.data.hidden_settings.is_some() {
+            return Ok(Self {
+                candy_machine,
+                loaded_count: 0,
+                config_lines: vec![],
+                loaded: vec![],
+                minted: vec![],
+            });
+        }
+
+        let items_available = candy_machine.data.items_available as usize;
+        let minted_start = minted_bitmask_offset(items_available);
+        if data.len() < minted_start + items_available / 8 + 1 {
+            return Err(ErrorCode::AccountDidNotDeserialize.into());
+        }
+
+        let loaded_count = u32::from_le_bytes(*array_ref![data, CONFIG_ARRAY_START, 4]);
+        let loaded_start = loaded_bitmask_offset(items_available);
+
+        let mut config_lines = Vec::with_capacity(items_available);
+        let mut loaded = Vec::with_capacity(items_available);
+        let mut minted = Vec::with_capacity(items_available);
+        for index in 0..items_available {
+            let (byte, mask) = bitmask_position(index);
+            let start = config_line_offset(index);
+            config_lines.push(read_config_line(&data[start..start + CONFIG_LINE_SIZE])?);
+            loaded.push(data[loaded_start + byte] & mask > 0);
+            minted.push(data[minted_start + byte] & mask > 0);
+        }
+
+        Ok(Self {
+            candy_machine,
+            loaded_count,
+            config_lines,
+            loaded,
+            minted,
+        })
+    }
+
+    /// Config lines that have been loaded but not minted yet.
+    pub fn available_config_lines(&self) -> Vec<(usize, &ConfigLine)> {
+        self.config_lines
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| self.loaded[*index] && !self.minted[*index])
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bitmask_position_matches_program_layout() {
+        assert_eq!(bitmask_position(0), (0, 0b1000_0000));
+        assert_eq!(bitmask_position(7), (0, 0b0000_0001));
+        assert_eq!(bitmask_position(8), (1, 0b1000_0000));
+        assert_eq!(bitmask_position(13), (1, 0b0000_0100));
+    }
+
+    #[test]
+    fn read_config_line_strips_padding() {
+        let line = ConfigLine {
+            name: "Item #1".to_string() + &"\0".repeat(MAX_NAME_LENGTH - 7),
+            uri: "https://arweave.net/1".to_string() + &"\0".repeat(MAX_URI_LENGTH - 21),
+        };
+        let data = line.try_to_vec().unwrap();
+        assert_eq!(data.len(), CONFIG_LINE_SIZE);
+
+        let decoded = read_config_line(&data).unwrap();
+        assert_eq!(decoded.name, "Item #1");
+        assert_eq!(decoded.uri, "https://arweave.net/1");
+    }
+}
@@ -1,5 +1,6 @@
 pub mod constants;
 pub mod errors;
+pub mod layout;
 pub mod processor;
 pub mod state;
 pub mod utils;
@@ -84,4 +85,27 @@ pub mod candy_machine {
     pub fn unlock_funds<'info>(ctx: Context<'_, '_, '_, 'info, UnlockFunds<'info>>) -> Result<()> {
         handle_unlock_funds(ctx)
     }
+
+    pub fn set_mint_receipts(ctx: Context<SetMintReceipts>) -> Result<()> {
+        handle_set_mint_receipts(ctx)
+    }
+
+    pub fn remove_mint_receipts(ctx: Context<SetMintReceipts>) -> Result<()> {
+        handle_remove_mint_receipts(ctx)
+    }
+
+    pub fn close_mint_receipt(ctx: Context<CloseMintReceipt>) -> Result<()> {
+        handle_close_mint_receipt(ctx)
+    }
+
+    pub fn set_end_settings(
+        ctx: Context<SetEndSettings>,
+        end_settings: Vec<EndSettings>,
+    ) -> Result<()> {
+        handle_set_end_settings(ctx, end_settings)
+    }
+
+    pub fn remove_end_settings(ctx: Context<RemoveEndSettings>) -> Result<()> {
+        handle_remove_end_settings(ctx)
+    }
 }
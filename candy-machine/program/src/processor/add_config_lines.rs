@@ -6,6 +6,7 @@ use mpl_token_metadata::state::{MAX_NAME_LENGTH, MAX_URI_LENGTH};
 
 use crate::{
     constants::{CONFIG_ARRAY_START, CONFIG_LINE_SIZE},
+    layout::{config_line_offset, loaded_bitmask_offset},
     CandyError, CandyMachine, ConfigLine,
 };
 
@@ -58,17 +59,14 @@ pub fn handle_add_config_lines(
     // remove unneeded u32 because we're just gonna edit the u32 at the front
     let serialized: &[u8] = &as_vec.as_slice()[4..];
 
-    let position = CONFIG_ARRAY_START + 4 + (index as usize) * CONFIG_LINE_SIZE;
+    let position = config_line_offset(index as usize);
 
     let array_slice: &mut [u8] =
         &mut data[position..position + fixed_config_lines.len() * CONFIG_LINE_SIZE];
 
     array_slice.copy_from_slice(serialized);
 
-    let bit_mask_vec_start = CONFIG_ARRAY_START
-        + 4
-        + (candy_machine.data.items_available as usize) * CONFIG_LINE_SIZE
-        + 4;
+    let bit_mask_vec_start = loaded_bitmask_offset(candy_machine.data.items_available as usize);
 
     let mut new_count = current_count;
     for i in 0..fixed_config_lines.len() {
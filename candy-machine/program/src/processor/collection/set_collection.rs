@@ -3,8 +3,8 @@ use mpl_token_metadata::{
     assertions::collection::assert_master_edition,
     instruction::approve_collection_authority,
     state::{Metadata, TokenMetadataAccount},
-    utils::create_or_allocate_account_raw,
 };
+use mpl_utils::create_or_allocate_account_raw;
 use solana_program::program::invoke;
 
 use crate::{
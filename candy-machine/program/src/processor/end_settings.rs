@@ -0,0 +1,55 @@
+use anchor_lang::{prelude::*, AccountsClose};
+
+use crate::{
+    constants::{END_SETTINGS_FEATURE_INDEX, MAX_END_SETTINGS},
+    remove_feature_flag, set_feature_flag, CandyError, CandyMachine, EndSettings, EndSettingsPDA,
+};
+
+/// Set the end settings PDA holding additional end conditions for the candy machine
+#[derive(Accounts)]
+pub struct SetEndSettings<'info> {
+    #[account(mut, has_one = authority)]
+    candy_machine: Account<'info, CandyMachine>,
+    #[account(mut)]
+    authority: Signer<'info>,
+    #[account(init, seeds = [EndSettingsPDA::PREFIX.as_bytes(), candy_machine.to_account_info().key.as_ref()], bump, space = EndSettingsPDA::SIZE, payer = authority)]
+    end_settings_pda: Account<'info, EndSettingsPDA>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handle_set_end_settings(
+    ctx: Context<SetEndSettings>,
+    end_settings: Vec<EndSettings>,
+) -> Result<()> {
+    if end_settings.len() > MAX_END_SETTINGS {
+        return err!(CandyError::TooManyEndSettings);
+    }
+
+    let candy_machine = &mut ctx.accounts.candy_machine;
+    let end_settings_pda = &mut ctx.accounts.end_settings_pda;
+    end_settings_pda.candy_machine = candy_machine.key();
+    end_settings_pda.end_settings = end_settings;
+
+    set_feature_flag(&mut candy_machine.data.uuid, END_SETTINGS_FEATURE_INDEX);
+    Ok(())
+}
+
+/// Remove the end settings PDA, returning its rent to the authority
+#[derive(Accounts)]
+pub struct RemoveEndSettings<'info> {
+    #[account(mut, has_one = authority)]
+    candy_machine: Account<'info, CandyMachine>,
+    #[account(mut)]
+    authority: Signer<'info>,
+    #[account(mut, seeds = [EndSettingsPDA::PREFIX.as_bytes(), candy_machine.to_account_info().key.as_ref()], bump)]
+    end_settings_pda: Account<'info, EndSettingsPDA>,
+}
+
+pub fn handle_remove_end_settings(ctx: Context<RemoveEndSettings>) -> Result<()> {
+    let candy_machine = &mut ctx.accounts.candy_machine;
+    remove_feature_flag(&mut candy_machine.data.uuid, END_SETTINGS_FEATURE_INDEX);
+    ctx.accounts
+        .end_settings_pda
+        .close(ctx.accounts.authority.to_account_info())?;
+    Ok(())
+}
@@ -3,13 +3,11 @@ use std::{cell::RefMut, ops::Deref};
 use anchor_lang::prelude::*;
 use anchor_spl::token::Token;
 use arrayref::array_ref;
-use mpl_token_metadata::{
-    instruction::{
-        create_master_edition_v3, create_metadata_accounts_v3, freeze_delegated_account,
-        update_metadata_accounts_v2,
-    },
-    state::{MAX_NAME_LENGTH, MAX_URI_LENGTH},
+use mpl_token_metadata::instruction::{
+    create_master_edition_v3, create_metadata_accounts_v3, freeze_delegated_account,
+    update_metadata_accounts_v2,
 };
+use mpl_utils::create_or_allocate_account_raw;
 use solana_gateway::{
     state::{GatewayTokenAccess, InPlaceGatewayToken},
     Gateway,
@@ -26,12 +24,13 @@ use spl_token::instruction::approve;
 use crate::{
     constants::{
         A_TOKEN, BLOCK_HASHES, BOT_FEE, COLLECTIONS_FEATURE_INDEX, COMPUTE_BUDGET,
-        CONFIG_ARRAY_START, CONFIG_LINE_SIZE, CUPCAKE_ID, EXPIRE_OFFSET, FREEZE_FEATURE_INDEX,
-        GUMDROP_ID, PREFIX,
+        CONFIG_LINE_SIZE, CUPCAKE_ID, END_SETTINGS_FEATURE_INDEX, EXPIRE_OFFSET,
+        FREEZE_FEATURE_INDEX, GUMDROP_ID, MINT_RECEIPT_FEATURE_INDEX, PREFIX,
     },
+    layout::{config_line_offset, minted_bitmask_offset, read_config_line},
     utils::*,
-    CandyError, CandyMachine, CandyMachineData, ConfigLine, EndSettingType, FreezePDA,
-    WhitelistMintMode, WhitelistMintSettings,
+    CandyError, CandyMachine, CandyMachineData, ConfigLine, EndSettingsPDA, FreezePDA, MintPhase,
+    MintReceipt, WhitelistMintMode, WhitelistMintSettings,
 };
 
 /// Mint a new NFT pseudo-randomly from the config array.
@@ -94,6 +93,10 @@ pub struct MintNFT<'info> {
     // freeze_pda (writable)
     // nft_token_account (writable)
     // freeze_ata (writable) // Only needed if spl token mint is enabled
+    // > Only needed if the end settings PDA is set
+    // end_settings_pda
+    // > Only needed if mint receipts are enabled
+    // mint_receipt (writable)
 }
 
 pub fn handle_mint_nft<'info>(
@@ -221,39 +224,30 @@ pub fn handle_mint_nft<'info>(
     }
 
     let mut price = candy_machine.data.price;
-    if let Some(es) = &candy_machine.data.end_settings {
-        match es.end_setting_type {
-            EndSettingType::Date => {
-                if clock.unix_timestamp > es.number as i64
-                    && !cmp_pubkeys(&ctx.accounts.payer.key(), &candy_machine.authority)
-                {
-                    punish_bots(
-                        CandyError::CandyMachineNotLive,
-                        payer.to_account_info(),
-                        ctx.accounts.candy_machine.to_account_info(),
-                        ctx.accounts.system_program.to_account_info(),
-                        BOT_FEE,
-                    )?;
-                    return Ok(());
-                }
-            }
-            EndSettingType::Amount => {
-                if candy_machine.items_redeemed >= es.number {
-                    if !cmp_pubkeys(&ctx.accounts.payer.key(), &candy_machine.authority) {
-                        punish_bots(
-                            CandyError::CandyMachineEmpty,
-                            payer.to_account_info(),
-                            ctx.accounts.candy_machine.to_account_info(),
-                            ctx.accounts.system_program.to_account_info(),
-                            BOT_FEE,
-                        )?;
-                        return Ok(());
-                    }
-                    return err!(CandyError::CandyMachineEmpty);
-                }
+    let end_settings_pda: Option<Account<EndSettingsPDA>> =
+        if is_feature_active(&candy_machine.data.uuid, END_SETTINGS_FEATURE_INDEX) {
+            // right before the mint receipt, which is always the last remaining account
+            let mut end_settings_index = get_expected_remaining_accounts_count(candy_machine) - 1;
+            if is_feature_active(&candy_machine.data.uuid, MINT_RECEIPT_FEATURE_INDEX) {
+                end_settings_index -= 1;
             }
-        }
-    }
+            let end_settings_pda: Account<EndSettingsPDA> =
+                Account::try_from(&ctx.remaining_accounts[end_settings_index])?;
+            end_settings_pda.assert_from_candy(&candy_machine.key())?;
+            Some(end_settings_pda)
+        } else {
+            None
+        };
+    candy_machine.assert_not_ended(
+        end_settings_pda
+            .as_ref()
+            .map(|end_settings_pda| end_settings_pda.end_settings.as_slice())
+            .unwrap_or_default(),
+        &payer.key(),
+        clock.unix_timestamp,
+    )?;
+
+    let mut phase = MintPhase::Public;
     let mut remaining_accounts_counter: usize = 0;
     if let Some(gatekeeper) = &candy_machine.data.gatekeeper {
         let gateway_token_info = &ctx.remaining_accounts[remaining_accounts_counter];
@@ -336,8 +330,10 @@ pub fn handle_mint_nft<'info>(
         match assert_is_ata(whitelist_token_account, &payer.key(), &ws.mint) {
             Ok(wta) => {
                 if wta.amount > 0 {
+                    phase = MintPhase::Whitelist;
                     match candy_machine.data.go_live_date {
                         None => {
+                            phase = MintPhase::Presale;
                             if !cmp_pubkeys(&ctx.accounts.payer.key(), &candy_machine.authority)
                                 && !ws.presale
                             {
@@ -352,6 +348,9 @@ pub fn handle_mint_nft<'info>(
                             }
                         }
                         Some(val) => {
+                            if clock.unix_timestamp < val {
+                                phase = MintPhase::Presale;
+                            }
                             if clock.unix_timestamp < val
                                 && !cmp_pubkeys(&ctx.accounts.payer.key(), &candy_machine.authority)
                                 && !ws.presale
@@ -545,7 +544,8 @@ pub fn handle_mint_nft<'info>(
         .checked_rem(candy_machine.data.items_available)
         .ok_or(CandyError::NumericalOverflowError)? as usize;
 
-    let config_line = get_config_line(candy_machine, modded, candy_machine.items_redeemed)?;
+    let (config_line, config_line_index) =
+        get_config_line_and_index(candy_machine, modded, candy_machine.items_redeemed)?;
 
     candy_machine.items_redeemed = candy_machine
         .items_redeemed
@@ -740,6 +740,52 @@ pub fn handle_mint_nft<'info>(
         freeze_pda.exit(&crate::id())?;
     }
 
+    if is_feature_active(&candy_machine.data.uuid, MINT_RECEIPT_FEATURE_INDEX) {
+        // always the last remaining account
+        let mint_receipt_info =
+            &ctx.remaining_accounts[get_expected_remaining_accounts_count(candy_machine) - 1];
+        let candy_pubkey = candy_machine.key();
+        let mint_pubkey = ctx.accounts.mint.key();
+        let seeds: &[&[u8]] = &[
+            MintReceipt::PREFIX.as_bytes(),
+            candy_pubkey.as_ref(),
+            mint_pubkey.as_ref(),
+        ];
+        let (expected_receipt_key, receipt_bump) =
+            Pubkey::find_program_address(seeds, &crate::id());
+        if !cmp_pubkeys(&expected_receipt_key, mint_receipt_info.key) {
+            return err!(CandyError::MismatchedMintReceiptPDA);
+        }
+
+        create_or_allocate_account_raw(
+            crate::id(),
+            mint_receipt_info,
+            &ctx.accounts.system_program.to_account_info(),
+            &payer.to_account_info(),
+            MintReceipt::SIZE,
+            &[
+                MintReceipt::PREFIX.as_bytes(),
+                candy_pubkey.as_ref(),
+                mint_pubkey.as_ref(),
+                &[receipt_bump],
+            ],
+        )?;
+
+        let mint_receipt = MintReceipt {
+            candy_machine: candy_pubkey,
+            mint: mint_pubkey,
+            payer: payer.key(),
+            config_line_index: config_line_index as u64,
+            phase,
+            price,
+            payment_mint: candy_machine.token_mint,
+            created_at: clock.unix_timestamp,
+        };
+        let mut data = mint_receipt_info.try_borrow_mut_data()?;
+        let mut writer: &mut [u8] = &mut data[..];
+        mint_receipt.try_serialize(&mut writer)?;
+    }
+
     Ok(())
 }
 
@@ -752,14 +798,7 @@ pub fn get_good_index(
     let mut index_to_use = index;
     let mut taken = 1;
     let mut found = false;
-    let bit_mask_vec_start = CONFIG_ARRAY_START
-        + 4
-        + (items_available) * CONFIG_LINE_SIZE
-        + 4
-        + items_available
-            .checked_div(8)
-            .ok_or(CandyError::NumericalOverflowError)?
-        + 4;
+    let bit_mask_vec_start = minted_bitmask_offset(items_available);
 
     while taken > 0 && index_to_use < items_available {
         let my_position_in_vec = bit_mask_vec_start
@@ -820,11 +859,24 @@ pub fn get_config_line(
     index: usize,
     mint_number: u64,
 ) -> Result<ConfigLine> {
+    get_config_line_and_index(a, index, mint_number).map(|(config_line, _)| config_line)
+}
+
+/// Same as [`get_config_line`], also returning the index of the config line that was used.
+/// With hidden settings the index is the mint number.
+pub fn get_config_line_and_index(
+    a: &Account<'_, CandyMachine>,
+    index: usize,
+    mint_number: u64,
+) -> Result<(ConfigLine, usize)> {
     if let Some(hs) = &a.data.hidden_settings {
-        return Ok(ConfigLine {
-            name: hs.name.clone() + "#" + &(mint_number + 1).to_string(),
-            uri: hs.uri.clone(),
-        });
+        return Ok((
+            ConfigLine {
+                name: hs.name.clone() + "#" + &(mint_number + 1).to_string(),
+                uri: hs.uri.clone(),
+            },
+            mint_number as usize,
+        ));
     }
     let a_info = a.to_account_info();
 
@@ -841,43 +893,14 @@ pub fn get_config_line(
         }
     }
 
-    if arr[CONFIG_ARRAY_START + 4 + index_to_use * (CONFIG_LINE_SIZE)] == 1 {
+    let line_start = config_line_offset(index_to_use);
+    if arr[line_start] == 1 {
         return err!(CandyError::CannotFindUsableConfigLine);
     }
 
-    let data_array = &mut arr[CONFIG_ARRAY_START + 4 + index_to_use * (CONFIG_LINE_SIZE)
-        ..CONFIG_ARRAY_START + 4 + (index_to_use + 1) * (CONFIG_LINE_SIZE)];
-
-    let mut name_vec = Vec::with_capacity(MAX_NAME_LENGTH);
-    let mut uri_vec = Vec::with_capacity(MAX_URI_LENGTH);
-
-    #[allow(clippy::needless_range_loop)]
-    for i in 4..4 + MAX_NAME_LENGTH {
-        if data_array[i] == 0 {
-            break;
-        }
-        name_vec.push(data_array[i])
-    }
+    let config_line = read_config_line(&arr[line_start..line_start + CONFIG_LINE_SIZE])?;
 
-    #[allow(clippy::needless_range_loop)]
-    for i in 8 + MAX_NAME_LENGTH..8 + MAX_NAME_LENGTH + MAX_URI_LENGTH {
-        if data_array[i] == 0 {
-            break;
-        }
-        uri_vec.push(data_array[i])
-    }
-    let config_line: ConfigLine = ConfigLine {
-        name: match String::from_utf8(name_vec) {
-            Ok(val) => val,
-            Err(_) => return err!(CandyError::InvalidString),
-        },
-        uri: match String::from_utf8(uri_vec) {
-            Ok(val) => val,
-            Err(_) => return err!(CandyError::InvalidString),
-        },
-    };
-
-    Ok(config_line)
+    Ok((config_line, index_to_use))
 }
 
 pub fn get_expected_remaining_accounts_count(candy: &CandyMachine) -> usize {
@@ -904,5 +927,11 @@ pub fn get_expected_remaining_accounts_count(candy: &CandyMachine) -> usize {
             expected_count += 1;
         }
     }
+    if is_feature_active(&candy.data.uuid, END_SETTINGS_FEATURE_INDEX) {
+        expected_count += 1;
+    }
+    if is_feature_active(&candy.data.uuid, MINT_RECEIPT_FEATURE_INDEX) {
+        expected_count += 1;
+    }
     expected_count
 }
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    cmp_pubkeys, constants::MINT_RECEIPT_FEATURE_INDEX, remove_feature_flag, set_feature_flag,
+    CandyError, CandyMachine, MintReceipt,
+};
+
+/// Enable or disable mint receipts for the candy machine.
+#[derive(Accounts)]
+pub struct SetMintReceipts<'info> {
+    #[account(mut, has_one = authority)]
+    candy_machine: Account<'info, CandyMachine>,
+    authority: Signer<'info>,
+}
+
+pub fn handle_set_mint_receipts(ctx: Context<SetMintReceipts>) -> Result<()> {
+    let candy_machine = &mut ctx.accounts.candy_machine;
+    set_feature_flag(&mut candy_machine.data.uuid, MINT_RECEIPT_FEATURE_INDEX);
+    Ok(())
+}
+
+pub fn handle_remove_mint_receipts(ctx: Context<SetMintReceipts>) -> Result<()> {
+    let candy_machine = &mut ctx.accounts.candy_machine;
+    remove_feature_flag(&mut candy_machine.data.uuid, MINT_RECEIPT_FEATURE_INDEX);
+    Ok(())
+}
+
+/// Close a mint receipt, returning its rent to the wallet that paid for the mint.
+#[derive(Accounts)]
+pub struct CloseMintReceipt<'info> {
+    #[account(
+        mut,
+        close = payer,
+        has_one = candy_machine,
+        has_one = payer,
+        seeds = [MintReceipt::PREFIX.as_bytes(), candy_machine.key().as_ref(), mint_receipt.mint.as_ref()],
+        bump
+    )]
+    mint_receipt: Account<'info, MintReceipt>,
+    /// CHECK: may already be closed by withdraw, checked in handler when the signer isn't the payer
+    candy_machine: UncheckedAccount<'info>,
+    /// CHECK: receipt payer, checked by has_one constraint
+    #[account(mut)]
+    payer: UncheckedAccount<'info>,
+    authority: Signer<'info>,
+}
+
+pub fn handle_close_mint_receipt(ctx: Context<CloseMintReceipt>) -> Result<()> {
+    let authority = &ctx.accounts.authority;
+
+    // The payer can always reclaim its own rent, anyone else must be the candy machine authority
+    if !cmp_pubkeys(authority.key, ctx.accounts.payer.key) {
+        let candy_machine_info = ctx.accounts.candy_machine.to_account_info();
+        let candy_machine: Account<CandyMachine> = Account::try_from(&candy_machine_info)
+            .map_err(|_| error!(CandyError::InvalidMintReceiptCloser))?;
+        if !cmp_pubkeys(authority.key, &candy_machine.authority) {
+            return err!(CandyError::InvalidMintReceiptCloser);
+        }
+    }
+
+    Ok(())
+}
@@ -1,15 +1,19 @@
 pub mod add_config_lines;
 pub mod collection;
+pub mod end_settings;
 pub mod freeze;
 pub mod initialize;
 pub mod mint;
+pub mod mint_receipt;
 pub mod update;
 pub mod withdraw;
 
 pub use add_config_lines::*;
 pub use collection::*;
+pub use end_settings::*;
 pub use freeze::*;
 pub use initialize::*;
 pub use mint::*;
+pub use mint_receipt::*;
 pub use update::*;
 pub use withdraw::*;
@@ -2,7 +2,7 @@ use anchor_lang::{prelude::*, AccountsClose};
 
 use crate::{
     cmp_pubkeys,
-    constants::{FREEZE_FEATURE_INDEX, FREEZE_LOCK_FEATURE_INDEX},
+    constants::{END_SETTINGS_FEATURE_INDEX, FREEZE_FEATURE_INDEX, FREEZE_LOCK_FEATURE_INDEX},
     is_feature_active, CandyError, CandyMachine, CollectionPDA,
 };
 
@@ -28,6 +28,9 @@ pub fn handle_withdraw_funds<'info>(
     if is_feature_active(&candy_machine.data.uuid, FREEZE_LOCK_FEATURE_INDEX) {
         return err!(CandyError::NoWithdrawWithFrozenFunds);
     }
+    if is_feature_active(&candy_machine.data.uuid, END_SETTINGS_FEATURE_INDEX) {
+        return err!(CandyError::NoWithdrawWithEndSettings);
+    }
 
     if !ctx.remaining_accounts.is_empty() {
         let candy_key = candy_machine.key();
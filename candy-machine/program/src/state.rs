@@ -1,4 +1,8 @@
-use crate::{constants::FREEZE_FEE, CandyError};
+use crate::{
+    cmp_pubkeys,
+    constants::{END_SETTINGS_SIZE, FREEZE_FEE, MAX_END_SETTINGS},
+    CandyError,
+};
 use anchor_lang::prelude::*;
 
 /// Candy machine state and config data.
@@ -74,6 +78,65 @@ impl FreezePDA {
     }
 }
 
+/// End settings PDA account, holds the end conditions checked on top of the candy machine
+/// `end_settings`.
+#[account]
+#[derive(Default, Debug)]
+pub struct EndSettingsPDA {
+    pub candy_machine: Pubkey,          // 32
+    pub end_settings: Vec<EndSettings>, // 4 + MAX_END_SETTINGS * END_SETTINGS_SIZE
+}
+
+impl EndSettingsPDA {
+    pub const SIZE: usize = 8 + 32 + 4 + MAX_END_SETTINGS * END_SETTINGS_SIZE;
+
+    pub const PREFIX: &'static str = "end_settings";
+
+    pub fn assert_from_candy(&self, candy_machine: &Pubkey) -> Result<()> {
+        if &self.candy_machine != candy_machine {
+            return err!(CandyError::MismatchedEndSettingsPDA);
+        }
+        Ok(())
+    }
+}
+
+/// Mint receipt PDA account, records how an NFT was minted from the candy machine.
+#[account]
+#[derive(Default, Debug, PartialEq, Eq)]
+pub struct MintReceipt {
+    pub candy_machine: Pubkey,        // 32
+    pub mint: Pubkey,                 // 32
+    pub payer: Pubkey,                // 32
+    pub config_line_index: u64,       // 8
+    pub phase: MintPhase,             // 1
+    pub price: u64,                   // 8
+    pub payment_mint: Option<Pubkey>, // 1 + 32
+    pub created_at: i64,              // 8
+}
+
+impl MintReceipt {
+    pub const SIZE: usize = 8 + 32 + 32 + 32 + 8 + 1 + 8 + 1 + 32 + 8;
+
+    pub const PREFIX: &'static str = "mint_receipt";
+}
+
+/// Phase of the mint an NFT was minted in.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MintPhase {
+    /// Minted by the general public.
+    Public,
+    /// Minted with a whitelist token before the go live date.
+    Presale,
+    /// Minted with a whitelist token after the go live date.
+    Whitelist,
+}
+
+impl Default for MintPhase {
+    fn default() -> Self {
+        MintPhase::Public
+    }
+}
+
 /// Candy machine settings data.
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Debug)]
 pub struct CandyMachineData {
@@ -104,10 +167,24 @@ impl CandyMachine {
             Ok(())
         }
     }
+
+    /// Checks `end_settings` and the additional end conditions of the end settings PDA,
+    /// failing with the first one that has been reached.
+    pub fn assert_not_ended(
+        &self,
+        additional_end_settings: &[EndSettings],
+        payer: &Pubkey,
+        current_timestamp: i64,
+    ) -> Result<()> {
+        for end_settings in self.data.end_settings.iter().chain(additional_end_settings) {
+            end_settings.assert_not_reached(self, payer, current_timestamp)?;
+        }
+        Ok(())
+    }
 }
 
 /// Individual config line for storing NFT data pre-mint.
-#[derive(AnchorSerialize, AnchorDeserialize, Debug)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
 pub struct ConfigLine {
     pub name: String,
     /// URI pointing to JSON representing the asset
@@ -120,10 +197,49 @@ pub struct EndSettings {
     pub number: u64,
 }
 
+impl EndSettings {
+    /// Fails with the error of this end condition if it has been reached.
+    /// The candy machine authority is only exempt from date and presale conditions.
+    pub fn assert_not_reached(
+        &self,
+        candy_machine: &CandyMachine,
+        payer: &Pubkey,
+        current_timestamp: i64,
+    ) -> Result<()> {
+        let is_authority = cmp_pubkeys(payer, &candy_machine.authority);
+        match self.end_setting_type {
+            EndSettingType::Date => {
+                if current_timestamp > self.number as i64 && !is_authority {
+                    return err!(CandyError::EndDateReached);
+                }
+            }
+            EndSettingType::Amount => {
+                if candy_machine.items_redeemed >= self.number {
+                    return err!(CandyError::EndAmountReached);
+                }
+            }
+            EndSettingType::PresaleAmount => {
+                let is_presale = match candy_machine.data.go_live_date {
+                    Some(go_live_date) => current_timestamp < go_live_date,
+                    None => true,
+                };
+                if is_presale && candy_machine.items_redeemed >= self.number && !is_authority {
+                    return err!(CandyError::PresaleAmountReached);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub enum EndSettingType {
+    /// Minting ends after the unix timestamp in `number`.
     Date,
+    /// Minting ends once `number` items have been redeemed.
     Amount,
+    /// Minting before the go live date ends once `number` items have been redeemed.
+    PresaleAmount,
 }
 
 // Unfortunate duplication of token metadata so that IDL picks it up.
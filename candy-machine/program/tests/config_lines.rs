@@ -0,0 +1,129 @@
+#![cfg(feature = "test-bpf")]
+#![allow(dead_code)]
+
+use mpl_candy_machine::layout::CandyMachineAccountData;
+use solana_program_test::*;
+use solana_sdk::signer::Signer;
+
+use crate::{
+    core::helpers::{airdrop, get_account},
+    utils::{
+        add_config_lines, auto_config, candy_machine_program_test,
+        helpers::{make_config_lines, sol, test_start},
+        CandyManager,
+    },
+};
+
+mod core;
+mod utils;
+
+async fn get_account_data(
+    context: &mut ProgramTestContext,
+    candy_manager: &CandyManager,
+) -> CandyMachineAccountData {
+    let account = get_account(context, &candy_manager.candy_machine.pubkey()).await;
+    CandyMachineAccountData::from_account_data(&account.data).unwrap()
+}
+
+#[tokio::test]
+async fn decode_added_config_lines() {
+    test_start("Decode Added Config Lines");
+    let mut context = candy_machine_program_test().start_with_context().await;
+    let context = &mut context;
+    let mut candy_manager = CandyManager::init(context, None, false, None, None, None).await;
+
+    let candy_data = auto_config(&candy_manager, Some(0), true, true, None, None);
+    let items_available = candy_data.items_available as usize;
+    candy_manager.create(context, candy_data).await.unwrap();
+
+    let decoded = get_account_data(context, &candy_manager).await;
+    assert_eq!(decoded.loaded_count, 0);
+    assert_eq!(decoded.config_lines.len(), items_available);
+    assert!(decoded.loaded.iter().all(|loaded| !loaded));
+    assert!(decoded.minted.iter().all(|minted| !minted));
+
+    add_config_lines(
+        context,
+        &candy_manager.candy_machine.pubkey(),
+        &candy_manager.authority,
+        0,
+        make_config_lines(0, 3),
+    )
+    .await
+    .unwrap();
+    add_config_lines(
+        context,
+        &candy_manager.candy_machine.pubkey(),
+        &candy_manager.authority,
+        8,
+        make_config_lines(8, 2),
+    )
+    .await
+    .unwrap();
+
+    let decoded = get_account_data(context, &candy_manager).await;
+    assert_eq!(decoded.loaded_count, 5);
+    for (index, line) in decoded.config_lines.iter().enumerate() {
+        let expected_loaded = index < 3 || index == 8 || index == 9;
+        assert_eq!(decoded.loaded[index], expected_loaded, "index {}", index);
+        if expected_loaded {
+            assert_eq!(line, &make_config_lines(index as u32, 1)[0]);
+        } else {
+            assert!(line.name.is_empty() && line.uri.is_empty());
+        }
+    }
+
+    // overwriting a loaded line must not change the count
+    add_config_lines(
+        context,
+        &candy_manager.candy_machine.pubkey(),
+        &candy_manager.authority,
+        1,
+        make_config_lines(20, 1),
+    )
+    .await
+    .unwrap();
+
+    let decoded = get_account_data(context, &candy_manager).await;
+    assert_eq!(decoded.loaded_count, 5);
+    assert_eq!(decoded.config_lines[1], make_config_lines(20, 1)[0]);
+}
+
+#[tokio::test]
+async fn decode_minted_bitmask() {
+    test_start("Decode Minted Bitmask");
+    let mut context = candy_machine_program_test().start_with_context().await;
+    let context = &mut context;
+    let mut candy_manager = CandyManager::init(context, None, false, None, None, None).await;
+
+    airdrop(context, &candy_manager.minter.pubkey(), sol(10.0))
+        .await
+        .unwrap();
+
+    let mut candy_data = auto_config(&candy_manager, Some(0), true, true, None, None);
+    candy_data.items_available = 10;
+    candy_manager.create(context, candy_data).await.unwrap();
+    add_config_lines(
+        context,
+        &candy_manager.candy_machine.pubkey(),
+        &candy_manager.authority,
+        0,
+        make_config_lines(0, 10),
+    )
+    .await
+    .unwrap();
+
+    for _ in 0..2 {
+        candy_manager
+            .mint_and_assert_successful(context, None, false)
+            .await
+            .unwrap();
+    }
+
+    let decoded = get_account_data(context, &candy_manager).await;
+    assert_eq!(decoded.candy_machine.items_redeemed, 2);
+    assert_eq!(decoded.loaded_count, 10);
+    assert!(decoded.loaded.iter().all(|loaded| *loaded));
+    assert_eq!(decoded.minted.iter().filter(|minted| **minted).count(), 2);
+    assert_eq!(decoded.available_config_lines().len(), 8);
+}
@@ -0,0 +1,201 @@
+#![cfg(feature = "test-bpf")]
+#![allow(dead_code)]
+
+use solana_program::instruction::InstructionError;
+use solana_program_test::*;
+use solana_sdk::{
+    signer::Signer,
+    transaction::TransactionError,
+    transport::{self, TransportError},
+};
+
+use mpl_candy_machine::{EndSettingType, EndSettings, WhitelistMintMode::NeverBurn};
+
+use crate::{
+    core::helpers::{airdrop, assert_account_empty},
+    utils::{
+        auto_config, candy_machine_program_test,
+        helpers::{find_end_settings_pda, sol, test_start},
+        CandyManager, WhitelistConfig,
+    },
+};
+
+mod core;
+mod utils;
+
+const END_DATE_REACHED: u32 = 6052;
+const END_AMOUNT_REACHED: u32 = 6053;
+const PRESALE_AMOUNT_REACHED: u32 = 6054;
+const TOO_MANY_END_SETTINGS: u32 = 6055;
+const NO_WITHDRAW_WITH_END_SETTINGS: u32 = 6059;
+
+fn custom_error<T>(result: transport::Result<T>) -> u32 {
+    match result {
+        Err(TransportError::TransactionError(TransactionError::InstructionError(
+            _,
+            InstructionError::Custom(err_num),
+        ))) => err_num,
+        _ => 0,
+    }
+}
+
+#[tokio::test]
+async fn amount_reached_before_date() {
+    test_start("Amount Reached Before Date");
+    let mut context = candy_machine_program_test().start_with_context().await;
+    let context = &mut context;
+    let mut candy_manager = CandyManager::init(context, None, false, None, None, None).await;
+
+    airdrop(context, &candy_manager.minter.pubkey(), sol(10.0))
+        .await
+        .unwrap();
+
+    let end_settings = Some(EndSettings {
+        end_setting_type: EndSettingType::Date,
+        number: i64::MAX as u64,
+    });
+    let candy_data = auto_config(&candy_manager, Some(0), true, true, end_settings, None);
+    candy_manager.create(context, candy_data).await.unwrap();
+    candy_manager.fill_config_lines(context).await.unwrap();
+    candy_manager
+        .set_end_settings(
+            context,
+            vec![EndSettings {
+                end_setting_type: EndSettingType::Amount,
+                number: 1,
+            }],
+        )
+        .await
+        .unwrap();
+
+    candy_manager
+        .mint_and_assert_successful(context, None, false)
+        .await
+        .unwrap();
+    let err = custom_error(candy_manager.mint_nft(context).await);
+    assert_eq!(err, END_AMOUNT_REACHED);
+}
+
+#[tokio::test]
+async fn date_reached_before_amount() {
+    test_start("Date Reached Before Amount");
+    let mut context = candy_machine_program_test().start_with_context().await;
+    let context = &mut context;
+    let mut candy_manager = CandyManager::init(context, None, false, None, None, None).await;
+
+    airdrop(context, &candy_manager.minter.pubkey(), sol(10.0))
+        .await
+        .unwrap();
+
+    let end_settings = Some(EndSettings {
+        end_setting_type: EndSettingType::Amount,
+        number: 5,
+    });
+    let candy_data = auto_config(&candy_manager, Some(0), true, true, end_settings, None);
+    candy_manager.create(context, candy_data).await.unwrap();
+    candy_manager.fill_config_lines(context).await.unwrap();
+    candy_manager
+        .set_end_settings(
+            context,
+            vec![EndSettings {
+                end_setting_type: EndSettingType::Date,
+                number: 1,
+            }],
+        )
+        .await
+        .unwrap();
+
+    let err = custom_error(candy_manager.mint_nft(context).await);
+    assert_eq!(err, END_DATE_REACHED);
+}
+
+#[tokio::test]
+async fn presale_amount_caps_whitelist_phase() {
+    test_start("Presale Amount Caps Whitelist Phase");
+    let mut context = candy_machine_program_test().start_with_context().await;
+    let context = &mut context;
+    let mut candy_manager = CandyManager::init(
+        context,
+        None,
+        false,
+        None,
+        Some(WhitelistConfig::new(NeverBurn, true, None)),
+        None,
+    )
+    .await;
+
+    airdrop(context, &candy_manager.minter.pubkey(), sol(10.0))
+        .await
+        .unwrap();
+
+    let candy_data = auto_config(&candy_manager, None, true, true, None, None);
+    candy_manager.create(context, candy_data).await.unwrap();
+    candy_manager.fill_config_lines(context).await.unwrap();
+    candy_manager
+        .set_end_settings(
+            context,
+            vec![EndSettings {
+                end_setting_type: EndSettingType::PresaleAmount,
+                number: 1,
+            }],
+        )
+        .await
+        .unwrap();
+
+    candy_manager
+        .mint_and_assert_successful(context, None, true)
+        .await
+        .unwrap();
+    let err = custom_error(candy_manager.mint_nft(context).await);
+    assert_eq!(err, PRESALE_AMOUNT_REACHED);
+}
+
+#[tokio::test]
+async fn too_many_end_settings() {
+    test_start("Too Many End Settings");
+    let mut context = candy_machine_program_test().start_with_context().await;
+    let context = &mut context;
+    let mut candy_manager = CandyManager::init(context, None, false, None, None, None).await;
+
+    let candy_data = auto_config(&candy_manager, Some(0), true, true, None, None);
+    candy_manager.create(context, candy_data).await.unwrap();
+
+    let end_settings = vec![
+        EndSettings {
+            end_setting_type: EndSettingType::Amount,
+            number: 1,
+        };
+        5
+    ];
+    let err = custom_error(candy_manager.set_end_settings(context, end_settings).await);
+    assert_eq!(err, TOO_MANY_END_SETTINGS);
+}
+
+#[tokio::test]
+async fn withdraw_requires_removed_end_settings() {
+    test_start("Withdraw Requires Removed End Settings");
+    let mut context = candy_machine_program_test().start_with_context().await;
+    let context = &mut context;
+    let mut candy_manager = CandyManager::init(context, None, false, None, None, None).await;
+
+    let candy_data = auto_config(&candy_manager, Some(0), true, true, None, None);
+    candy_manager.create(context, candy_data).await.unwrap();
+    candy_manager
+        .set_end_settings(
+            context,
+            vec![EndSettings {
+                end_setting_type: EndSettingType::Amount,
+                number: 1,
+            }],
+        )
+        .await
+        .unwrap();
+
+    let err = custom_error(candy_manager.withdraw(context).await);
+    assert_eq!(err, NO_WITHDRAW_WITH_END_SETTINGS);
+
+    candy_manager.remove_end_settings(context).await.unwrap();
+    let end_settings_pda = find_end_settings_pda(&candy_manager.candy_machine.pubkey()).0;
+    assert_account_empty(context, &end_settings_pda).await;
+    candy_manager.withdraw(context).await.unwrap();
+}
@@ -71,6 +71,8 @@ async fn fail_metadata_not_blank() {
         candy_manager.collection_info.clone(),
         candy_manager.gateway_info.clone(),
         candy_manager.freeze_info.clone(),
+        candy_manager.end_settings,
+        candy_manager.mint_receipts,
     )
     .await
     .unwrap_err()
@@ -141,6 +143,8 @@ async fn metadata_check_before_bot_tax() {
         candy_manager.collection_info.clone(),
         candy_manager.gateway_info.clone(),
         candy_manager.freeze_info.clone(),
+        candy_manager.end_settings,
+        candy_manager.mint_receipts,
     );
 
     ix[0].accounts.pop();
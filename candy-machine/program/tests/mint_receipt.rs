@@ -0,0 +1,172 @@
+#![cfg(feature = "test-bpf")]
+#![allow(dead_code)]
+
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+use mpl_candy_machine::{
+    constants::MINT_RECEIPT_FEATURE_INDEX, is_feature_active, MintPhase,
+    WhitelistMintMode::NeverBurn,
+};
+
+use crate::{
+    core::helpers::{airdrop, assert_account_empty, get_balance},
+    utils::{
+        auto_config, candy_machine_program_test, close_mint_receipt,
+        helpers::{find_mint_receipt, sol, test_start},
+        CandyManager, WhitelistConfig,
+    },
+};
+
+mod core;
+mod utils;
+
+#[tokio::test]
+async fn mint_receipt_records_provenance() {
+    test_start("Mint Receipt Records Provenance");
+    let mut context = candy_machine_program_test().start_with_context().await;
+    let context = &mut context;
+    let mut candy_manager = CandyManager::init(context, None, false, None, None, None).await;
+
+    airdrop(context, &candy_manager.minter.pubkey(), sol(10.0))
+        .await
+        .unwrap();
+
+    let candy_data = auto_config(&candy_manager, Some(0), true, true, None, None);
+    candy_manager.create(context, candy_data).await.unwrap();
+    candy_manager.fill_config_lines(context).await.unwrap();
+    candy_manager
+        .set_mint_receipts(context, true)
+        .await
+        .unwrap();
+
+    let candy_machine = candy_manager.get_candy(context).await;
+    assert!(is_feature_active(
+        &candy_machine.data.uuid,
+        MINT_RECEIPT_FEATURE_INDEX
+    ));
+
+    let new_nft = candy_manager
+        .mint_and_assert_successful(context, None, false)
+        .await
+        .unwrap();
+
+    let receipt = candy_manager
+        .get_mint_receipt(context, &new_nft.mint.pubkey())
+        .await;
+    assert_eq!(receipt.candy_machine, candy_manager.candy_machine.pubkey());
+    assert_eq!(receipt.mint, new_nft.mint.pubkey());
+    assert_eq!(receipt.payer, candy_manager.minter.pubkey());
+    assert_eq!(receipt.phase, MintPhase::Public);
+    assert_eq!(receipt.price, candy_machine.data.price);
+    assert_eq!(receipt.payment_mint, None);
+    assert!(receipt.config_line_index < candy_machine.data.items_available);
+}
+
+#[tokio::test]
+async fn mint_receipt_records_presale_phase() {
+    test_start("Mint Receipt Records Presale Phase");
+    let mut context = candy_machine_program_test().start_with_context().await;
+    let context = &mut context;
+    let mut candy_manager = CandyManager::init(
+        context,
+        None,
+        false,
+        None,
+        Some(WhitelistConfig::new(NeverBurn, true, Some(1))),
+        None,
+    )
+    .await;
+
+    airdrop(context, &candy_manager.minter.pubkey(), sol(10.0))
+        .await
+        .unwrap();
+
+    let candy_data = auto_config(&candy_manager, None, true, true, None, None);
+    candy_manager.create(context, candy_data).await.unwrap();
+    candy_manager.fill_config_lines(context).await.unwrap();
+    candy_manager
+        .set_mint_receipts(context, true)
+        .await
+        .unwrap();
+
+    let new_nft = candy_manager
+        .mint_and_assert_successful(context, None, true)
+        .await
+        .unwrap();
+
+    let receipt = candy_manager
+        .get_mint_receipt(context, &new_nft.mint.pubkey())
+        .await;
+    assert_eq!(receipt.phase, MintPhase::Presale);
+    assert_eq!(receipt.price, 1);
+}
+
+#[tokio::test]
+async fn close_mint_receipt_refunds_payer() {
+    test_start("Close Mint Receipt Refunds Payer");
+    let mut context = candy_machine_program_test().start_with_context().await;
+    let context = &mut context;
+    let mut candy_manager = CandyManager::init(context, None, false, None, None, None).await;
+
+    airdrop(context, &candy_manager.minter.pubkey(), sol(10.0))
+        .await
+        .unwrap();
+
+    let candy_data = auto_config(&candy_manager, Some(0), true, true, None, None);
+    candy_manager.create(context, candy_data).await.unwrap();
+    candy_manager.fill_config_lines(context).await.unwrap();
+    candy_manager
+        .set_mint_receipts(context, true)
+        .await
+        .unwrap();
+
+    let new_nft = candy_manager
+        .mint_and_assert_successful(context, None, false)
+        .await
+        .unwrap();
+    let candy_key = candy_manager.candy_machine.pubkey();
+    let mint = new_nft.mint.pubkey();
+    let receipt = find_mint_receipt(&candy_key, &mint).0;
+
+    // a random signer can't close the receipt
+    let stranger = Keypair::new();
+    airdrop(context, &stranger.pubkey(), sol(1.0))
+        .await
+        .unwrap();
+    close_mint_receipt(
+        context,
+        &candy_key,
+        &mint,
+        &candy_manager.minter.pubkey(),
+        &stranger,
+    )
+    .await
+    .unwrap_err();
+
+    let receipt_lamports = get_balance(context, &receipt).await;
+    let start_balance = get_balance(context, &candy_manager.minter.pubkey()).await;
+    close_mint_receipt(
+        context,
+        &candy_key,
+        &mint,
+        &candy_manager.minter.pubkey(),
+        &candy_manager.authority,
+    )
+    .await
+    .unwrap();
+    let end_balance = get_balance(context, &candy_manager.minter.pubkey()).await;
+
+    assert_account_empty(context, &receipt).await;
+    assert_eq!(end_balance - start_balance, receipt_lamports);
+
+    // minting without receipts enabled doesn't need the extra account
+    candy_manager
+        .set_mint_receipts(context, false)
+        .await
+        .unwrap();
+    candy_manager
+        .mint_and_assert_successful(context, None, false)
+        .await
+        .unwrap();
+}
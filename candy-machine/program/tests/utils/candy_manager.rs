@@ -14,7 +14,8 @@ use spl_token::state::AccountState;
 
 use mpl_candy_machine::{
     constants::{BOT_FEE, FREEZE_FEATURE_INDEX, FREEZE_LOCK_FEATURE_INDEX},
-    is_feature_active, CandyMachine, CandyMachineData, CollectionPDA, FreezePDA, WhitelistMintMode,
+    is_feature_active, CandyMachine, CandyMachineData, CollectionPDA, EndSettings, FreezePDA,
+    MintReceipt, WhitelistMintMode,
     WhitelistMintMode::{BurnEveryTime, NeverBurn},
 };
 
@@ -29,9 +30,12 @@ use crate::{
     },
     utils::{
         add_all_config_lines,
-        helpers::{find_candy_creator, find_collection_pda, sol, CandyTestLogger},
-        initialize_candy_machine, mint_nft, remove_collection, remove_freeze, set_collection,
-        set_freeze, thaw_nft, unlock_funds, update_authority, update_candy_machine, withdraw_funds,
+        helpers::{
+            find_candy_creator, find_collection_pda, find_mint_receipt, sol, CandyTestLogger,
+        },
+        initialize_candy_machine, mint_nft, remove_collection, remove_end_settings, remove_freeze,
+        set_collection, set_end_settings, set_freeze, set_mint_receipts, thaw_nft, unlock_funds,
+        update_authority, update_candy_machine, withdraw_funds,
     },
 };
 
@@ -46,6 +50,8 @@ pub struct CandyManager {
     pub whitelist_info: WhitelistInfo,
     pub gateway_info: GatekeeperInfo,
     pub freeze_info: FreezeInfo,
+    pub end_settings: bool,
+    pub mint_receipts: bool,
 }
 
 impl Clone for CandyManager {
@@ -60,6 +66,8 @@ impl Clone for CandyManager {
             whitelist_info: self.whitelist_info.clone(),
             gateway_info: self.gateway_info.clone(),
             freeze_info: self.freeze_info.clone(),
+            end_settings: self.end_settings,
+            mint_receipts: self.mint_receipts,
         }
     }
 }
@@ -503,6 +511,8 @@ impl CandyManager {
             whitelist_info,
             gateway_info,
             freeze_info,
+            end_settings: false,
+            mint_receipts: false,
         }
     }
 
@@ -644,6 +654,16 @@ impl CandyManager {
         FreezePDA::try_deserialize(&mut account.data.as_ref()).unwrap()
     }
 
+    pub async fn get_mint_receipt(
+        &self,
+        context: &mut ProgramTestContext,
+        mint: &Pubkey,
+    ) -> MintReceipt {
+        let receipt = find_mint_receipt(&self.candy_machine.pubkey(), mint).0;
+        let account = get_account(context, &receipt).await;
+        MintReceipt::try_deserialize(&mut account.data.as_ref()).unwrap()
+    }
+
     pub async fn assert_freeze_set(
         &self,
         context: &mut ProgramTestContext,
@@ -834,6 +854,53 @@ impl CandyManager {
         Ok(())
     }
 
+    pub async fn set_end_settings(
+        &mut self,
+        context: &mut ProgramTestContext,
+        end_settings: Vec<EndSettings>,
+    ) -> transport::Result<()> {
+        let logger = CandyTestLogger::new_start("Set End Settings");
+        set_end_settings(
+            context,
+            &self.candy_machine.pubkey(),
+            &self.authority,
+            end_settings,
+        )
+        .await?;
+        self.end_settings = true;
+        logger.end();
+        Ok(())
+    }
+
+    pub async fn remove_end_settings(
+        &mut self,
+        context: &mut ProgramTestContext,
+    ) -> transport::Result<()> {
+        let logger = CandyTestLogger::new_start("Remove End Settings");
+        remove_end_settings(context, &self.candy_machine.pubkey(), &self.authority).await?;
+        self.end_settings = false;
+        logger.end();
+        Ok(())
+    }
+
+    pub async fn set_mint_receipts(
+        &mut self,
+        context: &mut ProgramTestContext,
+        enabled: bool,
+    ) -> transport::Result<()> {
+        let logger = CandyTestLogger::new_start("Set Mint Receipts");
+        set_mint_receipts(
+            context,
+            &self.candy_machine.pubkey(),
+            &self.authority,
+            enabled,
+        )
+        .await?;
+        self.mint_receipts = enabled;
+        logger.end();
+        Ok(())
+    }
+
     pub async fn set_freeze(&mut self, context: &mut ProgramTestContext) -> transport::Result<()> {
         let logger = CandyTestLogger::new_start("Set freeze");
         set_freeze(
@@ -925,6 +992,8 @@ impl CandyManager {
             self.collection_info.clone(),
             self.gateway_info.clone(),
             self.freeze_info.clone(),
+            self.end_settings,
+            self.mint_receipts,
         )
         .await?;
         logger.end();
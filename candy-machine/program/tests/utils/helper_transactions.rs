@@ -10,7 +10,7 @@ use solana_sdk::{signature::Keypair, transaction::Transaction};
 
 use mpl_candy_machine::{
     constants::{CONFIG_ARRAY_START, CONFIG_LINE_SIZE},
-    CandyMachine, CandyMachineData, ConfigLine,
+    CandyMachine, CandyMachineData, ConfigLine, EndSettings,
     WhitelistMintMode::BurnEveryTime,
 };
 
@@ -18,7 +18,7 @@ use crate::{
     core::{helpers::update_blockhash, MasterEditionManager},
     utils::{
         candy_manager::{CollectionInfo, GatekeeperInfo, TokenInfo, WhitelistInfo},
-        helpers::make_config_lines,
+        helpers::{find_end_settings_pda, find_mint_receipt, make_config_lines},
         FreezeInfo,
     },
 };
@@ -501,6 +501,8 @@ pub fn mint_nft_ix(
     collection_info: CollectionInfo,
     gateway_info: GatekeeperInfo,
     freeze_info: FreezeInfo,
+    end_settings: bool,
+    mint_receipts: bool,
 ) -> Vec<Instruction> {
     let metadata = new_nft.metadata_pubkey;
     let master_edition = new_nft.edition_pubkey;
@@ -561,6 +563,20 @@ pub fn mint_nft_ix(
         }
     }
 
+    if end_settings {
+        accounts.push(AccountMeta::new_readonly(
+            find_end_settings_pda(candy_machine).0,
+            false,
+        ));
+    }
+
+    if mint_receipts {
+        accounts.push(AccountMeta::new(
+            find_mint_receipt(candy_machine, &mint).0,
+            false,
+        ));
+    }
+
     let data = mpl_candy_machine::instruction::MintNft { creator_bump }.data();
 
     let mut instructions = Vec::new();
@@ -620,6 +636,8 @@ pub async fn mint_nft(
     collection_info: CollectionInfo,
     gateway_info: GatekeeperInfo,
     freeze_info: FreezeInfo,
+    end_settings: bool,
+    mint_receipts: bool,
 ) -> Result<(), BanksClientError> {
     let ins = mint_nft_ix(
         candy_machine,
@@ -634,6 +652,8 @@ pub async fn mint_nft(
         collection_info,
         gateway_info,
         freeze_info,
+        end_settings,
+        mint_receipts,
     );
     let signers = vec![payer];
     update_blockhash(context).await?;
@@ -646,3 +666,132 @@ pub async fn mint_nft(
 
     context.banks_client.process_transaction(tx).await
 }
+
+pub async fn set_mint_receipts(
+    context: &mut ProgramTestContext,
+    candy_machine: &Pubkey,
+    authority: &Keypair,
+    enabled: bool,
+) -> Result<(), BanksClientError> {
+    let accounts = mpl_candy_machine::accounts::SetMintReceipts {
+        candy_machine: *candy_machine,
+        authority: authority.pubkey(),
+    }
+    .to_account_metas(None);
+
+    let data = if enabled {
+        mpl_candy_machine::instruction::SetMintReceipts {}.data()
+    } else {
+        mpl_candy_machine::instruction::RemoveMintReceipts {}.data()
+    };
+    let set_ix = Instruction {
+        program_id: mpl_candy_machine::id(),
+        data,
+        accounts,
+    };
+
+    update_blockhash(context).await?;
+    let tx = Transaction::new_signed_with_payer(
+        &[set_ix],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn set_end_settings(
+    context: &mut ProgramTestContext,
+    candy_machine: &Pubkey,
+    authority: &Keypair,
+    end_settings: Vec<EndSettings>,
+) -> Result<(), BanksClientError> {
+    let accounts = mpl_candy_machine::accounts::SetEndSettings {
+        candy_machine: *candy_machine,
+        authority: authority.pubkey(),
+        end_settings_pda: find_end_settings_pda(candy_machine).0,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_candy_machine::instruction::SetEndSettings { end_settings }.data();
+    let set_ix = Instruction {
+        program_id: mpl_candy_machine::id(),
+        data,
+        accounts,
+    };
+
+    update_blockhash(context).await?;
+    let tx = Transaction::new_signed_with_payer(
+        &[set_ix],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn remove_end_settings(
+    context: &mut ProgramTestContext,
+    candy_machine: &Pubkey,
+    authority: &Keypair,
+) -> Result<(), BanksClientError> {
+    let accounts = mpl_candy_machine::accounts::RemoveEndSettings {
+        candy_machine: *candy_machine,
+        authority: authority.pubkey(),
+        end_settings_pda: find_end_settings_pda(candy_machine).0,
+    }
+    .to_account_metas(None);
+
+    let data = mpl_candy_machine::instruction::RemoveEndSettings {}.data();
+    let remove_ix = Instruction {
+        program_id: mpl_candy_machine::id(),
+        data,
+        accounts,
+    };
+
+    update_blockhash(context).await?;
+    let tx = Transaction::new_signed_with_payer(
+        &[remove_ix],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn close_mint_receipt(
+    context: &mut ProgramTestContext,
+    candy_machine: &Pubkey,
+    mint: &Pubkey,
+    payer: &Pubkey,
+    authority: &Keypair,
+) -> Result<(), BanksClientError> {
+    let accounts = mpl_candy_machine::accounts::CloseMintReceipt {
+        mint_receipt: find_mint_receipt(candy_machine, mint).0,
+        candy_machine: *candy_machine,
+        payer: *payer,
+        authority: authority.pubkey(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_candy_machine::instruction::CloseMintReceipt {}.data();
+    let close_ix = Instruction {
+        program_id: mpl_candy_machine::id(),
+        data,
+        accounts,
+    };
+
+    update_blockhash(context).await?;
+    let tx = Transaction::new_signed_with_payer(
+        &[close_ix],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
@@ -3,7 +3,9 @@ use solana_program::{native_token::LAMPORTS_PER_SOL, pubkey::Pubkey};
 use spl_associated_token_account::get_associated_token_address;
 
 use crate::utils::{FreezeInfo, TokenInfo};
-use mpl_candy_machine::{constants::PREFIX as CANDY_PREFIX, CollectionPDA, ConfigLine};
+use mpl_candy_machine::{
+    constants::PREFIX as CANDY_PREFIX, CollectionPDA, ConfigLine, EndSettingsPDA, MintReceipt,
+};
 
 pub fn make_config_lines(start_index: u32, total: u8) -> Vec<ConfigLine> {
     let mut config_lines = Vec::with_capacity(total as usize);
@@ -28,6 +30,27 @@ pub fn find_collection_pda(candy_machine_key: &Pubkey) -> (Pubkey, u8) {
     )
 }
 
+pub fn find_end_settings_pda(candy_machine_key: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            EndSettingsPDA::PREFIX.as_bytes(),
+            candy_machine_key.as_ref(),
+        ],
+        &mpl_candy_machine::id(),
+    )
+}
+
+pub fn find_mint_receipt(candy_machine_key: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            MintReceipt::PREFIX.as_bytes(),
+            candy_machine_key.as_ref(),
+            mint.as_ref(),
+        ],
+        &mpl_candy_machine::id(),
+    )
+}
+
 pub fn find_freeze_ata(freeze_info: &FreezeInfo, token_info: &TokenInfo) -> Pubkey {
     get_associated_token_address(&freeze_info.pda, &token_info.mint)
 }
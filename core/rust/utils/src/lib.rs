@@ -1,10 +1,15 @@
 pub use account::*;
 pub use assertions::*;
 pub use misc::*;
+pub use versioning::*;
 
 mod account;
 mod assertions;
 mod misc;
+mod versioning;
+
+#[cfg(test)]
+mod test_utils;
 
 #[cfg(feature = "spl-token")]
 pub mod token;
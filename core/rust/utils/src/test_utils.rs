@@ -0,0 +1,98 @@
+//! Syscall stubs shared by the unit tests. They are installed once per test binary, so anything
+//! a test needs to look at afterwards is kept per thread.
+
+use std::{cell::RefCell, sync::Once};
+
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::{ProgramResult, MAX_PERMITTED_DATA_INCREASE, SUCCESS},
+    instruction::Instruction,
+    program_stubs::{set_syscall_stubs, SyscallStubs},
+    pubkey::Pubkey,
+    rent::Rent,
+    system_program,
+};
+
+thread_local! {
+    static INVOKED: RefCell<Vec<Instruction>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Provides the rent and clock sysvars, runs system transfers and records every other CPI.
+struct TestSyscallStubs;
+
+impl SyscallStubs for TestSyscallStubs {
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        unsafe { *(var_addr as *mut Clock) = Clock::default() };
+        SUCCESS
+    }
+
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        unsafe { *(var_addr as *mut Rent) = Rent::default() };
+        SUCCESS
+    }
+
+    fn sol_invoke_signed(
+        &self,
+        instruction: &Instruction,
+        account_infos: &[AccountInfo],
+        _signers_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        INVOKED.with(|invoked| invoked.borrow_mut().push(instruction.clone()));
+        if instruction.program_id != system_program::id() {
+            return Ok(());
+        }
+
+        // SystemInstruction::Transfer { lamports }
+        assert_eq!(instruction.data[..4], 2u32.to_le_bytes());
+        let lamports = u64::from_le_bytes(*arrayref::array_ref![instruction.data, 4, 8]);
+        let find = |key: &Pubkey| account_infos.iter().find(|info| info.key == key).unwrap();
+
+        let from = find(&instruction.accounts[0].pubkey);
+        assert!(from.is_signer);
+        **from.try_borrow_mut_lamports()? -= lamports;
+        **find(&instruction.accounts[1].pubkey).try_borrow_mut_lamports()? += lamports;
+        Ok(())
+    }
+}
+
+/// Installs the syscall stubs and forgets the CPIs recorded so far on this thread.
+pub fn setup() {
+    static STUBS: Once = Once::new();
+    STUBS.call_once(|| {
+        set_syscall_stubs(Box::new(TestSyscallStubs));
+    });
+    take_invoked();
+}
+
+/// Returns the instructions invoked on this thread since the last call.
+pub fn take_invoked() -> Vec<Instruction> {
+    INVOKED.with(|invoked| invoked.take())
+}
+
+/// Serializes `(key, is_signer, owner, lamports, data)` accounts the way the runtime passes
+/// them to a program, leaving room for `AccountInfo::realloc` to grow the data in place.
+pub fn serialize_input(accounts: &[(Pubkey, bool, Pubkey, u64, Vec<u8>)]) -> Vec<u64> {
+    let mut input = (accounts.len() as u64).to_le_bytes().to_vec();
+    for (key, is_signer, owner, lamports, data) in accounts {
+        input.extend([u8::MAX, *is_signer as u8, true as u8, false as u8]);
+        input.extend([0; 4]);
+        input.extend(key.as_ref());
+        input.extend(owner.as_ref());
+        input.extend(lamports.to_le_bytes());
+        input.extend((data.len() as u64).to_le_bytes());
+        input.extend(data);
+        input.resize(input.len() + MAX_PERMITTED_DATA_INCREASE, 0);
+        input.resize(input.len() + (8 - input.len() % 8) % 8, 0);
+        input.extend(0u64.to_le_bytes());
+    }
+    input.extend(0u64.to_le_bytes());
+    input.extend(Pubkey::default().as_ref());
+
+    // The runtime aligns the input to 8 bytes.
+    input.resize(input.len() + (8 - input.len() % 8) % 8, 0);
+    let mut aligned = vec![0u64; input.len() / 8];
+    unsafe { std::slice::from_raw_parts_mut(aligned.as_mut_ptr() as *mut u8, input.len()) }
+        .copy_from_slice(&input);
+    aligned
+}
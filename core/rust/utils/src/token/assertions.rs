@@ -4,7 +4,7 @@ use solana_program::{
 };
 use spl_token_2022::state::Account;
 
-use crate::assert_initialized;
+use super::unpack_initialized;
 
 pub static SPL_TOKEN_PROGRAM_IDS: [Pubkey; 2] = [
     pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
@@ -17,7 +17,11 @@ pub trait ToTokenAccount {
 
 impl ToTokenAccount for AccountInfo<'_> {
     fn to_token_account(self) -> Account {
-        assert_initialized(&self, ProgramError::UninitializedAccount).unwrap()
+        // Token-2022 accounts may carry extensions past the base account layout
+        let data = self.data.borrow();
+        unpack_initialized::<Account>(&data, ProgramError::UninitializedAccount)
+            .unwrap()
+            .base
     }
 }
 
@@ -46,6 +50,7 @@ pub fn assert_token_program_matches_package(
 /// * it's owner matches the provided owner
 /// * it's mint matches the provided mint
 /// * it holds more than than 0 tokens of the given mint.
+///
 /// Accepts either an &AccountInfo or an Account for token_account parameter.
 pub fn assert_holder(
     token_account: impl ToTokenAccount,
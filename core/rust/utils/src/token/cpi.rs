@@ -1,7 +1,34 @@
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program::invoke_signed,
+    program_error::ProgramError,
 };
 
+use super::{
+    assert_supported_account_extensions, assert_supported_mint_extensions, get_mint_decimals,
+    get_transfer_fee,
+};
+
+/// Token-2022 mints and accounts may use extensions that change how tokens are moved, so only the
+/// extensions the helpers know how to handle are let through.
+fn assert_supported_token_2022_accounts(
+    token_program: &AccountInfo,
+    mint: Option<&AccountInfo>,
+    token_accounts: &[&AccountInfo],
+) -> ProgramResult {
+    if *token_program.key != spl_token_2022::id() {
+        return Ok(());
+    }
+
+    if let Some(mint) = mint {
+        assert_supported_mint_extensions(mint, ProgramError::InvalidAccountData)?;
+    }
+    for token_account in token_accounts {
+        assert_supported_account_extensions(token_account, ProgramError::InvalidAccountData)?;
+    }
+
+    Ok(())
+}
+
 pub fn spl_token_burn(params: TokenBurnParams<'_, '_>) -> ProgramResult {
     let TokenBurnParams {
         mint,
@@ -11,6 +38,8 @@ pub fn spl_token_burn(params: TokenBurnParams<'_, '_>) -> ProgramResult {
         amount,
         authority_signer_seeds,
     } = params;
+    assert_supported_token_2022_accounts(&token_program, Some(&mint), &[&source])?;
+
     let mut seeds: Vec<&[&[u8]]> = vec![];
     if let Some(seed) = authority_signer_seeds {
         seeds.push(seed);
@@ -53,6 +82,8 @@ pub fn spl_token_close(params: TokenCloseParams<'_, '_>) -> ProgramResult {
         authority_signer_seeds,
         token_program,
     } = params;
+    assert_supported_token_2022_accounts(&token_program, None, &[&account])?;
+
     let mut seeds: Vec<&[&[u8]]> = vec![];
     if let Some(seed) = authority_signer_seeds {
         seeds.push(seed);
@@ -93,6 +124,8 @@ pub fn spl_token_mint_to(params: TokenMintToParams<'_, '_>) -> ProgramResult {
         amount,
         authority_signer_seeds,
     } = params;
+    assert_supported_token_2022_accounts(&token_program, Some(&mint), &[&destination])?;
+
     let mut seeds: Vec<&[&[u8]]> = vec![];
     if let Some(seed) = authority_signer_seeds {
         seeds.push(seed);
@@ -127,17 +160,34 @@ pub struct TokenMintToParams<'a: 'b, 'b> {
     pub token_program: AccountInfo<'a>,
 }
 
+/// Transfers tokens using the deprecated unchecked instruction for the original token program.
+/// Token-2022 requires the mint for transfers, so those go through [`spl_token_transfer_checked`].
 #[allow(deprecated)]
 pub fn spl_token_transfer(params: TokenTransferParams<'_, '_>) -> ProgramResult {
     let TokenTransferParams {
+        mint,
         source,
         destination,
         amount,
         authority,
         token_program,
         authority_signer_seeds,
-        ..
     } = params;
+
+    if *token_program.key == spl_token_2022::id() {
+        let decimals = get_mint_decimals(&mint)?;
+        return spl_token_transfer_checked(TokenTransferCheckedParams {
+            mint,
+            source,
+            destination,
+            amount,
+            authority,
+            authority_signer_seeds,
+            token_program,
+            decimals,
+        });
+    }
+
     let seeds = if let Some(seeds) = authority_signer_seeds {
         seeds
     } else {
@@ -188,14 +238,24 @@ pub fn spl_token_transfer_checked(params: TokenTransferCheckedParams<'_, '_>) ->
         authority_signer_seeds,
         decimals,
     } = params;
+    assert_supported_token_2022_accounts(&token_program, Some(&mint), &[&source, &destination])?;
+
     let seeds = if let Some(seeds) = authority_signer_seeds {
         seeds
     } else {
         &[]
     };
 
-    invoke_signed(
-        &spl_token_2022::instruction::transfer_checked(
+    // The fee is withheld from the destination, passing it along makes the token
+    // program fail if it changed since it was computed.
+    let fee = if *token_program.key == spl_token_2022::id() {
+        get_transfer_fee(&mint, amount)?
+    } else {
+        0
+    };
+
+    let instruction = if fee > 0 {
+        spl_token_2022::extension::transfer_fee::instruction::transfer_checked_with_fee(
             token_program.key,
             source.key,
             mint.key,
@@ -204,13 +264,29 @@ pub fn spl_token_transfer_checked(params: TokenTransferCheckedParams<'_, '_>) ->
             &[authority.key],
             amount,
             decimals,
-        )?,
+            fee,
+        )?
+    } else {
+        spl_token_2022::instruction::transfer_checked(
+            token_program.key,
+            source.key,
+            mint.key,
+            destination.key,
+            authority.key,
+            &[authority.key],
+            amount,
+            decimals,
+        )?
+    };
+
+    invoke_signed(
+        &instruction,
         &[source, mint, destination, authority],
         &[seeds],
     )
 }
 
-/// TokenTransferParams
+/// TokenTransferCheckedParams
 #[derive(Debug)]
 pub struct TokenTransferCheckedParams<'a: 'b, 'b> {
     /// mint
@@ -230,3 +306,290 @@ pub struct TokenTransferCheckedParams<'a: 'b, 'b> {
     /// decimals
     pub decimals: u8,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::{setup, take_invoked},
+        token::SPL_TOKEN_PROGRAM_IDS,
+    };
+    use solana_program::{program_option::COption, program_pack::Pack, pubkey::Pubkey};
+    use spl_token_2022::{
+        extension::{
+            immutable_owner::ImmutableOwner,
+            non_transferable::{NonTransferable, NonTransferableAccount},
+            transfer_fee::TransferFeeConfig,
+            ExtensionType, StateWithExtensionsMut,
+        },
+        state::{Account, AccountState, Mint},
+    };
+
+    struct TestAccount {
+        key: Pubkey,
+        owner: Pubkey,
+        lamports: u64,
+        data: Vec<u8>,
+    }
+
+    impl TestAccount {
+        fn new(owner: Pubkey, data: Vec<u8>) -> Self {
+            TestAccount {
+                key: Pubkey::new_unique(),
+                owner,
+                lamports: 0,
+                data,
+            }
+        }
+
+        fn info(&mut self) -> AccountInfo<'_> {
+            AccountInfo::new(
+                &self.key,
+                true,
+                true,
+                &mut self.lamports,
+                &mut self.data,
+                &self.owner,
+                false,
+                0,
+            )
+        }
+    }
+
+    fn mint() -> Mint {
+        Mint {
+            mint_authority: COption::Some(Pubkey::new_unique()),
+            supply: 1_000_000,
+            decimals: 6,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        }
+    }
+
+    fn mint_data_with(extension_type: ExtensionType) -> Vec<u8> {
+        let len = ExtensionType::try_calculate_account_len::<Mint>(&[extension_type]).unwrap();
+        let mut data = vec![0; len];
+        let mut state = StateWithExtensionsMut::<Mint>::unpack_uninitialized(&mut data).unwrap();
+        match extension_type {
+            ExtensionType::TransferFeeConfig => {
+                let config = state.init_extension::<TransferFeeConfig>(true).unwrap();
+                config.older_transfer_fee.transfer_fee_basis_points = 100.into();
+                config.older_transfer_fee.maximum_fee = 5_000.into();
+                config.newer_transfer_fee = config.older_transfer_fee;
+            }
+            ExtensionType::NonTransferable => {
+                state.init_extension::<NonTransferable>(true).unwrap();
+            }
+            _ => unreachable!(),
+        }
+        state.base = mint();
+        state.pack_base();
+        state.init_account_type().unwrap();
+        data
+    }
+
+    fn account_data_with(extension_type: ExtensionType) -> Vec<u8> {
+        let len = ExtensionType::try_calculate_account_len::<Account>(&[extension_type]).unwrap();
+        let mut data = vec![0; len];
+        let mut state = StateWithExtensionsMut::<Account>::unpack_uninitialized(&mut data).unwrap();
+        match extension_type {
+            ExtensionType::ImmutableOwner => {
+                state.init_extension::<ImmutableOwner>(true).unwrap();
+            }
+            ExtensionType::NonTransferableAccount => {
+                state
+                    .init_extension::<NonTransferableAccount>(true)
+                    .unwrap();
+            }
+            _ => unreachable!(),
+        }
+        state.base = Account {
+            mint: Pubkey::new_unique(),
+            owner: Pubkey::new_unique(),
+            amount: 10_000,
+            state: AccountState::Initialized,
+            ..Account::default()
+        };
+        state.pack_base();
+        state.init_account_type().unwrap();
+        data
+    }
+
+    #[test]
+    fn transfer_withholds_token_2022_fee() {
+        setup();
+        let token_program_id = spl_token_2022::id();
+        let mut mint = TestAccount::new(
+            token_program_id,
+            mint_data_with(ExtensionType::TransferFeeConfig),
+        );
+        let mut source = TestAccount::new(
+            token_program_id,
+            account_data_with(ExtensionType::ImmutableOwner),
+        );
+        let mut destination = TestAccount::new(
+            token_program_id,
+            account_data_with(ExtensionType::ImmutableOwner),
+        );
+        let mut authority = TestAccount::new(Pubkey::default(), vec![]);
+        let mut token_program = TestAccount::new(Pubkey::default(), vec![]);
+        token_program.key = token_program_id;
+
+        spl_token_transfer(TokenTransferParams {
+            mint: mint.info(),
+            source: source.info(),
+            destination: destination.info(),
+            amount: 10_000,
+            authority: authority.info(),
+            authority_signer_seeds: None,
+            token_program: token_program.info(),
+        })
+        .unwrap();
+
+        assert_eq!(
+            take_invoked(),
+            [
+                spl_token_2022::extension::transfer_fee::instruction::transfer_checked_with_fee(
+                    &token_program_id,
+                    &source.key,
+                    &mint.key,
+                    &destination.key,
+                    &authority.key,
+                    &[&authority.key],
+                    10_000,
+                    6,
+                    100,
+                )
+                .unwrap()
+            ]
+        );
+    }
+
+    #[test]
+    fn transfer_rejects_unsupported_account_extension() {
+        setup();
+        let token_program_id = spl_token_2022::id();
+        let mut mint = TestAccount::new(
+            token_program_id,
+            mint_data_with(ExtensionType::TransferFeeConfig),
+        );
+        let mut source = TestAccount::new(
+            token_program_id,
+            account_data_with(ExtensionType::ImmutableOwner),
+        );
+        let mut destination = TestAccount::new(
+            token_program_id,
+            account_data_with(ExtensionType::NonTransferableAccount),
+        );
+        let mut authority = TestAccount::new(Pubkey::default(), vec![]);
+        let mut token_program = TestAccount::new(Pubkey::default(), vec![]);
+        token_program.key = token_program_id;
+
+        let err = spl_token_transfer_checked(TokenTransferCheckedParams {
+            mint: mint.info(),
+            source: source.info(),
+            destination: destination.info(),
+            amount: 10_000,
+            authority: authority.info(),
+            authority_signer_seeds: None,
+            token_program: token_program.info(),
+            decimals: 6,
+        })
+        .unwrap_err();
+
+        assert_eq!(err, ProgramError::InvalidAccountData);
+        assert!(take_invoked().is_empty());
+    }
+
+    #[test]
+    fn burn_rejects_unsupported_mint_extension() {
+        setup();
+        let token_program_id = spl_token_2022::id();
+        let mut mint = TestAccount::new(
+            token_program_id,
+            mint_data_with(ExtensionType::NonTransferable),
+        );
+        let mut source = TestAccount::new(
+            token_program_id,
+            account_data_with(ExtensionType::ImmutableOwner),
+        );
+        let mut authority = TestAccount::new(Pubkey::default(), vec![]);
+        let mut token_program = TestAccount::new(Pubkey::default(), vec![]);
+        token_program.key = token_program_id;
+
+        let err = spl_token_burn(TokenBurnParams {
+            mint: mint.info(),
+            source: source.info(),
+            amount: 1,
+            authority: authority.info(),
+            authority_signer_seeds: None,
+            token_program: token_program.info(),
+        })
+        .unwrap_err();
+
+        assert_eq!(err, ProgramError::InvalidAccountData);
+        assert!(take_invoked().is_empty());
+    }
+
+    #[test]
+    fn close_rejects_unsupported_account_extension() {
+        setup();
+        let token_program_id = spl_token_2022::id();
+        let mut account = TestAccount::new(
+            token_program_id,
+            account_data_with(ExtensionType::NonTransferableAccount),
+        );
+        let mut destination = TestAccount::new(Pubkey::default(), vec![]);
+        let mut owner = TestAccount::new(Pubkey::default(), vec![]);
+        let mut token_program = TestAccount::new(Pubkey::default(), vec![]);
+        token_program.key = token_program_id;
+
+        let err = spl_token_close(TokenCloseParams {
+            account: account.info(),
+            destination: destination.info(),
+            owner: owner.info(),
+            authority_signer_seeds: None,
+            token_program: token_program.info(),
+        })
+        .unwrap_err();
+
+        assert_eq!(err, ProgramError::InvalidAccountData);
+        assert!(take_invoked().is_empty());
+    }
+
+    #[test]
+    fn original_token_program_skips_extension_checks() {
+        setup();
+        let token_program_id = SPL_TOKEN_PROGRAM_IDS[0];
+        let mut mint_data = vec![0; Mint::LEN];
+        Mint::pack(mint(), &mut mint_data).unwrap();
+        let mut mint = TestAccount::new(token_program_id, mint_data);
+        let mut destination = TestAccount::new(token_program_id, vec![0; Account::LEN]);
+        let mut authority = TestAccount::new(Pubkey::default(), vec![]);
+        let mut token_program = TestAccount::new(Pubkey::default(), vec![]);
+        token_program.key = token_program_id;
+
+        spl_token_mint_to(TokenMintToParams {
+            mint: mint.info(),
+            destination: destination.info(),
+            amount: 1,
+            authority: authority.info(),
+            authority_signer_seeds: None,
+            token_program: token_program.info(),
+        })
+        .unwrap();
+
+        assert_eq!(
+            take_invoked(),
+            [spl_token_2022::instruction::mint_to(
+                &token_program_id,
+                &mint.key,
+                &destination.key,
+                &authority.key,
+                &[],
+                1,
+            )
+            .unwrap()]
+        );
+    }
+}
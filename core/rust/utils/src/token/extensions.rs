@@ -0,0 +1,246 @@
+use solana_program::{
+    account_info::AccountInfo,
+    clock::{Clock, Epoch},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    sysvar::Sysvar,
+};
+use spl_token_2022::{
+    error::TokenError,
+    extension::{
+        transfer_fee::TransferFeeConfig, BaseState, BaseStateWithExtensions, ExtensionType,
+        StateWithExtensions,
+    },
+    state::{Account, Mint},
+};
+
+/// Mint extensions that don't change how tokens are moved by the helpers in this crate.
+pub const SUPPORTED_MINT_EXTENSIONS: [ExtensionType; 5] = [
+    ExtensionType::Uninitialized,
+    ExtensionType::TransferFeeConfig,
+    ExtensionType::MintCloseAuthority,
+    ExtensionType::MetadataPointer,
+    ExtensionType::TokenMetadata,
+];
+
+/// Token account extensions that don't change how tokens are moved by the helpers in this crate.
+pub const SUPPORTED_ACCOUNT_EXTENSIONS: [ExtensionType; 3] = [
+    ExtensionType::Uninitialized,
+    ExtensionType::TransferFeeAmount,
+    ExtensionType::ImmutableOwner,
+];
+
+fn assert_supported_extensions<S: BaseState>(
+    data: &[u8],
+    supported: &[ExtensionType],
+    error: impl Into<ProgramError>,
+) -> ProgramResult {
+    let state = StateWithExtensions::<S>::unpack(data)?;
+
+    for extension_type in state.get_extension_types()? {
+        if !supported.contains(&extension_type) {
+            msg!("Unsupported token extension: {:?}", extension_type);
+            return Err(error.into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Asserts that the mint, owned by either token program, only uses extensions
+/// listed in [`SUPPORTED_MINT_EXTENSIONS`].
+pub fn assert_supported_mint_extensions(
+    mint_info: &AccountInfo,
+    error: impl Into<ProgramError>,
+) -> ProgramResult {
+    assert_supported_extensions::<Mint>(
+        &mint_info.try_borrow_data()?,
+        &SUPPORTED_MINT_EXTENSIONS,
+        error,
+    )
+}
+
+/// Asserts that the token account, owned by either token program, only uses
+/// extensions listed in [`SUPPORTED_ACCOUNT_EXTENSIONS`].
+pub fn assert_supported_account_extensions(
+    token_account_info: &AccountInfo,
+    error: impl Into<ProgramError>,
+) -> ProgramResult {
+    assert_supported_extensions::<Account>(
+        &token_account_info.try_borrow_data()?,
+        &SUPPORTED_ACCOUNT_EXTENSIONS,
+        error,
+    )
+}
+
+/// Fee withheld when transferring `amount` tokens of the mint during `epoch`.
+/// Mints without the transfer fee extension, including every mint of the
+/// original token program, have no fee.
+pub fn calculate_transfer_fee(
+    mint_data: &[u8],
+    epoch: Epoch,
+    amount: u64,
+) -> Result<u64, ProgramError> {
+    let mint = StateWithExtensions::<Mint>::unpack(mint_data)?;
+
+    match mint.get_extension::<TransferFeeConfig>() {
+        Ok(transfer_fee_config) => transfer_fee_config
+            .calculate_epoch_fee(epoch, amount)
+            .ok_or_else(|| TokenError::Overflow.into()),
+        Err(_) => Ok(0),
+    }
+}
+
+/// Fee withheld when transferring `amount` tokens of the mint in the current epoch.
+pub fn get_transfer_fee(mint_info: &AccountInfo, amount: u64) -> Result<u64, ProgramError> {
+    let epoch = Clock::get()?.epoch;
+    calculate_transfer_fee(&mint_info.try_borrow_data()?, epoch, amount)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::token::ToTokenAccount;
+    use solana_program::{program_option::COption, program_pack::Pack, pubkey::Pubkey};
+    use spl_token_2022::{
+        extension::{
+            immutable_owner::ImmutableOwner, non_transferable::NonTransferableAccount,
+            StateWithExtensionsMut,
+        },
+        state::AccountState,
+    };
+
+    fn mint() -> Mint {
+        Mint {
+            mint_authority: COption::Some(Pubkey::new_unique()),
+            supply: 1_000_000,
+            decimals: 6,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        }
+    }
+
+    fn classic_mint_data() -> Vec<u8> {
+        let mut data = vec![0; Mint::LEN];
+        Mint::pack(mint(), &mut data).unwrap();
+        data
+    }
+
+    fn mint_data_with_fee(basis_points: u16, maximum_fee: u64) -> Vec<u8> {
+        let len =
+            ExtensionType::try_calculate_account_len::<Mint>(&[ExtensionType::TransferFeeConfig])
+                .unwrap();
+        let mut data = vec![0; len];
+        let mut state = StateWithExtensionsMut::<Mint>::unpack_uninitialized(&mut data).unwrap();
+        let config = state.init_extension::<TransferFeeConfig>(true).unwrap();
+        config.older_transfer_fee.transfer_fee_basis_points = basis_points.into();
+        config.older_transfer_fee.maximum_fee = maximum_fee.into();
+        config.newer_transfer_fee.epoch = 10u64.into();
+        config.newer_transfer_fee.transfer_fee_basis_points = (basis_points * 2).into();
+        config.newer_transfer_fee.maximum_fee = maximum_fee.into();
+        state.base = mint();
+        state.pack_base();
+        state.init_account_type().unwrap();
+        data
+    }
+
+    fn account_data_with(extension_type: ExtensionType) -> Vec<u8> {
+        let len = ExtensionType::try_calculate_account_len::<Account>(&[extension_type]).unwrap();
+        let mut data = vec![0; len];
+        let mut state = StateWithExtensionsMut::<Account>::unpack_uninitialized(&mut data).unwrap();
+        match extension_type {
+            ExtensionType::ImmutableOwner => {
+                state.init_extension::<ImmutableOwner>(true).unwrap();
+            }
+            ExtensionType::NonTransferableAccount => {
+                state
+                    .init_extension::<NonTransferableAccount>(true)
+                    .unwrap();
+            }
+            _ => unreachable!(),
+        }
+        state.base = Account {
+            mint: Pubkey::new_unique(),
+            owner: Pubkey::new_unique(),
+            amount: 1,
+            state: AccountState::Initialized,
+            ..Account::default()
+        };
+        state.pack_base();
+        state.init_account_type().unwrap();
+        data
+    }
+
+    #[test]
+    fn classic_mint_has_no_transfer_fee() {
+        assert_eq!(
+            calculate_transfer_fee(&classic_mint_data(), 0, 1_000).unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    fn transfer_fee_follows_epoch() {
+        let data = mint_data_with_fee(100, 5_000);
+        assert_eq!(calculate_transfer_fee(&data, 0, 10_000).unwrap(), 100);
+        assert_eq!(calculate_transfer_fee(&data, 10, 10_000).unwrap(), 200);
+        // the fee is capped by the maximum
+        assert_eq!(calculate_transfer_fee(&data, 0, 10_000_000).unwrap(), 5_000);
+    }
+
+    #[test]
+    fn supported_extensions_are_accepted() {
+        let error = ProgramError::InvalidAccountData;
+        assert_supported_extensions::<Mint>(
+            &classic_mint_data(),
+            &SUPPORTED_MINT_EXTENSIONS,
+            error.clone(),
+        )
+        .unwrap();
+        assert_supported_extensions::<Mint>(
+            &mint_data_with_fee(100, 5_000),
+            &SUPPORTED_MINT_EXTENSIONS,
+            error.clone(),
+        )
+        .unwrap();
+        assert_supported_extensions::<Account>(
+            &account_data_with(ExtensionType::ImmutableOwner),
+            &SUPPORTED_ACCOUNT_EXTENSIONS,
+            error,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn reads_token_account_with_extensions() {
+        let key = Pubkey::new_unique();
+        let owner = spl_token_2022::id();
+        let mut lamports = 0;
+        let mut data = account_data_with(ExtensionType::ImmutableOwner);
+        let expected = StateWithExtensions::<Account>::unpack(&data).unwrap().base;
+        let account_info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+
+        assert_eq!(account_info.to_token_account(), expected);
+    }
+
+    #[test]
+    fn unsupported_extensions_are_rejected() {
+        let err = assert_supported_extensions::<Account>(
+            &account_data_with(ExtensionType::NonTransferableAccount),
+            &SUPPORTED_ACCOUNT_EXTENSIONS,
+            ProgramError::Custom(42),
+        )
+        .unwrap_err();
+        assert_eq!(err, ProgramError::Custom(42));
+    }
+}
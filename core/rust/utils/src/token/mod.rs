@@ -1,7 +1,9 @@
 pub use assertions::*;
 pub use cpi::*;
+pub use extensions::*;
 pub use utils::*;
 
 mod assertions;
 mod cpi;
+mod extensions;
 mod utils;
@@ -0,0 +1,239 @@
+use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{assert_owned_by, resize_or_reallocate_account_raw};
+
+/// An account whose layout has changed over time and that knows how to read
+/// every layout it has ever been written with.
+///
+/// Implementors describe how to tell layouts apart (a version byte, the
+/// account length, ...) and how to turn any of them into the latest struct,
+/// which lets programs add fields without padding their size constants ahead
+/// of time.
+pub trait VersionedAccount: Sized {
+    /// Version of the layout produced by [`VersionedAccount::write`].
+    const LATEST_VERSION: u8;
+
+    /// Returns the layout version of the raw account data.
+    fn detect_version(data: &[u8]) -> Result<u8, ProgramError>;
+
+    /// Reads account data written with layout `version` into the latest struct.
+    fn migrate(version: u8, data: &[u8]) -> Result<Self, ProgramError>;
+
+    /// Number of bytes needed to store the account with the latest layout.
+    fn space(&self) -> usize;
+
+    /// Writes the account with the latest layout to the start of `data`.
+    fn write(&self, data: &mut [u8]) -> Result<(), ProgramError>;
+}
+
+/// Loads `account_info` as the latest version of `T`, migrating it in place if
+/// it was written with an older layout.
+///
+/// When the migrated account does not fit in the current allocation, the
+/// account is reallocated and its rent topped up from `payer_info`, which must
+/// then be a signer. Accounts are never shrunk.
+pub fn migrate_account<'a, T: VersionedAccount>(
+    program_id: &Pubkey,
+    account_info: &AccountInfo<'a>,
+    payer_info: &AccountInfo<'a>,
+    system_program_info: &AccountInfo<'a>,
+) -> Result<T, ProgramError> {
+    assert_owned_by(account_info, program_id, ProgramError::IllegalOwner)?;
+
+    let (version, account) = {
+        let data = account_info.try_borrow_data()?;
+        let version = T::detect_version(&data)?;
+        if version > T::LATEST_VERSION {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        (version, T::migrate(version, &data)?)
+    };
+
+    if version == T::LATEST_VERSION {
+        return Ok(account);
+    }
+
+    let space = account.space();
+    if space > account_info.data_len() {
+        resize_or_reallocate_account_raw(account_info, payer_info, system_program_info, space)?;
+    }
+
+    account.write(&mut account_info.try_borrow_mut_data()?)?;
+
+    Ok(account)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{serialize_input, setup};
+    use solana_program::{
+        entrypoint::deserialize, native_token::LAMPORTS_PER_SOL, rent::Rent, system_program,
+    };
+
+    // v0: [0, amount: u32]
+    // v1: [1, amount: u64, bump: u8]
+    #[derive(Debug, PartialEq)]
+    struct Counter {
+        amount: u64,
+        bump: u8,
+    }
+
+    impl VersionedAccount for Counter {
+        const LATEST_VERSION: u8 = 1;
+
+        fn detect_version(data: &[u8]) -> Result<u8, ProgramError> {
+            data.first()
+                .copied()
+                .ok_or(ProgramError::InvalidAccountData)
+        }
+
+        fn migrate(version: u8, data: &[u8]) -> Result<Self, ProgramError> {
+            match version {
+                0 => Ok(Counter {
+                    amount: u32::from_le_bytes(*arrayref::array_ref![data, 1, 4]) as u64,
+                    bump: 255,
+                }),
+                1 => Ok(Counter {
+                    amount: u64::from_le_bytes(*arrayref::array_ref![data, 1, 8]),
+                    bump: data[9],
+                }),
+                _ => Err(ProgramError::InvalidAccountData),
+            }
+        }
+
+        fn space(&self) -> usize {
+            10
+        }
+
+        fn write(&self, data: &mut [u8]) -> Result<(), ProgramError> {
+            data[0] = Self::LATEST_VERSION;
+            data[1..9].copy_from_slice(&self.amount.to_le_bytes());
+            data[9] = self.bump;
+            Ok(())
+        }
+    }
+
+    fn migrate(
+        program_id: &Pubkey,
+        owner: &Pubkey,
+        data: &mut [u8],
+    ) -> Result<Counter, ProgramError> {
+        let key = Pubkey::new_unique();
+        let payer_key = Pubkey::new_unique();
+        let system_program_id = system_program::id();
+        let (mut lamports, mut payer_lamports, mut system_lamports) = (0, 0, 0);
+        let (mut payer_data, mut system_data) = (vec![], vec![]);
+
+        let account_info =
+            AccountInfo::new(&key, false, true, &mut lamports, data, owner, false, 0);
+        let payer_info = AccountInfo::new(
+            &payer_key,
+            true,
+            true,
+            &mut payer_lamports,
+            &mut payer_data,
+            &system_program_id,
+            false,
+            0,
+        );
+        let system_program_info = AccountInfo::new(
+            &system_program_id,
+            false,
+            false,
+            &mut system_lamports,
+            &mut system_data,
+            &system_program_id,
+            true,
+            0,
+        );
+
+        migrate_account(program_id, &account_info, &payer_info, &system_program_info)
+    }
+
+    #[test]
+    fn grows_account_and_tops_up_rent() {
+        setup();
+        let program_id = Pubkey::new_unique();
+        let mut input = serialize_input(&[
+            (
+                Pubkey::new_unique(),
+                false,
+                program_id,
+                0,
+                vec![0, 42, 0, 0, 0],
+            ),
+            (
+                Pubkey::new_unique(),
+                true,
+                system_program::id(),
+                LAMPORTS_PER_SOL,
+                vec![],
+            ),
+            (system_program::id(), false, system_program::id(), 0, vec![]),
+        ]);
+        let (_, accounts, _) = unsafe { deserialize(input.as_mut_ptr() as *mut u8) };
+
+        let counter: Counter =
+            migrate_account(&program_id, &accounts[0], &accounts[1], &accounts[2]).unwrap();
+        assert_eq!(
+            counter,
+            Counter {
+                amount: 42,
+                bump: 255
+            }
+        );
+
+        let rent = Rent::default().minimum_balance(10);
+        assert_eq!(accounts[0].lamports(), rent);
+        assert_eq!(accounts[1].lamports(), LAMPORTS_PER_SOL - rent);
+        assert_eq!(
+            *accounts[0].try_borrow_data().unwrap(),
+            [1, 42, 0, 0, 0, 0, 0, 0, 0, 255]
+        );
+    }
+
+    #[test]
+    fn migrates_old_layout_in_place() {
+        let program_id = Pubkey::new_unique();
+        let mut data = [0, 42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+
+        let counter = migrate(&program_id, &program_id, &mut data).unwrap();
+        assert_eq!(
+            counter,
+            Counter {
+                amount: 42,
+                bump: 255
+            }
+        );
+        assert_eq!(data, [1, 42, 0, 0, 0, 0, 0, 0, 0, 255, 0, 0]);
+    }
+
+    #[test]
+    fn leaves_latest_layout_untouched() {
+        let program_id = Pubkey::new_unique();
+        let mut data = [1, 7, 0, 0, 0, 0, 0, 0, 0, 3, 9, 9];
+
+        let counter = migrate(&program_id, &program_id, &mut data).unwrap();
+        assert_eq!(counter, Counter { amount: 7, bump: 3 });
+        assert_eq!(data, [1, 7, 0, 0, 0, 0, 0, 0, 0, 3, 9, 9]);
+    }
+
+    #[test]
+    fn rejects_unknown_version() {
+        let program_id = Pubkey::new_unique();
+        let mut data = [2, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+
+        let err = migrate(&program_id, &program_id, &mut data).unwrap_err();
+        assert_eq!(err, ProgramError::InvalidAccountData);
+    }
+
+    #[test]
+    fn rejects_foreign_account() {
+        let program_id = Pubkey::new_unique();
+        let mut data = [0, 42, 0, 0, 0, 0, 0, 0, 0, 0];
+
+        let err = migrate(&program_id, &Pubkey::new_unique(), &mut data).unwrap_err();
+        assert_eq!(err, ProgramError::IllegalOwner);
+    }
+}